@@ -91,17 +91,17 @@ fn main() {
     println!(
         "  - Event 1: {} @ {}",
         event1.text,
-        event1.location.name.as_deref().unwrap_or("Unknown")
+        event1.location.name().unwrap_or("Unknown")
     );
     println!(
         "  - Event 2: {} @ {}",
         event2.text,
-        event2.location.name.as_deref().unwrap_or("Unknown")
+        event2.location.name().unwrap_or("Unknown")
     );
     println!(
         "  - Event 3: {} @ {}",
         event3.text,
-        event3.location.name.as_deref().unwrap_or("Unknown")
+        event3.location.name().unwrap_or("Unknown")
     );
     println!();
 
@@ -156,7 +156,7 @@ fn main() {
         println!("  - Lat: {:.4}° to {:.4}°", bounds.min_lat, bounds.max_lat);
         println!("  - Lon: {:.4}° to {:.4}°", bounds.min_lon, bounds.max_lon);
         let center = bounds.center();
-        println!("  - Center: ({:.4}°, {:.4}°)", center.lat, center.lon);
+        println!("  - Center: ({:.4}°, {:.4}°)", center.lat(), center.lon());
     }
     println!();
 
@@ -109,8 +109,8 @@ fn main() {
         println!(
             "  Cluster {}: ({:.4}, {:.4}) - {} members",
             i,
-            centroid.lat,
-            centroid.lon,
+            centroid.lat(),
+            centroid.lon(),
             cluster.event_indices.len()
         );
     }
@@ -187,10 +187,10 @@ fn print_clustering_result(result: &ClusteringResult, name: &str) {
 fn haversine(loc1: &Location, loc2: &Location) -> f64 {
     let r = 6_371_000.0; // Earth's radius in meters
 
-    let lat1 = loc1.lat.to_radians();
-    let lat2 = loc2.lat.to_radians();
-    let dlat = (loc2.lat - loc1.lat).to_radians();
-    let dlon = (loc2.lon - loc1.lon).to_radians();
+    let lat1 = loc1.lat().to_radians();
+    let lat2 = loc2.lat().to_radians();
+    let dlat = (loc2.lat() - loc1.lat()).to_radians();
+    let dlon = (loc2.lon() - loc1.lon()).to_radians();
 
     let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
     let c = 2.0 * a.sqrt().asin();
@@ -45,7 +45,7 @@ fn main() {
         
         // Only test first place to avoid hitting rate limits
         if let Some(loc) = nominatim.lookup(test_places[0]) {
-            println!("✓ {}: ({:.4}, {:.4})", test_places[0], loc.lat, loc.lon);
+            println!("✓ {}: ({:.4}, {:.4})", test_places[0], loc.lat(), loc.lon());
         }
 
         // Test 3: Wikidata
@@ -54,7 +54,7 @@ fn main() {
         let wikidata = GazetteerWikidata::new();
         
         if let Some(loc) = wikidata.lookup(test_places[1]) {
-            println!("✓ {}: ({:.4}, {:.4})", test_places[1], loc.lat, loc.lon);
+            println!("✓ {}: ({:.4}, {:.4})", test_places[1], loc.lat(), loc.lon());
         }
 
         // Test 4: GeoNames (if username provided)
@@ -63,7 +63,7 @@ fn main() {
             let geonames = GazetteerGeoNames::new(username);
             
             if let Some(loc) = geonames.lookup(test_places[2]) {
-                println!("✓ {}: ({:.4}, {:.4})", test_places[2], loc.lat, loc.lon);
+                println!("✓ {}: ({:.4}, {:.4})", test_places[2], loc.lat(), loc.lon());
             }
         } else {
             println!("\n--- GeoNames ---");
@@ -80,13 +80,13 @@ fn main() {
         
         // Test with a place in built-in (should use built-in)
         if let Some(loc) = multi.lookup("Paris") {
-            println!("✓ Paris: ({:.4}, {:.4}) [from built-in]", loc.lat, loc.lon);
+            println!("✓ Paris: ({:.4}, {:.4}) [from built-in]", loc.lat(), loc.lon());
         }
 
         // Test with a place not in built-in (should fall back to Nominatim)
         println!("\nNote: Next lookup will query Nominatim API...");
         if let Some(loc) = multi.lookup("Seattle") {
-            println!("✓ Seattle: ({:.4}, {:.4}) [from Nominatim fallback]", loc.lat, loc.lon);
+            println!("✓ Seattle: ({:.4}, {:.4}) [from Nominatim fallback]", loc.lat(), loc.lon());
         }
     }
 
@@ -103,7 +103,7 @@ fn main() {
 fn test_gazetteer(gaz: &dyn Gazetteer, places: &[&str]) {
     for place in places {
         if let Some(loc) = gaz.lookup(place) {
-            println!("✓ {}: ({:.4}, {:.4})", place, loc.lat, loc.lon);
+            println!("✓ {}: ({:.4}, {:.4})", place, loc.lat(), loc.lon());
         } else {
             println!("✗ {}: Not found", place);
         }
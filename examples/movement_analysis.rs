@@ -157,8 +157,8 @@ fn main() {
         println!(
             "\nStop {}: ({:.4}, {:.4})",
             i + 1,
-            stop.location.lat,
-            stop.location.lon
+            stop.location.lat(),
+            stop.location.lon()
         );
         println!("  Duration: {:.0} minutes", stop.duration_secs / 60.0);
         println!("  Events during stop: {}", stop.event_count);
@@ -56,7 +56,7 @@ fn main() {
     let dc = Location::new(38.9072, -77.0369);
 
     // Note: haversine_distance takes lat/lon as separate f64 arguments
-    let distance = haversine_distance(nyc.lat, nyc.lon, dc.lat, dc.lon);
+    let distance = haversine_distance(nyc.lat(), nyc.lon(), dc.lat(), dc.lon());
     println!("NYC to Washington DC:");
     println!(
         "  Distance: {:.2} km ({:.2} miles)",
@@ -77,7 +77,7 @@ fn main() {
     for (i, name) in leg_names.iter().enumerate() {
         let loc1 = locations[i];
         let loc2 = locations[i + 1];
-        let dist = haversine_distance(loc1.lat, loc1.lon, loc2.lat, loc2.lon);
+        let dist = haversine_distance(loc1.lat(), loc1.lon(), loc2.lat(), loc2.lon());
         println!("  {}: {:.1} km", name, dist / 1000.0);
     }
 
@@ -86,7 +86,7 @@ fn main() {
     println!("{}", separator);
 
     // Note: bearing takes lat/lon as separate f64 arguments
-    let b = bearing(nyc.lat, nyc.lon, dc.lat, dc.lon);
+    let b = bearing(nyc.lat(), nyc.lon(), dc.lat(), dc.lon());
     println!("Bearing from NYC to DC: {:.1}°", b);
 
     // Determine cardinal direction
@@ -111,7 +111,7 @@ fn main() {
     let dist = 100_000.0; // 100 km
 
     // Note: destination_point returns (lat, lon) tuple
-    let (dest_lat, dest_lon) = destination_point(start.lat, start.lon, heading, dist);
+    let (dest_lat, dest_lon) = destination_point(start.lat(), start.lon(), heading, dist);
     println!("Starting from NYC, heading South for 100 km:");
     println!("  Destination: ({:.4}, {:.4})", dest_lat, dest_lon);
 
@@ -127,7 +127,7 @@ fn main() {
     );
 
     if let Some(centroid) = &metrics.centroid {
-        println!("Centroid: ({:.4}, {:.4})", centroid.lat, centroid.lon);
+        println!("Centroid: ({:.4}, {:.4})", centroid.lat(), centroid.lon());
     }
 
     if let Some(bounds) = &metrics.bounds {
@@ -5,6 +5,7 @@
 //!
 //! Run with: `cargo run --example temporal_analysis`
 
+use chrono_tz::Tz;
 use spatial_narrative::analysis::{detect_bursts, detect_gaps, event_rate, TemporalMetrics, TimeBin};
 use spatial_narrative::core::{Event, Location, Narrative, Timestamp};
 
@@ -108,16 +109,12 @@ fn main() {
     println!("\n⏱️ Event Rate Analysis");
     println!("{}", separator);
 
-    // Get events per hour
-    let hourly_bins = event_rate(&events, TimeBin::Hour);
+    // Get events per hour, in UTC wall-clock time
+    let hourly_bins = event_rate(&events, TimeBin::Hour, Tz::UTC);
 
     println!("Hourly event distribution:");
     for bin in &hourly_bins {
-        // Extract hour from the start timestamp
-        let ts_str = bin.start.to_string();
-        let time_part = ts_str.split('T').nth(1).unwrap_or(&ts_str);
-        let hour = &time_part[..2];
-        println!("  {}:00 - {} events", hour, bin.count);
+        println!("  {} - {} events", bin.start.datetime.format("%H:00"), bin.count);
     }
 
     // Calculate overall rate
@@ -190,34 +187,11 @@ fn main() {
     println!("\n📈 Activity Pattern Analysis");
     println!("{}", separator);
 
-    // Analyze activity by extracting hour from timestamp strings
-    let morning_count = events
-        .iter()
-        .filter(|e| {
-            let ts_str = e.timestamp.to_string();
-            let time_part = ts_str.split('T').nth(1).unwrap_or("00");
-            let hour: u32 = time_part[..2].parse().unwrap_or(0);
-            hour >= 6 && hour < 12
-        })
-        .count();
-    let afternoon_count = events
-        .iter()
-        .filter(|e| {
-            let ts_str = e.timestamp.to_string();
-            let time_part = ts_str.split('T').nth(1).unwrap_or("00");
-            let hour: u32 = time_part[..2].parse().unwrap_or(0);
-            hour >= 12 && hour < 18
-        })
-        .count();
-    let evening_count = events
-        .iter()
-        .filter(|e| {
-            let ts_str = e.timestamp.to_string();
-            let time_part = ts_str.split('T').nth(1).unwrap_or("00");
-            let hour: u32 = time_part[..2].parse().unwrap_or(0);
-            hour >= 18 || hour < 6
-        })
-        .count();
+    // Bucket activity by local hour-of-day (DST-correct, unlike string slicing).
+    let by_hour = TemporalMetrics::activity_by_hour(&events, Tz::UTC);
+    let morning_count: u32 = by_hour[6..12].iter().sum();
+    let afternoon_count: u32 = by_hour[12..18].iter().sum();
+    let evening_count: u32 = by_hour[18..24].iter().sum::<u32>() + by_hour[0..6].iter().sum::<u32>();
 
     println!("Morning (6am-12pm): {} events", morning_count);
     println!("Afternoon (12pm-6pm): {} events", afternoon_count);
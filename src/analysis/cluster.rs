@@ -0,0 +1,334 @@
+//! Near-duplicate event clustering with spatial/temporal gating.
+//!
+//! News ingestion often produces many near-identical events describing the
+//! same real-world happening — the same story picked up by several feeds.
+//! [`cluster_events`] groups events that are simultaneously textually
+//! similar, spatially close, and temporally close, using union-find over a
+//! coarse lat/lon/time grid so only events in neighboring cells are ever
+//! compared, rather than scanning every pair in the narrative.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::core::{Event, EventId};
+use crate::text::{Tokenizer, WhitespaceTokenizer};
+
+/// Thresholds controlling [`cluster_events`].
+#[derive(Debug, Clone)]
+pub struct ClusterConfig {
+    /// Minimum Jaccard similarity of word-shingle sets for two events to be
+    /// considered the same story.
+    pub sim_threshold: f64,
+    /// Maximum great-circle distance between two events, in kilometers.
+    pub max_km: f64,
+    /// Maximum time gap between two events.
+    pub max_window: chrono::Duration,
+    /// Number of consecutive words per shingle.
+    pub shingle_size: usize,
+}
+
+/// A group of near-duplicate events.
+#[derive(Debug, Clone)]
+pub struct Cluster {
+    /// IDs of every event in the cluster, including the representative.
+    pub members: Vec<EventId>,
+    /// The id of the earliest-timestamped member, used as the cluster's
+    /// canonical representative.
+    pub representative: EventId,
+}
+
+/// Groups `events` into near-duplicate clusters under `cfg`.
+///
+/// Two events join the same cluster when their word-shingle sets have a
+/// Jaccard similarity of at least [`cfg.sim_threshold`](ClusterConfig::sim_threshold),
+/// AND their great-circle distance is at most `cfg.max_km`, AND their
+/// timestamps are within `cfg.max_window` of each other. Candidate pairs are
+/// limited to events sharing or neighboring a coarse spatial/time grid cell
+/// sized off `cfg.max_km`/`cfg.max_window`, so clustering stays far from the
+/// worst-case O(n²) pairwise comparison on large narratives.
+pub fn cluster_events(events: &[Event], cfg: &ClusterConfig) -> Vec<Cluster> {
+    if events.is_empty() {
+        return Vec::new();
+    }
+
+    let shingles: Vec<HashSet<String>> = events
+        .iter()
+        .map(|e| shingle_set(&e.text, cfg.shingle_size))
+        .collect();
+
+    let mut uf = UnionFind::new(events.len());
+
+    for (a, b) in candidate_pairs(events, cfg) {
+        if uf.find(a) == uf.find(b) {
+            continue;
+        }
+        if jaccard(&shingles[a], &shingles[b]) < cfg.sim_threshold {
+            continue;
+        }
+        let distance_km = events[a].location.haversine_distance(&events[b].location) / 1000.0;
+        if distance_km > cfg.max_km {
+            continue;
+        }
+        let gap_ms = events[a]
+            .timestamp
+            .duration_since(&events[b].timestamp)
+            .num_milliseconds()
+            .abs();
+        if gap_ms > cfg.max_window.num_milliseconds() {
+            continue;
+        }
+        uf.union(a, b);
+    }
+
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for i in 0..events.len() {
+        let root = uf.find(i);
+        groups.entry(root).or_default().push(i);
+    }
+
+    groups
+        .into_values()
+        .map(|indices| {
+            let representative = indices
+                .iter()
+                .copied()
+                .min_by_key(|&i| events[i].timestamp.unix_timestamp_millis())
+                .expect("cluster always has at least one member");
+            Cluster {
+                members: indices.iter().map(|&i| events[i].id.clone()).collect(),
+                representative: events[representative].id.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Builds the lowercased word-shingle set for `text`, reusing the same
+/// word-boundary rules as [`WhitespaceTokenizer`] (shared with
+/// [`KeywordExtractor`](crate::text::KeywordExtractor)'s tokenization).
+fn shingle_set(text: &str, shingle_size: usize) -> HashSet<String> {
+    let words: Vec<String> = WhitespaceTokenizer
+        .tokenize(text)
+        .into_iter()
+        .map(|w| w.to_lowercase())
+        .collect();
+
+    if words.is_empty() {
+        return HashSet::new();
+    }
+
+    let shingle_size = shingle_size.clamp(1, words.len());
+    words
+        .windows(shingle_size)
+        .map(|window| window.join(" "))
+        .collect()
+}
+
+/// Jaccard similarity of two shingle sets: `|a ∩ b| / |a ∪ b|`, `1.0` when
+/// both are empty.
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// Enumerates candidate event-index pairs sharing or neighboring a coarse
+/// lat/lon/time grid cell, so [`cluster_events`] never has to compare every
+/// pair in the narrative.
+fn candidate_pairs(events: &[Event], cfg: &ClusterConfig) -> Vec<(usize, usize)> {
+    // ~111 km per degree of latitude; coarse on purpose, since the exact
+    // haversine/Jaccard checks run on every surfaced candidate anyway.
+    let cell_deg = (cfg.max_km / 111.0).max(1e-6);
+    let window_ms = cfg.max_window.num_milliseconds().max(1);
+    // A degree of longitude shrinks with cos(latitude), so longitude cells
+    // are widened for the most poleward event to keep pairs within `max_km`
+    // east-west in neighboring cells.
+    let max_abs_lat = events.iter().map(|e| e.location.lat.abs()).fold(0.0_f64, f64::max);
+    let lon_cell_deg = (cell_deg / max_abs_lat.to_radians().cos().max(1e-6)).min(360.0);
+
+    let mut buckets: HashMap<(i64, i64, i64), Vec<usize>> = HashMap::new();
+    for (i, event) in events.iter().enumerate() {
+        let lat_cell = (event.location.lat / cell_deg).floor() as i64;
+        let lon_cell = (event.location.lon / lon_cell_deg).floor() as i64;
+        let time_cell = event.timestamp.unix_timestamp_millis() / window_ms;
+        buckets
+            .entry((lat_cell, lon_cell, time_cell))
+            .or_default()
+            .push(i);
+    }
+
+    let mut seen: HashSet<(usize, usize)> = HashSet::new();
+    let mut pairs = Vec::new();
+    for (&(lat_cell, lon_cell, time_cell), indices) in &buckets {
+        for dlat in -1..=1 {
+            for dlon in -1..=1 {
+                for dtime in -1..=1 {
+                    let neighbor = (lat_cell + dlat, lon_cell + dlon, time_cell + dtime);
+                    let Some(neighbor_indices) = buckets.get(&neighbor) else {
+                        continue;
+                    };
+                    for &a in indices {
+                        for &b in neighbor_indices {
+                            let pair = if a < b { (a, b) } else { (b, a) };
+                            if pair.0 != pair.1 && seen.insert(pair) {
+                                pairs.push(pair);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    pairs
+}
+
+/// Disjoint-set forest with path compression, used to merge events into
+/// clusters as qualifying pairs are discovered.
+struct UnionFind {
+    parent: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+        }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let ra = self.find(a);
+        let rb = self.find(b);
+        if ra != rb {
+            self.parent[ra] = rb;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Location, Timestamp};
+
+    fn event(text: &str, lat: f64, lon: f64, ts: &str) -> Event {
+        Event::builder()
+            .location(Location::new(lat, lon))
+            .timestamp(Timestamp::parse(ts).unwrap())
+            .text(text)
+            .build()
+    }
+
+    fn config() -> ClusterConfig {
+        ClusterConfig {
+            sim_threshold: 0.5,
+            max_km: 5.0,
+            max_window: chrono::Duration::hours(2),
+            shingle_size: 2,
+        }
+    }
+
+    #[test]
+    fn test_similar_nearby_events_cluster_together() {
+        let events = vec![
+            event(
+                "Wildfire forces evacuations near the ridge",
+                34.05,
+                -118.25,
+                "2024-06-01T10:00:00Z",
+            ),
+            event(
+                "Wildfire forces evacuations near the ridge tonight",
+                34.06,
+                -118.24,
+                "2024-06-01T10:30:00Z",
+            ),
+        ];
+
+        let clusters = cluster_events(&events, &config());
+
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].members.len(), 2);
+        assert_eq!(clusters[0].representative, events[0].id);
+    }
+
+    #[test]
+    fn test_dissimilar_text_stays_separate() {
+        let events = vec![
+            event(
+                "Wildfire forces evacuations near the ridge",
+                34.05,
+                -118.25,
+                "2024-06-01T10:00:00Z",
+            ),
+            event(
+                "City council approves new budget",
+                34.05,
+                -118.25,
+                "2024-06-01T10:00:00Z",
+            ),
+        ];
+
+        let clusters = cluster_events(&events, &config());
+
+        assert_eq!(clusters.len(), 2);
+    }
+
+    #[test]
+    fn test_far_apart_events_stay_separate() {
+        let events = vec![
+            event(
+                "Wildfire forces evacuations near the ridge",
+                34.05,
+                -118.25,
+                "2024-06-01T10:00:00Z",
+            ),
+            event(
+                "Wildfire forces evacuations near the ridge",
+                48.85,
+                2.35,
+                "2024-06-01T10:00:00Z",
+            ),
+        ];
+
+        let clusters = cluster_events(&events, &config());
+
+        assert_eq!(clusters.len(), 2);
+    }
+
+    #[test]
+    fn test_outside_time_window_stays_separate() {
+        let events = vec![
+            event(
+                "Wildfire forces evacuations near the ridge",
+                34.05,
+                -118.25,
+                "2024-06-01T10:00:00Z",
+            ),
+            event(
+                "Wildfire forces evacuations near the ridge",
+                34.05,
+                -118.25,
+                "2024-06-03T10:00:00Z",
+            ),
+        ];
+
+        let clusters = cluster_events(&events, &config());
+
+        assert_eq!(clusters.len(), 2);
+    }
+
+    #[test]
+    fn test_empty_input_returns_no_clusters() {
+        let clusters = cluster_events(&[], &config());
+        assert!(clusters.is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+//! Spatial-temporal queries and Wikipedia-geonotice-style region/window
+//! annotations.
+//!
+//! [`GeoTemporalFilter`] narrows a narrative down to events that happened
+//! inside a geographic box *and* a time window, answering questions like
+//! "which of Alice's stops happened in Midtown between 09:00 and 15:00".
+//! [`Notice`] is the inverse: a region and window bound to a message, the way
+//! [Wikipedia's geonotice banners](https://meta.wikimedia.org/wiki/Geonotice)
+//! are targeted, and [`active_notices`] picks out the ones live at a given
+//! instant.
+
+use crate::core::{Event, GeoBounds, Narrative, Timestamp};
+
+/// A geographic box plus a time window, used to select events that fall
+/// inside both.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoTemporalFilter {
+    /// Geographic bounds, antimeridian-crossing boxes included.
+    pub bounds: GeoBounds,
+    /// Inclusive start of the time window.
+    pub start: Timestamp,
+    /// Exclusive end of the time window.
+    pub end: Timestamp,
+}
+
+impl GeoTemporalFilter {
+    /// Creates a new spatial-temporal filter.
+    pub fn new(bounds: GeoBounds, start: Timestamp, end: Timestamp) -> Self {
+        Self { bounds, start, end }
+    }
+
+    /// Checks whether `event` falls inside both the bounds and the
+    /// `[start, end)` window.
+    fn matches(&self, event: &Event) -> bool {
+        self.bounds.contains(&event.location)
+            && event.timestamp >= self.start
+            && event.timestamp < self.end
+    }
+}
+
+/// Returns the events of `narrative` that fall inside `filter`'s region and
+/// `[start, end)` time window.
+pub fn events_in_region<'a>(narrative: &'a Narrative, filter: &GeoTemporalFilter) -> Vec<&'a Event> {
+    narrative.events().iter().filter(|e| filter.matches(e)).collect()
+}
+
+/// A region and time window bound to a message, modeled on Wikipedia's
+/// geonotice banners: a notice is live only while the reader (or, here, a
+/// query instant) falls within its `[start, end)` window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notice {
+    /// Region the notice applies to.
+    pub bounds: GeoBounds,
+    /// Inclusive start of the notice's active window.
+    pub start: Timestamp,
+    /// Exclusive end of the notice's active window.
+    pub end: Timestamp,
+    /// The message to display while active.
+    pub message: String,
+}
+
+impl Notice {
+    /// Creates a new notice.
+    pub fn new(bounds: GeoBounds, start: Timestamp, end: Timestamp, message: impl Into<String>) -> Self {
+        Self {
+            bounds,
+            start,
+            end,
+            message: message.into(),
+        }
+    }
+
+    /// Returns true if `at` falls within this notice's `[start, end)` window.
+    pub fn is_active_at(&self, at: &Timestamp) -> bool {
+        *at >= self.start && *at < self.end
+    }
+}
+
+/// Returns the notices among `notices` whose time window covers `at`.
+///
+/// Region targeting is left to the caller (e.g. `notice.bounds.contains(loc)`)
+/// since, unlike [`events_in_region`], there is no single location to test
+/// against here — just an instant.
+pub fn active_notices<'a>(notices: &'a [Notice], at: &Timestamp) -> Vec<&'a Notice> {
+    notices.iter().filter(|n| n.is_active_at(at)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Location;
+
+    fn event_at(lat: f64, lon: f64, time: &str) -> Event {
+        Event::new(Location::new(lat, lon), Timestamp::parse(time).unwrap(), "e")
+    }
+
+    fn narrative_with(events: Vec<Event>) -> Narrative {
+        Narrative::builder().title("t").events(events).build()
+    }
+
+    #[test]
+    fn test_events_in_region_filters_by_bounds_and_window() {
+        let narrative = narrative_with(vec![
+            event_at(40.75, -73.98, "2024-01-20T10:00:00Z"), // Midtown, in window
+            event_at(40.75, -73.98, "2024-01-20T16:00:00Z"), // Midtown, outside window
+            event_at(34.05, -118.24, "2024-01-20T10:00:00Z"), // LA, outside bounds
+        ]);
+
+        let filter = GeoTemporalFilter::new(
+            GeoBounds::new(40.70, -74.02, 40.80, -73.93),
+            Timestamp::parse("2024-01-20T09:00:00Z").unwrap(),
+            Timestamp::parse("2024-01-20T15:00:00Z").unwrap(),
+        );
+
+        let hits = events_in_region(&narrative, &filter);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].location.lon, -73.98);
+    }
+
+    #[test]
+    fn test_events_in_region_handles_antimeridian_crossing_bounds() {
+        let narrative = narrative_with(vec![
+            event_at(-17.7, 179.5, "2024-01-20T10:00:00Z"), // Fiji, just east
+            event_at(-17.7, -179.5, "2024-01-20T10:00:00Z"), // Fiji, just west
+            event_at(-17.7, 0.0, "2024-01-20T10:00:00Z"), // outside the box
+        ]);
+
+        let filter = GeoTemporalFilter::new(
+            GeoBounds::new(-20.0, 170.0, -15.0, -170.0),
+            Timestamp::parse("2024-01-20T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-01-21T00:00:00Z").unwrap(),
+        );
+
+        let hits = events_in_region(&narrative, &filter);
+        assert_eq!(hits.len(), 2);
+    }
+
+    #[test]
+    fn test_window_end_is_exclusive() {
+        let narrative = narrative_with(vec![event_at(0.0, 0.0, "2024-01-20T15:00:00Z")]);
+        let filter = GeoTemporalFilter::new(
+            GeoBounds::default(),
+            Timestamp::parse("2024-01-20T09:00:00Z").unwrap(),
+            Timestamp::parse("2024-01-20T15:00:00Z").unwrap(),
+        );
+        assert!(events_in_region(&narrative, &filter).is_empty());
+    }
+
+    #[test]
+    fn test_active_notices_picks_window_covering_instant() {
+        let notices = vec![
+            Notice::new(
+                GeoBounds::default(),
+                Timestamp::parse("2024-01-20T00:00:00Z").unwrap(),
+                Timestamp::parse("2024-01-21T00:00:00Z").unwrap(),
+                "day one",
+            ),
+            Notice::new(
+                GeoBounds::default(),
+                Timestamp::parse("2024-01-21T00:00:00Z").unwrap(),
+                Timestamp::parse("2024-01-22T00:00:00Z").unwrap(),
+                "day two",
+            ),
+        ];
+
+        let at = Timestamp::parse("2024-01-20T12:00:00Z").unwrap();
+        let active = active_notices(&notices, &at);
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].message, "day one");
+    }
+}
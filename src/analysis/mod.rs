@@ -4,11 +4,32 @@
 //! including spatial metrics, temporal metrics, clustering,
 //! and movement analysis.
 //!
+//! # Available
+//!
+//! - [`TemporalMetrics`] - Duration, inter-event gaps, hour/weekday activity
+//! - [`event_rate`], [`detect_gaps`], [`detect_bursts`] - Time-binned rate and anomaly detection
+//! - [`temporal_similarity`] - Instant-based closeness score between two events, immune to local-clock coincidence
+//! - [`Trajectory`], [`MovementAnalyzer`] - Trajectory extraction, stop detection, path simplification
+//! - [`OnlineMovementAnalyzer`] - Incremental stop/segment detection for unbounded event streams
+//! - [`GeoTemporalFilter`], [`events_in_region`] - Combined region+window event queries
+//! - [`Notice`], [`active_notices`] - Geonotice-style region/window message targeting
+//! - [`cluster_events`] - Near-duplicate event clustering with spatial/temporal gating
+//!
 //! # Planned Features
 //!
 //! - `SpatialMetrics` - Geographic extent, distance, dispersion
-//! - `TemporalMetrics` - Duration, event rate, gaps
-//! - `MovementAnalyzer` - Trajectory extraction and analysis
-//! - `SpatialClustering` - DBSCAN, k-means clustering
 
-// TODO: Phase 5 implementation
+mod cluster;
+mod geonotice;
+mod movement;
+mod temporal;
+
+pub use cluster::{cluster_events, Cluster, ClusterConfig};
+pub use geonotice::{active_notices, events_in_region, GeoTemporalFilter, Notice};
+pub use movement::{
+    detect_stops, MovementAnalyzer, MovementUpdate, OnlineMovementAnalyzer, RunningStats, Stop,
+    StopThreshold, Trajectory,
+};
+pub use temporal::{
+    detect_bursts, detect_gaps, event_rate, temporal_similarity, Bin, TemporalMetrics, TimeBin,
+};
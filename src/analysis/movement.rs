@@ -0,0 +1,617 @@
+//! Trajectory extraction, stop detection, and movement segmentation.
+
+use crate::core::{Event, GeoBounds, Location, Timestamp};
+
+/// An ordered sequence of events tracing a single continuous movement path.
+#[derive(Debug, Clone)]
+pub struct Trajectory {
+    /// Identifier for this trajectory (e.g. a trip or vehicle id).
+    pub id: String,
+    /// Events making up the path, in chronological order.
+    pub events: Vec<Event>,
+}
+
+impl Trajectory {
+    /// Creates a trajectory from an id and a set of events, sorting them
+    /// chronologically.
+    pub fn new(id: impl Into<String>, mut events: Vec<Event>) -> Self {
+        events.sort_by_key(|e| e.timestamp.clone());
+        Self {
+            id: id.into(),
+            events,
+        }
+    }
+
+    /// Returns the number of points in the trajectory.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if the trajectory has no points.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Total great-circle distance covered, in meters.
+    pub fn total_distance(&self) -> f64 {
+        self.events
+            .windows(2)
+            .map(|pair| haversine_meters(&pair[0].location, &pair[1].location))
+            .sum()
+    }
+
+    /// Elapsed time between the first and last point, in seconds.
+    pub fn duration_secs(&self) -> f64 {
+        match (self.events.first(), self.events.last()) {
+            (Some(first), Some(last)) => {
+                (last.timestamp.datetime - first.timestamp.datetime).num_seconds() as f64
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Average speed over the whole trajectory, in meters/second.
+    pub fn avg_speed(&self) -> f64 {
+        let duration = self.duration_secs();
+        if duration > 0.0 {
+            self.total_distance() / duration
+        } else {
+            0.0
+        }
+    }
+
+    /// Bounding box enclosing every point, or `None` if the trajectory is empty.
+    pub fn bounds(&self) -> Option<GeoBounds> {
+        GeoBounds::from_locations(self.events.iter().map(|e| &e.location))
+    }
+
+    /// Speed between each pair of consecutive points, in meters/second,
+    /// timestamped at the later point.
+    pub fn velocity_profile(&self) -> Vec<(Timestamp, f64)> {
+        self.events
+            .windows(2)
+            .map(|pair| {
+                let distance = haversine_meters(&pair[0].location, &pair[1].location);
+                let elapsed = (pair[1].timestamp.datetime - pair[0].timestamp.datetime)
+                    .num_seconds() as f64;
+                let speed = if elapsed > 0.0 { distance / elapsed } else { 0.0 };
+                (pair[1].timestamp.clone(), speed)
+            })
+            .collect()
+    }
+
+    /// Simplifies the path with the Douglas-Peucker algorithm, dropping
+    /// points that deviate from the straight line between their neighbors by
+    /// less than `tolerance_meters`. Endpoints are always kept.
+    pub fn simplify(&self, tolerance_meters: f64) -> Trajectory {
+        let kept = douglas_peucker(&self.events, tolerance_meters);
+        Trajectory {
+            id: self.id.clone(),
+            events: kept,
+        }
+    }
+}
+
+/// Parameters for identifying a stop within a trajectory.
+#[derive(Debug, Clone, Copy)]
+pub struct StopThreshold {
+    /// Radius, in meters, within which consecutive points are considered
+    /// part of the same stop.
+    pub radius_m: f64,
+    /// Minimum time, in seconds, a cluster of points must span to count as a
+    /// stop rather than a brief slowdown.
+    pub min_duration_secs: f64,
+}
+
+/// A detected period where a trajectory stayed within a small radius.
+#[derive(Debug, Clone)]
+pub struct Stop {
+    /// Centroid location of the stop.
+    pub location: Location,
+    /// Time the stop began.
+    pub start: Timestamp,
+    /// Time the stop ended.
+    pub end: Timestamp,
+    /// Duration of the stop, in seconds.
+    pub duration_secs: f64,
+    /// Number of events observed during the stop.
+    pub event_count: usize,
+}
+
+/// Detects stops in a trajectory: runs of consecutive points all within
+/// `threshold.radius_m` of their centroid, spanning at least
+/// `threshold.min_duration_secs`.
+pub fn detect_stops(trajectory: &Trajectory, threshold: &StopThreshold) -> Vec<Stop> {
+    let mut stops = Vec::new();
+    let events = &trajectory.events;
+    let mut i = 0;
+
+    while i < events.len() {
+        let mut cluster = vec![&events[i]];
+        let mut j = i + 1;
+
+        while j < events.len() {
+            let centroid = centroid_of(&cluster);
+            let distance = haversine_meters(&centroid, &events[j].location);
+            if distance <= threshold.radius_m {
+                cluster.push(&events[j]);
+                j += 1;
+            } else {
+                break;
+            }
+        }
+
+        let start = &cluster.first().unwrap().timestamp;
+        let end = &cluster.last().unwrap().timestamp;
+        let duration = (end.datetime - start.datetime).num_seconds() as f64;
+
+        if duration >= threshold.min_duration_secs {
+            stops.push(Stop {
+                location: centroid_of(&cluster),
+                start: start.clone(),
+                end: end.clone(),
+                duration_secs: duration,
+                event_count: cluster.len(),
+            });
+        }
+
+        i = j.max(i + 1);
+    }
+
+    stops
+}
+
+/// Extracts trajectories from event streams and detects stops and movement
+/// segments within them.
+#[derive(Debug, Clone)]
+pub struct MovementAnalyzer {
+    stop_threshold: StopThreshold,
+}
+
+impl MovementAnalyzer {
+    /// Creates an analyzer using the given stop-detection threshold.
+    pub fn with_stop_threshold(stop_threshold: StopThreshold) -> Self {
+        Self { stop_threshold }
+    }
+
+    /// Builds a [`Trajectory`] from a set of events.
+    pub fn extract_trajectory(&self, id: impl Into<String>, events: Vec<Event>) -> Trajectory {
+        Trajectory::new(id, events)
+    }
+
+    /// Detects stops in `trajectory` using this analyzer's threshold.
+    pub fn detect_stops(&self, trajectory: &Trajectory) -> Vec<Stop> {
+        detect_stops(trajectory, &self.stop_threshold)
+    }
+
+    /// Splits a trajectory into the moving segments between its stops,
+    /// discarding the stationary clusters themselves.
+    pub fn movement_segments(&self, trajectory: &Trajectory) -> Vec<Trajectory> {
+        let stops = self.detect_stops(trajectory);
+        if stops.is_empty() {
+            return vec![trajectory.clone()];
+        }
+
+        let mut segments = Vec::new();
+        let mut current = Vec::new();
+
+        for event in &trajectory.events {
+            let in_stop = stops
+                .iter()
+                .any(|stop| event.timestamp >= stop.start && event.timestamp <= stop.end);
+
+            if in_stop {
+                if current.len() > 1 {
+                    segments.push(Trajectory::new(trajectory.id.clone(), current.clone()));
+                }
+                current.clear();
+            } else {
+                current.push(event.clone());
+            }
+        }
+
+        if current.len() > 1 {
+            segments.push(Trajectory::new(trajectory.id.clone(), current));
+        }
+
+        segments
+    }
+}
+
+/// An update emitted by [`OnlineMovementAnalyzer::ingest`] as the stream
+/// confirms it.
+#[derive(Debug, Clone)]
+pub enum MovementUpdate {
+    /// The moving segment since the last stop (or the start of the stream)
+    /// has ended.
+    Segment(Trajectory),
+    /// A stop has been confirmed.
+    Stop(Stop),
+}
+
+/// Online mean and variance of a stream of values, computed incrementally
+/// with Welford's algorithm so no history needs to be retained.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunningStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl RunningStats {
+    fn update(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        let delta2 = value - self.mean;
+        self.m2 += delta * delta2;
+    }
+
+    /// Running mean of the observed values.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Running (population) variance of the observed values.
+    pub fn variance(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            self.m2 / self.count as f64
+        }
+    }
+}
+
+/// Incrementally detects stops and moving segments in an unbounded stream of
+/// events, without buffering the whole trajectory in memory.
+///
+/// Holds a candidate cluster of recent events that fall within
+/// [`StopThreshold::radius_m`] of their running centroid; once the cluster
+/// spans at least `min_duration_secs` it is a confirmed stop, and when a new
+/// event breaks away from the centroid the cluster is flushed — a [`Stop`] if
+/// it qualified, otherwise its points are folded back into the moving segment
+/// they were actually part of. The segment since the last stop is flushed
+/// whenever a stop is confirmed.
+#[derive(Debug, Clone)]
+pub struct OnlineMovementAnalyzer {
+    threshold: StopThreshold,
+    cluster: Vec<Event>,
+    segment: Vec<Event>,
+    last_event: Option<Event>,
+    velocity_stats: RunningStats,
+}
+
+impl OnlineMovementAnalyzer {
+    /// Creates an online analyzer using the given stop-detection threshold.
+    pub fn new(threshold: StopThreshold) -> Self {
+        Self {
+            threshold,
+            cluster: Vec::new(),
+            segment: Vec::new(),
+            last_event: None,
+            velocity_stats: RunningStats::default(),
+        }
+    }
+
+    /// Feeds the next event in the stream, returning any [`Stop`]s or moving
+    /// [`Trajectory`] segments the new event confirmed.
+    pub fn ingest(&mut self, event: Event) -> Vec<MovementUpdate> {
+        if let Some(last) = &self.last_event {
+            let distance = haversine_meters(&last.location, &event.location);
+            let elapsed = (event.timestamp.datetime - last.timestamp.datetime).num_seconds() as f64;
+            if elapsed > 0.0 {
+                self.velocity_stats.update(distance / elapsed);
+            }
+        }
+        self.last_event = Some(event.clone());
+
+        if self.cluster.is_empty() {
+            self.cluster.push(event);
+            return Vec::new();
+        }
+
+        let centroid = centroid_owned(&self.cluster);
+        if haversine_meters(&centroid, &event.location) <= self.threshold.radius_m {
+            self.cluster.push(event);
+            return Vec::new();
+        }
+
+        let mut updates = Vec::new();
+        let duration = cluster_duration_secs(&self.cluster);
+
+        if duration >= self.threshold.min_duration_secs {
+            if self.segment.len() > 1 {
+                updates.push(MovementUpdate::Segment(Trajectory::new(
+                    "segment",
+                    std::mem::take(&mut self.segment),
+                )));
+            } else {
+                self.segment.clear();
+            }
+            updates.push(MovementUpdate::Stop(stop_from_cluster(&self.cluster)));
+        } else {
+            self.segment.append(&mut self.cluster);
+        }
+
+        self.cluster = vec![event];
+        updates
+    }
+
+    /// Flushes any buffered cluster/segment at the end of the stream,
+    /// consuming the analyzer.
+    pub fn finish(mut self) -> Vec<MovementUpdate> {
+        let mut updates = Vec::new();
+        let duration = cluster_duration_secs(&self.cluster);
+
+        if duration >= self.threshold.min_duration_secs && self.cluster.len() > 1 {
+            if self.segment.len() > 1 {
+                updates.push(MovementUpdate::Segment(Trajectory::new(
+                    "segment",
+                    std::mem::take(&mut self.segment),
+                )));
+            }
+            updates.push(MovementUpdate::Stop(stop_from_cluster(&self.cluster)));
+        } else {
+            self.segment.append(&mut self.cluster);
+            if self.segment.len() > 1 {
+                updates.push(MovementUpdate::Segment(Trajectory::new(
+                    "segment",
+                    self.segment,
+                )));
+            }
+        }
+
+        updates
+    }
+
+    /// Running mean speed across all ingested events, in meters/second.
+    pub fn velocity_mean(&self) -> f64 {
+        self.velocity_stats.mean()
+    }
+
+    /// Running variance of speed across all ingested events.
+    pub fn velocity_variance(&self) -> f64 {
+        self.velocity_stats.variance()
+    }
+}
+
+fn cluster_duration_secs(cluster: &[Event]) -> f64 {
+    match (cluster.first(), cluster.last()) {
+        (Some(first), Some(last)) => {
+            (last.timestamp.datetime - first.timestamp.datetime).num_seconds() as f64
+        }
+        _ => 0.0,
+    }
+}
+
+fn stop_from_cluster(cluster: &[Event]) -> Stop {
+    let start = cluster.first().expect("cluster is non-empty").timestamp.clone();
+    let end = cluster.last().expect("cluster is non-empty").timestamp.clone();
+    Stop {
+        location: centroid_owned(cluster),
+        duration_secs: (end.datetime - start.datetime).num_seconds() as f64,
+        start,
+        end,
+        event_count: cluster.len(),
+    }
+}
+
+fn centroid_owned(events: &[Event]) -> Location {
+    let count = events.len() as f64;
+    let lat = events.iter().map(|e| e.location.lat).sum::<f64>() / count;
+    let lon = events.iter().map(|e| e.location.lon).sum::<f64>() / count;
+    Location::new(lat, lon)
+}
+
+fn centroid_of(events: &[&Event]) -> Location {
+    let count = events.len() as f64;
+    let lat = events.iter().map(|e| e.location.lat).sum::<f64>() / count;
+    let lon = events.iter().map(|e| e.location.lon).sum::<f64>() / count;
+    Location::new(lat, lon)
+}
+
+/// Great-circle distance between two locations, in meters.
+fn haversine_meters(a: &Location, b: &Location) -> f64 {
+    let r = 6_371_000.0_f64;
+    let (phi1, phi2) = (a.lat.to_radians(), b.lat.to_radians());
+    let dphi = (b.lat - a.lat).to_radians();
+    let dlambda = (b.lon - a.lon).to_radians();
+    let h = (dphi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (dlambda / 2.0).sin().powi(2);
+    r * 2.0 * h.sqrt().asin()
+}
+
+/// Perpendicular distance, in meters, from `point` to the great circle
+/// through `start` and `end`, via the spherical cross-track distance formula.
+/// The distance is to the whole great circle, not clipped to the segment
+/// between the two endpoints.
+fn cross_track_distance_meters(point: &Location, start: &Location, end: &Location) -> f64 {
+    let d13 = haversine_meters(start, point) / 6_371_000.0;
+    let bearing13 = initial_bearing(start, point);
+    let bearing12 = initial_bearing(start, end);
+    (d13.sin() * (bearing13 - bearing12).sin()).asin().abs() * 6_371_000.0
+}
+
+fn initial_bearing(from: &Location, to: &Location) -> f64 {
+    let (phi1, phi2) = (from.lat.to_radians(), to.lat.to_radians());
+    let dlambda = (to.lon - from.lon).to_radians();
+    let y = dlambda.sin() * phi2.cos();
+    let x = phi1.cos() * phi2.sin() - phi1.sin() * phi2.cos() * dlambda.cos();
+    y.atan2(x)
+}
+
+fn douglas_peucker(events: &[Event], tolerance_meters: f64) -> Vec<Event> {
+    if events.len() < 3 {
+        return events.to_vec();
+    }
+
+    let (start, end) = (&events[0], &events[events.len() - 1]);
+    let (mut farthest_index, mut farthest_distance) = (0, 0.0);
+
+    for (i, event) in events.iter().enumerate().take(events.len() - 1).skip(1) {
+        let distance = cross_track_distance_meters(&event.location, &start.location, &end.location);
+        if distance > farthest_distance {
+            farthest_index = i;
+            farthest_distance = distance;
+        }
+    }
+
+    if farthest_distance > tolerance_meters {
+        let mut left = douglas_peucker(&events[..=farthest_index], tolerance_meters);
+        let right = douglas_peucker(&events[farthest_index..], tolerance_meters);
+        left.pop();
+        left.extend(right);
+        left
+    } else {
+        vec![start.clone(), end.clone()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Timestamp;
+
+    fn event(lat: f64, lon: f64, secs: i64, text: &str) -> Event {
+        Event::new(
+            Location::new(lat, lon),
+            Timestamp::from_unix(secs).unwrap(),
+            text,
+        )
+    }
+
+    #[test]
+    fn test_trajectory_basic_metrics() {
+        let events = vec![
+            event(40.0, -74.0, 0, "a"),
+            event(40.1, -74.0, 3600, "b"),
+        ];
+        let trajectory = Trajectory::new("t1", events);
+
+        assert_eq!(trajectory.len(), 2);
+        assert!(trajectory.total_distance() > 0.0);
+        assert_eq!(trajectory.duration_secs(), 3600.0);
+        assert!(trajectory.avg_speed() > 0.0);
+        assert!(trajectory.bounds().is_some());
+    }
+
+    #[test]
+    fn test_velocity_profile_length() {
+        let events = vec![
+            event(40.0, -74.0, 0, "a"),
+            event(40.1, -74.0, 3600, "b"),
+            event(40.2, -74.0, 7200, "c"),
+        ];
+        let trajectory = Trajectory::new("t1", events);
+        assert_eq!(trajectory.velocity_profile().len(), 2);
+    }
+
+    #[test]
+    fn test_detect_stops_finds_stationary_cluster() {
+        let events = vec![
+            event(40.0, -74.0, 0, "arrive"),
+            event(40.0001, -74.0001, 600, "wait"),
+            event(40.0002, -74.0002, 1200, "leave"),
+            event(41.0, -75.0, 1260, "moving away"),
+        ];
+        let trajectory = Trajectory::new("t1", events);
+        let threshold = StopThreshold {
+            radius_m: 100.0,
+            min_duration_secs: 600.0,
+        };
+
+        let stops = detect_stops(&trajectory, &threshold);
+        assert_eq!(stops.len(), 1);
+        assert_eq!(stops[0].event_count, 3);
+        assert_eq!(stops[0].duration_secs, 1200.0);
+    }
+
+    #[test]
+    fn test_movement_analyzer_segments_around_stop() {
+        let events = vec![
+            event(40.0, -74.0, 0, "depart"),
+            event(40.05, -74.0, 300, "en route"),
+            event(40.1, -74.0, 600, "arrive"),
+            event(40.1001, -74.0001, 1200, "wait"),
+            event(40.1, -74.0, 1800, "leave"),
+            event(40.2, -74.0, 2100, "en route"),
+            event(40.3, -74.0, 2400, "arrive 2"),
+        ];
+
+        let analyzer = MovementAnalyzer::with_stop_threshold(StopThreshold {
+            radius_m: 50.0,
+            min_duration_secs: 500.0,
+        });
+
+        let trajectory = analyzer.extract_trajectory("route", events);
+        let stops = analyzer.detect_stops(&trajectory);
+        assert_eq!(stops.len(), 1);
+
+        let segments = analyzer.movement_segments(&trajectory);
+        assert_eq!(segments.len(), 2);
+    }
+
+    #[test]
+    fn test_simplify_drops_collinear_points() {
+        let events = vec![
+            event(40.0, -74.0, 0, "a"),
+            event(40.1, -74.0, 600, "b"),
+            event(40.2, -74.0, 1200, "c"),
+        ];
+        let trajectory = Trajectory::new("t1", events);
+        let simplified = trajectory.simplify(500.0);
+        assert_eq!(simplified.len(), 2);
+    }
+
+    #[test]
+    fn test_online_analyzer_emits_segment_then_stop() {
+        let threshold = StopThreshold {
+            radius_m: 50.0,
+            min_duration_secs: 500.0,
+        };
+        let mut analyzer = OnlineMovementAnalyzer::new(threshold);
+
+        let stream = vec![
+            event(40.0, -74.0, 0, "depart"),
+            event(40.05, -74.0, 300, "en route"),
+            event(40.1, -74.0, 600, "arrive"),
+            event(40.1001, -74.0001, 1200, "wait"),
+            event(40.1, -74.0, 1800, "leave"),
+            event(40.2, -74.0, 2100, "en route"),
+        ];
+
+        let mut all_updates = Vec::new();
+        for e in stream {
+            all_updates.extend(analyzer.ingest(e));
+        }
+
+        let stops: Vec<_> = all_updates
+            .iter()
+            .filter_map(|u| match u {
+                MovementUpdate::Stop(s) => Some(s),
+                _ => None,
+            })
+            .collect();
+        let segments: Vec<_> = all_updates
+            .iter()
+            .filter_map(|u| match u {
+                MovementUpdate::Segment(s) => Some(s),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(stops.len(), 1);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].len(), 2);
+        assert!(analyzer.velocity_mean() > 0.0);
+    }
+
+    #[test]
+    fn test_running_stats_matches_known_mean_and_variance() {
+        let mut stats = RunningStats::default();
+        for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            stats.update(value);
+        }
+        assert!((stats.mean() - 5.0).abs() < 1e-9);
+        assert!((stats.variance() - 4.0).abs() < 1e-9);
+    }
+}
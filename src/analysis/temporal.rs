@@ -0,0 +1,415 @@
+//! Temporal metrics over event collections: rates, gaps, bursts, and activity patterns.
+
+use chrono::{Datelike, NaiveDateTime, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+use std::collections::BTreeMap;
+
+use crate::core::{Event, TimeRange, Timestamp};
+
+/// Aggregate temporal statistics over an event collection.
+///
+/// Built by [`TemporalMetrics::from_events`]; the duration and gap fields are
+/// `0.0` for an empty or single-event collection, since there is no gap to
+/// measure, and `time_range` is `None` only for an empty one.
+#[derive(Debug, Clone)]
+pub struct TemporalMetrics {
+    /// Number of events the metrics were computed over.
+    pub event_count: usize,
+    /// Span between the earliest and latest event, in seconds.
+    pub duration_secs: f64,
+    /// Earliest and latest event timestamps, if any events were supplied.
+    pub time_range: Option<TimeRange>,
+    /// Mean gap between consecutive events, in seconds.
+    pub avg_inter_event_time: f64,
+    /// Smallest gap between consecutive events, in seconds.
+    pub min_inter_event_time: f64,
+    /// Largest gap between consecutive events, in seconds.
+    pub max_inter_event_time: f64,
+}
+
+impl TemporalMetrics {
+    /// Computes aggregate temporal statistics over `events`.
+    pub fn from_events(events: &[Event]) -> Self {
+        let mut sorted: Vec<&Event> = events.iter().collect();
+        sorted.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+
+        if sorted.is_empty() {
+            return Self {
+                event_count: 0,
+                duration_secs: 0.0,
+                time_range: None,
+                avg_inter_event_time: 0.0,
+                min_inter_event_time: 0.0,
+                max_inter_event_time: 0.0,
+            };
+        }
+
+        let first = sorted.first().unwrap().timestamp.clone();
+        let last = sorted.last().unwrap().timestamp.clone();
+        let duration_secs = last.duration_since(&first).num_milliseconds() as f64 / 1000.0;
+
+        let gaps: Vec<f64> = sorted
+            .windows(2)
+            .map(|pair| pair[1].timestamp.duration_since(&pair[0].timestamp).num_milliseconds() as f64 / 1000.0)
+            .collect();
+
+        let (avg, min, max) = if gaps.is_empty() {
+            (0.0, 0.0, 0.0)
+        } else {
+            let sum: f64 = gaps.iter().sum();
+            let min = gaps.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max = gaps.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            (sum / gaps.len() as f64, min, max)
+        };
+
+        Self {
+            event_count: sorted.len(),
+            duration_secs,
+            time_range: Some(TimeRange::new(first, last)),
+            avg_inter_event_time: avg,
+            min_inter_event_time: min,
+            max_inter_event_time: max,
+        }
+    }
+
+    /// Counts events per local hour-of-day (0..24) in the wall clock of `tz`.
+    ///
+    /// DST-correct: an event's bucket is the hour an observer in `tz` would
+    /// have seen on their clock, not the UTC hour.
+    pub fn activity_by_hour(events: &[Event], tz: Tz) -> [u32; 24] {
+        let mut buckets = [0u32; 24];
+        for event in events {
+            let hour = local_datetime(&event.timestamp, tz).hour();
+            buckets[hour as usize] += 1;
+        }
+        buckets
+    }
+
+    /// Counts events per local weekday (`[Mon, Tue, Wed, Thu, Fri, Sat, Sun]`)
+    /// in the wall clock of `tz`.
+    pub fn activity_by_weekday(events: &[Event], tz: Tz) -> [u32; 7] {
+        let mut buckets = [0u32; 7];
+        for event in events {
+            let weekday = local_datetime(&event.timestamp, tz).weekday().num_days_from_monday();
+            buckets[weekday as usize] += 1;
+        }
+        buckets
+    }
+}
+
+/// A bucket width for [`event_rate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeBin {
+    /// One bucket per local hour.
+    Hour,
+    /// One bucket per local calendar day.
+    Day,
+    /// One bucket per local calendar week (Monday-aligned).
+    Week,
+    /// One bucket per local calendar month.
+    Month,
+    /// One bucket per weekday (Mon..Sun), ignoring the specific calendar date.
+    DayOfWeek,
+}
+
+/// The event count within one [`TimeBin`]-wide bucket.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bin {
+    /// Start of the bucket, expressed as a UTC instant.
+    ///
+    /// For [`TimeBin::DayOfWeek`], this is the earliest occurrence of that
+    /// weekday among `events` rather than a sequential window boundary.
+    pub start: Timestamp,
+    /// Number of events falling in this bucket.
+    pub count: usize,
+}
+
+/// Buckets `events` into [`TimeBin`]-wide windows, truncated in the wall clock
+/// of `tz`, and counts each bucket.
+///
+/// Only buckets containing at least one event are returned, sorted by bucket
+/// start (or, for [`TimeBin::DayOfWeek`], by weekday order starting Monday).
+pub fn event_rate(events: &[Event], bin: TimeBin, tz: Tz) -> Vec<Bin> {
+    let mut buckets: BTreeMap<i64, (NaiveDateTime, usize)> = BTreeMap::new();
+
+    for event in events {
+        let local = local_datetime(&event.timestamp, tz).naive_local();
+        let (key, truncated) = bucket_key(local, bin);
+        let entry = buckets.entry(key).or_insert((truncated, 0));
+        entry.1 += 1;
+        if truncated < entry.0 {
+            entry.0 = truncated;
+        }
+    }
+
+    buckets
+        .into_values()
+        .map(|(truncated, count)| Bin {
+            start: Timestamp::new(local_naive_to_utc(truncated, tz)),
+            count,
+        })
+        .collect()
+}
+
+/// Detects gaps between consecutive events (sorted by time) longer than
+/// `threshold_secs`, returning the `[previous, next)` span of each.
+pub fn detect_gaps(events: &[Event], threshold_secs: f64) -> Vec<TimeRange> {
+    let mut sorted: Vec<&Event> = events.iter().collect();
+    sorted.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+
+    sorted
+        .windows(2)
+        .filter_map(|pair| {
+            let gap_secs =
+                pair[1].timestamp.duration_since(&pair[0].timestamp).num_milliseconds() as f64 / 1000.0;
+            if gap_secs > threshold_secs {
+                Some(TimeRange::new(pair[0].timestamp.clone(), pair[1].timestamp.clone()))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Detects runs of at least `min_count` events that all fall within a
+/// `window_secs`-wide span of each other.
+///
+/// Greedily extends each run as far as it can go within the window, then
+/// resumes scanning after it, so bursts never overlap in the result.
+pub fn detect_bursts(events: &[Event], window_secs: f64, min_count: usize) -> Vec<TimeRange> {
+    let mut sorted: Vec<&Event> = events.iter().collect();
+    sorted.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+
+    let mut bursts = Vec::new();
+    let mut i = 0;
+    while i < sorted.len() {
+        let mut j = i;
+        while j + 1 < sorted.len() {
+            let span = sorted[j + 1]
+                .timestamp
+                .duration_since(&sorted[i].timestamp)
+                .num_milliseconds() as f64
+                / 1000.0;
+            if span <= window_secs {
+                j += 1;
+            } else {
+                break;
+            }
+        }
+
+        if j - i + 1 >= min_count {
+            bursts.push(TimeRange::new(sorted[i].timestamp.clone(), sorted[j].timestamp.clone()));
+            i = j + 1;
+        } else {
+            i += 1;
+        }
+    }
+
+    bursts
+}
+
+/// Scores how close two events' instants are in `[0.0, 1.0]`, decaying
+/// exponentially with their gap in seconds (`half_life_secs` is the gap at
+/// which the score is `0.5`).
+///
+/// Always compares true UTC instants ([`Timestamp::datetime`]), never each
+/// event's local wall clock, so "09:00 local" in one narrative's timezone and
+/// "09:00 local" in another's score low unless those instants are actually
+/// close in absolute time — even though their local reckonings read the
+/// same. Call [`Event::resolve_timezone`](crate::core::Event::resolve_timezone)
+/// beforehand if a source's coordinates, rather than an explicit offset,
+/// should determine the zone used for any local-time display alongside this
+/// score.
+pub fn temporal_similarity(a: &Event, b: &Event, half_life_secs: f64) -> f64 {
+    let gap_secs = (a.timestamp.duration_since(&b.timestamp).num_milliseconds().abs() as f64) / 1000.0;
+    if half_life_secs <= 0.0 {
+        return if gap_secs == 0.0 { 1.0 } else { 0.0 };
+    }
+    0.5f64.powf(gap_secs / half_life_secs)
+}
+
+/// Converts a timestamp's UTC instant into `tz`'s wall-clock representation.
+fn local_datetime(ts: &Timestamp, tz: Tz) -> chrono::DateTime<Tz> {
+    tz.from_utc_datetime(&ts.datetime.naive_utc())
+}
+
+/// Truncates a local wall-clock instant to its [`TimeBin`] bucket, returning a
+/// sortable key alongside the truncated local instant.
+fn bucket_key(local: NaiveDateTime, bin: TimeBin) -> (i64, NaiveDateTime) {
+    let key_of = |naive: NaiveDateTime| Utc.from_utc_datetime(&naive).timestamp();
+    match bin {
+        TimeBin::Hour => {
+            let truncated = local.date().and_hms_opt(local.hour(), 0, 0).unwrap();
+            (key_of(truncated), truncated)
+        }
+        TimeBin::Day => {
+            let truncated = local.date().and_hms_opt(0, 0, 0).unwrap();
+            (key_of(truncated), truncated)
+        }
+        TimeBin::Week => {
+            let monday = local.date() - chrono::Duration::days(local.weekday().num_days_from_monday() as i64);
+            let truncated = monday.and_hms_opt(0, 0, 0).unwrap();
+            (key_of(truncated), truncated)
+        }
+        TimeBin::Month => {
+            let first = chrono::NaiveDate::from_ymd_opt(local.year(), local.month(), 1).unwrap();
+            let truncated = first.and_hms_opt(0, 0, 0).unwrap();
+            (key_of(truncated), truncated)
+        }
+        TimeBin::DayOfWeek => {
+            let truncated = local.date().and_hms_opt(0, 0, 0).unwrap();
+            (local.weekday().num_days_from_monday() as i64, truncated)
+        }
+    }
+}
+
+/// Re-localizes a truncated wall-clock instant back to a UTC instant,
+/// resolving DST ambiguity by preferring the earlier of two candidates and
+/// falling back to a UTC reading inside a spring-forward gap.
+fn local_naive_to_utc(local: NaiveDateTime, tz: Tz) -> chrono::DateTime<Utc> {
+    match tz.from_local_datetime(&local) {
+        chrono::LocalResult::Single(dt) => dt.with_timezone(&Utc),
+        chrono::LocalResult::Ambiguous(earliest, _) => earliest.with_timezone(&Utc),
+        chrono::LocalResult::None => Utc.from_utc_datetime(&local),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Location;
+
+    fn event_at(dt: &str) -> Event {
+        Event::new(Location::new(0.0, 0.0), Timestamp::parse(dt).unwrap(), "e")
+    }
+
+    #[test]
+    fn test_from_events_empty() {
+        let metrics = TemporalMetrics::from_events(&[]);
+        assert_eq!(metrics.event_count, 0);
+        assert!(metrics.time_range.is_none());
+    }
+
+    #[test]
+    fn test_from_events_computes_gaps() {
+        let events = vec![
+            event_at("2024-01-20T08:00:00Z"),
+            event_at("2024-01-20T08:30:00Z"),
+            event_at("2024-01-20T12:00:00Z"),
+        ];
+        let metrics = TemporalMetrics::from_events(&events);
+
+        assert_eq!(metrics.event_count, 3);
+        assert_eq!(metrics.duration_secs, 4.0 * 3600.0);
+        assert_eq!(metrics.min_inter_event_time, 1800.0);
+        assert_eq!(metrics.max_inter_event_time, 3.5 * 3600.0);
+    }
+
+    #[test]
+    fn test_activity_by_hour_respects_timezone() {
+        // 2024-03-15T23:30:00Z is 2024-03-15T19:30:00-04:00 in New York (EDT,
+        // in effect since 2024-03-10).
+        let events = vec![event_at("2024-03-15T23:30:00Z")];
+
+        let utc_buckets = TemporalMetrics::activity_by_hour(&events, Tz::UTC);
+        assert_eq!(utc_buckets[23], 1);
+
+        let ny_buckets = TemporalMetrics::activity_by_hour(&events, chrono_tz::America::New_York);
+        assert_eq!(ny_buckets[19], 1);
+    }
+
+    #[test]
+    fn test_activity_by_weekday() {
+        // 2024-03-18 is a Monday.
+        let events = vec![event_at("2024-03-18T10:00:00Z"), event_at("2024-03-18T11:00:00Z")];
+        let buckets = TemporalMetrics::activity_by_weekday(&events, Tz::UTC);
+        assert_eq!(buckets[0], 2);
+        assert_eq!(buckets[1..].iter().sum::<u32>(), 0);
+    }
+
+    #[test]
+    fn test_event_rate_hourly() {
+        let events = vec![
+            event_at("2024-01-20T08:00:00Z"),
+            event_at("2024-01-20T08:30:00Z"),
+            event_at("2024-01-20T12:00:00Z"),
+        ];
+        let bins = event_rate(&events, TimeBin::Hour, Tz::UTC);
+
+        assert_eq!(bins.len(), 2);
+        assert_eq!(bins[0].count, 2);
+        assert_eq!(bins[1].count, 1);
+    }
+
+    #[test]
+    fn test_event_rate_day_of_week() {
+        let events = vec![
+            event_at("2024-03-18T10:00:00Z"), // Monday
+            event_at("2024-03-25T10:00:00Z"), // also Monday
+            event_at("2024-03-19T10:00:00Z"), // Tuesday
+        ];
+        let bins = event_rate(&events, TimeBin::DayOfWeek, Tz::UTC);
+
+        assert_eq!(bins.len(), 2);
+        assert_eq!(bins[0].count, 2);
+        assert_eq!(bins[1].count, 1);
+    }
+
+    #[test]
+    fn test_detect_gaps() {
+        let events = vec![
+            event_at("2024-01-20T08:00:00Z"),
+            event_at("2024-01-20T08:30:00Z"),
+            event_at("2024-01-20T12:00:00Z"),
+        ];
+        let gaps = detect_gaps(&events, 3600.0);
+        assert_eq!(gaps.len(), 1);
+        assert_eq!(gaps[0].start.to_rfc3339(), "2024-01-20T08:30:00+00:00");
+        assert_eq!(gaps[0].end.to_rfc3339(), "2024-01-20T12:00:00+00:00");
+    }
+
+    #[test]
+    fn test_detect_bursts() {
+        let events = vec![
+            event_at("2024-01-20T12:00:00Z"),
+            event_at("2024-01-20T12:02:00Z"),
+            event_at("2024-01-20T12:05:00Z"),
+            event_at("2024-01-20T18:00:00Z"),
+        ];
+        let bursts = detect_bursts(&events, 900.0, 3);
+        assert_eq!(bursts.len(), 1);
+        assert_eq!(bursts[0].start.to_rfc3339(), "2024-01-20T12:00:00+00:00");
+        assert_eq!(bursts[0].end.to_rfc3339(), "2024-01-20T12:05:00+00:00");
+    }
+
+    #[test]
+    fn test_temporal_similarity_identical_instant_is_one() {
+        let a = event_at("2024-01-20T12:00:00Z");
+        let b = event_at("2024-01-20T12:00:00Z");
+        assert_eq!(temporal_similarity(&a, &b, 3600.0), 1.0);
+    }
+
+    #[test]
+    fn test_temporal_similarity_decays_with_gap() {
+        let a = event_at("2024-01-20T12:00:00Z");
+        let b = event_at("2024-01-20T13:00:00Z");
+        assert!((temporal_similarity(&a, &b, 3600.0) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_temporal_similarity_ignores_matching_local_wall_clock_in_different_zones() {
+        // Both read "09:00" on their own local clock, but in zones 13 hours
+        // apart (Auckland is UTC+13 in January) these are not close instants.
+        let a = Timestamp::with_zone(
+            Timestamp::parse("2024-01-20T09:00:00Z").unwrap().datetime,
+            chrono_tz::UTC,
+        );
+        let b = Timestamp::with_zone(
+            Timestamp::parse("2024-01-20T09:00:00Z").unwrap().datetime - chrono::Duration::hours(13),
+            chrono_tz::Pacific::Auckland,
+        );
+        let event_a = Event::new(Location::new(0.0, 0.0), a, "a");
+        let event_b = Event::new(Location::new(0.0, 0.0), b, "b");
+        assert!(temporal_similarity(&event_a, &event_b, 3600.0) < 0.01);
+    }
+}
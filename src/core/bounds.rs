@@ -1,9 +1,9 @@
 //! Geographic and temporal bounds for filtering and queries.
 
-use chrono::{Datelike, Duration, TimeZone};
+use chrono::{DateTime, Datelike, Duration, TimeZone};
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Location, Timestamp};
+use crate::core::{Location, TemporalPrecision, Timestamp};
 
 /// Geographic bounding box.
 ///
@@ -82,11 +82,18 @@ impl GeoBounds {
     }
 
     /// Checks if a location is within these bounds.
+    ///
+    /// Handles antimeridian-crossing boxes (`min_lon > max_lon`, e.g. a box
+    /// spanning Fiji's `170°E` to `-170°E`) by treating longitude containment
+    /// as "east of `min_lon` OR west of `max_lon`" instead of "between" them.
     pub fn contains(&self, location: &Location) -> bool {
-        location.lat >= self.min_lat
-            && location.lat <= self.max_lat
-            && location.lon >= self.min_lon
-            && location.lon <= self.max_lon
+        let lon_in_bounds = if self.min_lon <= self.max_lon {
+            location.lon >= self.min_lon && location.lon <= self.max_lon
+        } else {
+            location.lon >= self.min_lon || location.lon <= self.max_lon
+        };
+
+        location.lat >= self.min_lat && location.lat <= self.max_lat && lon_in_bounds
     }
 
     /// Checks if these bounds intersect with other bounds.
@@ -97,6 +104,30 @@ impl GeoBounds {
             && self.max_lon >= other.min_lon
     }
 
+    /// Returns these bounds as a GeoJSON/STAC `bbox` array.
+    ///
+    /// The order follows the specification: `[min_lon, min_lat, max_lon, max_lat]`
+    /// (the "west, south, east, north" corners).
+    pub fn to_bbox(&self) -> Vec<f64> {
+        vec![self.min_lon, self.min_lat, self.max_lon, self.max_lat]
+    }
+
+    /// Parses a GeoJSON/STAC `bbox` array into bounds.
+    ///
+    /// Accepts the 4-element `[min_lon, min_lat, max_lon, max_lat]` form and the
+    /// 6-element form carrying elevation (the two elevation entries are ignored,
+    /// as [`GeoBounds`] tracks only the horizontal extent). Returns `None` for
+    /// any other length.
+    pub fn from_bbox(bbox: &[f64]) -> Option<Self> {
+        match bbox {
+            [min_lon, min_lat, max_lon, max_lat]
+            | [min_lon, min_lat, _, max_lon, max_lat, _] => {
+                Some(Self::new(*min_lat, *min_lon, *max_lat, *max_lon))
+            }
+            _ => None,
+        }
+    }
+
     /// Returns the intersection of two bounds, if any.
     pub fn intersection(&self, other: &GeoBounds) -> Option<GeoBounds> {
         if !self.intersects(other) {
@@ -167,6 +198,32 @@ impl GeoBounds {
         Location::new(self.min_lat, self.max_lon)
     }
 
+    /// Projects a location into an `(x, y)` coordinate pair, mapping longitude
+    /// and latitude independently into `x_limit` and `y_limit`.
+    ///
+    /// A zero-width or zero-height box maps the corresponding axis to its lower
+    /// limit. This is the spatial counterpart to [`TimeRange::map_coord`].
+    pub fn map_coord(
+        &self,
+        location: &Location,
+        x_limit: (i32, i32),
+        y_limit: (i32, i32),
+    ) -> (i32, i32) {
+        let x = if self.width() == 0.0 {
+            x_limit.0
+        } else {
+            let fx = (location.lon - self.min_lon) / self.width();
+            x_limit.0 + ((x_limit.1 - x_limit.0) as f64 * fx) as i32
+        };
+        let y = if self.height() == 0.0 {
+            y_limit.0
+        } else {
+            let fy = (location.lat - self.min_lat) / self.height();
+            y_limit.0 + ((y_limit.1 - y_limit.0) as f64 * fy) as i32
+        };
+        (x, y)
+    }
+
     /// Converts to a geo-types Rect.
     pub fn to_geo_rect(&self) -> geo_types::Rect<f64> {
         geo_types::Rect::new(
@@ -214,47 +271,144 @@ impl TimeRange {
     }
 
     /// Creates a time range for a specific year.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `year` is outside chrono's supported range. Use
+    /// [`try_year`](Self::try_year) to handle untrusted input gracefully.
     pub fn year(year: i32) -> Self {
-        let start = Timestamp::parse(&format!("{}", year)).unwrap();
+        Self::try_year(year).expect("invalid year for TimeRange::year")
+    }
+
+    /// Fallible variant of [`year`](Self::year).
+    pub fn try_year(year: i32) -> std::result::Result<Self, RangeError> {
+        let start_dt = chrono::NaiveDate::from_ymd_opt(year, 1, 1)
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .ok_or(RangeError::Year { year })?;
         let end_dt = chrono::NaiveDate::from_ymd_opt(year, 12, 31)
-            .unwrap()
-            .and_hms_opt(23, 59, 59)
-            .unwrap();
+            .and_then(|d| d.and_hms_opt(23, 59, 59))
+            .ok_or(RangeError::Year { year })?;
+
+        let start =
+            Timestamp::with_precision(chrono::Utc.from_utc_datetime(&start_dt), TemporalPrecision::Year);
         let end = Timestamp::new(chrono::Utc.from_utc_datetime(&end_dt));
-        Self::new(start, end)
+        Ok(Self::new(start, end))
     }
 
     /// Creates a time range for a specific month.
+    ///
+    /// # Panics
+    ///
+    /// Panics on an out-of-range `month`. Use [`try_month`](Self::try_month) to
+    /// handle untrusted input gracefully.
     pub fn month(year: i32, month: u32) -> Self {
-        let start = Timestamp::parse(&format!("{}-{:02}", year, month)).unwrap();
+        Self::try_month(year, month).expect("invalid month for TimeRange::month")
+    }
+
+    /// Fallible variant of [`month`](Self::month).
+    pub fn try_month(year: i32, month: u32) -> std::result::Result<Self, RangeError> {
+        let err = || RangeError::Month { year, month };
 
-        // Calculate last day of month
+        let start_dt = chrono::NaiveDate::from_ymd_opt(year, month, 1)
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .ok_or_else(err)?;
+
+        // The first day of the next month, minus one day, is the last of this.
         let next_month = if month == 12 { 1 } else { month + 1 };
         let next_year = if month == 12 { year + 1 } else { year };
         let last_day = chrono::NaiveDate::from_ymd_opt(next_year, next_month, 1)
-            .unwrap()
-            .pred_opt()
-            .unwrap()
+            .and_then(|d| d.pred_opt())
+            .ok_or_else(err)?
             .day();
 
         let end_dt = chrono::NaiveDate::from_ymd_opt(year, month, last_day)
-            .unwrap()
-            .and_hms_opt(23, 59, 59)
-            .unwrap();
-        let end = Timestamp::new(chrono::Utc.from_utc_datetime(&end_dt));
+            .and_then(|d| d.and_hms_opt(23, 59, 59))
+            .ok_or_else(err)?;
 
-        Self::new(start, end)
+        let start = Timestamp::with_precision(
+            chrono::Utc.from_utc_datetime(&start_dt),
+            TemporalPrecision::Month,
+        );
+        let end = Timestamp::new(chrono::Utc.from_utc_datetime(&end_dt));
+        Ok(Self::new(start, end))
     }
 
     /// Creates a time range for a specific day.
+    ///
+    /// # Panics
+    ///
+    /// Panics on an out-of-range date. Use [`try_day`](Self::try_day) to handle
+    /// untrusted input gracefully.
     pub fn day(year: i32, month: u32, day: u32) -> Self {
-        let start = Timestamp::parse(&format!("{}-{:02}-{:02}", year, month, day)).unwrap();
-        let end_dt = chrono::NaiveDate::from_ymd_opt(year, month, day)
-            .unwrap()
-            .and_hms_opt(23, 59, 59)
-            .unwrap();
+        Self::try_day(year, month, day).expect("invalid date for TimeRange::day")
+    }
+
+    /// Fallible variant of [`day`](Self::day).
+    pub fn try_day(year: i32, month: u32, day: u32) -> std::result::Result<Self, RangeError> {
+        let err = || RangeError::Day { year, month, day };
+
+        let date = chrono::NaiveDate::from_ymd_opt(year, month, day).ok_or_else(err)?;
+        let start_dt = date.and_hms_opt(0, 0, 0).ok_or_else(err)?;
+        let end_dt = date.and_hms_opt(23, 59, 59).ok_or_else(err)?;
+
+        let start = Timestamp::with_precision(
+            chrono::Utc.from_utc_datetime(&start_dt),
+            TemporalPrecision::Day,
+        );
         let end = Timestamp::new(chrono::Utc.from_utc_datetime(&end_dt));
-        Self::new(start, end)
+        Ok(Self::new(start, end))
+    }
+
+    /// Creates a time range for a month whose edges snap to local midnight in
+    /// the given timezone rather than UTC midnight.
+    ///
+    /// DST gaps and ambiguous local times are resolved toward the earliest
+    /// valid instant rather than panicking.
+    pub fn month_in_zone(
+        year: i32,
+        month: u32,
+        tz: chrono_tz::Tz,
+    ) -> std::result::Result<Self, RangeError> {
+        let err = || RangeError::Month { year, month };
+        let next_month = if month == 12 { 1 } else { month + 1 };
+        let next_year = if month == 12 { year + 1 } else { year };
+
+        let start_naive = chrono::NaiveDate::from_ymd_opt(year, month, 1)
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .ok_or_else(err)?;
+        let end_naive = chrono::NaiveDate::from_ymd_opt(next_year, next_month, 1)
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .ok_or_else(err)?;
+
+        Ok(Self::new(
+            local_midnight(start_naive, tz),
+            local_boundary_end(end_naive, tz),
+        ))
+    }
+
+    /// Creates a time range for a day whose edges snap to local midnight in the
+    /// given timezone rather than UTC midnight.
+    ///
+    /// DST gaps and ambiguous local times are resolved toward the earliest
+    /// valid instant rather than panicking.
+    pub fn day_in_zone(
+        year: i32,
+        month: u32,
+        day: u32,
+        tz: chrono_tz::Tz,
+    ) -> std::result::Result<Self, RangeError> {
+        let err = || RangeError::Day { year, month, day };
+        let date = chrono::NaiveDate::from_ymd_opt(year, month, day).ok_or_else(err)?;
+        let start_naive = date.and_hms_opt(0, 0, 0).ok_or_else(err)?;
+        let end_naive = date
+            .succ_opt()
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .ok_or_else(err)?;
+
+        Ok(Self::new(
+            local_midnight(start_naive, tz),
+            local_boundary_end(end_naive, tz),
+        ))
     }
 
     /// Creates a time range from now going back by the given duration.
@@ -324,6 +478,147 @@ impl TimeRange {
         self.end.duration_since(&self.start)
     }
 
+    /// Generates evenly spaced, calendar-aligned tick positions for a time axis.
+    ///
+    /// Picks the finest unit among {second, minute, hour, day, month, year}
+    /// whose count across the span is `<= max_ticks` (falling back to year when
+    /// none fits), snaps the first tick up to
+    /// the next boundary of that unit, then steps by whole units until past
+    /// [`end`](Self::end). Year/month stepping uses [`chrono::NaiveDate`]
+    /// arithmetic so February and leap years stay aligned; sub-day units step by
+    /// [`Duration`].
+    pub fn axis_ticks(&self, max_ticks: usize) -> Vec<Timestamp> {
+        use chrono::NaiveDate;
+
+        let max_ticks = max_ticks.max(1);
+        let start = self.start.datetime;
+        let end = self.end.datetime;
+        if end <= start {
+            return vec![self.start.clone()];
+        }
+
+        let span = end - start;
+        // Approximate counts per unit to choose the coarsest that fits.
+        let year_count = (end.year() - start.year()).max(0) as usize;
+        let month_count =
+            (((end.year() - start.year()) * 12) + end.month() as i32 - start.month() as i32)
+                .max(0) as usize;
+        let day_count = span.num_days().max(0) as usize;
+        let hour_count = span.num_hours().max(0) as usize;
+        let minute_count = span.num_minutes().max(0) as usize;
+        let second_count = span.num_seconds().max(0) as usize;
+
+        let unit = if second_count <= max_ticks {
+            TickUnit::Second
+        } else if minute_count <= max_ticks {
+            TickUnit::Minute
+        } else if hour_count <= max_ticks {
+            TickUnit::Hour
+        } else if day_count <= max_ticks {
+            TickUnit::Day
+        } else if month_count <= max_ticks {
+            TickUnit::Month
+        } else {
+            TickUnit::Year
+        };
+
+        let mut ticks = Vec::new();
+        let mut cursor = match unit {
+            TickUnit::Year => {
+                let next_year = if start == year_floor(start) {
+                    start.year()
+                } else {
+                    start.year() + 1
+                };
+                utc_from_date(NaiveDate::from_ymd_opt(next_year, 1, 1))
+            }
+            TickUnit::Month => {
+                let floored = month_floor(start);
+                if floored == start {
+                    floored
+                } else {
+                    add_months(floored, 1)
+                }
+            }
+            TickUnit::Day => {
+                let floored = day_floor(start);
+                if floored == start {
+                    floored
+                } else {
+                    floored + Duration::days(1)
+                }
+            }
+            TickUnit::Hour => ceil_duration(start, Duration::hours(1)),
+            TickUnit::Minute => ceil_duration(start, Duration::minutes(1)),
+            TickUnit::Second => ceil_duration(start, Duration::seconds(1)),
+        };
+
+        while cursor <= end {
+            ticks.push(Timestamp::new(cursor));
+            cursor = match unit {
+                TickUnit::Year => add_months(cursor, 12),
+                TickUnit::Month => add_months(cursor, 1),
+                TickUnit::Day => cursor + Duration::days(1),
+                TickUnit::Hour => cursor + Duration::hours(1),
+                TickUnit::Minute => cursor + Duration::minutes(1),
+                TickUnit::Second => cursor + Duration::seconds(1),
+            };
+        }
+
+        ticks
+    }
+
+    /// Projects a timestamp into a linear `[limit.0, limit.1]` coordinate range.
+    ///
+    /// Uses nanosecond precision when both the value and total spans fit in
+    /// `i64` nanoseconds (roughly ±292 years); otherwise falls back to seconds
+    /// so multi-century ranges still map. A zero-length span returns `limit.0`.
+    pub fn map_coord(&self, ts: &Timestamp, limit: (i32, i32)) -> i32 {
+        let value_span = ts.datetime - self.start.datetime;
+        let total_span = self.end.datetime - self.start.datetime;
+
+        let fraction = match (
+            value_span.num_nanoseconds(),
+            total_span.num_nanoseconds(),
+        ) {
+            (Some(_), Some(0)) => return limit.0,
+            (Some(value_ns), Some(total_ns)) => value_ns as f64 / total_ns as f64,
+            _ => {
+                let total_s = total_span.num_seconds();
+                if total_s == 0 {
+                    return limit.0;
+                }
+                value_span.num_seconds() as f64 / total_s as f64
+            }
+        };
+
+        limit.0 + ((limit.1 - limit.0) as f64 * fraction) as i32
+    }
+
+    /// Splits the range on true calendar boundaries for the given unit.
+    ///
+    /// The first sub-range runs from [`start`](Self::start) to the end of its
+    /// containing unit, each interior range spans one full unit, and the last is
+    /// clipped to [`end`](Self::end). Month/year boundaries are stepped with
+    /// [`chrono::NaiveDate`] arithmetic (respecting month lengths and leap
+    /// years), and adjacent ranges are contiguous with no gap or overlap.
+    pub fn split_by_calendar(&self, unit: CalendarUnit) -> Vec<TimeRange> {
+        let mut ranges = Vec::new();
+        let mut cursor = self.start.datetime;
+
+        while cursor <= self.end.datetime {
+            let next_boundary = unit.next_boundary(cursor);
+            let seg_end = (next_boundary - Duration::seconds(1)).min(self.end.datetime);
+            ranges.push(TimeRange::new(
+                Timestamp::new(cursor),
+                Timestamp::new(seg_end),
+            ));
+            cursor = next_boundary;
+        }
+
+        ranges
+    }
+
     /// Splits the range into smaller ranges of the given duration.
     pub fn split(&self, chunk_duration: Duration) -> Vec<TimeRange> {
         let mut ranges = Vec::new();
@@ -343,12 +638,414 @@ impl TimeRange {
 
         ranges
     }
+
+    /// Parses a human, relative time expression into a range anchored at `now`.
+    ///
+    /// Recognizes a leading keyword (`last`/`past`/`next`/`in`/`since`/`before`)
+    /// followed by an integer and a unit (`second`/`minute`/`hour`/`day`/`week`,
+    /// singular or plural), plus the bare anchors `today`, `yesterday`, and
+    /// `now`:
+    ///
+    /// - `last`/`past N unit` → `[now - N·unit, now]`
+    /// - `next`/`in N unit` → `[now, now + N·unit]`
+    /// - `since N unit` / `before N unit` → `[now - N·unit, now]` / `[earliest, now - N·unit]`
+    /// - `today` → `[start-of-day, now]`
+    /// - `yesterday` → the whole previous calendar day
+    ///
+    /// Returns `None` on unrecognized input rather than panicking, so it can
+    /// back a query bar without a heavyweight grammar dependency.
+    pub fn parse_relative(input: &str, now: &Timestamp) -> Option<TimeRange> {
+        let lowered = input.trim().to_lowercase();
+        let now_dt = now.datetime;
+
+        match lowered.as_str() {
+            "now" => return Some(TimeRange::new(now.clone(), now.clone())),
+            "today" => {
+                let start = day_floor(now_dt);
+                return Some(TimeRange::new(Timestamp::new(start), now.clone()));
+            }
+            "yesterday" => {
+                let start = day_floor(now_dt) - Duration::days(1);
+                let end = day_floor(now_dt) - Duration::seconds(1);
+                return Some(TimeRange::new(Timestamp::new(start), Timestamp::new(end)));
+            }
+            _ => {}
+        }
+
+        let mut tokens = lowered.split_whitespace();
+        let keyword = tokens.next()?;
+        let amount: i64 = tokens.next()?.parse().ok()?;
+        if amount < 0 {
+            return None;
+        }
+        let unit = tokens.next()?;
+        if tokens.next().is_some() {
+            return None; // trailing garbage
+        }
+
+        let span = unit_duration(unit, amount)?;
+
+        match keyword {
+            "last" | "past" | "since" => {
+                Some(TimeRange::new(Timestamp::new(now_dt - span), now.clone()))
+            }
+            "next" | "in" => Some(TimeRange::new(now.clone(), Timestamp::new(now_dt + span))),
+            "before" => {
+                let boundary = Timestamp::new(now_dt - span);
+                // An open-ended lower bound, clamped to chrono's representable range.
+                let earliest = Timestamp::from_unix_millis(-8_000_000_000_000_000)?;
+                Some(TimeRange::new(earliest, boundary))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Convert a unit word and count into a [`Duration`], or `None` if unrecognized.
+fn unit_duration(unit: &str, amount: i64) -> Option<Duration> {
+    match unit.trim_end_matches('s') {
+        "second" => Some(Duration::seconds(amount)),
+        "minute" => Some(Duration::minutes(amount)),
+        "hour" => Some(Duration::hours(amount)),
+        "day" => Some(Duration::days(amount)),
+        "week" => Some(Duration::weeks(amount)),
+        _ => None,
+    }
+}
+
+/// Maps a [`TimeRange`] onto a one-dimensional pixel extent for timeline rendering.
+///
+/// A `TimeAxis` pairs a time range with a pixel interval `(x0, x1)` and exposes
+/// the two operations a renderer needs: projecting a [`Timestamp`] to a pixel
+/// coordinate, and generating "nice" calendar-aligned tick marks. It is modelled
+/// on plotters' datetime coordinate: the heavy lifting (linear projection,
+/// boundary-aligned ticks) is reused from [`TimeRange::map_coord`] and
+/// [`TimeRange::axis_ticks`].
+#[derive(Debug, Clone)]
+pub struct TimeAxis {
+    range: TimeRange,
+    x0: i32,
+    x1: i32,
 }
 
+impl TimeAxis {
+    /// Creates an axis spanning `range` across the pixel interval `[x0, x1]`.
+    pub fn new(range: TimeRange, x0: i32, x1: i32) -> Self {
+        Self { range, x0, x1 }
+    }
+
+    /// Projects a timestamp onto its pixel coordinate within `[x0, x1]`.
+    ///
+    /// Linear interpolation at nanosecond resolution, falling back to seconds for
+    /// ranges too long to count in `i64` nanoseconds (see
+    /// [`TimeRange::map_coord`]).
+    pub fn map_coord(&self, ts: &Timestamp) -> i32 {
+        self.range.map_coord(ts, (self.x0, self.x1))
+    }
+
+    /// Returns calendar-aligned tick timestamps.
+    ///
+    /// Delegates to [`TimeRange::axis_ticks`], which selects the finest unit whose
+    /// count fits within `max_ticks` and snaps ticks to unit boundaries; when
+    /// both ends of the range fall on boundaries this yields `max_ticks + 1`
+    /// ticks at most.
+    pub fn key_points(&self, max_ticks: usize) -> Vec<Timestamp> {
+        self.range.axis_ticks(max_ticks)
+    }
+
+    /// Returns tick timestamps paired with their mapped pixel coordinates.
+    pub fn ticks(&self, max_ticks: usize) -> Vec<(Timestamp, i32)> {
+        self.key_points(max_ticks)
+            .into_iter()
+            .map(|ts| {
+                let x = self.map_coord(&ts);
+                (ts, x)
+            })
+            .collect()
+    }
+
+    /// The time range this axis covers.
+    pub fn range(&self) -> &TimeRange {
+        &self.range
+    }
+
+    /// The pixel extent `(x0, x1)` of this axis.
+    pub fn extent(&self) -> (i32, i32) {
+        (self.x0, self.x1)
+    }
+}
+
+/// A natural calendar unit for [`TimeRange::split_by_calendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarUnit {
+    /// Calendar year, aligned to January 1st.
+    Year,
+    /// Calendar month, aligned to the 1st.
+    Month,
+    /// ISO week, aligned to Monday.
+    Week,
+    /// Calendar day, aligned to midnight.
+    Day,
+}
+
+impl CalendarUnit {
+    /// Returns the start of the unit strictly following the one containing `dt`.
+    fn next_boundary(self, dt: DateTime<chrono::Utc>) -> DateTime<chrono::Utc> {
+        match self {
+            CalendarUnit::Year => {
+                utc_from_date(chrono::NaiveDate::from_ymd_opt(dt.year() + 1, 1, 1))
+            }
+            CalendarUnit::Month => add_months(month_floor(dt), 1),
+            CalendarUnit::Week => monday_floor(dt) + Duration::days(7),
+            CalendarUnit::Day => day_floor(dt) + Duration::days(1),
+        }
+    }
+}
+
+/// Truncates a datetime to midnight of the most recent Monday.
+fn monday_floor(dt: DateTime<chrono::Utc>) -> DateTime<chrono::Utc> {
+    let days_from_monday = dt.weekday().num_days_from_monday() as i64;
+    day_floor(dt) - Duration::days(days_from_monday)
+}
+
+/// The unit that [`TimeRange::axis_ticks`] steps by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TickUnit {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+/// Builds a UTC datetime at midnight from an optional naive date.
+fn utc_from_date(date: Option<chrono::NaiveDate>) -> DateTime<chrono::Utc> {
+    let naive = date
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .unwrap_or_default();
+    chrono::Utc.from_utc_datetime(&naive)
+}
+
+/// Truncates a datetime to the start of its year.
+fn year_floor(dt: DateTime<chrono::Utc>) -> DateTime<chrono::Utc> {
+    utc_from_date(chrono::NaiveDate::from_ymd_opt(dt.year(), 1, 1))
+}
+
+/// Truncates a datetime to the start of its month.
+fn month_floor(dt: DateTime<chrono::Utc>) -> DateTime<chrono::Utc> {
+    utc_from_date(chrono::NaiveDate::from_ymd_opt(dt.year(), dt.month(), 1))
+}
+
+/// Truncates a datetime to the start of its day.
+fn day_floor(dt: DateTime<chrono::Utc>) -> DateTime<chrono::Utc> {
+    utc_from_date(Some(dt.date_naive()))
+}
+
+/// Adds whole calendar months, clamping to the last valid day of the target
+/// month so leap years and short months stay aligned.
+fn add_months(dt: DateTime<chrono::Utc>, months: u32) -> DateTime<chrono::Utc> {
+    let total = (dt.year() as i64) * 12 + (dt.month() as i64 - 1) + months as i64;
+    let year = (total.div_euclid(12)) as i32;
+    let month = (total.rem_euclid(12)) as u32 + 1;
+
+    // Clamp day to the last day of the resulting month.
+    let last_day = {
+        let (ny, nm) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+        chrono::NaiveDate::from_ymd_opt(ny, nm, 1)
+            .and_then(|d| d.pred_opt())
+            .map(|d| d.day())
+            .unwrap_or(28)
+    };
+    let day = dt.day().min(last_day);
+    utc_from_date(chrono::NaiveDate::from_ymd_opt(year, month, day))
+}
+
+/// Rounds a datetime up to the next multiple of `unit` (for sub-day units).
+fn ceil_duration(dt: DateTime<chrono::Utc>, unit: Duration) -> DateTime<chrono::Utc> {
+    let unit_ns = unit.num_nanoseconds().unwrap_or(1).max(1);
+    let ts = dt.timestamp_nanos_opt().unwrap_or(0);
+    let rem = ts.rem_euclid(unit_ns);
+    if rem == 0 {
+        dt
+    } else {
+        dt + Duration::nanoseconds(unit_ns - rem)
+    }
+}
+
+/// Resolves a naive local datetime to a UTC [`Timestamp`] at the start of a
+/// range, picking the earliest valid instant across DST discontinuities.
+fn local_midnight(naive: chrono::NaiveDateTime, tz: chrono_tz::Tz) -> Timestamp {
+    use chrono::TimeZone as _;
+    let local = tz
+        .from_local_datetime(&naive)
+        .earliest()
+        // Inside a spring-forward gap, the first valid instant is where the
+        // clock lands an hour later.
+        .or_else(|| tz.from_local_datetime(&(naive + Duration::hours(1))).earliest())
+        .unwrap_or_else(|| tz.from_utc_datetime(&naive));
+    let mut ts = Timestamp::new(local.with_timezone(&chrono::Utc));
+    ts.zone = Some(tz);
+    ts
+}
+
+/// Resolves the *exclusive* next-unit boundary to an inclusive end [`Timestamp`]
+/// (one second before local midnight of the following unit).
+fn local_boundary_end(next_start_naive: chrono::NaiveDateTime, tz: chrono_tz::Tz) -> Timestamp {
+    let next = local_midnight(next_start_naive, tz);
+    let mut ts = Timestamp::new(next.datetime - Duration::seconds(1));
+    ts.zone = Some(tz);
+    ts
+}
+
+/// Error returned by the fallible calendar constructors of [`TimeRange`].
+///
+/// Each variant names the field that was out of range, mirroring the `None`
+/// that chrono's `from_ymd_opt`/`and_hms_opt` would have produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeError {
+    /// The year is outside the supported calendar range.
+    Year {
+        /// The offending year.
+        year: i32,
+    },
+    /// The year/month combination is invalid.
+    Month {
+        /// The offending year.
+        year: i32,
+        /// The offending month (1-12).
+        month: u32,
+    },
+    /// The year/month/day combination is invalid.
+    Day {
+        /// The offending year.
+        year: i32,
+        /// The offending month (1-12).
+        month: u32,
+        /// The offending day-of-month.
+        day: u32,
+    },
+}
+
+impl std::fmt::Display for RangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RangeError::Year { year } => write!(f, "invalid year: {year}"),
+            RangeError::Month { year, month } => write!(f, "invalid year-month: {year}-{month:02}"),
+            RangeError::Day { year, month, day } => {
+                write!(f, "invalid date: {year}-{month:02}-{day:02}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_split_by_calendar_months() {
+        let range = TimeRange::new(
+            Timestamp::parse("2024-01-15T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-03-10T00:00:00Z").unwrap(),
+        );
+        let parts = range.split_by_calendar(CalendarUnit::Month);
+        assert_eq!(parts.len(), 3);
+        // Contiguous with no gap beyond the 1s inclusive boundary.
+        for pair in parts.windows(2) {
+            let gap = pair[1].start.datetime - pair[0].end.datetime;
+            assert_eq!(gap.num_seconds(), 1);
+        }
+        assert_eq!(parts[0].start, range.start);
+        assert_eq!(parts.last().unwrap().end, range.end);
+    }
+
+    #[test]
+    fn test_map_coord_time_and_space() {
+        let range = TimeRange::new(
+            Timestamp::parse("2024-01-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-01-03T00:00:00Z").unwrap(),
+        );
+        let mid = Timestamp::parse("2024-01-02T00:00:00Z").unwrap();
+        assert_eq!(range.map_coord(&range.start, (0, 100)), 0);
+        assert_eq!(range.map_coord(&mid, (0, 100)), 50);
+
+        // Zero-length span returns the lower limit.
+        let point = TimeRange::new(range.start.clone(), range.start.clone());
+        assert_eq!(point.map_coord(&mid, (0, 100)), 0);
+
+        let bounds = GeoBounds::new(0.0, 0.0, 10.0, 20.0);
+        let loc = Location::new(5.0, 10.0);
+        assert_eq!(bounds.map_coord(&loc, (0, 200), (0, 100)), (100, 50));
+    }
+
+    #[test]
+    fn test_time_axis_maps_ticks_to_pixels() {
+        let range = TimeRange::new(
+            Timestamp::parse("2024-01-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-01-05T00:00:00Z").unwrap(),
+        );
+        let axis = TimeAxis::new(range.clone(), 0, 400);
+        assert_eq!(axis.map_coord(&range.start), 0);
+        assert_eq!(axis.map_coord(&range.end), 400);
+
+        let ticks = axis.ticks(10);
+        assert!(!ticks.is_empty());
+        // Every tick maps inside the extent and agrees with map_coord.
+        for (ts, x) in &ticks {
+            assert!(*x >= 0 && *x <= 400);
+            assert_eq!(axis.map_coord(ts), *x);
+        }
+    }
+
+    #[test]
+    fn test_axis_ticks_daily() {
+        let range = TimeRange::new(
+            Timestamp::parse("2024-03-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-03-05T00:00:00Z").unwrap(),
+        );
+        let ticks = range.axis_ticks(10);
+        assert!(!ticks.is_empty());
+        // Ticks land on day boundaries and stay within the range.
+        for t in &ticks {
+            assert_eq!(t.datetime.format("%H:%M:%S").to_string(), "00:00:00");
+            assert!(t >= &range.start && t <= &range.end);
+        }
+    }
+
+    #[test]
+    fn test_axis_ticks_monthly_alignment() {
+        let range = TimeRange::new(
+            Timestamp::parse("2024-01-15T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-12-15T00:00:00Z").unwrap(),
+        );
+        let ticks = range.axis_ticks(12);
+        // All ticks fall on the first of a month.
+        for t in &ticks {
+            assert_eq!(t.datetime.day(), 1);
+        }
+    }
+
+    #[test]
+    fn test_timerange_zone_aware_day() {
+        // 23:00 on the 15th in New York is the 16th in UTC, but the local-day
+        // range must still bracket that instant.
+        let tz: chrono_tz::Tz = "America/New_York".parse().unwrap();
+        let range = TimeRange::day_in_zone(2024, 3, 15, tz).unwrap();
+        let evening = Timestamp::parse("2024-03-16T03:00:00Z").unwrap(); // 23:00 local
+        assert!(range.contains(&evening));
+        assert_eq!(range.start.local_date().to_string(), "2024-03-15");
+    }
+
+    #[test]
+    fn test_timerange_try_constructors() {
+        assert!(TimeRange::try_month(2024, 13).is_err());
+        assert!(TimeRange::try_day(2024, 2, 30).is_err());
+        assert!(TimeRange::try_month(2024, 3).is_ok());
+    }
+
     #[test]
     fn test_geobounds_contains() {
         let bounds = GeoBounds::new(37.0, -123.0, 38.5, -121.5);
@@ -434,4 +1131,46 @@ mod tests {
         // Should be approximately 24 hours minus 1 second
         assert!(duration.num_hours() >= 23);
     }
+
+    #[test]
+    fn test_parse_relative_last_and_next() {
+        let now = Timestamp::parse("2024-03-15T12:00:00Z").unwrap();
+
+        let last = TimeRange::parse_relative("last 3 hours", &now).unwrap();
+        assert_eq!(last.end, now);
+        assert_eq!(
+            last.start.datetime,
+            now.datetime - Duration::hours(3)
+        );
+
+        let next = TimeRange::parse_relative("in 30 minutes", &now).unwrap();
+        assert_eq!(next.start, now);
+        assert_eq!(next.end.datetime, now.datetime + Duration::minutes(30));
+    }
+
+    #[test]
+    fn test_parse_relative_anchors() {
+        let now = Timestamp::parse("2024-03-15T12:00:00Z").unwrap();
+
+        let today = TimeRange::parse_relative("today", &now).unwrap();
+        assert_eq!(
+            today.start.datetime,
+            Timestamp::parse("2024-03-15T00:00:00Z").unwrap().datetime
+        );
+        assert_eq!(today.end, now);
+
+        let yesterday = TimeRange::parse_relative("yesterday", &now).unwrap();
+        assert_eq!(
+            yesterday.start.datetime,
+            Timestamp::parse("2024-03-14T00:00:00Z").unwrap().datetime
+        );
+    }
+
+    #[test]
+    fn test_parse_relative_rejects_garbage() {
+        let now = Timestamp::parse("2024-03-15T12:00:00Z").unwrap();
+        assert!(TimeRange::parse_relative("whenever soon", &now).is_none());
+        assert!(TimeRange::parse_relative("last 3 fortnights", &now).is_none());
+        assert!(TimeRange::parse_relative("last 3 hours please", &now).is_none());
+    }
 }
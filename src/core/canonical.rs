@@ -0,0 +1,62 @@
+//! Canonical JSON encoding shared by content-addressing and signing.
+//!
+//! Both [`Event::content_id`](crate::core::Event::content_id) and
+//! [`io::signing`](crate::io) need a byte encoding that is deterministic
+//! regardless of map ordering or whitespace, so that the same logical value
+//! always hashes (or signs) to the same bytes: object keys are sorted
+//! lexicographically, no insignificant whitespace is emitted, and strings
+//! keep serde's JSON escaping rather than being joined with ad hoc
+//! delimiters that a field's own content could collide with.
+
+use serde_json::Value;
+
+/// Recursively encode a JSON value with lexicographically sorted object keys
+/// and no insignificant whitespace.
+pub fn canonical_json(value: &Value) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_canonical(value, &mut out);
+    out
+}
+
+fn write_canonical(value: &Value, out: &mut Vec<u8>) {
+    match value {
+        Value::Object(map) => {
+            let mut sorted: Vec<(&String, &Value)> = map.iter().collect();
+            sorted.sort_by(|a, b| a.0.cmp(b.0));
+            out.push(b'{');
+            for (i, (k, v)) in sorted.iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                // Keys are JSON strings, so serde's string escaping is canonical.
+                out.extend_from_slice(Value::String((*k).clone()).to_string().as_bytes());
+                out.push(b':');
+                write_canonical(v, out);
+            }
+            out.push(b'}');
+        }
+        Value::Array(items) => {
+            out.push(b'[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(b']');
+        }
+        // Scalars already serialize to a single canonical form.
+        other => out.extend_from_slice(other.to_string().as_bytes()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_json_sorts_keys() {
+        let value: Value = serde_json::from_str(r#"{"b":1,"a":{"d":2,"c":3}}"#).unwrap();
+        assert_eq!(canonical_json(&value), br#"{"a":{"c":3,"d":2},"b":1}"#);
+    }
+}
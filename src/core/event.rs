@@ -1,9 +1,13 @@
 //! Event representation - something that happened at a place and time.
 
+use base64::Engine;
+use ed25519_dalek::{Signer, Verifier};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
 
+use super::canonical::canonical_json;
 use crate::core::{Location, SourceRef, Timestamp};
 use crate::error::{Error, Result};
 
@@ -79,7 +83,17 @@ pub struct Event {
     /// Unique identifier.
     pub id: EventId,
     /// Geographic location.
+    ///
+    /// For a trajectory event (see [`path`](Self::path)) this is the
+    /// representative vertex — the centroid of the path.
     pub location: Location,
+    /// Ordered trajectory vertices when the event spans a path rather than a
+    /// single point (imported from a `LineString` or `MultiPoint` feature).
+    ///
+    /// Empty for an ordinary point event; when non-empty, [`location`](Self::location)
+    /// is the representative centroid of these vertices.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<Location>,
     /// When the event occurred.
     pub timestamp: Timestamp,
     /// Description of the event.
@@ -93,6 +107,52 @@ pub struct Event {
     /// Categorical tags.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
+    /// Recurrence rule, when this event represents a repeating activity.
+    ///
+    /// This event is the anchor occurrence; use
+    /// [`Narrative::expand_recurrences`](crate::core::Narrative::expand_recurrences)
+    /// to materialize the concrete occurrences it produces within a window.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<crate::core::Recurrence>,
+    /// Ed25519 signature over [`content_id`](Self::content_id), attesting to
+    /// this event's authorship and tamper-evidence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<EventSignature>,
+    /// References to other events in the same narrative (e.g. "follow-up
+    /// to", "caused by"), as opposed to [`sources`](Self::sources), which
+    /// point to external material.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<EventRef>,
+}
+
+/// A reference from one event to another, naming the relationship between
+/// them (e.g. `"follow-up to"`, `"caused by"`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventRef {
+    /// The referenced event.
+    pub target: EventId,
+    /// The relationship this event has to `target`.
+    pub relation: String,
+}
+
+impl EventRef {
+    /// Creates a new event reference.
+    pub fn new(target: EventId, relation: impl Into<String>) -> Self {
+        Self {
+            target,
+            relation: relation.into(),
+        }
+    }
+}
+
+/// An Ed25519 signature over an event's [`content_id`](Event::content_id),
+/// carrying the public key needed to verify it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventSignature {
+    /// Base64url (no padding) encoding of the 32-byte Ed25519 public key.
+    pub public_key: String,
+    /// Hex-encoded Ed25519 signature bytes.
+    pub signature: String,
 }
 
 impl Event {
@@ -101,11 +161,15 @@ impl Event {
         Self {
             id: EventId::new(),
             location,
+            path: Vec::new(),
             timestamp,
             text: text.into(),
             metadata: HashMap::new(),
             sources: Vec::new(),
             tags: Vec::new(),
+            recurrence: None,
+            signature: None,
+            references: Vec::new(),
         }
     }
 
@@ -147,10 +211,145 @@ impl Event {
         self.sources.push(source);
     }
 
+    /// Scans [`text`](Self::text) for `#`-prefixed alphanumeric tokens (e.g.
+    /// `#downtown`), strips the `#`, lowercases them, and merges them into
+    /// [`tags`](Self::tags) without duplicates via [`add_tag`](Self::add_tag).
+    ///
+    /// A `#` only starts a hashtag at a word boundary — at the start of the
+    /// text or preceded by a non-alphanumeric character — so mid-word usages
+    /// like `C#` or `foo#bar` are left alone.
+    pub fn extract_hashtags(&mut self) {
+        for tag in hashtags(&self.text) {
+            self.add_tag(tag);
+        }
+    }
+
     /// Returns the location as a geo-types Point.
     pub fn to_geo_point(&self) -> geo_types::Point<f64> {
         self.location.to_geo_point()
     }
+
+    /// Returns true when this event carries a multi-vertex [`path`](Self::path).
+    pub fn is_trajectory(&self) -> bool {
+        !self.path.is_empty()
+    }
+
+    /// Returns true when the event falls within the spatial window `bounds`.
+    ///
+    /// A trajectory event intersects when any of its vertices (or its
+    /// representative location) lies inside `bounds`, so a path passing through
+    /// the window is not missed. Intended as a cheap pre-filter before fuller
+    /// processing.
+    pub fn intersects_bbox(&self, bounds: &crate::core::GeoBounds) -> bool {
+        bounds.contains(&self.location) || self.path.iter().any(|v| bounds.contains(v))
+    }
+
+    /// Returns true when the event's timestamp falls within `range`.
+    ///
+    /// A cheap temporal pre-filter mirroring [`intersects_bbox`](Self::intersects_bbox).
+    pub fn intersects_datetime(&self, range: &crate::core::TimeRange) -> bool {
+        range.contains(&self.timestamp)
+    }
+
+    /// Resolves this event's timezone from its [`location`](Self::location)
+    /// via [`resolve_timezone`](crate::core::resolve_timezone) and attaches it
+    /// to [`timestamp.zone`](Timestamp::zone), unless a zone is already set.
+    ///
+    /// A no-op (returning `false`) when [`timestamp.zone`](Timestamp::zone) is
+    /// already `Some`, so re-resolving never clobbers a zone the source
+    /// explicitly recorded.
+    pub fn resolve_timezone(&mut self) -> bool {
+        if self.timestamp.zone.is_some() {
+            return false;
+        }
+        match crate::core::resolve_timezone_for(&self.location) {
+            Some(tz) => {
+                self.timestamp.zone = Some(tz);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Deterministic SHA-256 over this event's canonical content: its
+    /// location (rounded to 7 decimal places, ~1cm), RFC3339 timestamp,
+    /// text, and sorted tags/metadata. `id` and `signature` are excluded, so
+    /// two independent ingests of the same real-world event — or the same
+    /// event re-signed — produce the same content id.
+    pub fn content_id(&self) -> [u8; 32] {
+        Sha256::digest(self.canonical_bytes()).into()
+    }
+
+    /// Builds the canonical byte form hashed by [`content_id`](Self::content_id).
+    ///
+    /// Delegates to [`canonical_json`](super::canonical_json) rather than an
+    /// ad hoc delimited string, so that fields can never be shifted into one
+    /// another (e.g. a tag containing `,` colliding with the tag separator)
+    /// and the encoding stays injective.
+    fn canonical_bytes(&self) -> Vec<u8> {
+        let mut tags = self.tags.clone();
+        tags.sort();
+
+        let metadata: BTreeMap<&str, &str> = self
+            .metadata
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+
+        let value = serde_json::json!({
+            "lat": format!("{:.7}", self.location.lat),
+            "lon": format!("{:.7}", self.location.lon),
+            "timestamp": self.timestamp.to_rfc3339(),
+            "text": self.text,
+            "tags": tags,
+            "metadata": metadata,
+        });
+
+        canonical_json(&value)
+    }
+
+    /// Signs this event's [`content_id`](Self::content_id), attaching the
+    /// resulting [`EventSignature`].
+    pub fn sign(&mut self, key: &ed25519_dalek::SigningKey) {
+        let sig = key.sign(&self.content_id());
+        self.signature = Some(EventSignature {
+            public_key: base64::engine::general_purpose::URL_SAFE_NO_PAD
+                .encode(key.verifying_key().as_bytes()),
+            signature: hex::encode(sig.to_bytes()),
+        });
+    }
+
+    /// Verifies the attached [`signature`](Self::signature) against the
+    /// recomputed [`content_id`](Self::content_id).
+    ///
+    /// Returns `Ok(false)` when no signature is attached. Returns an error
+    /// if the signature is attached but malformed (bad encoding, wrong key
+    /// length); returns `Ok(false)` if it is well-formed but does not verify.
+    pub fn verify(&self) -> Result<bool> {
+        let Some(sig) = &self.signature else {
+            return Ok(false);
+        };
+
+        let key_bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(&sig.public_key)
+            .map_err(|e| Error::ParseError(format!("invalid signature public key: {e}")))?;
+        let key_array: [u8; 32] = key_bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| Error::ParseError("public key must be 32 bytes".to_string()))?;
+        let verifying_key = ed25519_dalek::VerifyingKey::from_bytes(&key_array)
+            .map_err(|e| Error::ParseError(format!("invalid public key: {e}")))?;
+
+        let sig_bytes = hex::decode(&sig.signature)
+            .map_err(|e| Error::ParseError(format!("invalid signature hex: {e}")))?;
+        let sig_array: [u8; 64] = sig_bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| Error::ParseError("signature must be 64 bytes".to_string()))?;
+        let signature = ed25519_dalek::Signature::from_bytes(&sig_array);
+
+        Ok(verifying_key.verify(&self.content_id(), &signature).is_ok())
+    }
 }
 
 /// Builder for constructing [`Event`] instances.
@@ -158,11 +357,15 @@ impl Event {
 pub struct EventBuilder {
     id: Option<EventId>,
     location: Option<Location>,
+    path: Vec<Location>,
     timestamp: Option<Timestamp>,
     text: Option<String>,
     metadata: HashMap<String, String>,
     sources: Vec<SourceRef>,
     tags: Vec<String>,
+    recurrence: Option<crate::core::Recurrence>,
+    extract_hashtags: bool,
+    references: Vec<EventRef>,
 }
 
 impl EventBuilder {
@@ -189,6 +392,18 @@ impl EventBuilder {
         self
     }
 
+    /// Sets the trajectory path for an event that spans several vertices.
+    ///
+    /// When no [`location`](Self::location) has been set, the representative
+    /// location defaults to the centroid of the supplied vertices.
+    pub fn path(mut self, path: impl IntoIterator<Item = Location>) -> Self {
+        self.path = path.into_iter().collect();
+        if self.location.is_none() {
+            self.location = centroid(&self.path);
+        }
+        self
+    }
+
     /// Sets the timestamp.
     pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
         self.timestamp = Some(timestamp);
@@ -237,20 +452,56 @@ impl EventBuilder {
         self
     }
 
+    /// Sets the recurrence rule, making this event the anchor of a repeating series.
+    pub fn recurrence(mut self, recurrence: crate::core::Recurrence) -> Self {
+        self.recurrence = Some(recurrence);
+        self
+    }
+
+    /// Enables automatic hashtag extraction: on [`build`](Self::build) /
+    /// [`try_build`](Self::try_build), `#`-prefixed tokens in `text` are
+    /// parsed out via [`Event::extract_hashtags`] and merged into `tags`.
+    pub fn extract_hashtags(mut self) -> Self {
+        self.extract_hashtags = true;
+        self
+    }
+
+    /// Adds a reference to another event, naming the relationship (e.g.
+    /// `"follow-up to"`, `"caused by"`).
+    pub fn reference(mut self, target: EventId, relation: impl Into<String>) -> Self {
+        self.references.push(EventRef::new(target, relation));
+        self
+    }
+
+    /// Adds multiple references to other events.
+    pub fn references(mut self, references: impl IntoIterator<Item = EventRef>) -> Self {
+        self.references.extend(references);
+        self
+    }
+
     /// Builds the Event.
     ///
     /// Uses current time if timestamp is not set.
     /// Uses empty string if text is not set.
     pub fn build(self) -> Event {
-        Event {
+        let extract_hashtags = self.extract_hashtags;
+        let mut event = Event {
             id: self.id.unwrap_or_default(),
             location: self.location.unwrap_or_default(),
+            path: self.path,
             timestamp: self.timestamp.unwrap_or_else(Timestamp::now),
             text: self.text.unwrap_or_default(),
             metadata: self.metadata,
             sources: self.sources,
             tags: self.tags,
+            recurrence: self.recurrence,
+            signature: None,
+            references: self.references,
+        };
+        if extract_hashtags {
+            event.extract_hashtags();
         }
+        event
     }
 
     /// Builds the Event, returning an error if required fields are missing.
@@ -258,17 +509,67 @@ impl EventBuilder {
         let location = self.location.ok_or(Error::MissingField("location"))?;
         let timestamp = self.timestamp.ok_or(Error::MissingField("timestamp"))?;
         let text = self.text.ok_or(Error::MissingField("text"))?;
+        let extract_hashtags = self.extract_hashtags;
 
-        Ok(Event {
+        let mut event = Event {
             id: self.id.unwrap_or_default(),
             location,
+            path: self.path,
             timestamp,
             text,
             metadata: self.metadata,
             sources: self.sources,
             tags: self.tags,
-        })
+            recurrence: self.recurrence,
+            signature: None,
+            references: self.references,
+        };
+        if extract_hashtags {
+            event.extract_hashtags();
+        }
+        Ok(event)
+    }
+}
+
+/// Finds `#`-prefixed alphanumeric tokens in `text`, lowercased and with the
+/// `#` stripped. A `#` only starts a token at a word boundary, so mid-word
+/// usages are ignored.
+fn hashtags(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tags = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == '#' && (i == 0 || !chars[i - 1].is_alphanumeric()) {
+            let start = i + 1;
+            let mut end = start;
+            while end < chars.len() && chars[end].is_alphanumeric() {
+                end += 1;
+            }
+            if end > start {
+                tags.push(chars[start..end].iter().collect::<String>().to_lowercase());
+            }
+            i = end;
+        } else {
+            i += 1;
+        }
     }
+    tags
+}
+
+/// Returns the arithmetic centroid of a set of vertices, or `None` when empty.
+fn centroid(path: &[Location]) -> Option<Location> {
+    if path.is_empty() {
+        return None;
+    }
+    let n = path.len() as f64;
+    let lat = path.iter().map(|l| l.lat).sum::<f64>() / n;
+    let lon = path.iter().map(|l| l.lon).sum::<f64>() / n;
+    let mut loc = Location::new(lat, lon);
+    // Preserve an average elevation when every vertex carries one.
+    if path.iter().all(|l| l.elevation.is_some()) {
+        loc.elevation = Some(path.iter().filter_map(|l| l.elevation).sum::<f64>() / n);
+    }
+    Some(loc)
 }
 
 #[cfg(test)]
@@ -357,6 +658,44 @@ mod tests {
         assert_eq!(event.get_metadata("key2"), Some("value2"));
     }
 
+    #[test]
+    fn test_event_resolve_timezone_sets_zone_from_location() {
+        let mut event = Event::new(
+            Location::new(35.6762, 139.6503),
+            Timestamp::parse("2024-03-15T14:30:00Z").unwrap(),
+            "Test event",
+        );
+        assert!(event.resolve_timezone());
+        assert_eq!(event.timestamp.zone, Some(chrono_tz::Asia::Tokyo));
+    }
+
+    #[test]
+    fn test_event_resolve_timezone_does_not_overwrite_existing_zone() {
+        let mut event = Event::new(
+            Location::new(35.6762, 139.6503),
+            Timestamp::with_zone(
+                Timestamp::parse("2024-03-15T14:30:00Z").unwrap().datetime,
+                chrono_tz::America::New_York,
+            ),
+            "Test event",
+        );
+        assert!(!event.resolve_timezone());
+        assert_eq!(event.timestamp.zone, Some(chrono_tz::America::New_York));
+    }
+
+    #[test]
+    fn test_event_builder_recurrence() {
+        let rule = crate::core::Recurrence::new(crate::core::Freq::Daily, 1).count(5);
+        let event = Event::builder()
+            .location(Location::new(0.0, 0.0))
+            .timestamp(Timestamp::now())
+            .text("standup")
+            .recurrence(rule.clone())
+            .build();
+
+        assert_eq!(event.recurrence, Some(rule));
+    }
+
     #[test]
     fn test_event_serialization() {
         let event = Event::builder()
@@ -372,4 +711,146 @@ mod tests {
         assert_eq!(event.text, parsed.text);
         assert_eq!(event.location.lat, parsed.location.lat);
     }
+
+    fn build_event() -> Event {
+        Event::builder()
+            .location(Location::new(40.7128, -74.0060))
+            .timestamp(Timestamp::parse("2024-03-15T14:30:00Z").unwrap())
+            .text("Protest at City Hall")
+            .tag("protest")
+            .tag("politics")
+            .metadata("participants", "1000")
+            .build()
+    }
+
+    #[test]
+    fn test_content_id_is_deterministic_and_ignores_id() {
+        let a = build_event();
+        let b = build_event();
+        // Each carries its own independently generated random id...
+        assert_ne!(a.id, b.id);
+        // ...but identical content still hashes to the same content id.
+        assert_eq!(a.content_id(), b.content_id());
+    }
+
+    #[test]
+    fn test_content_id_is_order_independent_for_tags_and_metadata() {
+        let a = Event::builder()
+            .location(Location::new(40.7128, -74.0060))
+            .timestamp(Timestamp::parse("2024-03-15T14:30:00Z").unwrap())
+            .text("Protest at City Hall")
+            .tag("protest")
+            .tag("politics")
+            .metadata("participants", "1000")
+            .build();
+
+        let b = Event::builder()
+            .location(Location::new(40.7128, -74.0060))
+            .timestamp(Timestamp::parse("2024-03-15T14:30:00Z").unwrap())
+            .text("Protest at City Hall")
+            .tag("politics")
+            .tag("protest")
+            .metadata("participants", "1000")
+            .build();
+
+        assert_eq!(a.content_id(), b.content_id());
+    }
+
+    #[test]
+    fn test_content_id_does_not_collide_across_tag_boundaries() {
+        let a = Event::builder()
+            .location(Location::new(40.7128, -74.0060))
+            .timestamp(Timestamp::parse("2024-03-15T14:30:00Z").unwrap())
+            .text("Protest at City Hall")
+            .tag("a,b")
+            .build();
+
+        let b = Event::builder()
+            .location(Location::new(40.7128, -74.0060))
+            .timestamp(Timestamp::parse("2024-03-15T14:30:00Z").unwrap())
+            .text("Protest at City Hall")
+            .tag("a")
+            .tag("b")
+            .build();
+
+        assert_ne!(a.content_id(), b.content_id());
+    }
+
+    #[test]
+    fn test_content_id_changes_with_text() {
+        let a = build_event();
+        let mut b = build_event();
+        b.text = "A different event".to_string();
+
+        assert_ne!(a.content_id(), b.content_id());
+    }
+
+    #[test]
+    fn test_sign_and_verify_roundtrip() {
+        let key = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+        let mut event = build_event();
+        event.sign(&key);
+
+        assert!(event.signature.is_some());
+        assert!(event.verify().unwrap());
+    }
+
+    #[test]
+    fn test_verify_detects_tampering() {
+        let key = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+        let mut event = build_event();
+        event.sign(&key);
+
+        event.text = "Tampered text".to_string();
+        assert!(!event.verify().unwrap());
+    }
+
+    #[test]
+    fn test_verify_returns_false_without_signature() {
+        let event = build_event();
+        assert!(!event.verify().unwrap());
+    }
+
+    #[test]
+    fn test_extract_hashtags_merges_into_tags() {
+        let mut event = Event::builder()
+            .location(Location::new(0.0, 0.0))
+            .timestamp(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+            .text("Flooding near #downtown #Emergency")
+            .build();
+
+        event.extract_hashtags();
+
+        assert!(event.has_tag("downtown"));
+        assert!(event.has_tag("emergency"));
+        assert_eq!(event.tags.len(), 2);
+    }
+
+    #[test]
+    fn test_extract_hashtags_ignores_mid_word_and_dedupes() {
+        let mut event = Event::builder()
+            .location(Location::new(0.0, 0.0))
+            .timestamp(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+            .text("Learning C# and #coding, more #coding tips")
+            .tag("coding")
+            .build();
+
+        event.extract_hashtags();
+
+        // "C#" is mid-word, so only "coding" is recognized, and it was
+        // already present as a manual tag, so no duplicate is added.
+        assert_eq!(event.tags, vec!["coding"]);
+    }
+
+    #[test]
+    fn test_builder_extract_hashtags_toggle() {
+        let event = Event::builder()
+            .location(Location::new(0.0, 0.0))
+            .timestamp(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+            .text("Protest at #cityhall")
+            .extract_hashtags()
+            .build();
+
+        assert!(event.has_tag("cityhall"));
+    }
 }
@@ -0,0 +1,564 @@
+//! A small composable filter language for querying narratives.
+//!
+//! Expressions combine field predicates and geo primitives with the boolean
+//! operators `AND`, `OR`, and `NOT`, for example:
+//!
+//! ```text
+//! tag = "landfall" AND _geoRadius(25.0, -80.0, 50000) AND timestamp > "2024-03-15T00:00:00Z"
+//! ```
+//!
+//! [`Filter::parse`] turns such a string into an AST, and
+//! [`Filter::evaluate`] runs a node against a single [`Event`]. The whole query
+//! is usually driven through [`Narrative::query`](crate::core::Narrative::query),
+//! which returns a new narrative of the matching events.
+
+use crate::core::{Event, GeoBounds, Location, Timestamp};
+use crate::{Error, Result};
+
+/// Maximum parser recursion depth, guarding against pathological nesting.
+const MAX_DEPTH: usize = 2000;
+
+/// Comparison operator for a field condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparator {
+    /// `=`
+    Eq,
+    /// `!=`
+    Ne,
+    /// `<`
+    Lt,
+    /// `>`
+    Gt,
+}
+
+/// The event field a condition applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    /// Matches against the event's tags.
+    Tag,
+    /// Matches against the event's text.
+    Text,
+    /// Matches against the `category` metadata entry.
+    Category,
+    /// Matches against the event's timestamp.
+    Timestamp,
+}
+
+impl Field {
+    fn parse(name: &str) -> Result<Field> {
+        match name {
+            "tag" => Ok(Field::Tag),
+            "text" => Ok(Field::Text),
+            "category" => Ok(Field::Category),
+            "timestamp" => Ok(Field::Timestamp),
+            other => Err(Error::InvalidFormat(format!("unknown filter field {:?}", other))),
+        }
+    }
+}
+
+/// A parsed filter expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    /// Both sub-filters must match.
+    And(Box<Filter>, Box<Filter>),
+    /// Either sub-filter must match.
+    Or(Box<Filter>, Box<Filter>),
+    /// The sub-filter must not match.
+    Not(Box<Filter>),
+    /// A single field comparison.
+    Condition {
+        /// Field under test.
+        field: Field,
+        /// Comparison operator.
+        op: Comparator,
+        /// Right-hand value (verbatim from the query).
+        value: String,
+    },
+    /// An inclusive range `field low TO high`.
+    Range {
+        /// Field under test.
+        field: Field,
+        /// Inclusive lower bound.
+        low: String,
+        /// Inclusive upper bound.
+        high: String,
+    },
+    /// Keeps events within `meters` of `(lat, lon)` by great-circle distance.
+    GeoRadius {
+        /// Centre latitude.
+        lat: f64,
+        /// Centre longitude.
+        lon: f64,
+        /// Radius in metres.
+        meters: f64,
+    },
+    /// Keeps events inside the axis-aligned box.
+    GeoBoundingBox {
+        /// North-east corner `(lat, lon)`.
+        top_right: (f64, f64),
+        /// South-west corner `(lat, lon)`.
+        bottom_left: (f64, f64),
+    },
+}
+
+impl Filter {
+    /// Parse a filter expression from a string.
+    pub fn parse(input: &str) -> Result<Filter> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let filter = parser.parse_or(0)?;
+        if parser.pos != parser.tokens.len() {
+            return Err(Error::InvalidFormat(
+                "trailing tokens after filter expression".to_string(),
+            ));
+        }
+        Ok(filter)
+    }
+
+    /// Evaluate this filter against a single event.
+    pub fn evaluate(&self, event: &Event) -> bool {
+        match self {
+            Filter::And(a, b) => a.evaluate(event) && b.evaluate(event),
+            Filter::Or(a, b) => a.evaluate(event) || b.evaluate(event),
+            Filter::Not(inner) => !inner.evaluate(event),
+            Filter::Condition { field, op, value } => eval_condition(*field, *op, value, event),
+            Filter::Range { field, low, high } => eval_range(*field, low, high, event),
+            Filter::GeoRadius { lat, lon, meters } => {
+                haversine_meters(&event.location, *lat, *lon) <= *meters
+            }
+            Filter::GeoBoundingBox {
+                top_right,
+                bottom_left,
+            } => GeoBounds::new(bottom_left.0, bottom_left.1, top_right.0, top_right.1)
+                .contains(&event.location),
+        }
+    }
+}
+
+fn eval_condition(field: Field, op: Comparator, value: &str, event: &Event) -> bool {
+    match field {
+        Field::Tag => match op {
+            Comparator::Eq => event.has_tag(value),
+            Comparator::Ne => !event.has_tag(value),
+            _ => false,
+        },
+        Field::Text => compare_str(&event.text, value, op),
+        Field::Category => match event.metadata.get("category") {
+            Some(category) => compare_str(category, value, op),
+            None => op == Comparator::Ne,
+        },
+        Field::Timestamp => match Timestamp::parse(value) {
+            Ok(bound) => compare_timestamp(&event.timestamp, &bound, op),
+            Err(_) => false,
+        },
+    }
+}
+
+fn eval_range(field: Field, low: &str, high: &str, event: &Event) -> bool {
+    match field {
+        Field::Timestamp => match (Timestamp::parse(low), Timestamp::parse(high)) {
+            (Ok(lo), Ok(hi)) => event.timestamp >= lo && event.timestamp <= hi,
+            _ => false,
+        },
+        Field::Text => event.text.as_str() >= low && event.text.as_str() <= high,
+        Field::Category => match event.metadata.get("category") {
+            Some(c) => c.as_str() >= low && c.as_str() <= high,
+            None => false,
+        },
+        Field::Tag => event.tags.iter().any(|t| t.as_str() >= low && t.as_str() <= high),
+    }
+}
+
+fn compare_str(actual: &str, expected: &str, op: Comparator) -> bool {
+    match op {
+        Comparator::Eq => actual == expected,
+        Comparator::Ne => actual != expected,
+        Comparator::Lt => actual < expected,
+        Comparator::Gt => actual > expected,
+    }
+}
+
+fn compare_timestamp(actual: &Timestamp, bound: &Timestamp, op: Comparator) -> bool {
+    match op {
+        Comparator::Eq => actual == bound,
+        Comparator::Ne => actual != bound,
+        Comparator::Lt => actual < bound,
+        Comparator::Gt => actual > bound,
+    }
+}
+
+/// Great-circle distance from `loc` to `(lat, lon)` in metres.
+fn haversine_meters(loc: &Location, lat: f64, lon: f64) -> f64 {
+    const EARTH_RADIUS_M: f64 = 6_371_000.0;
+    let (lat1, lon1) = (loc.lat.to_radians(), loc.lon.to_radians());
+    let (lat2, lon2) = (lat.to_radians(), lon.to_radians());
+    let dlat = lat2 - lat1;
+    let dlon = lon2 - lon1;
+    let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+    EARTH_RADIUS_M * 2.0 * a.sqrt().asin()
+}
+
+// ---- Tokenizer ----
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Num(f64),
+    Eq,
+    Ne,
+    Lt,
+    Gt,
+    And,
+    Or,
+    Not,
+    To,
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Comma,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '[' => {
+                tokens.push(Token::LBracket);
+                i += 1;
+            }
+            ']' => {
+                tokens.push(Token::RBracket);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            '=' => {
+                tokens.push(Token::Eq);
+                i += 1;
+            }
+            '<' => {
+                tokens.push(Token::Lt);
+                i += 1;
+            }
+            '>' => {
+                tokens.push(Token::Gt);
+                i += 1;
+            }
+            '!' => {
+                if chars.get(i + 1) == Some(&'=') {
+                    tokens.push(Token::Ne);
+                    i += 2;
+                } else {
+                    return Err(Error::InvalidFormat("expected '=' after '!'".to_string()));
+                }
+            }
+            '"' => {
+                let mut value = String::new();
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    value.push(chars[i]);
+                    i += 1;
+                }
+                if i >= chars.len() {
+                    return Err(Error::InvalidFormat("unterminated string literal".to_string()));
+                }
+                i += 1; // closing quote
+                tokens.push(Token::Str(value));
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '+' => {
+                let start = i;
+                i += 1;
+                while i < chars.len()
+                    && (chars[i].is_ascii_digit() || matches!(chars[i], '.' | 'e' | 'E' | '-' | '+'))
+                {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let num = text
+                    .parse::<f64>()
+                    .map_err(|_| Error::InvalidFormat(format!("invalid number {:?}", text)))?;
+                tokens.push(Token::Num(num));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                i += 1;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                tokens.push(match word.as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    "TO" => Token::To,
+                    _ => Token::Ident(word),
+                });
+            }
+            other => {
+                return Err(Error::InvalidFormat(format!(
+                    "unexpected character {:?} in filter",
+                    other
+                )))
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+// ---- Parser ----
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        if token.is_some() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn eat(&mut self, expected: &Token) -> bool {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: &Token) -> Result<()> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(Error::InvalidFormat(format!("expected {:?}", expected)))
+        }
+    }
+
+    fn guard(&self, depth: usize) -> Result<()> {
+        if depth > MAX_DEPTH {
+            return Err(Error::InvalidFormat(
+                "filter expression nested too deeply".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn parse_or(&mut self, depth: usize) -> Result<Filter> {
+        self.guard(depth)?;
+        let mut left = self.parse_and(depth + 1)?;
+        while self.eat(&Token::Or) {
+            let right = self.parse_and(depth + 1)?;
+            left = Filter::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self, depth: usize) -> Result<Filter> {
+        self.guard(depth)?;
+        let mut left = self.parse_not(depth + 1)?;
+        while self.eat(&Token::And) {
+            let right = self.parse_not(depth + 1)?;
+            left = Filter::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_not(&mut self, depth: usize) -> Result<Filter> {
+        self.guard(depth)?;
+        if self.eat(&Token::Not) {
+            Ok(Filter::Not(Box::new(self.parse_not(depth + 1)?)))
+        } else {
+            self.parse_primary(depth + 1)
+        }
+    }
+
+    fn parse_primary(&mut self, depth: usize) -> Result<Filter> {
+        self.guard(depth)?;
+        if self.eat(&Token::LParen) {
+            let inner = self.parse_or(depth + 1)?;
+            self.expect(&Token::RParen)?;
+            return Ok(inner);
+        }
+
+        match self.peek() {
+            Some(Token::Ident(name)) if name == "_geoRadius" => self.parse_geo_radius(),
+            Some(Token::Ident(name)) if name == "_geoBoundingBox" => self.parse_geo_bbox(),
+            _ => self.parse_condition(),
+        }
+    }
+
+    fn parse_geo_radius(&mut self) -> Result<Filter> {
+        self.next(); // _geoRadius
+        self.expect(&Token::LParen)?;
+        let lat = self.expect_num()?;
+        self.expect(&Token::Comma)?;
+        let lon = self.expect_num()?;
+        self.expect(&Token::Comma)?;
+        let meters = self.expect_num()?;
+        self.expect(&Token::RParen)?;
+        Ok(Filter::GeoRadius { lat, lon, meters })
+    }
+
+    fn parse_geo_bbox(&mut self) -> Result<Filter> {
+        self.next(); // _geoBoundingBox
+        self.expect(&Token::LParen)?;
+        let top_right = self.parse_coord_pair()?;
+        self.expect(&Token::Comma)?;
+        let bottom_left = self.parse_coord_pair()?;
+        self.expect(&Token::RParen)?;
+
+        if top_right.0 < bottom_left.0 {
+            return Err(Error::InvalidFormat(
+                "_geoBoundingBox top-right latitude is below bottom-left latitude".to_string(),
+            ));
+        }
+
+        Ok(Filter::GeoBoundingBox {
+            top_right,
+            bottom_left,
+        })
+    }
+
+    fn parse_coord_pair(&mut self) -> Result<(f64, f64)> {
+        self.expect(&Token::LBracket)?;
+        let lat = self.expect_num()?;
+        self.expect(&Token::Comma)?;
+        let lon = self.expect_num()?;
+        self.expect(&Token::RBracket)?;
+        Ok((lat, lon))
+    }
+
+    fn parse_condition(&mut self) -> Result<Filter> {
+        let field = match self.next() {
+            Some(Token::Ident(name)) => Field::parse(&name)?,
+            other => {
+                return Err(Error::InvalidFormat(format!(
+                    "expected a field name, found {:?}",
+                    other
+                )))
+            }
+        };
+
+        let op = match self.peek() {
+            Some(Token::Eq) => Some(Comparator::Eq),
+            Some(Token::Ne) => Some(Comparator::Ne),
+            Some(Token::Lt) => Some(Comparator::Lt),
+            Some(Token::Gt) => Some(Comparator::Gt),
+            _ => None,
+        };
+
+        if let Some(op) = op {
+            self.next();
+            let value = self.expect_value()?;
+            return Ok(Filter::Condition { field, op, value });
+        }
+
+        // Range form: field low TO high.
+        let low = self.expect_value()?;
+        self.expect(&Token::To)?;
+        let high = self.expect_value()?;
+        Ok(Filter::Range { field, low, high })
+    }
+
+    fn expect_num(&mut self) -> Result<f64> {
+        match self.next() {
+            Some(Token::Num(n)) => Ok(n),
+            other => Err(Error::InvalidFormat(format!("expected a number, found {:?}", other))),
+        }
+    }
+
+    fn expect_value(&mut self) -> Result<String> {
+        match self.next() {
+            Some(Token::Str(s)) => Ok(s),
+            Some(Token::Num(n)) => Ok(n.to_string()),
+            Some(Token::Ident(s)) => Ok(s),
+            other => Err(Error::InvalidFormat(format!("expected a value, found {:?}", other))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(text: &str, lat: f64, lon: f64, time: &str, tags: &[&str]) -> Event {
+        Event::builder()
+            .location(Location::new(lat, lon))
+            .timestamp(Timestamp::parse(time).unwrap())
+            .text(text)
+            .tags(tags.iter().map(|t| t.to_string()))
+            .build()
+    }
+
+    #[test]
+    fn test_parse_and_evaluate_compound() {
+        let filter = Filter::parse(
+            "tag = \"landfall\" AND _geoRadius(25.0, -80.0, 50000) AND timestamp > \"2024-03-15T00:00:00Z\"",
+        )
+        .unwrap();
+
+        let hit = event("storm", 25.1, -80.1, "2024-03-16T00:00:00Z", &["landfall"]);
+        let miss_tag = event("storm", 25.1, -80.1, "2024-03-16T00:00:00Z", &["other"]);
+        let miss_time = event("storm", 25.1, -80.1, "2024-03-14T00:00:00Z", &["landfall"]);
+
+        assert!(filter.evaluate(&hit));
+        assert!(!filter.evaluate(&miss_tag));
+        assert!(!filter.evaluate(&miss_time));
+    }
+
+    #[test]
+    fn test_not_and_or_precedence() {
+        let filter = Filter::parse("text = \"a\" OR NOT tag = \"x\"").unwrap();
+        let e = event("b", 0.0, 0.0, "2024-01-01T00:00:00Z", &["y"]);
+        assert!(filter.evaluate(&e));
+    }
+
+    #[test]
+    fn test_geo_bounding_box_validation() {
+        // top-right latitude below bottom-left latitude is rejected.
+        let err = Filter::parse("_geoBoundingBox([10.0, 20.0], [30.0, 5.0])");
+        assert!(err.is_err());
+
+        let ok = Filter::parse("_geoBoundingBox([30.0, 20.0], [10.0, 5.0])").unwrap();
+        let inside = event("x", 20.0, 10.0, "2024-01-01T00:00:00Z", &[]);
+        let outside = event("x", 40.0, 10.0, "2024-01-01T00:00:00Z", &[]);
+        assert!(ok.evaluate(&inside));
+        assert!(!ok.evaluate(&outside));
+    }
+
+    #[test]
+    fn test_timestamp_range() {
+        let filter =
+            Filter::parse("timestamp \"2024-01-01T00:00:00Z\" TO \"2024-12-31T00:00:00Z\"").unwrap();
+        let inside = event("x", 0.0, 0.0, "2024-06-01T00:00:00Z", &[]);
+        let outside = event("x", 0.0, 0.0, "2025-06-01T00:00:00Z", &[]);
+        assert!(filter.evaluate(&inside));
+        assert!(!filter.evaluate(&outside));
+    }
+}
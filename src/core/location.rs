@@ -35,21 +35,98 @@ use serde::{Deserialize, Serialize};
 /// - Latitude: -90° to +90° (negative = South)
 /// - Longitude: -180° to +180° (negative = West)
 /// - Elevation: meters above sea level (optional)
+/// Coordinate reference system a [`Location`]'s `lat`/`lon` are expressed in.
+///
+/// `#[non_exhaustive]` because more datums may be supported later; today the
+/// only variant is [`Crs::Wgs84`], which is also the default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[non_exhaustive]
+pub enum Crs {
+    /// WGS84 (EPSG:4326), the reference system used throughout this crate.
+    #[default]
+    Wgs84,
+}
+
+impl Crs {
+    /// Validates `(lat, lon)` against this CRS's coordinate ranges.
+    pub fn validate(&self, lat: f64, lon: f64) -> Result<()> {
+        match self {
+            Crs::Wgs84 => {
+                if !(-90.0..=90.0).contains(&lat) {
+                    return Err(Error::InvalidLatitude(lat));
+                }
+                if !(-180.0..=180.0).contains(&lon) {
+                    return Err(Error::InvalidLongitude(lon));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Location {
     /// Latitude in decimal degrees (-90 to 90).
-    pub lat: f64,
+    ///
+    /// Private so it can't be reassigned without revalidating; use
+    /// [`lat`](Self::lat)/[`set_lat`](Self::set_lat).
+    pub(crate) lat: f64,
     /// Longitude in decimal degrees (-180 to 180).
-    pub lon: f64,
+    ///
+    /// Private so it can't be reassigned without revalidating; use
+    /// [`lon`](Self::lon)/[`set_lon`](Self::set_lon).
+    pub(crate) lon: f64,
     /// Elevation in meters above sea level.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub elevation: Option<f64>,
+    pub(crate) elevation: Option<f64>,
+    /// Instantaneous ground speed in meters per second, for GPS/tracker data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f64>,
+    /// Heading (course over ground) in degrees clockwise from true north.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<f64>,
     /// Uncertainty radius in meters.
+    ///
+    /// Private so a negative uncertainty can't be assigned directly; use
+    /// [`uncertainty_meters`](Self::uncertainty_meters)/
+    /// [`set_uncertainty_meters`](Self::set_uncertainty_meters).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub uncertainty_meters: Option<f64>,
+    pub(crate) uncertainty_meters: Option<f64>,
     /// Human-readable place name.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub(crate) name: Option<String>,
+    /// Coordinate reference system `lat`/`lon` are expressed in.
+    #[serde(default)]
+    pub crs: Crs,
+}
+
+/// Coordinate reference system a [`Location`]'s `lat`/`lon` are expressed in.
+///
+/// `#[non_exhaustive]` because more datums may be supported later; today the
+/// only variant is [`Crs::Wgs84`], which is also the default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[non_exhaustive]
+pub enum Crs {
+    /// WGS84 (EPSG:4326), the reference system used throughout this crate.
+    #[default]
+    Wgs84,
+}
+
+impl Crs {
+    /// Validates `(lat, lon)` against this CRS's coordinate ranges.
+    pub fn validate(&self, lat: f64, lon: f64) -> Result<()> {
+        match self {
+            Crs::Wgs84 => {
+                if !(-90.0..=90.0).contains(&lat) {
+                    return Err(Error::InvalidLatitude(lat));
+                }
+                if !(-180.0..=180.0).contains(&lon) {
+                    return Err(Error::InvalidLongitude(lon));
+                }
+                Ok(())
+            }
+        }
+    }
 }
 
 impl Location {
@@ -72,8 +149,11 @@ impl Location {
             lat,
             lon,
             elevation: None,
+            speed: None,
+            heading: None,
             uncertainty_meters: None,
             name: None,
+            crs: Crs::default(),
         }
     }
 
@@ -89,8 +169,11 @@ impl Location {
             lat,
             lon,
             elevation: Some(elevation),
+            speed: None,
+            heading: None,
             uncertainty_meters: None,
             name: None,
+            crs: Crs::default(),
         }
     }
 
@@ -99,23 +182,17 @@ impl Location {
         LocationBuilder::new()
     }
 
-    /// Checks if the coordinates are valid WGS84 values.
+    /// Checks if the coordinates are valid for this location's [`Crs`].
     ///
-    /// Returns `true` if latitude is between -90 and 90,
+    /// For WGS84, that means latitude is between -90 and 90,
     /// and longitude is between -180 and 180.
     pub fn is_valid(&self) -> bool {
-        self.lat >= -90.0 && self.lat <= 90.0 && self.lon >= -180.0 && self.lon <= 180.0
+        self.crs.validate(self.lat, self.lon).is_ok()
     }
 
     /// Validates the location, returning an error if invalid.
     pub fn validate(&self) -> Result<()> {
-        if self.lat < -90.0 || self.lat > 90.0 {
-            return Err(Error::InvalidLatitude(self.lat));
-        }
-        if self.lon < -180.0 || self.lon > 180.0 {
-            return Err(Error::InvalidLongitude(self.lon));
-        }
-        Ok(())
+        self.crs.validate(self.lat, self.lon)
     }
 
     /// Returns the coordinates as a tuple (lat, lon).
@@ -123,6 +200,68 @@ impl Location {
         (self.lat, self.lon)
     }
 
+    /// Latitude in decimal degrees.
+    pub fn lat(&self) -> f64 {
+        self.lat
+    }
+
+    /// Longitude in decimal degrees.
+    pub fn lon(&self) -> f64 {
+        self.lon
+    }
+
+    /// Elevation in meters above sea level, if known.
+    pub fn elevation(&self) -> Option<f64> {
+        self.elevation
+    }
+
+    /// Uncertainty radius in meters, if known.
+    pub fn uncertainty_meters(&self) -> Option<f64> {
+        self.uncertainty_meters
+    }
+
+    /// Human-readable place name, if known.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Sets the latitude, rejecting a value out of range for this location's
+    /// [`Crs`] and leaving the existing latitude unchanged on error.
+    pub fn set_lat(&mut self, lat: f64) -> Result<()> {
+        self.crs.validate(lat, self.lon)?;
+        self.lat = lat;
+        Ok(())
+    }
+
+    /// Sets the longitude, rejecting a value out of range for this location's
+    /// [`Crs`] and leaving the existing longitude unchanged on error.
+    pub fn set_lon(&mut self, lon: f64) -> Result<()> {
+        self.crs.validate(self.lat, lon)?;
+        self.lon = lon;
+        Ok(())
+    }
+
+    /// Sets the elevation in meters above sea level.
+    pub fn set_elevation(&mut self, elevation: Option<f64>) {
+        self.elevation = elevation;
+    }
+
+    /// Sets the uncertainty radius in meters, rejecting a negative value.
+    pub fn set_uncertainty_meters(&mut self, uncertainty: Option<f64>) -> Result<()> {
+        if let Some(u) = uncertainty {
+            if u < 0.0 {
+                return Err(Error::InvalidFormat("uncertainty must not be negative".to_string()));
+            }
+        }
+        self.uncertainty_meters = uncertainty;
+        Ok(())
+    }
+
+    /// Sets the human-readable place name.
+    pub fn set_name(&mut self, name: Option<String>) {
+        self.name = name;
+    }
+
     /// Returns the coordinates as a geo-types Point.
     pub fn to_geo_point(&self) -> geo_types::Point<f64> {
         geo_types::Point::new(self.lon, self.lat)
@@ -132,6 +271,182 @@ impl Location {
     pub fn from_geo_point(point: geo_types::Point<f64>) -> Self {
         Self::new(point.y(), point.x())
     }
+
+    /// Great-circle (haversine) distance to `other`, in meters, using
+    /// Earth radius R = 6,371,000 m.
+    pub fn haversine_distance(&self, other: &Location) -> f64 {
+        let r = 6_371_000.0_f64;
+        let (phi1, phi2) = (self.lat.to_radians(), other.lat.to_radians());
+        let dphi = (other.lat - self.lat).to_radians();
+        let dlambda = (other.lon - self.lon).to_radians();
+        let a = (dphi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (dlambda / 2.0).sin().powi(2);
+        let c = 2.0 * a.sqrt().atan2((1.0 - a).sqrt());
+        r * c
+    }
+
+    /// Forward azimuth (initial bearing) from `self` to `other`, in degrees
+    /// clockwise from true north, normalized to `[0, 360)`.
+    pub fn initial_bearing(&self, other: &Location) -> f64 {
+        let (phi1, phi2) = (self.lat.to_radians(), other.lat.to_radians());
+        let dlambda = (other.lon - self.lon).to_radians();
+        let y = dlambda.sin() * phi2.cos();
+        let x = phi1.cos() * phi2.sin() - phi1.sin() * phi2.cos() * dlambda.cos();
+        let theta = y.atan2(x).to_degrees();
+        (theta + 360.0) % 360.0
+    }
+
+    /// Sum of `self` and `other`'s uncertainty radii in meters, if both are
+    /// known — useful for judging whether a measured
+    /// [`haversine_distance`](Self::haversine_distance) between two
+    /// uncertain locations is actually significant.
+    pub fn combined_uncertainty_meters(&self, other: &Location) -> Option<f64> {
+        Some(self.uncertainty_meters? + other.uncertainty_meters?)
+    }
+
+    /// Renders this location as an [RFC 5870](https://www.rfc-editor.org/rfc/rfc5870)
+    /// `geo:` URI: `geo:<lat>,<lon>[,<altitude>][;u=<uncertainty>]`.
+    ///
+    /// Altitude and the `u=` uncertainty parameter are included only when
+    /// [`elevation`](Self::elevation)/[`uncertainty_meters`](Self::uncertainty_meters)
+    /// are set. `crs=wgs84` is omitted since it's the default. The
+    /// [`name`](Self::name) field has no geo-URI representation and is dropped.
+    pub fn to_geo_uri(&self) -> String {
+        let mut uri = format!("geo:{},{}", self.lat, self.lon);
+        if let Some(elevation) = self.elevation {
+            uri.push_str(&format!(",{}", elevation));
+        }
+        if let Some(uncertainty) = self.uncertainty_meters {
+            uri.push_str(&format!(";u={}", uncertainty));
+        }
+        uri
+    }
+
+    /// Parses an [RFC 5870](https://www.rfc-editor.org/rfc/rfc5870) `geo:` URI
+    /// into a [`Location`].
+    ///
+    /// Accepts `geo:<lat>,<lon>[,<altitude>][;u=<uncertainty>][;crs=wgs84]`;
+    /// `crs` is case-insensitive and only `wgs84` is accepted. Altitude maps
+    /// to [`elevation`](Self::elevation) and `u=` to
+    /// [`uncertainty_meters`](Self::uncertainty_meters); the
+    /// [`name`](Self::name) field has no geo-URI representation and is left
+    /// `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::MissingScheme` without a leading `geo:`,
+    /// `Error::InvalidCoord` for a missing or unparsable latitude,
+    /// longitude, or altitude, `Error::InvalidFormat` for a negative
+    /// uncertainty or unsupported `crs`, and
+    /// `Error::InvalidLatitude`/`Error::InvalidLongitude` for an
+    /// out-of-range coordinate.
+    pub fn from_geo_uri(s: &str) -> Result<Self> {
+        let body = s.strip_prefix("geo:").ok_or(Error::MissingScheme)?;
+
+        let mut sections = body.split(';');
+        let coords = sections.next().unwrap_or("");
+
+        let mut numbers = coords.split(',');
+        let lat: f64 = numbers
+            .next()
+            .filter(|s| !s.is_empty())
+            .ok_or(Error::InvalidCoord)?
+            .parse()
+            .map_err(|_| Error::InvalidCoord)?;
+        let lon: f64 = numbers
+            .next()
+            .filter(|s| !s.is_empty())
+            .ok_or(Error::InvalidCoord)?
+            .parse()
+            .map_err(|_| Error::InvalidCoord)?;
+        let elevation = numbers
+            .next()
+            .map(|alt| alt.parse::<f64>().map_err(|_| Error::InvalidCoord))
+            .transpose()?;
+
+        let mut location = Location::new(lat, lon);
+        location.elevation = elevation;
+
+        for param in sections {
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+            match key {
+                "u" => {
+                    let uncertainty: f64 = value
+                        .parse()
+                        .map_err(|_| Error::InvalidFormat(format!("invalid uncertainty: {}", value)))?;
+                    if uncertainty < 0.0 {
+                        return Err(Error::InvalidFormat("uncertainty must not be negative".to_string()));
+                    }
+                    location.uncertainty_meters = Some(uncertainty);
+                }
+                "crs" => {
+                    if !value.eq_ignore_ascii_case("wgs84") {
+                        return Err(Error::InvalidFormat(format!("unsupported crs: {}", value)));
+                    }
+                    location.crs = Crs::Wgs84;
+                }
+                _ => {}
+            }
+        }
+
+        location.validate()?;
+        Ok(location)
+    }
+}
+
+/// Converts a [`Location`] into a `geo:` [`url::Url`](url::Url), via
+/// [`to_geo_uri`](Location::to_geo_uri). Requires the `url` feature.
+#[cfg(feature = "url")]
+impl From<&Location> for url::Url {
+    fn from(location: &Location) -> Self {
+        location
+            .to_geo_uri()
+            .parse()
+            .expect("geo URI serialization always produces a valid URL")
+    }
+}
+
+#[cfg(feature = "url")]
+impl From<Location> for url::Url {
+    fn from(location: Location) -> Self {
+        Self::from(&location)
+    }
+}
+
+/// Parses a `geo:` [`url::Url`](url::Url) into a [`Location`], via
+/// [`from_geo_uri`](Location::from_geo_uri). Requires the `url` feature.
+///
+/// # Errors
+///
+/// Returns `Error::MissingScheme` if the URL's scheme isn't `geo`, and
+/// otherwise whatever [`from_geo_uri`](Location::from_geo_uri) would return
+/// for the URL's string form.
+#[cfg(feature = "url")]
+impl TryFrom<&url::Url> for Location {
+    type Error = Error;
+
+    fn try_from(url: &url::Url) -> Result<Self> {
+        if url.scheme() != "geo" {
+            return Err(Error::MissingScheme);
+        }
+        Self::from_geo_uri(url.as_str())
+    }
+}
+
+#[cfg(feature = "url")]
+impl TryFrom<url::Url> for Location {
+    type Error = Error;
+
+    fn try_from(url: url::Url) -> Result<Self> {
+        Self::try_from(&url)
+    }
+}
+
+impl std::str::FromStr for Location {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_geo_uri(s)
+    }
 }
 
 impl Default for Location {
@@ -158,8 +473,11 @@ pub struct LocationBuilder {
     lat: Option<f64>,
     lon: Option<f64>,
     elevation: Option<f64>,
+    speed: Option<f64>,
+    heading: Option<f64>,
     uncertainty_meters: Option<f64>,
     name: Option<String>,
+    crs: Option<Crs>,
 }
 
 impl LocationBuilder {
@@ -193,6 +511,18 @@ impl LocationBuilder {
         self
     }
 
+    /// Sets the ground speed in meters per second.
+    pub fn speed(mut self, speed: f64) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+
+    /// Sets the heading in degrees clockwise from true north.
+    pub fn heading(mut self, heading: f64) -> Self {
+        self.heading = Some(heading);
+        self
+    }
+
     /// Sets the uncertainty radius in meters.
     pub fn uncertainty_meters(mut self, uncertainty: f64) -> Self {
         self.uncertainty_meters = Some(uncertainty);
@@ -205,6 +535,12 @@ impl LocationBuilder {
         self
     }
 
+    /// Sets the coordinate reference system.
+    pub fn crs(mut self, crs: Crs) -> Self {
+        self.crs = Some(crs);
+        self
+    }
+
     /// Builds the Location, returning an error if required fields are missing.
     pub fn build(self) -> Result<Location> {
         let lat = self.lat.ok_or(Error::MissingField("lat"))?;
@@ -214,8 +550,11 @@ impl LocationBuilder {
             lat,
             lon,
             elevation: self.elevation,
+            speed: self.speed,
+            heading: self.heading,
             uncertainty_meters: self.uncertainty_meters,
             name: self.name,
+            crs: self.crs.unwrap_or_default(),
         };
 
         location.validate()?;
@@ -302,4 +641,220 @@ mod tests {
         let parsed: Location = serde_json::from_str(&json).unwrap();
         assert_eq!(loc, parsed);
     }
+
+    #[test]
+    fn test_to_geo_uri_minimal() {
+        let loc = Location::new(40.7128, -74.006);
+        assert_eq!(loc.to_geo_uri(), "geo:40.7128,-74.006");
+    }
+
+    #[test]
+    fn test_to_geo_uri_with_elevation_and_uncertainty() {
+        let mut loc = Location::with_elevation(27.9881, 86.925, 8848.86);
+        loc.uncertainty_meters = Some(10.0);
+        assert_eq!(loc.to_geo_uri(), "geo:27.9881,86.925,8848.86;u=10");
+    }
+
+    #[test]
+    fn test_from_geo_uri_minimal_roundtrips() {
+        let loc = Location::from_geo_uri("geo:40.7128,-74.006").unwrap();
+        assert_eq!(loc.lat, 40.7128);
+        assert_eq!(loc.lon, -74.006);
+        assert_eq!(loc.elevation, None);
+        assert_eq!(loc.uncertainty_meters, None);
+        assert_eq!(loc.name, None);
+    }
+
+    #[test]
+    fn test_from_geo_uri_with_altitude_uncertainty_and_crs() {
+        let loc = Location::from_geo_uri("geo:27.9881,86.9250,8848.86;u=10;crs=WGS84").unwrap();
+        assert_eq!(loc.lat, 27.9881);
+        assert_eq!(loc.lon, 86.9250);
+        assert_eq!(loc.elevation, Some(8848.86));
+        assert_eq!(loc.uncertainty_meters, Some(10.0));
+    }
+
+    #[test]
+    fn test_from_geo_uri_missing_scheme() {
+        let result = Location::from_geo_uri("40.7128,-74.006");
+        assert!(matches!(result, Err(Error::MissingScheme)));
+    }
+
+    #[test]
+    fn test_from_geo_uri_missing_longitude() {
+        let result = Location::from_geo_uri("geo:40.7128");
+        assert!(matches!(result, Err(Error::InvalidCoord)));
+    }
+
+    #[test]
+    fn test_from_geo_uri_unparsable_coordinate() {
+        let result = Location::from_geo_uri("geo:abc,-74.006");
+        assert!(matches!(result, Err(Error::InvalidCoord)));
+    }
+
+    #[test]
+    fn test_from_geo_uri_negative_uncertainty() {
+        let result = Location::from_geo_uri("geo:40.7128,-74.006;u=-5");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_geo_uri_out_of_range_latitude() {
+        let result = Location::from_geo_uri("geo:95.0,-74.006");
+        assert!(matches!(result, Err(Error::InvalidLatitude(_))));
+    }
+
+    #[test]
+    fn test_from_geo_uri_unsupported_crs() {
+        let result = Location::from_geo_uri("geo:40.7128,-74.006;crs=nad83");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_str_parses_geo_uri() {
+        let loc: Location = "geo:40.7128,-74.006".parse().unwrap();
+        assert_eq!(loc.lat, 40.7128);
+    }
+
+    #[test]
+    fn test_location_defaults_to_wgs84_crs() {
+        let loc = Location::new(40.7128, -74.006);
+        assert_eq!(loc.crs, Crs::Wgs84);
+    }
+
+    #[test]
+    fn test_crs_validate_rejects_out_of_range_coordinates() {
+        assert!(Crs::Wgs84.validate(40.0, 0.0).is_ok());
+        assert!(matches!(Crs::Wgs84.validate(91.0, 0.0), Err(Error::InvalidLatitude(_))));
+        assert!(matches!(Crs::Wgs84.validate(0.0, 181.0), Err(Error::InvalidLongitude(_))));
+    }
+
+    #[test]
+    fn test_location_builder_sets_crs() {
+        let loc = Location::builder()
+            .coordinates(40.7128, -74.006)
+            .crs(Crs::Wgs84)
+            .build()
+            .unwrap();
+        assert_eq!(loc.crs, Crs::Wgs84);
+    }
+
+    #[test]
+    fn test_location_deserializes_without_crs_field() {
+        let json = r#"{"lat":40.7128,"lon":-74.006}"#;
+        let loc: Location = serde_json::from_str(json).unwrap();
+        assert_eq!(loc.crs, Crs::Wgs84);
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn test_location_to_url_roundtrips_through_geo_uri() {
+        let loc = Location::new(40.7128, -74.006);
+        let url: url::Url = (&loc).into();
+        assert_eq!(url.as_str(), "geo:40.7128,-74.006");
+        let parsed: Location = (&url).try_into().unwrap();
+        assert_eq!(parsed, loc);
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn test_location_try_from_url_rejects_wrong_scheme() {
+        let url = url::Url::parse("https://example.com/40.7128,-74.006").unwrap();
+        let result = Location::try_from(url);
+        assert!(matches!(result, Err(Error::MissingScheme)));
+    }
+
+    #[test]
+    fn test_location_getters() {
+        let loc = Location::builder()
+            .coordinates(40.7128, -74.006)
+            .elevation(10.0)
+            .uncertainty_meters(5.0)
+            .name("NYC")
+            .build()
+            .unwrap();
+
+        assert_eq!(loc.lat(), 40.7128);
+        assert_eq!(loc.lon(), -74.006);
+        assert_eq!(loc.elevation(), Some(10.0));
+        assert_eq!(loc.uncertainty_meters(), Some(5.0));
+        assert_eq!(loc.name(), Some("NYC"));
+    }
+
+    #[test]
+    fn test_set_lat_rejects_out_of_range_value() {
+        let mut loc = Location::new(40.7128, -74.006);
+        let result = loc.set_lat(91.0);
+        assert!(matches!(result, Err(Error::InvalidLatitude(_))));
+        assert_eq!(loc.lat(), 40.7128);
+    }
+
+    #[test]
+    fn test_set_lon_accepts_valid_value() {
+        let mut loc = Location::new(40.7128, -74.006);
+        loc.set_lon(-73.0).unwrap();
+        assert_eq!(loc.lon(), -73.0);
+    }
+
+    #[test]
+    fn test_set_uncertainty_meters_rejects_negative_value() {
+        let mut loc = Location::new(40.7128, -74.006);
+        let result = loc.set_uncertainty_meters(Some(-1.0));
+        assert!(result.is_err());
+        assert_eq!(loc.uncertainty_meters(), None);
+    }
+
+    #[test]
+    fn test_haversine_distance_nyc_to_dc() {
+        let nyc = Location::new(40.7128, -74.006);
+        let dc = Location::new(38.9072, -77.0369);
+        let distance = nyc.haversine_distance(&dc);
+        assert!((distance - 327_000.0).abs() < 5_000.0);
+    }
+
+    #[test]
+    fn test_haversine_distance_is_zero_for_same_point() {
+        let loc = Location::new(40.7128, -74.006);
+        assert_eq!(loc.haversine_distance(&loc), 0.0);
+    }
+
+    #[test]
+    fn test_initial_bearing_due_north() {
+        let a = Location::new(0.0, 0.0);
+        let b = Location::new(10.0, 0.0);
+        assert!((a.initial_bearing(&b) - 0.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_initial_bearing_due_east() {
+        let a = Location::new(0.0, 0.0);
+        let b = Location::new(0.0, 10.0);
+        assert!((a.initial_bearing(&b) - 90.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_initial_bearing_normalized_into_0_360() {
+        let a = Location::new(0.0, 0.0);
+        let b = Location::new(0.0, -10.0);
+        let bearing = a.initial_bearing(&b);
+        assert!((0.0..360.0).contains(&bearing));
+        assert!((bearing - 270.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_combined_uncertainty_meters_sums_when_both_known() {
+        let mut a = Location::new(0.0, 0.0);
+        a.set_uncertainty_meters(Some(10.0)).unwrap();
+        let mut b = Location::new(1.0, 1.0);
+        b.set_uncertainty_meters(Some(5.0)).unwrap();
+        assert_eq!(a.combined_uncertainty_meters(&b), Some(15.0));
+    }
+
+    #[test]
+    fn test_combined_uncertainty_meters_none_when_either_unknown() {
+        let a = Location::new(0.0, 0.0);
+        let mut b = Location::new(1.0, 1.0);
+        b.set_uncertainty_meters(Some(5.0)).unwrap();
+        assert_eq!(a.combined_uncertainty_meters(&b), None);
+    }
 }
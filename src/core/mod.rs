@@ -6,19 +6,31 @@
 //! - [`Event`] - Something that happened at a place and time
 //! - [`Narrative`] - A collection of related events
 //! - [`SourceRef`] - Reference to source material
+//! - [`resolve_timezone`] - Coarse IANA zone lookup from coordinates
 
 mod bounds;
+mod canonical;
 mod event;
+mod filter;
 mod location;
 mod narrative;
+mod recurrence;
 mod source;
+mod time_backend;
 mod timestamp;
+mod timezone;
 mod traits;
 
-pub use bounds::{GeoBounds, TimeRange};
-pub use event::{Event, EventBuilder, EventId};
-pub use location::{Location, LocationBuilder};
+pub use bounds::{CalendarUnit, GeoBounds, RangeError, TimeAxis, TimeRange};
+pub use canonical::canonical_json;
+pub use event::{Event, EventBuilder, EventId, EventRef, EventSignature};
+pub use filter::{Comparator, Field, Filter};
+pub use location::{Crs, Location, LocationBuilder};
 pub use narrative::{Narrative, NarrativeBuilder, NarrativeId, NarrativeMetadata};
+pub use recurrence::{expand, occurrence_timestamps, Freq, Recurrence};
 pub use source::{SourceRef, SourceType};
-pub use timestamp::{TemporalPrecision, Timestamp};
+pub use timestamp::{
+    CalendarDuration, SubsecondFormat, TemporalPrecision, TimeScale, Timestamp, TimestampRange,
+};
+pub use timezone::{resolve_timezone, resolve_timezone_for};
 pub use traits::{SpatialEntity, TemporalEntity};
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::core::{Event, EventId, GeoBounds, TimeRange, Timestamp};
+use crate::core::{Event, EventId, Filter, GeoBounds, TimeRange, Timestamp};
 use crate::error::{Error, Result};
 
 /// Unique identifier for a narrative.
@@ -212,6 +212,26 @@ impl Narrative {
             .collect()
     }
 
+    /// Returns events that intersect the spatial window `bounds`.
+    ///
+    /// Unlike [`filter_spatial`](Self::filter_spatial), which tests only the
+    /// representative location, this also matches a trajectory event when any of
+    /// its path vertices falls inside `bounds`.
+    pub fn intersecting_bbox(&self, bounds: &GeoBounds) -> Vec<&Event> {
+        self.events
+            .iter()
+            .filter(|e| e.intersects_bbox(bounds))
+            .collect()
+    }
+
+    /// Returns events whose timestamp intersects `range`.
+    pub fn intersecting_datetime(&self, range: &TimeRange) -> Vec<&Event> {
+        self.events
+            .iter()
+            .filter(|e| e.intersects_datetime(range))
+            .collect()
+    }
+
     /// Filters events by tag.
     pub fn filter_by_tag(&self, tag: &str) -> Vec<&Event> {
         self.events.iter().filter(|e| e.has_tag(tag)).collect()
@@ -219,7 +239,13 @@ impl Narrative {
 
     /// Returns the geographic bounds of all events.
     pub fn bounds(&self) -> Option<GeoBounds> {
-        let locations: Vec<_> = self.events.iter().map(|e| &e.location).collect();
+        // Include every trajectory vertex, not just the representative location,
+        // so a path-shaped event contributes its full extent.
+        let locations: Vec<_> = self
+            .events
+            .iter()
+            .flat_map(|e| std::iter::once(&e.location).chain(e.path.iter()))
+            .collect();
         GeoBounds::from_locations(locations)
     }
 
@@ -284,11 +310,54 @@ impl Narrative {
         }
     }
 
+    /// Run a filter-language query and return the matching events as a new narrative.
+    ///
+    /// The expression is parsed into a [`Filter`] and evaluated against every
+    /// event; see the [`filter`](crate::core::filter) module for the grammar.
+    /// This is the composable entry point that unifies the single-dimension
+    /// [`filter_spatial`](Self::filter_spatial),
+    /// [`filter_temporal`](Self::filter_temporal), and
+    /// [`filter_by_tag`](Self::filter_by_tag) helpers.
+    pub fn query(&self, expression: &str) -> Result<Narrative> {
+        let filter = Filter::parse(expression)?;
+        Ok(self.filter(|event| filter.evaluate(event)))
+    }
+
     /// Merges another narrative into this one.
     pub fn merge(&mut self, other: Narrative) {
         self.events.extend(other.events);
         self.metadata.modified = Some(Timestamp::now());
     }
+
+    /// Creates a new narrative with every recurring event replaced by the
+    /// concrete occurrences it produces within `[range_start, range_end]`.
+    ///
+    /// Events carrying a [`recurrence`](Event::recurrence) rule are expanded
+    /// via [`expand`](crate::core::expand); events without one pass through
+    /// unchanged. The anchor event itself is not included unless `expand`
+    /// produces an occurrence at its own timestamp.
+    pub fn expand_recurrences(&self, range_start: Timestamp, range_end: Timestamp) -> Narrative {
+        let mut events = Vec::new();
+        for event in &self.events {
+            match &event.recurrence {
+                Some(rule) => {
+                    events.extend(crate::core::expand(
+                        event,
+                        rule,
+                        (range_start.clone(), range_end.clone()),
+                    ));
+                }
+                None => events.push(event.clone()),
+            }
+        }
+        Narrative {
+            id: NarrativeId::new(),
+            title: format!("{} (expanded)", self.title),
+            events,
+            metadata: NarrativeMetadata::with_created_now(),
+            tags: self.tags.clone(),
+        }
+    }
 }
 
 impl Default for Narrative {
@@ -499,6 +568,35 @@ mod tests {
         assert_eq!(sorted[2].text, "Third");
     }
 
+    #[test]
+    fn test_narrative_expand_recurrences() {
+        let mut narrative = Narrative::new("Test");
+        let recurring = Event::builder()
+            .location(Location::new(40.0, -74.0))
+            .timestamp(Timestamp::parse("2024-03-01T08:00:00Z").unwrap())
+            .text("standup")
+            .recurrence(crate::core::Recurrence::new(crate::core::Freq::Daily, 1).count(3))
+            .build();
+        narrative.add_event(recurring);
+        narrative.add_event(make_event(41.0, -75.0, "2024-03-05T09:00:00Z", "one-off"));
+
+        let expanded = narrative.expand_recurrences(
+            Timestamp::parse("2024-03-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-04-01T00:00:00Z").unwrap(),
+        );
+
+        assert_eq!(expanded.events.len(), 4);
+        assert_eq!(
+            expanded
+                .events
+                .iter()
+                .filter(|e| e.text == "standup")
+                .count(),
+            3
+        );
+        assert!(expanded.events.iter().any(|e| e.text == "one-off"));
+    }
+
     #[test]
     fn test_narrative_serialization() {
         let narrative = Narrative::builder()
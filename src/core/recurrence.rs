@@ -0,0 +1,523 @@
+//! Recurrence rules for expanding a single [`Event`] into concrete occurrences.
+
+use chrono::{Datelike, Timelike, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Event, Timestamp};
+use crate::error::{Error, Result};
+
+/// How often a [`Recurrence`] repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Freq {
+    /// Repeat every `interval` days.
+    Daily,
+    /// Repeat every `interval` weeks.
+    Weekly,
+    /// Repeat every `interval` months.
+    Monthly,
+    /// Repeat every `interval` years.
+    Yearly,
+}
+
+/// A recurrence rule describing how an [`Event`] repeats.
+///
+/// Mirrors the handful of RFC 5545 `RRULE` fields needed to model repeating
+/// activities (a daily commute, a weekly market) so the realized occurrences
+/// can be fed into time-series analysis. Use [`expand`] to turn a rule and an
+/// anchor event into the concrete [`Event`]s it produces,
+/// [`occurrence_timestamps`] for just the bare instants (e.g. for indexing a
+/// non-`Event` item at each occurrence), or [`Recurrence::parse_rrule`] to
+/// build a rule straight from an RFC 5545 `RRULE` string.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recurrence {
+    /// The base repetition frequency.
+    pub freq: Freq,
+    /// Number of frequency units between occurrences (must be positive).
+    pub interval: u32,
+    /// Stop after this many occurrences have been emitted, if set.
+    pub count: Option<u32>,
+    /// Stop once an occurrence would fall after this instant (inclusive), if set.
+    pub until: Option<Timestamp>,
+    /// For [`Freq::Weekly`], emit one occurrence per listed weekday within
+    /// each stepped week instead of a single occurrence on the anchor's
+    /// weekday. Ignored for other frequencies.
+    pub by_weekday: Vec<Weekday>,
+    /// Keep only occurrences landing on one of these days-of-month, if non-empty.
+    pub by_monthday: Vec<u32>,
+}
+
+impl Recurrence {
+    /// Create a rule with the given frequency and interval and no other bounds.
+    ///
+    /// `interval` is clamped to at least 1 so expansion always makes progress.
+    pub fn new(freq: Freq, interval: u32) -> Self {
+        Self {
+            freq,
+            interval: interval.max(1),
+            count: None,
+            until: None,
+            by_weekday: Vec::new(),
+            by_monthday: Vec::new(),
+        }
+    }
+
+    /// Stop after `count` occurrences.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Stop once an occurrence would pass `until` (inclusive).
+    pub fn until(mut self, until: Timestamp) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Restrict weekly occurrences to the given weekdays.
+    pub fn by_weekday(mut self, weekdays: impl IntoIterator<Item = Weekday>) -> Self {
+        self.by_weekday = weekdays.into_iter().collect();
+        self
+    }
+
+    /// Restrict occurrences to the given days-of-month.
+    pub fn by_monthday(mut self, days: impl IntoIterator<Item = u32>) -> Self {
+        self.by_monthday = days.into_iter().collect();
+        self
+    }
+
+    /// Parse an RFC 5545 `RRULE` string (the part after `RRULE:`, if present)
+    /// into a [`Recurrence`].
+    ///
+    /// Supports the `FREQ` (`DAILY`/`WEEKLY`/`MONTHLY`/`YEARLY`), `INTERVAL`,
+    /// `COUNT`, `UNTIL`, and `BYDAY` fields; any other field is ignored.
+    /// `UNTIL` is parsed with [`Timestamp::parse`], accepting both the basic
+    /// `YYYYMMDDTHHMMSSZ` form RFC 5545 specifies and a plain RFC 3339 instant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_narrative::core::{Freq, Recurrence};
+    ///
+    /// let rule = Recurrence::parse_rrule("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE,FR;COUNT=10").unwrap();
+    /// assert_eq!(rule.freq, Freq::Weekly);
+    /// assert_eq!(rule.interval, 2);
+    /// assert_eq!(rule.count, Some(10));
+    /// ```
+    pub fn parse_rrule(rule: &str) -> Result<Self> {
+        let body = rule.strip_prefix("RRULE:").unwrap_or(rule);
+
+        let mut freq = None;
+        let mut interval = 1u32;
+        let mut count = None;
+        let mut until = None;
+        let mut by_weekday = Vec::new();
+
+        for field in body.split(';') {
+            let field = field.trim();
+            if field.is_empty() {
+                continue;
+            }
+            let (key, value) = field
+                .split_once('=')
+                .ok_or_else(|| Error::ParseError(format!("malformed RRULE field: {}", field)))?;
+
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    freq = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Freq::Daily,
+                        "WEEKLY" => Freq::Weekly,
+                        "MONTHLY" => Freq::Monthly,
+                        "YEARLY" => Freq::Yearly,
+                        other => {
+                            return Err(Error::ParseError(format!("unsupported RRULE FREQ: {}", other)))
+                        }
+                    });
+                }
+                "INTERVAL" => {
+                    interval = value
+                        .parse()
+                        .map_err(|_| Error::ParseError(format!("invalid RRULE INTERVAL: {}", value)))?;
+                }
+                "COUNT" => {
+                    count = Some(
+                        value
+                            .parse()
+                            .map_err(|_| Error::ParseError(format!("invalid RRULE COUNT: {}", value)))?,
+                    );
+                }
+                "UNTIL" => {
+                    until = Some(parse_rrule_until(value)?);
+                }
+                "BYDAY" => {
+                    by_weekday = value
+                        .split(',')
+                        .map(parse_rrule_weekday)
+                        .collect::<Result<Vec<_>>>()?;
+                }
+                _ => {}
+            }
+        }
+
+        let freq = freq.ok_or_else(|| Error::ParseError("RRULE missing required FREQ field".to_string()))?;
+        let mut recurrence = Recurrence::new(freq, interval).by_weekday(by_weekday);
+        if let Some(count) = count {
+            recurrence = recurrence.count(count);
+        }
+        if let Some(until) = until {
+            recurrence = recurrence.until(until);
+        }
+        Ok(recurrence)
+    }
+}
+
+/// Parse an RRULE `UNTIL` value, accepting both the RFC 5545 basic form
+/// (`YYYYMMDDTHHMMSSZ`) and a plain RFC 3339 instant.
+fn parse_rrule_until(value: &str) -> Result<Timestamp> {
+    if let Ok(ts) = Timestamp::parse(value) {
+        return Ok(ts);
+    }
+    // RFC 5545 basic form: "20240315T140000Z" -> "2024-03-15T14:00:00Z".
+    if value.len() == 16 && value.ends_with('Z') {
+        let rfc3339 = format!(
+            "{}-{}-{}T{}:{}:{}Z",
+            &value[0..4],
+            &value[4..6],
+            &value[6..8],
+            &value[9..11],
+            &value[11..13],
+            &value[13..15],
+        );
+        return Timestamp::parse(&rfc3339);
+    }
+    Err(Error::ParseError(format!("invalid RRULE UNTIL: {}", value)))
+}
+
+/// Parse one RFC 5545 `BYDAY` token (`MO`, `TU`, …) into a [`Weekday`].
+///
+/// Numeric prefixes (`1MO`, `-1FR`, used for "first Monday"/"last Friday" in
+/// `MONTHLY`/`YEARLY` rules) are not supported; only the bare two-letter
+/// weekday code is recognized.
+fn parse_rrule_weekday(token: &str) -> Result<Weekday> {
+    match token.trim().to_ascii_uppercase().as_str() {
+        "MO" => Ok(Weekday::Mon),
+        "TU" => Ok(Weekday::Tue),
+        "WE" => Ok(Weekday::Wed),
+        "TH" => Ok(Weekday::Thu),
+        "FR" => Ok(Weekday::Fri),
+        "SA" => Ok(Weekday::Sat),
+        "SU" => Ok(Weekday::Sun),
+        other => Err(Error::ParseError(format!("invalid RRULE BYDAY value: {}", other))),
+    }
+}
+
+/// Expand `event` into its concrete recurring occurrences inside `window`.
+///
+/// Delegates to [`occurrence_timestamps`] for the instants, then wraps each in
+/// a clone of `event` with its own [`EventId`](crate::core::EventId) — see
+/// that function for the expansion semantics. Each generated [`Event`] keeps
+/// the anchor's location, path, text, tags, sources, and metadata.
+pub fn expand(event: &Event, rule: &Recurrence, window: (Timestamp, Timestamp)) -> Vec<Event> {
+    occurrence_timestamps(&event.timestamp, rule, window)
+        .into_iter()
+        .map(|timestamp| {
+            let mut occurrence = event.clone();
+            occurrence.id = crate::core::EventId::new();
+            occurrence.timestamp = timestamp;
+            occurrence
+        })
+        .collect()
+}
+
+/// Expand `rule` into the concrete instants it produces inside `window`,
+/// starting from `anchor`.
+///
+/// Walks forward from `anchor`, stepping by `rule.interval` units of
+/// `rule.freq`. For [`Freq::Weekly`] rules with `by_weekday` set, each stepped
+/// week emits one occurrence per listed weekday (in weekday order) rather than
+/// a single occurrence on the anchor's own weekday. A candidate whose
+/// day-of-month isn't in a non-empty `rule.by_monthday` is skipped, as is a
+/// candidate landing on a non-existent calendar date (e.g. the 30th of
+/// February). Expansion stops once `rule.count` occurrences have been
+/// produced, a candidate passes `rule.until`, or a candidate falls after
+/// `window.1`; candidates before `window.0` are dropped without counting
+/// toward `count`.
+pub fn occurrence_timestamps(
+    anchor: &Timestamp,
+    rule: &Recurrence,
+    window: (Timestamp, Timestamp),
+) -> Vec<Timestamp> {
+    let (window_start, window_end) = window;
+    let mut occurrences = Vec::new();
+    let mut emitted = 0u32;
+    let mut step: u64 = 0;
+
+    loop {
+        if let Some(count) = rule.count {
+            if emitted >= count {
+                break;
+            }
+        }
+
+        let week_start = match step_candidate(anchor.datetime, rule.freq, rule.interval, step) {
+            Some(dt) => dt,
+            None => {
+                step += 1;
+                continue;
+            }
+        };
+        step += 1;
+
+        if week_start.timestamp_millis() > window_end.to_unix_millis() {
+            break;
+        }
+        if let Some(until) = &rule.until {
+            if week_start.timestamp_millis() > until.to_unix_millis() {
+                break;
+            }
+        }
+
+        for candidate in week_candidates(week_start, rule.freq, &rule.by_weekday) {
+            if let Some(count) = rule.count {
+                if emitted >= count {
+                    break;
+                }
+            }
+            if candidate.timestamp_millis() > window_end.to_unix_millis() {
+                continue;
+            }
+            if let Some(until) = &rule.until {
+                if candidate.timestamp_millis() > until.to_unix_millis() {
+                    continue;
+                }
+            }
+            if candidate.timestamp_millis() < window_start.to_unix_millis() {
+                continue;
+            }
+            if !rule.by_monthday.is_empty() && !rule.by_monthday.contains(&candidate.day()) {
+                continue;
+            }
+
+            occurrences.push(Timestamp::with_precision(candidate, anchor.precision));
+            emitted += 1;
+        }
+    }
+
+    occurrences
+}
+
+/// Compute the candidate instant for a given step, or `None` if the step
+/// lands on a calendar date that does not exist.
+fn step_candidate(
+    start: chrono::DateTime<chrono::Utc>,
+    freq: Freq,
+    interval: u32,
+    step: u64,
+) -> Option<chrono::DateTime<chrono::Utc>> {
+    let offset = step * interval as u64;
+    match freq {
+        Freq::Daily => Some(start + chrono::Duration::days(offset as i64)),
+        Freq::Weekly => Some(start + chrono::Duration::weeks(offset as i64)),
+        Freq::Monthly => add_months_strict(start, offset as i64),
+        Freq::Yearly => add_months_strict(start, offset as i64 * 12),
+    }
+}
+
+/// Expand a single stepped instant into the occurrences it represents.
+///
+/// For [`Freq::Weekly`] with a non-empty `by_weekday`, returns one instant per
+/// listed weekday within `week_start`'s week (in weekday order); otherwise
+/// returns just `week_start` itself.
+fn week_candidates(
+    week_start: chrono::DateTime<chrono::Utc>,
+    freq: Freq,
+    by_weekday: &[Weekday],
+) -> Vec<chrono::DateTime<chrono::Utc>> {
+    if freq != Freq::Weekly || by_weekday.is_empty() {
+        return vec![week_start];
+    }
+
+    let start_of_week = week_start - chrono::Duration::days(week_start.weekday().num_days_from_monday() as i64);
+    let mut days: Vec<Weekday> = by_weekday.to_vec();
+    days.sort_by_key(|d| d.num_days_from_monday());
+
+    days.into_iter()
+        .map(|d| start_of_week + chrono::Duration::days(d.num_days_from_monday() as i64))
+        .collect()
+}
+
+/// Add `months` calendar months to `dt`, returning `None` if the resulting
+/// day-of-month does not exist (rather than clamping to the month's last day).
+fn add_months_strict(
+    dt: chrono::DateTime<chrono::Utc>,
+    months: i64,
+) -> Option<chrono::DateTime<chrono::Utc>> {
+    use chrono::TimeZone;
+
+    let total = dt.year() as i64 * 12 + (dt.month() as i64 - 1) + months;
+    let year = total.div_euclid(12) as i32;
+    let month = total.rem_euclid(12) as u32 + 1;
+
+    let date = chrono::NaiveDate::from_ymd_opt(year, month, dt.day())?;
+    let time = chrono::NaiveTime::from_hms_nano_opt(
+        dt.hour(),
+        dt.minute(),
+        dt.second(),
+        dt.nanosecond(),
+    )?;
+    Some(chrono::Utc.from_utc_datetime(&date.and_time(time)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Location;
+
+    fn anchor(dt: &str) -> Event {
+        Event::builder()
+            .location(Location::new(40.0, -73.0))
+            .timestamp(Timestamp::parse(dt).unwrap())
+            .text("commute")
+            .build()
+    }
+
+    #[test]
+    fn test_expand_daily() {
+        let event = anchor("2024-03-01T08:00:00Z");
+        let rule = Recurrence::new(Freq::Daily, 1).count(3);
+        let window = (
+            Timestamp::parse("2024-03-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-04-01T00:00:00Z").unwrap(),
+        );
+
+        let occurrences = expand(&event, &rule, window);
+
+        assert_eq!(occurrences.len(), 3);
+        assert_eq!(occurrences[0].timestamp.to_rfc3339(), "2024-03-01T08:00:00+00:00");
+        assert_eq!(occurrences[1].timestamp.to_rfc3339(), "2024-03-02T08:00:00+00:00");
+        assert_eq!(occurrences[2].timestamp.to_rfc3339(), "2024-03-03T08:00:00+00:00");
+        assert_eq!(occurrences[0].text, "commute");
+        assert_eq!(occurrences[0].location, event.location);
+        assert_ne!(occurrences[0].id, event.id);
+    }
+
+    #[test]
+    fn test_expand_weekly_by_weekday() {
+        // 2024-03-04 is a Monday.
+        let event = anchor("2024-03-04T09:00:00Z");
+        let rule = Recurrence::new(Freq::Weekly, 1)
+            .by_weekday([Weekday::Mon, Weekday::Wed, Weekday::Fri])
+            .count(6);
+        let window = (
+            Timestamp::parse("2024-03-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-04-01T00:00:00Z").unwrap(),
+        );
+
+        let occurrences = expand(&event, &rule, window);
+
+        let days: Vec<_> = occurrences
+            .iter()
+            .map(|e| e.timestamp.datetime.weekday())
+            .collect();
+        assert_eq!(occurrences.len(), 6);
+        assert_eq!(
+            days,
+            vec![
+                Weekday::Mon,
+                Weekday::Wed,
+                Weekday::Fri,
+                Weekday::Mon,
+                Weekday::Wed,
+                Weekday::Fri,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_stops_at_window_end() {
+        let event = anchor("2024-03-01T08:00:00Z");
+        let rule = Recurrence::new(Freq::Daily, 1);
+        let window = (
+            Timestamp::parse("2024-03-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-03-03T12:00:00Z").unwrap(),
+        );
+
+        let occurrences = expand(&event, &rule, window);
+
+        assert_eq!(occurrences.len(), 3);
+    }
+
+    #[test]
+    fn test_expand_until_bound() {
+        let event = anchor("2024-03-01T08:00:00Z");
+        let rule = Recurrence::new(Freq::Daily, 1).until(Timestamp::parse("2024-03-02T08:00:00Z").unwrap());
+        let window = (
+            Timestamp::parse("2024-03-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-04-01T00:00:00Z").unwrap(),
+        );
+
+        let occurrences = expand(&event, &rule, window);
+
+        assert_eq!(occurrences.len(), 2);
+    }
+
+    #[test]
+    fn test_expand_by_monthday_skips_invalid_days() {
+        let event = anchor("2024-01-31T08:00:00Z");
+        let rule = Recurrence::new(Freq::Monthly, 1).by_monthday([31]);
+        let window = (
+            Timestamp::parse("2024-01-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-05-01T00:00:00Z").unwrap(),
+        );
+
+        let occurrences = expand(&event, &rule, window);
+
+        // Only Jan 31 and Mar 31 exist in the window; Feb/Apr are skipped, not clamped.
+        let months: Vec<u32> = occurrences
+            .iter()
+            .map(|e| e.timestamp.datetime.month())
+            .collect();
+        assert_eq!(months, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_parse_rrule_weekly_with_byday_and_count() {
+        let rule = Recurrence::parse_rrule("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE,FR;COUNT=10").unwrap();
+
+        assert_eq!(rule.freq, Freq::Weekly);
+        assert_eq!(rule.interval, 2);
+        assert_eq!(rule.count, Some(10));
+        assert_eq!(rule.by_weekday, vec![Weekday::Mon, Weekday::Wed, Weekday::Fri]);
+    }
+
+    #[test]
+    fn test_parse_rrule_accepts_rrule_prefix_and_until() {
+        let rule = Recurrence::parse_rrule("RRULE:FREQ=DAILY;UNTIL=20240315T140000Z").unwrap();
+
+        assert_eq!(rule.freq, Freq::Daily);
+        assert_eq!(rule.interval, 1);
+        assert_eq!(
+            rule.until.unwrap().to_rfc3339(),
+            "2024-03-15T14:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_parse_rrule_defaults_interval_to_one() {
+        let rule = Recurrence::parse_rrule("FREQ=MONTHLY").unwrap();
+
+        assert_eq!(rule.freq, Freq::Monthly);
+        assert_eq!(rule.interval, 1);
+        assert!(rule.count.is_none());
+        assert!(rule.until.is_none());
+    }
+
+    #[test]
+    fn test_parse_rrule_rejects_missing_freq() {
+        assert!(Recurrence::parse_rrule("INTERVAL=2;COUNT=5").is_err());
+    }
+
+    #[test]
+    fn test_parse_rrule_rejects_unknown_freq() {
+        assert!(Recurrence::parse_rrule("FREQ=HOURLY").is_err());
+    }
+}
@@ -0,0 +1,71 @@
+//! Swappable date/time backend for [`Timestamp`](super::Timestamp).
+//!
+//! Downstream crates that standardize on `time 0.3` to avoid pulling in
+//! `chrono` could not previously depend on this library cleanly. To resolve
+//! that, the concrete date/time type and the RFC 3339 parse/format primitives
+//! live behind a Cargo feature: the default `chrono` backend and an alternative
+//! `time-backend`. Both expose the same [`DateTimeUtc`] alias and the same
+//! `now`/`parse_rfc3339`/`to_rfc3339` surface, so public call sites in
+//! `Timestamp`, `SourceRef::date` and the `io` JSON paths are unaffected by the
+//! choice. The output is not byte-identical: the chrono backend renders UTC as
+//! `+00:00` and its parser also accepts chrono's looser `FromStr` forms, while
+//! the `time` backend renders UTC as `Z` and accepts strict RFC 3339 only.
+
+#[cfg(not(feature = "time-backend"))]
+pub use chrono_backend::*;
+#[cfg(feature = "time-backend")]
+pub use time_impl::*;
+
+#[cfg(not(feature = "time-backend"))]
+mod chrono_backend {
+    use crate::error::{Error, Result};
+    use chrono::{DateTime, Utc};
+
+    /// The backend datetime type: a UTC instant.
+    pub type DateTimeUtc = DateTime<Utc>;
+
+    /// Returns the current moment in UTC.
+    pub fn now() -> DateTimeUtc {
+        Utc::now()
+    }
+
+    /// Parses a full RFC 3339 timestamp.
+    pub fn parse_rfc3339(s: &str) -> Result<DateTimeUtc> {
+        if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+            return Ok(dt.with_timezone(&Utc));
+        }
+        s.parse::<DateTime<Utc>>()
+            .map_err(|_| Error::InvalidTimestamp(s.to_string()))
+    }
+
+    /// Formats an instant as an RFC 3339 string.
+    pub fn to_rfc3339(dt: &DateTimeUtc) -> String {
+        dt.to_rfc3339()
+    }
+}
+
+#[cfg(feature = "time-backend")]
+mod time_impl {
+    use crate::error::{Error, Result};
+    use time::{format_description::well_known::Rfc3339, OffsetDateTime, UtcOffset};
+
+    /// The backend datetime type: a UTC instant.
+    pub type DateTimeUtc = OffsetDateTime;
+
+    /// Returns the current moment in UTC.
+    pub fn now() -> DateTimeUtc {
+        OffsetDateTime::now_utc()
+    }
+
+    /// Parses a full RFC 3339 timestamp.
+    pub fn parse_rfc3339(s: &str) -> Result<DateTimeUtc> {
+        OffsetDateTime::parse(s, &Rfc3339)
+            .map(|dt| dt.to_offset(UtcOffset::UTC))
+            .map_err(|_| Error::InvalidTimestamp(s.to_string()))
+    }
+
+    /// Formats an instant as an RFC 3339 string.
+    pub fn to_rfc3339(dt: &DateTimeUtc) -> String {
+        dt.format(&Rfc3339).unwrap_or_default()
+    }
+}
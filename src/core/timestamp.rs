@@ -1,9 +1,129 @@
 //! Timestamp representation with precision awareness.
 
+use super::time_backend;
 use crate::error::{Error, Result};
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, Offset, TimeZone, Timelike, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+/// The time scale an instant is expressed in.
+///
+/// Narratives mixing GNSS logs, civil records, and local diaries reconcile
+/// several clocks. A [`Timestamp`] always stores its instant as a canonical UTC
+/// `DateTime`, so ordering and [`TimeRange`](super::TimeRange) queries stay
+/// correct; `TimeScale` records the scale a source used so readings can be
+/// converted back and forth with the known offsets:
+///
+/// - `Gpst` = `Tai` − 19 s
+/// - `Tai` = `Utc` + the accumulated leap-second count for the date
+/// - `Local` carries a fixed UTC offset (civil wall-clock time)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TimeScale {
+    /// Coordinated Universal Time (the canonical scale).
+    Utc,
+    /// International Atomic Time.
+    Tai,
+    /// GPS time.
+    Gpst,
+    /// Civil local time at a fixed offset from UTC.
+    Local(FixedOffset),
+}
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        TimeScale::Utc
+    }
+}
+
+impl TimeScale {
+    /// Returns true for the canonical [`Utc`](TimeScale::Utc) scale.
+    pub fn is_utc(&self) -> bool {
+        matches!(self, TimeScale::Utc)
+    }
+
+    /// Seconds to add to a UTC instant to obtain this scale's clock reading.
+    ///
+    /// The leap-second–dependent scales are evaluated on `date`, which is close
+    /// enough for conversion since leap seconds change only at day boundaries.
+    fn offset_seconds(&self, date: NaiveDate) -> i64 {
+        match self {
+            TimeScale::Utc => 0,
+            TimeScale::Tai => leap_seconds(date),
+            TimeScale::Gpst => leap_seconds(date) - 19,
+            TimeScale::Local(off) => off.local_minus_utc() as i64,
+        }
+    }
+}
+
+/// Accumulated TAI − UTC leap seconds effective on `date`.
+///
+/// A small built-in table keyed by the date each leap second took effect; the
+/// value is the total offset in force on or after that date. Dates before the
+/// first entry predate the modern UTC leap-second regime and return 0.
+fn leap_seconds(date: NaiveDate) -> i64 {
+    // (year, month, day, cumulative TAI - UTC seconds)
+    const TABLE: &[(i32, u32, u32, i64)] = &[
+        (1972, 1, 1, 10),
+        (1980, 1, 1, 19),
+        (1985, 7, 1, 23),
+        (1992, 7, 1, 27),
+        (1999, 1, 1, 32),
+        (2006, 1, 1, 33),
+        (2009, 1, 1, 34),
+        (2012, 7, 1, 35),
+        (2015, 7, 1, 36),
+        (2017, 1, 1, 37),
+    ];
+
+    let mut offset = 0;
+    for &(y, m, d, seconds) in TABLE {
+        let effective = NaiveDate::from_ymd_opt(y, m, d).expect("valid leap-second date");
+        if date >= effective {
+            offset = seconds;
+        } else {
+            break;
+        }
+    }
+    offset
+}
+
+/// Infers a timestamp's precision from its RFC 3339 rendering.
+///
+/// Mirrors [`Timestamp::format_with_precision`]: that renderer emits a literal
+/// `Z` with zero-filled minute/second fields for `Hour`/`Minute`, while the
+/// full `Second` form uses the `+00:00` offset and a `Millisecond` carries a
+/// fractional part. A plain offset string with non-zero seconds is `Second`.
+fn detect_rfc3339_precision(s: &str) -> TemporalPrecision {
+    let time = match s.split_once('T') {
+        Some((_, t)) => t,
+        None => return TemporalPrecision::Second,
+    };
+    if time.contains('.') {
+        return TemporalPrecision::Millisecond;
+    }
+    let zulu = match time.strip_suffix('Z').or_else(|| time.strip_suffix('z')) {
+        Some(t) => t,
+        None => return TemporalPrecision::Second,
+    };
+    match zulu.split(':').collect::<Vec<_>>().as_slice() {
+        [_, "00", "00"] => TemporalPrecision::Hour,
+        [_, _, "00"] => TemporalPrecision::Minute,
+        _ => TemporalPrecision::Second,
+    }
+}
+
+// Fuzzy-date recognizers for [`Timestamp::parse_flexible`], tried in order from
+// coarsest to most specific. Each is anchored so it must consume the whole
+// (trimmed) input rather than a leading fragment.
+static DECADE_OR_YEAR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(~?|before ?)(\d{4})(s?)$").unwrap());
+static YEAR_SPAN: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\d{4})[-/](\d{4})$").unwrap());
+static YEAR_MONTH: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\d{4})-(\d{2})$").unwrap());
+static CENTURY: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(late|mid|early)? ?C(\d{2})$").unwrap());
+static US_DATE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\d{1,2})[/ ](\d{2})[/ ](\d{4})$").unwrap());
+static MONTH_YEAR: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\d{2})/(\d{4})$").unwrap());
+
 /// Precision level for timestamps.
 ///
 /// Real-world data often has varying levels of temporal precision.
@@ -11,6 +131,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum TemporalPrecision {
+    /// A whole century (e.g., "C19" for the 1800s)
+    Century,
+    /// A single decade (e.g., "1920s")
+    Decade,
     /// Year only (e.g., "2024")
     Year,
     /// Year and month (e.g., "2024-03")
@@ -28,6 +152,25 @@ pub enum TemporalPrecision {
     Millisecond,
 }
 
+/// Controls how many fractional-second digits [`Timestamp::to_rfc3339_opts`] emits.
+///
+/// Mirrors [`chrono::SecondsFormat`]: the fixed variants always render that many
+/// digits, while `AutoSi` trims to the shortest SI grouping (3/6/9) that keeps
+/// full fidelity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubsecondFormat {
+    /// No fractional seconds.
+    Secs,
+    /// Exactly three digits (milliseconds).
+    Millis,
+    /// Exactly six digits (microseconds).
+    Micros,
+    /// Exactly nine digits (nanoseconds).
+    Nanos,
+    /// Shortest SI grouping that preserves all non-zero digits.
+    AutoSi,
+}
+
 /// A timestamp with timezone awareness and precision tracking.
 ///
 /// Timestamps in spatial narratives often come from sources with varying
@@ -57,6 +200,28 @@ pub struct Timestamp {
     /// The precision of this timestamp.
     #[serde(default)]
     pub precision: TemporalPrecision,
+    /// The IANA timezone the observer reckoned by, if known.
+    ///
+    /// The stored instant is always UTC; this field records the zone used for
+    /// "human reckoning" — local-date grouping and display — without affecting
+    /// absolute ordering or containment checks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zone: Option<chrono_tz::Tz>,
+    /// The time scale the source reckoned by.
+    ///
+    /// The stored instant is always canonical UTC regardless of scale; this
+    /// records the originating scale so readings can be recovered with
+    /// [`to_scale`](Self::to_scale).
+    #[serde(default, skip_serializing_if = "TimeScale::is_utc")]
+    pub scale: TimeScale,
+    /// The UTC offset the source recorded, if it carried one.
+    ///
+    /// The stored [`datetime`](Self::datetime) is always normalized to UTC, but
+    /// email/news feeds (RFC 2822) note the observer's offset — retaining it lets
+    /// [`local_datetime`](Self::local_datetime) re-render the original wall clock
+    /// without affecting ordering.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_offset: Option<FixedOffset>,
 }
 
 impl Timestamp {
@@ -65,6 +230,9 @@ impl Timestamp {
         Self {
             datetime,
             precision: TemporalPrecision::Second,
+            zone: None,
+            scale: TimeScale::Utc,
+            source_offset: None,
         }
     }
 
@@ -73,12 +241,67 @@ impl Timestamp {
         Self {
             datetime,
             precision,
+            zone: None,
+            scale: TimeScale::Utc,
+            source_offset: None,
         }
     }
 
+    /// Creates a timezone-aware timestamp.
+    ///
+    /// The instant is stored in UTC; `tz` records the zone the observer
+    /// reckoned by, so that [`local_date`](Self::local_date) and display reflect
+    /// the wall-clock date they perceived.
+    pub fn with_zone(datetime: DateTime<Utc>, tz: chrono_tz::Tz) -> Self {
+        Self {
+            datetime,
+            precision: TemporalPrecision::Second,
+            zone: Some(tz),
+            scale: TimeScale::Utc,
+            source_offset: None,
+        }
+    }
+
+    /// Returns a copy of this timestamp with its reckoning zone set to `tz`.
+    ///
+    /// The stored instant is unchanged; only the zone used for
+    /// [`local_date`](Self::local_date) and local-time display is updated.
+    pub fn with_timezone(&self, tz: chrono_tz::Tz) -> Self {
+        Self {
+            zone: Some(tz),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the wall-clock calendar date in the stored zone.
+    ///
+    /// Falls back to the UTC date when no zone is set. This is the date an
+    /// observer in `zone` would file the event under — an event at 23:00 local
+    /// on the 15th groups under the 15th, not the following UTC day.
+    pub fn local_date(&self) -> chrono::NaiveDate {
+        use chrono::TimeZone as _;
+        match self.zone {
+            Some(tz) => tz.from_utc_datetime(&self.datetime.naive_utc()).date_naive(),
+            None => self.datetime.date_naive(),
+        }
+    }
+
+    /// Re-applies the stored [`source_offset`](Self::source_offset) for display.
+    ///
+    /// Returns the instant in its original offset (e.g. `-05:00` for an event
+    /// recorded on the US east coast), falling back to UTC when no offset was
+    /// retained. Ordering is unaffected — that always uses the normalized UTC
+    /// [`datetime`](Self::datetime).
+    pub fn local_datetime(&self) -> DateTime<FixedOffset> {
+        let offset = self
+            .source_offset
+            .unwrap_or_else(|| FixedOffset::east_opt(0).expect("zero offset is valid"));
+        self.datetime.with_timezone(&offset)
+    }
+
     /// Creates a timestamp for the current moment.
     pub fn now() -> Self {
-        Self::new(Utc::now())
+        Self::new(time_backend::now())
     }
 
     /// Parses a timestamp from an ISO 8601 string.
@@ -89,15 +312,22 @@ impl Timestamp {
     /// - `2024-03-15` (date only, day precision)
     /// - `2024-03` (year-month, month precision)
     /// - `2024` (year only, year precision)
+    /// - `Mon, 15 Mar 2024 14:30:00 -0500` (RFC 2822, offset retained)
     pub fn parse(s: &str) -> Result<Self> {
-        // Try full ISO 8601 with timezone
-        if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
-            return Ok(Self::new(dt.with_timezone(&Utc)));
+        // Try full RFC 3339 (backend-provided).
+        // Recover the precision from the truncation pattern so that
+        // `parse(t.format_with_precision())` round-trips (see
+        // [`detect_rfc3339_precision`]).
+        if let Ok(dt) = time_backend::parse_rfc3339(s) {
+            return Ok(Self::with_precision(dt, detect_rfc3339_precision(s)));
         }
 
-        // Try full ISO 8601 with Z suffix
-        if let Ok(dt) = s.parse::<DateTime<Utc>>() {
-            return Ok(Self::new(dt));
+        // Try RFC 2822 (email/news feeds), keeping the source offset so the
+        // original wall clock can be recovered while ordering stays on UTC.
+        if let Ok(dt) = DateTime::parse_from_rfc2822(s) {
+            let mut ts = Self::new(dt.with_timezone(&Utc));
+            ts.source_offset = Some(*dt.offset());
+            return Ok(ts);
         }
 
         // Try date only (YYYY-MM-DD)
@@ -147,6 +377,135 @@ impl Timestamp {
         Err(Error::InvalidTimestamp(s.to_string()))
     }
 
+    /// Parses an imprecise or historical date into a timestamp and precision.
+    ///
+    /// Archival and GeoJSON sources often carry dates that are not strict
+    /// RFC 3339 — `1920s`, `~1914`, `before 1910`, `C19` (the 19th century),
+    /// `1914-1918`, or `06/1918`. This recognizer tries a sequence of shapes,
+    /// from coarsest to most specific, and returns a timestamp anchored to the
+    /// representative (earliest) instant with a matching
+    /// [`TemporalPrecision`]. When a shape denotes a span the start year is
+    /// kept and [`to_range`](Self::to_range) widens it for temporal queries.
+    ///
+    /// Input that matches none of the fuzzy shapes falls back to the strict
+    /// [`parse`](Self::parse) grammar and only then errors.
+    pub fn parse_flexible(s: &str) -> Result<Self> {
+        let s = s.trim();
+
+        // `1920s` (decade) or `~1914` / `before 1910` / `1914` (year).
+        if let Some(caps) = DECADE_OR_YEAR.captures(s) {
+            let year: i32 = caps[2]
+                .parse()
+                .map_err(|_| Error::InvalidTimestamp(s.to_string()))?;
+            let precision = if caps.get(3).is_some_and(|m| !m.as_str().is_empty()) {
+                TemporalPrecision::Decade
+            } else {
+                TemporalPrecision::Year
+            };
+            return Ok(Self::with_precision(utc_ymd(year, 1, 1), precision));
+        }
+
+        // `1914-1918` / `1914/1918`: a span anchored at its start year.
+        if let Some(caps) = YEAR_SPAN.captures(s) {
+            let start: i32 = caps[1]
+                .parse()
+                .map_err(|_| Error::InvalidTimestamp(s.to_string()))?;
+            return Ok(Self::with_precision(utc_ymd(start, 1, 1), TemporalPrecision::Year));
+        }
+
+        // `1914-06`: year and month.
+        if let Some(caps) = YEAR_MONTH.captures(s) {
+            let year: i32 = caps[1]
+                .parse()
+                .map_err(|_| Error::InvalidTimestamp(s.to_string()))?;
+            let month: u32 = caps[2]
+                .parse()
+                .map_err(|_| Error::InvalidTimestamp(s.to_string()))?;
+            if let Some(naive) = NaiveDate::from_ymd_opt(year, month, 1) {
+                return Ok(Self::with_precision(
+                    Utc.from_utc_datetime(&naive.and_hms_opt(0, 0, 0).unwrap()),
+                    TemporalPrecision::Month,
+                ));
+            }
+        }
+
+        // `C19` / `late C18`: a century, optionally modified.
+        if let Some(caps) = CENTURY.captures(s) {
+            let n: i32 = caps[2]
+                .parse()
+                .map_err(|_| Error::InvalidTimestamp(s.to_string()))?;
+            let offset = match caps.get(1).map(|m| m.as_str()) {
+                Some("early") => 10,
+                Some("mid") => 50,
+                Some("late") => 90,
+                _ => 0,
+            };
+            let year = (n - 1) * 100 + offset;
+            return Ok(Self::with_precision(utc_ymd(year, 1, 1), TemporalPrecision::Century));
+        }
+
+        // `3/15/1918` (US M/D/Y): day precision.
+        if let Some(caps) = US_DATE.captures(s) {
+            let month: u32 = caps[1].parse().unwrap_or(1);
+            let day: u32 = caps[2].parse().unwrap_or(1);
+            let year: i32 = caps[3]
+                .parse()
+                .map_err(|_| Error::InvalidTimestamp(s.to_string()))?;
+            if let Some(naive) = NaiveDate::from_ymd_opt(year, month, day) {
+                return Ok(Self::with_precision(
+                    Utc.from_utc_datetime(&naive.and_hms_opt(0, 0, 0).unwrap()),
+                    TemporalPrecision::Day,
+                ));
+            }
+        }
+
+        // `06/1918` (month/year): month precision.
+        if let Some(caps) = MONTH_YEAR.captures(s) {
+            let month: u32 = caps[1].parse().unwrap_or(1);
+            let year: i32 = caps[2]
+                .parse()
+                .map_err(|_| Error::InvalidTimestamp(s.to_string()))?;
+            if let Some(naive) = NaiveDate::from_ymd_opt(year, month, 1) {
+                return Ok(Self::with_precision(
+                    Utc.from_utc_datetime(&naive.and_hms_opt(0, 0, 0).unwrap()),
+                    TemporalPrecision::Month,
+                ));
+            }
+        }
+
+        // Nothing fuzzy matched: defer to the strict grammar.
+        Self::parse(s)
+    }
+
+    /// Parses a timestamp whose wall-clock reading is expressed in `scale`.
+    ///
+    /// The string is read with the same grammar as [`parse`](Self::parse), then
+    /// the reading is converted to a canonical UTC instant using the scale's
+    /// offset, so the result compares and range-queries correctly against
+    /// timestamps from other scales. The originating scale is retained and can
+    /// be recovered with [`to_scale`](Self::to_scale).
+    pub fn parse_with_scale(s: &str, scale: TimeScale) -> Result<Self> {
+        let reading = Self::parse(s)?;
+        let offset = chrono::Duration::seconds(scale.offset_seconds(reading.datetime.date_naive()));
+        Ok(Self {
+            datetime: reading.datetime - offset,
+            precision: reading.precision,
+            zone: reading.zone,
+            scale,
+            source_offset: reading.source_offset,
+        })
+    }
+
+    /// Returns the wall-clock reading of this instant in the given `scale`.
+    ///
+    /// The canonical UTC instant is unchanged; the returned `DateTime` carries
+    /// the broken-down clock reading an observer on `scale` would record (for
+    /// example GPS time, which currently runs 18 s ahead of UTC).
+    pub fn to_scale(&self, scale: TimeScale) -> DateTime<Utc> {
+        let offset = chrono::Duration::seconds(scale.offset_seconds(self.datetime.date_naive()));
+        self.datetime + offset
+    }
+
     /// Creates a timestamp from Unix epoch seconds.
     pub fn from_unix(secs: i64) -> Option<Self> {
         DateTime::from_timestamp(secs, 0).map(Self::new)
@@ -173,22 +532,86 @@ impl Timestamp {
         self.datetime.timestamp_millis()
     }
 
+    /// The UTC offset to render this instant in, if a local reckoning is known.
+    ///
+    /// Prefers an explicitly retained [`source_offset`](Self::source_offset),
+    /// then the standing offset of the stored [`zone`](Self::zone) at this
+    /// instant, and finally `None` when the timestamp is a bare UTC instant.
+    fn display_offset(&self) -> Option<FixedOffset> {
+        if let Some(offset) = self.source_offset {
+            return Some(offset);
+        }
+        self.zone
+            .map(|tz| tz.offset_from_utc_datetime(&self.datetime.naive_utc()).fix())
+    }
+
     /// Formats the timestamp as an ISO 8601 string.
+    ///
+    /// When the timestamp carries a [`source_offset`](Self::source_offset) or a
+    /// [`zone`](Self::zone), the string preserves that offset (e.g. a Berlin
+    /// event renders as `…+01:00`); a bare UTC instant goes through the backend
+    /// formatter, which renders it as `+00:00` under the default chrono backend.
     pub fn to_rfc3339(&self) -> String {
-        self.datetime.to_rfc3339()
+        match self.display_offset() {
+            Some(offset) => self.datetime.with_timezone(&offset).to_rfc3339(),
+            None => time_backend::to_rfc3339(&self.datetime),
+        }
+    }
+
+    /// Renders the wall-clock time plus a human zone label (exemplar city).
+    ///
+    /// For a zone-anchored event this reads as an observer would file it —
+    /// `2024-03-15T14:30:00+01:00 (Berlin)` — using a CLDR-style exemplar-city
+    /// lookup keyed by IANA id. Without a [`zone`](Self::zone) it falls back to
+    /// the offset-only [`local_datetime`](Self::local_datetime) rendering.
+    pub fn format_local_with_zone(&self) -> String {
+        match self.zone {
+            Some(tz) => {
+                let local = tz.from_utc_datetime(&self.datetime.naive_utc());
+                format!(
+                    "{} ({})",
+                    local.format("%Y-%m-%dT%H:%M:%S%:z"),
+                    exemplar_city(tz)
+                )
+            }
+            None => self
+                .local_datetime()
+                .format("%Y-%m-%dT%H:%M:%S%:z")
+                .to_string(),
+        }
+    }
+
+    /// Formats the timestamp with explicit control over the fractional seconds.
+    ///
+    /// Mirrors [`chrono::SecondsFormat`]: [`SubsecondFormat::Secs`] omits the
+    /// fraction, `Millis`/`Micros`/`Nanos` emit a fixed 3/6/9 digits, and
+    /// `AutoSi` trims trailing zero groups. When `use_z` is true the UTC offset
+    /// is rendered as `Z`, otherwise as `+00:00`.
+    pub fn to_rfc3339_opts(&self, fmt: SubsecondFormat, use_z: bool) -> String {
+        let secs_format = match fmt {
+            SubsecondFormat::Secs => chrono::SecondsFormat::Secs,
+            SubsecondFormat::Millis => chrono::SecondsFormat::Millis,
+            SubsecondFormat::Micros => chrono::SecondsFormat::Micros,
+            SubsecondFormat::Nanos => chrono::SecondsFormat::Nanos,
+            SubsecondFormat::AutoSi => chrono::SecondsFormat::AutoSi,
+        };
+        self.datetime.to_rfc3339_opts(secs_format, use_z)
     }
 
     /// Formats the timestamp according to precision.
     pub fn format_with_precision(&self) -> String {
         match self.precision {
+            TemporalPrecision::Century => format!("C{:02}", self.datetime.year() / 100 + 1),
+            TemporalPrecision::Decade => format!("{}s", self.datetime.year()),
             TemporalPrecision::Year => self.datetime.format("%Y").to_string(),
             TemporalPrecision::Month => self.datetime.format("%Y-%m").to_string(),
             TemporalPrecision::Day => self.datetime.format("%Y-%m-%d").to_string(),
             TemporalPrecision::Hour => self.datetime.format("%Y-%m-%dT%H:00:00Z").to_string(),
             TemporalPrecision::Minute => self.datetime.format("%Y-%m-%dT%H:%M:00Z").to_string(),
-            TemporalPrecision::Second | TemporalPrecision::Millisecond => {
-                self.datetime.to_rfc3339()
-            },
+            TemporalPrecision::Second => self.datetime.to_rfc3339(),
+            TemporalPrecision::Millisecond => {
+                self.to_rfc3339_opts(SubsecondFormat::Millis, true)
+            }
         }
     }
 
@@ -206,6 +629,219 @@ impl Timestamp {
     pub fn duration_since(&self, earlier: &Timestamp) -> chrono::Duration {
         self.datetime.signed_duration_since(earlier.datetime)
     }
+
+    /// Decomposes the gap since `earlier` into calendar fields.
+    ///
+    /// Unlike [`duration_since`](Self::duration_since), which yields an exact
+    /// span of seconds, this expresses the gap the way a timeline reads it —
+    /// "1 year, 2 months, 3 days" — borrowing a month when the later
+    /// day-of-month precedes the earlier one (clamping to the target month's
+    /// last valid day). Assumes `self` is at or after `earlier`.
+    pub fn calendar_duration_since(&self, earlier: &Timestamp) -> CalendarDuration {
+        let (a, b) = (earlier.datetime, self.datetime);
+
+        let mut total_months =
+            (b.year() as i64 - a.year() as i64) * 12 + (b.month() as i64 - a.month() as i64);
+        let mut anchor = add_calendar_months(a, total_months);
+        if anchor > b {
+            total_months -= 1;
+            anchor = add_calendar_months(a, total_months);
+        }
+
+        let rem = b - anchor;
+        let days = rem.num_days();
+        CalendarDuration {
+            years: total_months.div_euclid(12),
+            months: total_months.rem_euclid(12),
+            days,
+            remainder: rem - chrono::Duration::days(days),
+        }
+    }
+
+    /// Whole calendar years elapsed between this timestamp and now.
+    ///
+    /// Subtracts a year when the current month/day is earlier than this
+    /// timestamp's (the birthday rule), and saturates to 0 for future dates.
+    pub fn elapsed_years(&self) -> u32 {
+        let now = time_backend::now();
+        if now <= self.datetime {
+            return 0;
+        }
+        let mut years = now.year() - self.datetime.year();
+        if (now.month(), now.day()) < (self.datetime.month(), self.datetime.day()) {
+            years -= 1;
+        }
+        years.max(0) as u32
+    }
+
+    /// Expands the instant to the half-open interval implied by its precision.
+    ///
+    /// A `Month`-precision timestamp means "sometime in that month", so
+    /// comparisons against a single instant are misleadingly exact; the returned
+    /// [`TimestampRange`] captures the true span (`[start, end)`), with month and
+    /// year boundaries computed via [`chrono::NaiveDate`] so rollovers and leap
+    /// Februaries stay correct.
+    pub fn to_range(&self) -> TimestampRange {
+        let dt = self.datetime;
+        let (start, end) = match self.precision {
+            TemporalPrecision::Century => {
+                // The century containing this year: [C00, C00+100).
+                let base = dt.year() - dt.year().rem_euclid(100);
+                (utc_ymd(base, 1, 1), utc_ymd(base + 100, 1, 1))
+            }
+            TemporalPrecision::Decade => {
+                let base = dt.year() - dt.year().rem_euclid(10);
+                (utc_ymd(base, 1, 1), utc_ymd(base + 10, 1, 1))
+            }
+            TemporalPrecision::Year => (
+                utc_ymd(dt.year(), 1, 1),
+                utc_ymd(dt.year() + 1, 1, 1),
+            ),
+            TemporalPrecision::Month => {
+                let (ny, nm) = if dt.month() == 12 {
+                    (dt.year() + 1, 1)
+                } else {
+                    (dt.year(), dt.month() + 1)
+                };
+                (utc_ymd(dt.year(), dt.month(), 1), utc_ymd(ny, nm, 1))
+            }
+            TemporalPrecision::Day => {
+                let start = utc_ymd(dt.year(), dt.month(), dt.day());
+                (start, start + chrono::Duration::days(1))
+            }
+            TemporalPrecision::Hour => {
+                let start = dt
+                    .with_minute(0)
+                    .and_then(|d| d.with_second(0))
+                    .and_then(|d| d.with_nanosecond(0))
+                    .unwrap_or(dt);
+                (start, start + chrono::Duration::hours(1))
+            }
+            TemporalPrecision::Minute => {
+                let start = dt
+                    .with_second(0)
+                    .and_then(|d| d.with_nanosecond(0))
+                    .unwrap_or(dt);
+                (start, start + chrono::Duration::minutes(1))
+            }
+            TemporalPrecision::Second => {
+                let start = dt.with_nanosecond(0).unwrap_or(dt);
+                (start, start + chrono::Duration::seconds(1))
+            }
+            TemporalPrecision::Millisecond => {
+                let millis = dt.nanosecond() / 1_000_000 * 1_000_000;
+                let start = dt.with_nanosecond(millis).unwrap_or(dt);
+                (start, start + chrono::Duration::milliseconds(1))
+            }
+        };
+        TimestampRange { start, end }
+    }
+}
+
+/// The half-open interval `[start, end)` implied by a [`Timestamp`]'s precision.
+///
+/// Produced by [`Timestamp::to_range`]. Its predicates let a query layer reason
+/// honestly about coarse timestamps: a `Month`-precision value overlaps every
+/// instant in that month rather than pretending to be a single point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimestampRange {
+    /// Inclusive start of the interval.
+    pub start: DateTime<Utc>,
+    /// Exclusive end of the interval.
+    pub end: DateTime<Utc>,
+}
+
+impl TimestampRange {
+    /// Returns true if `ts`'s instant falls within `[start, end)`.
+    pub fn contains(&self, ts: &Timestamp) -> bool {
+        self.start <= ts.datetime && ts.datetime < self.end
+    }
+
+    /// Returns true if the two intervals share any instant.
+    pub fn overlaps(&self, other: &TimestampRange) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Returns true only if this interval lies entirely before `other`.
+    ///
+    /// Unlike a naive instant comparison, this never orders two intervals that
+    /// overlap — a `Month` timestamp is not "before" a `Day` inside it.
+    pub fn definitely_before(&self, other: &TimestampRange) -> bool {
+        self.end <= other.start
+    }
+
+    /// Returns true if any instant in this interval precedes some instant in
+    /// `other` — the optimistic counterpart of [`definitely_before`](Self::definitely_before).
+    pub fn possibly_before(&self, other: &TimestampRange) -> bool {
+        self.start < other.end
+    }
+}
+
+/// A nominal (calendar) span between two timestamps.
+///
+/// ISO 8601 classifies a [`chrono::Duration`] as an "accurate" span of seconds,
+/// which cannot express "3 months" or "2 years"; narrative timelines need those
+/// nominal units. The `remainder` carries the leftover sub-day span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarDuration {
+    /// Whole calendar years.
+    pub years: i64,
+    /// Whole calendar months beyond the years (0..12).
+    pub months: i64,
+    /// Whole days beyond the months.
+    pub days: i64,
+    /// Sub-day remainder.
+    pub remainder: chrono::Duration,
+}
+
+/// Adds whole calendar months to `dt`, preserving the time of day and clamping
+/// the day to the target month's last valid day (so Jan 31 + 1 month = Feb 28/29).
+fn add_calendar_months(dt: DateTime<Utc>, months: i64) -> DateTime<Utc> {
+    let total = dt.year() as i64 * 12 + (dt.month() as i64 - 1) + months;
+    let year = total.div_euclid(12) as i32;
+    let month = total.rem_euclid(12) as u32 + 1;
+
+    let mut day = dt.day();
+    let date = loop {
+        if let Some(d) = NaiveDate::from_ymd_opt(year, month, day) {
+            break d;
+        }
+        day -= 1;
+    };
+    Utc.from_utc_datetime(&date.and_time(dt.time()))
+}
+
+/// Builds a UTC datetime at midnight from year/month/day, clamping invalid
+/// dates to the Unix epoch (unreachable for the callers in this module).
+fn utc_ymd(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+    NaiveDate::from_ymd_opt(year, month, day)
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|ndt| Utc.from_utc_datetime(&ndt))
+        .unwrap_or_else(|| DateTime::from_timestamp(0, 0).expect("epoch is valid"))
+}
+
+/// Returns the CLDR-style exemplar city for an IANA zone.
+///
+/// A handful of common zones get their localized English display names; every
+/// other zone derives a readable city from the last `/`-separated component of
+/// its IANA id (`America/Argentina/Buenos_Aires` → `Buenos Aires`).
+fn exemplar_city(tz: chrono_tz::Tz) -> String {
+    match tz.name() {
+        "Europe/Berlin" => "Berlin".to_string(),
+        "Europe/London" => "London".to_string(),
+        "Europe/Paris" => "Paris".to_string(),
+        "America/New_York" => "New York".to_string(),
+        "America/Los_Angeles" => "Los Angeles".to_string(),
+        "Asia/Tokyo" => "Tokyo".to_string(),
+        "Asia/Kolkata" => "Kolkata".to_string(),
+        "Australia/Sydney" => "Sydney".to_string(),
+        "UTC" => "UTC".to_string(),
+        name => name
+            .rsplit('/')
+            .next()
+            .unwrap_or(name)
+            .replace('_', " "),
+    }
 }
 
 impl Default for Timestamp {
@@ -245,6 +881,43 @@ impl From<DateTime<Utc>> for Timestamp {
     }
 }
 
+/// Compact string (de)serialization for [`Timestamp`].
+///
+/// The default derive emits a `{ "datetime": ..., "precision": ... }` object,
+/// which is verbose and can round-trip an internally inconsistent pair. This
+/// helper instead renders a timestamp as the single string produced by
+/// [`format_with_precision`](Timestamp::format_with_precision) (`"2024-03"` for
+/// month precision, `"2024-03-15T14:30:00.123Z"` for millisecond) and recovers
+/// it on the way back through [`parse`](Timestamp::parse), rejecting malformed
+/// or ambiguous input. Use it with `#[serde(with = "timestamp::compact")]` on a
+/// `Timestamp` field; existing struct-based payloads keep deserializing via the
+/// derive.
+///
+/// Note that only [`precision`](Timestamp::precision) survives the compact form;
+/// the [`zone`](Timestamp::zone), [`scale`](Timestamp::scale), and
+/// [`source_offset`](Timestamp::source_offset) side-channels are not encoded.
+pub mod compact {
+    use super::Timestamp;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes a timestamp as its precision-truncated string form.
+    pub fn serialize<S>(timestamp: &Timestamp, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        timestamp.format_with_precision().serialize(serializer)
+    }
+
+    /// Deserializes a timestamp from its compact string, recovering precision.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        Timestamp::parse(&raw).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,6 +936,59 @@ mod tests {
         assert_eq!(ts.datetime.day(), 15);
     }
 
+    #[test]
+    fn test_parse_flexible_decade_and_year() {
+        let decade = Timestamp::parse_flexible("1920s").unwrap();
+        assert_eq!(decade.precision, TemporalPrecision::Decade);
+        assert_eq!(decade.datetime.year(), 1920);
+
+        let year = Timestamp::parse_flexible("~1914").unwrap();
+        assert_eq!(year.precision, TemporalPrecision::Year);
+        assert_eq!(year.datetime.year(), 1914);
+
+        assert_eq!(
+            Timestamp::parse_flexible("before 1910").unwrap().datetime.year(),
+            1910
+        );
+    }
+
+    #[test]
+    fn test_parse_flexible_century_and_span() {
+        let century = Timestamp::parse_flexible("C19").unwrap();
+        assert_eq!(century.precision, TemporalPrecision::Century);
+        assert_eq!(century.datetime.year(), 1800);
+        // The century widens to the full 1800s for temporal queries.
+        assert_eq!(century.to_range().start.year(), 1800);
+        assert_eq!(century.to_range().end.year(), 1900);
+
+        assert_eq!(
+            Timestamp::parse_flexible("late C18").unwrap().datetime.year(),
+            1790
+        );
+
+        let span = Timestamp::parse_flexible("1914-1918").unwrap();
+        assert_eq!(span.precision, TemporalPrecision::Year);
+        assert_eq!(span.datetime.year(), 1914);
+    }
+
+    #[test]
+    fn test_parse_flexible_month_forms_and_fallback() {
+        assert_eq!(
+            Timestamp::parse_flexible("06/1918").unwrap().precision,
+            TemporalPrecision::Month
+        );
+        let us = Timestamp::parse_flexible("3/15/1918").unwrap();
+        assert_eq!(us.precision, TemporalPrecision::Day);
+        assert_eq!(us.datetime.month(), 3);
+        assert_eq!(us.datetime.day(), 15);
+
+        // Unmatched fuzzy input falls through to the strict grammar, which
+        // reads a `Z` string with a zero seconds field as minute precision.
+        let strict = Timestamp::parse_flexible("2024-03-15T14:30:00Z").unwrap();
+        assert_eq!(strict.precision, TemporalPrecision::Minute);
+        assert!(Timestamp::parse_flexible("not a date").is_err());
+    }
+
     #[test]
     fn test_timestamp_parse_date_only() {
         let ts = Timestamp::parse("2024-03-15").unwrap();
@@ -322,6 +1048,164 @@ mod tests {
         assert_eq!(duration.num_days(), 1);
     }
 
+    #[test]
+    fn test_timestamp_parse_with_scale_gpst() {
+        // GPS time runs 18 s ahead of UTC as of 2017 (TAI-UTC = 37, GPST = TAI-19).
+        let gpst = Timestamp::parse_with_scale("2020-01-01T00:00:18Z", TimeScale::Gpst).unwrap();
+        let utc = Timestamp::parse("2020-01-01T00:00:00Z").unwrap();
+        assert_eq!(gpst.datetime, utc.datetime);
+        assert_eq!(gpst.scale, TimeScale::Gpst);
+        // Round-trips back to the original reading.
+        assert_eq!(
+            gpst.to_scale(TimeScale::Gpst),
+            utc.datetime + chrono::Duration::seconds(18)
+        );
+    }
+
+    #[test]
+    fn test_precision_round_trip() {
+        let base = Utc.with_ymd_and_hms(2024, 3, 15, 14, 30, 45).unwrap();
+        let cases = [
+            Timestamp::with_precision(
+                Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+                TemporalPrecision::Year,
+            ),
+            Timestamp::with_precision(
+                Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap(),
+                TemporalPrecision::Month,
+            ),
+            Timestamp::with_precision(
+                Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap(),
+                TemporalPrecision::Day,
+            ),
+            Timestamp::with_precision(
+                Utc.with_ymd_and_hms(2024, 3, 15, 14, 0, 0).unwrap(),
+                TemporalPrecision::Hour,
+            ),
+            Timestamp::with_precision(
+                Utc.with_ymd_and_hms(2024, 3, 15, 14, 30, 0).unwrap(),
+                TemporalPrecision::Minute,
+            ),
+            Timestamp::with_precision(base, TemporalPrecision::Second),
+            Timestamp::with_precision(
+                base + chrono::Duration::milliseconds(123),
+                TemporalPrecision::Millisecond,
+            ),
+        ];
+
+        for t in cases {
+            let round_tripped = Timestamp::parse(&t.format_with_precision()).unwrap();
+            assert_eq!(round_tripped, t, "round trip failed for {:?}", t.precision);
+        }
+    }
+
+    #[test]
+    fn test_calendar_duration_since_borrows_month() {
+        let earlier = Timestamp::parse("2022-01-20T00:00:00Z").unwrap();
+        let later = Timestamp::parse("2024-03-15T06:00:00Z").unwrap();
+        let d = later.calendar_duration_since(&earlier);
+        // Day-of-month 15 < 20, so borrow a month; the leap February yields
+        // 2 years, 1 month, 24 days.
+        assert_eq!(d.years, 2);
+        assert_eq!(d.months, 1);
+        assert_eq!(d.days, 24);
+        assert_eq!(d.remainder, chrono::Duration::hours(6));
+    }
+
+    #[test]
+    fn test_to_rfc3339_opts_subsecond() {
+        let ts = Timestamp::from_unix_millis(1_710_513_045_123).unwrap();
+        assert_eq!(
+            ts.to_rfc3339_opts(SubsecondFormat::Secs, true),
+            "2024-03-15T14:30:45Z"
+        );
+        assert_eq!(
+            ts.to_rfc3339_opts(SubsecondFormat::Millis, true),
+            "2024-03-15T14:30:45.123Z"
+        );
+        // Millisecond precision now renders its fractional digits.
+        assert!(ts.format_with_precision().contains(".123"));
+    }
+
+    #[test]
+    fn test_to_range_month_spans_full_month() {
+        let ts = Timestamp::parse("2024-02").unwrap();
+        let range = ts.to_range();
+        assert_eq!(range.start, Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap());
+        // Leap February rolls into March 1st.
+        assert_eq!(range.end, Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap());
+
+        let mid = Timestamp::parse("2024-02-15T12:00:00Z").unwrap();
+        assert!(range.contains(&mid));
+    }
+
+    #[test]
+    fn test_range_precision_aware_ordering() {
+        let march = Timestamp::parse("2024-03").unwrap().to_range();
+        let mar_15 = Timestamp::parse("2024-03-15").unwrap().to_range();
+        let april = Timestamp::parse("2024-04").unwrap().to_range();
+
+        // A day inside March does not order against the whole month.
+        assert!(!march.definitely_before(&mar_15));
+        assert!(march.overlaps(&mar_15));
+        assert!(march.possibly_before(&mar_15));
+
+        // But March is unambiguously before April.
+        assert!(march.definitely_before(&april));
+        assert!(!march.overlaps(&april));
+    }
+
+    #[test]
+    fn test_parse_rfc2822_retains_offset() {
+        let ts = Timestamp::parse("Fri, 15 Mar 2024 14:30:00 -0500").unwrap();
+        // Normalized to UTC for comparison.
+        assert_eq!(
+            ts.datetime,
+            Utc.with_ymd_and_hms(2024, 3, 15, 19, 30, 0).unwrap()
+        );
+        assert_eq!(ts.source_offset, FixedOffset::west_opt(5 * 3600));
+        // Local rendering re-applies the -05:00 offset.
+        assert_eq!(ts.local_datetime().hour(), 14);
+
+        // A timestamp ordered across offsets still compares on UTC.
+        let utc = Timestamp::parse("2024-03-15T18:00:00Z").unwrap();
+        assert!(utc < ts);
+    }
+
+    #[test]
+    fn test_format_local_with_zone_exemplar_city() {
+        let instant = Utc.with_ymd_and_hms(2024, 3, 15, 13, 30, 0).unwrap();
+        let ts = Timestamp::with_zone(instant, chrono_tz::Europe::Berlin);
+
+        // 13:30 UTC is 14:30 in Berlin (CET, +01:00) with a city label.
+        assert_eq!(
+            ts.format_local_with_zone(),
+            "2024-03-15T14:30:00+01:00 (Berlin)"
+        );
+        // to_rfc3339 now preserves the zone's offset rather than normalizing to UTC.
+        assert_eq!(ts.to_rfc3339(), "2024-03-15T14:30:00+01:00");
+
+        // A bare UTC instant is unaffected.
+        let utc = Timestamp::parse("2024-03-15T14:30:00Z").unwrap();
+        assert_eq!(utc.to_rfc3339(), "2024-03-15T14:30:00+00:00");
+    }
+
+    #[test]
+    fn test_with_timezone_keeps_instant_sets_zone() {
+        let utc = Timestamp::parse("2024-03-15T14:30:00Z").unwrap();
+        let berlin = utc.with_timezone(chrono_tz::Europe::Berlin);
+
+        assert_eq!(berlin.datetime, utc.datetime);
+        assert_eq!(berlin.zone, Some(chrono_tz::Europe::Berlin));
+        assert_eq!(berlin.to_rfc3339(), "2024-03-15T15:30:00+01:00");
+    }
+
+    #[test]
+    fn test_timestamp_parse_defaults_to_utc() {
+        let ts = Timestamp::parse("2024-03-15T14:30:00Z").unwrap();
+        assert_eq!(ts.scale, TimeScale::Utc);
+    }
+
     #[test]
     fn test_timestamp_serialization() {
         let ts = Timestamp::parse("2024-03-15T14:30:00Z").unwrap();
@@ -330,5 +1214,54 @@ mod tests {
         assert_eq!(ts.datetime, parsed.datetime);
     }
 
-    use chrono::Datelike;
+    use chrono::{Datelike, Timelike};
+
+    #[test]
+    fn test_timestamp_compact_round_trip_every_precision() {
+        #[derive(Serialize, Deserialize)]
+        struct Wrap {
+            #[serde(with = "compact")]
+            ts: Timestamp,
+        }
+
+        let dt = Utc
+            .with_ymd_and_hms(2024, 3, 15, 14, 30, 45)
+            .unwrap()
+            .with_nanosecond(123_000_000)
+            .unwrap();
+
+        let precisions = [
+            (TemporalPrecision::Year, "\"2024\""),
+            (TemporalPrecision::Month, "\"2024-03\""),
+            (TemporalPrecision::Day, "\"2024-03-15\""),
+            (TemporalPrecision::Hour, "\"2024-03-15T14:00:00Z\""),
+            (TemporalPrecision::Minute, "\"2024-03-15T14:30:00Z\""),
+            (TemporalPrecision::Millisecond, "\"2024-03-15T14:30:45.123Z\""),
+        ];
+
+        for (precision, expected) in precisions {
+            let wrap = Wrap {
+                ts: Timestamp::with_precision(dt, precision),
+            };
+            let json = serde_json::to_string(&wrap).unwrap();
+            assert_eq!(json, format!("{{\"ts\":{expected}}}"));
+
+            let back: Wrap = serde_json::from_str(&json).unwrap();
+            assert_eq!(back.ts.precision, precision);
+            let expected_ts = Timestamp::parse(expected.trim_matches('"')).unwrap();
+            assert_eq!(back.ts.datetime, expected_ts.datetime);
+        }
+    }
+
+    #[test]
+    fn test_timestamp_compact_rejects_malformed() {
+        #[derive(Deserialize)]
+        struct Wrap {
+            #[serde(with = "compact")]
+            #[allow(dead_code)]
+            ts: Timestamp,
+        }
+
+        assert!(serde_json::from_str::<Wrap>("{\"ts\":\"not-a-date\"}").is_err());
+    }
 }
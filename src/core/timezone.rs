@@ -0,0 +1,188 @@
+//! Coarse timezone resolution from geographic coordinates.
+//!
+//! Real timezone boundaries are irregular, often concave multi-polygons
+//! published by the IANA tz database. This module bundles a deliberately
+//! simplified set of rectangular boundary rings for a sample of major zones
+//! so [`resolve_timezone`] can answer "which zone is this point probably in"
+//! without shipping the full boundary dataset. Treat the result as a
+//! best-effort hint: coastal points, islands, and any zone this bundle omits
+//! fall back to the nearest boundary's centroid rather than failing outright.
+
+use super::Location;
+
+/// A coarse timezone boundary: an IANA zone name plus a simplified polygon
+/// ring of `(lon, lat)` vertices approximating the zone's populated extent.
+struct ZoneBoundary {
+    name: &'static str,
+    ring: &'static [(f64, f64)],
+}
+
+/// Bundled coarse boundaries for a sample of major IANA zones. Each ring is a
+/// simplified quadrilateral rather than the true boundary; rings are not
+/// guaranteed non-overlapping, so [`resolve_timezone`] returns the first
+/// match in this order.
+const ZONE_BOUNDARIES: &[ZoneBoundary] = &[
+    ZoneBoundary {
+        name: "America/Los_Angeles",
+        ring: &[(-124.5, 32.5), (-114.0, 32.5), (-114.0, 49.0), (-124.5, 49.0)],
+    },
+    ZoneBoundary {
+        name: "America/Denver",
+        ring: &[(-114.0, 31.0), (-102.0, 31.0), (-102.0, 49.0), (-114.0, 49.0)],
+    },
+    ZoneBoundary {
+        name: "America/Chicago",
+        ring: &[(-102.0, 25.5), (-90.0, 25.5), (-90.0, 49.0), (-102.0, 49.0)],
+    },
+    ZoneBoundary {
+        name: "America/New_York",
+        ring: &[(-90.0, 24.5), (-67.0, 24.5), (-67.0, 47.5), (-90.0, 47.5)],
+    },
+    ZoneBoundary {
+        name: "America/Sao_Paulo",
+        ring: &[(-57.0, -33.0), (-34.0, -33.0), (-34.0, -14.0), (-57.0, -14.0)],
+    },
+    ZoneBoundary {
+        name: "Europe/London",
+        ring: &[(-8.0, 49.9), (2.0, 49.9), (2.0, 60.9), (-8.0, 60.9)],
+    },
+    ZoneBoundary {
+        name: "Europe/Paris",
+        ring: &[(2.0, 42.0), (15.0, 42.0), (15.0, 51.5), (2.0, 51.5)],
+    },
+    ZoneBoundary {
+        name: "Europe/Berlin",
+        ring: &[(5.5, 47.0), (15.0, 47.0), (15.0, 55.0), (5.5, 55.0)],
+    },
+    ZoneBoundary {
+        name: "Europe/Moscow",
+        ring: &[(30.0, 50.0), (49.0, 50.0), (49.0, 68.0), (30.0, 68.0)],
+    },
+    ZoneBoundary {
+        name: "Africa/Cairo",
+        ring: &[(25.0, 22.0), (36.0, 22.0), (36.0, 31.7), (25.0, 31.7)],
+    },
+    ZoneBoundary {
+        name: "Africa/Johannesburg",
+        ring: &[(16.0, -35.0), (33.0, -35.0), (33.0, -22.0), (16.0, -22.0)],
+    },
+    ZoneBoundary {
+        name: "Asia/Dubai",
+        ring: &[(51.0, 22.5), (56.5, 22.5), (56.5, 26.5), (51.0, 26.5)],
+    },
+    ZoneBoundary {
+        name: "Asia/Kolkata",
+        ring: &[(68.0, 8.0), (90.0, 8.0), (90.0, 36.0), (68.0, 36.0)],
+    },
+    ZoneBoundary {
+        name: "Asia/Shanghai",
+        ring: &[(73.0, 18.0), (135.0, 18.0), (135.0, 53.5), (73.0, 53.5)],
+    },
+    ZoneBoundary {
+        name: "Asia/Tokyo",
+        ring: &[(129.0, 31.0), (146.0, 31.0), (146.0, 45.5), (129.0, 45.5)],
+    },
+    ZoneBoundary {
+        name: "Australia/Sydney",
+        ring: &[(141.0, -39.0), (154.0, -39.0), (154.0, -28.0), (141.0, -28.0)],
+    },
+    ZoneBoundary {
+        name: "Pacific/Auckland",
+        ring: &[(166.0, -47.5), (179.0, -47.5), (179.0, -34.0), (166.0, -34.0)],
+    },
+];
+
+/// Resolves the IANA timezone whose bundled coarse boundary contains
+/// `(lat, lon)`.
+///
+/// Each candidate ring is tested with the standard even-odd ray-casting
+/// point-in-polygon rule, in [`ZONE_BOUNDARIES`] order; the first containing
+/// ring's zone wins. When no ring contains the point (a gap between
+/// boundaries, e.g. coastal rounding, or a zone this bundle omits), falls
+/// back to the zone whose ring centroid is nearest the point.
+///
+/// Returns `None` only if the bundle is empty, which it never is in
+/// practice.
+pub fn resolve_timezone(lat: f64, lon: f64) -> Option<chrono_tz::Tz> {
+    for boundary in ZONE_BOUNDARIES {
+        if ring_contains(boundary.ring, lon, lat) {
+            return parse_zone(boundary.name);
+        }
+    }
+
+    ZONE_BOUNDARIES
+        .iter()
+        .min_by(|a, b| {
+            centroid_distance(a.ring, lon, lat)
+                .partial_cmp(&centroid_distance(b.ring, lon, lat))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .and_then(|boundary| parse_zone(boundary.name))
+}
+
+/// Resolves the timezone for `location` via [`resolve_timezone`].
+pub fn resolve_timezone_for(location: &Location) -> Option<chrono_tz::Tz> {
+    resolve_timezone(location.lat, location.lon)
+}
+
+fn parse_zone(name: &str) -> Option<chrono_tz::Tz> {
+    name.parse().ok()
+}
+
+/// Standard even-odd ray-casting point-in-polygon test: casts a ray in the
+/// +x direction from `(x, y)` and counts ring edge crossings.
+fn ring_contains(ring: &[(f64, f64)], x: f64, y: f64) -> bool {
+    let mut inside = false;
+    for i in 0..ring.len() {
+        let (x1, y1) = ring[i];
+        let (x2, y2) = ring[(i + 1) % ring.len()];
+        let crosses = (y1 > y) != (y2 > y);
+        if crosses {
+            let x_at_y = x1 + (y - y1) * (x2 - x1) / (y2 - y1);
+            if x < x_at_y {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Planar (degree-space) distance from `(x, y)` to `ring`'s arithmetic
+/// centroid — sufficient for choosing among coarse fallback candidates
+/// without pulling in a full haversine for what is already an approximation.
+fn centroid_distance(ring: &[(f64, f64)], x: f64, y: f64) -> f64 {
+    let n = ring.len() as f64;
+    let cx = ring.iter().map(|(px, _)| px).sum::<f64>() / n;
+    let cy = ring.iter().map(|(_, py)| py).sum::<f64>() / n;
+    ((x - cx).powi(2) + (y - cy).powi(2)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_timezone_new_york() {
+        let tz = resolve_timezone(40.7128, -74.0060).unwrap();
+        assert_eq!(tz, chrono_tz::America::New_York);
+    }
+
+    #[test]
+    fn test_resolve_timezone_tokyo() {
+        let tz = resolve_timezone(35.6762, 139.6503).unwrap();
+        assert_eq!(tz, chrono_tz::Asia::Tokyo);
+    }
+
+    #[test]
+    fn test_resolve_timezone_falls_back_to_nearest_centroid_in_gap() {
+        // Mid-Atlantic: outside every bundled ring, should still resolve to
+        // something rather than returning None.
+        assert!(resolve_timezone(40.0, -40.0).is_some());
+    }
+
+    #[test]
+    fn test_resolve_timezone_for_location() {
+        let loc = Location::new(35.6762, 139.6503);
+        assert_eq!(resolve_timezone_for(&loc), resolve_timezone(35.6762, 139.6503));
+    }
+}
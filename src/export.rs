@@ -0,0 +1,152 @@
+//! Export of narratives to calendar and note-taking formats.
+//!
+//! Unlike the formats in [`io`](crate::io), these are one-way: there is no
+//! structured data to round-trip back into a [`Narrative`], just plain text
+//! meant for calendar apps and note systems to consume.
+//!
+//! # Example
+//!
+//! ```rust
+//! use spatial_narrative::export::{to_ical, to_org};
+//! use spatial_narrative::prelude::*;
+//!
+//! let narrative = Narrative::builder()
+//!     .title("Field Trip")
+//!     .event(Event::builder()
+//!         .location(Location::new(40.7128, -74.0060))
+//!         .timestamp(Timestamp::parse("2024-01-20T08:00:00Z").unwrap())
+//!         .text("Arrived at the site")
+//!         .build())
+//!     .build();
+//!
+//! let ical = to_ical(&narrative);
+//! assert!(ical.contains("BEGIN:VCALENDAR"));
+//!
+//! let org = to_org(&narrative);
+//! assert!(org.contains("<2024-01-20"));
+//! ```
+
+use crate::core::{Event, Narrative};
+
+/// Serialize a narrative to an iCalendar (RFC 5545) `VCALENDAR` of `VEVENT`s.
+///
+/// Each event becomes one `VEVENT`, with `DTSTART` from its timestamp,
+/// `SUMMARY` from its text, and a `GEO:lat;lon` property from its location.
+pub fn to_ical(narrative: &Narrative) -> String {
+    let mut out = String::new();
+    out.push_str("BEGIN:VCALENDAR\r\n");
+    out.push_str("VERSION:2.0\r\n");
+    out.push_str("PRODID:-//spatial-narrative//export//EN\r\n");
+
+    for event in &narrative.events {
+        out.push_str("BEGIN:VEVENT\r\n");
+        out.push_str(&format!("UID:{}\r\n", event.id.0));
+        out.push_str(&format!("DTSTART:{}\r\n", ical_datetime(event)));
+        out.push_str(&format!("SUMMARY:{}\r\n", ical_escape(&event.text)));
+        out.push_str(&format!(
+            "GEO:{};{}\r\n",
+            event.location.lat, event.location.lon
+        ));
+        out.push_str("END:VEVENT\r\n");
+    }
+
+    out.push_str("END:VCALENDAR\r\n");
+    out
+}
+
+/// Serialize a narrative to org-mode active timestamps and clock entries.
+///
+/// Each event gets an active timestamp line `<YYYY-MM-DD Day HH:MM> text`,
+/// where `Day` is the abbreviated weekday (e.g. `Sat`).
+/// Consecutive events are additionally treated as an interval and get a
+/// `CLOCK: [start]--[end] => HH:MM` line giving the elapsed duration.
+pub fn to_org(narrative: &Narrative) -> String {
+    let mut out = String::new();
+    let events = narrative.events_chronological();
+
+    for (i, event) in events.iter().enumerate() {
+        out.push_str(&format!(
+            "<{}> {}\n",
+            event.timestamp.datetime.format("%Y-%m-%d %a %H:%M"),
+            event.text
+        ));
+
+        if let Some(next) = events.get(i + 1) {
+            let duration = next.timestamp.datetime - event.timestamp.datetime;
+            let total_minutes = duration.num_minutes().max(0);
+            out.push_str(&format!(
+                "CLOCK: [{}]--[{}] => {:02}:{:02}\n",
+                event.timestamp.datetime.format("%Y-%m-%d %a %H:%M"),
+                next.timestamp.datetime.format("%Y-%m-%d %a %H:%M"),
+                total_minutes / 60,
+                total_minutes % 60
+            ));
+        }
+    }
+
+    out
+}
+
+fn ical_datetime(event: &Event) -> String {
+    event.timestamp.datetime.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape characters iCalendar reserves in `TEXT` values (RFC 5545 §3.3.11).
+fn ical_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace(';', "\\;")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Location, Timestamp};
+
+    fn sample_narrative() -> Narrative {
+        let mut narrative = Narrative::new("Trip");
+        narrative.add_event(Event::new(
+            Location::new(40.7128, -74.0060),
+            Timestamp::parse("2024-01-20T08:00:00Z").unwrap(),
+            "Arrived",
+        ));
+        narrative.add_event(Event::new(
+            Location::new(40.7580, -73.9855),
+            Timestamp::parse("2024-01-20T09:30:00Z").unwrap(),
+            "Lunch",
+        ));
+        narrative
+    }
+
+    #[test]
+    fn test_to_ical_wraps_events_in_vcalendar() {
+        let ical = to_ical(&sample_narrative());
+        assert!(ical.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ical.trim_end().ends_with("END:VCALENDAR"));
+        assert_eq!(ical.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ical.contains("DTSTART:20240120T080000Z"));
+        assert!(ical.contains("SUMMARY:Arrived"));
+        assert!(ical.contains("GEO:40.7128;-74.006"));
+    }
+
+    #[test]
+    fn test_to_ical_escapes_reserved_characters() {
+        let mut narrative = Narrative::new("Trip");
+        narrative.add_event(Event::new(
+            Location::new(0.0, 0.0),
+            Timestamp::parse("2024-01-20T08:00:00Z").unwrap(),
+            "Comma, semicolon; backslash\\",
+        ));
+        let ical = to_ical(&narrative);
+        assert!(ical.contains("SUMMARY:Comma\\, semicolon\\; backslash\\\\"));
+    }
+
+    #[test]
+    fn test_to_org_emits_active_timestamps_and_clock() {
+        let org = to_org(&sample_narrative());
+        assert!(org.contains("<2024-01-20 Sat 08:00> Arrived"));
+        assert!(org.contains("<2024-01-20 Sat 09:30> Lunch"));
+        assert!(org.contains("CLOCK: [2024-01-20 Sat 08:00]--[2024-01-20 Sat 09:30] => 01:30"));
+    }
+}
@@ -27,5 +27,6 @@
 mod narrative_graph;
 
 pub use narrative_graph::{
-    DotOptions, EdgeType, EdgeWeight, NarrativeGraph, NodeId, PathInfo, SubgraphResult,
+    ContractedEdge, ContractedGraph, DominatorTree, DotOptions, EdgeChange, EdgeType, EdgeWeight,
+    GraphDiff, NarrativeGraph, NodeId, PathInfo, PathMetric, SubgraphResult,
 };
@@ -2,13 +2,17 @@
 //!
 //! Uses petgraph for the underlying graph structure.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use petgraph::graph::{DiGraph, NodeIndex};
 use petgraph::visit::EdgeRef;
-use petgraph::algo::{dijkstra, has_path_connecting};
+use petgraph::algo::has_path_connecting;
 use petgraph::Direction;
+use serde::de::Deserializer;
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
 
 use crate::core::{Event, EventId, GeoBounds, TimeRange, Location};
+use crate::error::Result;
 
 /// Unique identifier for a node in the narrative graph.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -22,7 +26,7 @@ impl NodeId {
 }
 
 /// Type of relationship between events.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EdgeType {
     /// Temporal sequence (A happens before B)
     Temporal,
@@ -44,8 +48,17 @@ impl Default for EdgeType {
     }
 }
 
+/// How to weight hops when searching for the longest temporal chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathMetric {
+    /// Each temporal hop counts as 1 (longest event count).
+    Hops,
+    /// Each hop counts as the elapsed seconds between the two events.
+    TemporalSeconds,
+}
+
 /// Weight/metadata for an edge in the graph.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EdgeWeight {
     /// Type of relationship
     pub edge_type: EdgeType,
@@ -215,45 +228,953 @@ impl NarrativeGraph {
     /// Find the shortest path between two nodes.
     ///
     /// Returns path information including the sequence of nodes and total weight.
+    /// Edge cost is `1.0 - weight` (a stronger connection is a cheaper hop), and
+    /// the search records predecessor pointers as it goes so the path is read
+    /// back in a single pass rather than rescanning predecessors per hop.
     pub fn shortest_path(&self, from: NodeId, to: NodeId) -> Option<PathInfo> {
-        // Use Dijkstra with inverted weights (higher weight = lower cost)
-        let costs = dijkstra(&self.graph, from.0, Some(to.0), |e| {
-            1.0 - e.weight().weight
-        });
+        self.dijkstra_path(from, to, &HashSet::new(), &HashSet::new())
+    }
 
-        if !costs.contains_key(&to.0) {
+    /// Dijkstra over a `d`-ary heap, optionally ignoring some nodes and edges.
+    ///
+    /// `blocked_nodes` and `blocked_edges` let Yen's algorithm carve spur paths
+    /// out of an already-discovered tree without mutating the graph. The forward
+    /// search keeps a `came_from` map so the returned path is reconstructed in
+    /// one reverse walk.
+    fn dijkstra_path(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        blocked_nodes: &HashSet<NodeIndex>,
+        blocked_edges: &HashSet<(NodeIndex, NodeIndex)>,
+    ) -> Option<PathInfo> {
+        if blocked_nodes.contains(&from.0) || blocked_nodes.contains(&to.0) {
             return None;
         }
 
-        // Reconstruct path
-        let mut path = vec![to];
-        let mut current = to.0;
+        let mut dist: HashMap<NodeIndex, f64> = HashMap::new();
+        let mut came_from: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let mut heap = DaryHeap::new(DEFAULT_HEAP_ARITY);
+
+        dist.insert(from.0, 0.0);
+        heap.push(0.0, from.0);
+
+        while let Some((d, current)) = heap.pop() {
+            if current == to.0 {
+                let mut path = vec![NodeId(current)];
+                let mut cursor = current;
+                while cursor != from.0 {
+                    let prev = came_from[&cursor];
+                    path.push(NodeId(prev));
+                    cursor = prev;
+                }
+                path.reverse();
+                return Some(PathInfo {
+                    nodes: path,
+                    total_weight: d,
+                });
+            }
 
-        while current != from.0 {
-            let predecessors: Vec<_> = self.graph
-                .neighbors_directed(current, Direction::Incoming)
-                .collect();
+            // Stale heap entry left over from a since-improved distance.
+            if d > *dist.get(&current).unwrap_or(&f64::INFINITY) {
+                continue;
+            }
 
-            let best = predecessors.iter()
-                .filter(|&&n| costs.contains_key(&n))
-                .min_by(|&&a, &&b| {
-                    costs[&a].partial_cmp(&costs[&b]).unwrap()
-                });
+            for edge in self.graph.edges_directed(current, Direction::Outgoing) {
+                let next = edge.target();
+                if blocked_nodes.contains(&next) || blocked_edges.contains(&(current, next)) {
+                    continue;
+                }
+                let tentative = d + (1.0 - edge.weight().weight);
+                if tentative < *dist.get(&next).unwrap_or(&f64::INFINITY) {
+                    dist.insert(next, tentative);
+                    came_from.insert(next, current);
+                    heap.push(tentative, next);
+                }
+            }
+        }
 
-            if let Some(&next) = best {
-                path.push(NodeId(next));
-                current = next;
-            } else {
+        None
+    }
+
+    /// Find up to `k` loopless shortest paths from `from` to `to` (Yen's algorithm).
+    ///
+    /// The first path is the plain [`shortest_path`](Self::shortest_path). Each
+    /// subsequent path is found by taking every spur node along the last
+    /// accepted path, temporarily removing the edges that previously-found paths
+    /// sharing the same root would reuse (and the root nodes themselves, to keep
+    /// paths loopless), computing a spur path to the target, and splicing
+    /// root + spur into a candidate pool. The cheapest unique candidate is
+    /// promoted to the result each round until `k` paths are found or the pool
+    /// runs dry. Paths are returned in increasing order of total cost.
+    pub fn k_shortest_paths(&self, from: NodeId, to: NodeId, k: usize) -> Vec<PathInfo> {
+        let mut result: Vec<PathInfo> = Vec::new();
+        if k == 0 {
+            return result;
+        }
+
+        let first = match self.dijkstra_path(from, to, &HashSet::new(), &HashSet::new()) {
+            Some(path) => path,
+            None => return result,
+        };
+        result.push(first);
+
+        let mut candidates: Vec<PathInfo> = Vec::new();
+
+        while result.len() < k {
+            let prev = result.last().unwrap().nodes.clone();
+
+            for i in 0..prev.len().saturating_sub(1) {
+                let spur_node = prev[i];
+                let root = &prev[..=i];
+
+                // Block edges reused by any accepted path sharing this root.
+                let mut blocked_edges: HashSet<(NodeIndex, NodeIndex)> = HashSet::new();
+                for p in result.iter() {
+                    if p.nodes.len() > i && p.nodes[..=i] == *root {
+                        blocked_edges.insert((p.nodes[i].0, p.nodes[i + 1].0));
+                    }
+                }
+
+                // Block the root nodes preceding the spur so spurs stay loopless.
+                let blocked_nodes: HashSet<NodeIndex> =
+                    root[..i].iter().map(|n| n.0).collect();
+
+                if let Some(spur) =
+                    self.dijkstra_path(spur_node, to, &blocked_nodes, &blocked_edges)
+                {
+                    let mut nodes = root[..i].to_vec();
+                    nodes.extend(spur.nodes.iter().cloned());
+
+                    if let Some(total) = self.path_cost(&nodes) {
+                        let candidate = PathInfo {
+                            nodes,
+                            total_weight: total,
+                        };
+                        let mut known = result.iter().chain(candidates.iter());
+                        if !known.any(|p| p.nodes == candidate.nodes) {
+                            candidates.push(candidate);
+                        }
+                    }
+                }
+            }
+
+            if candidates.is_empty() {
                 break;
             }
+
+            let best_idx = candidates
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| {
+                    a.total_weight
+                        .partial_cmp(&b.total_weight)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .map(|(idx, _)| idx)
+                .unwrap();
+            result.push(candidates.remove(best_idx));
+        }
+
+        result
+    }
+
+    /// Total cost (`sum of 1.0 - weight`) of a node sequence, or `None` if any
+    /// consecutive pair is not connected by an edge.
+    fn path_cost(&self, nodes: &[NodeId]) -> Option<f64> {
+        let mut total = 0.0;
+        for pair in nodes.windows(2) {
+            let edge = self.graph.find_edge(pair[0].0, pair[1].0)?;
+            total += 1.0 - self.graph[edge].weight;
+        }
+        Some(total)
+    }
+
+    /// Find the shortest path using A* with a great-circle heuristic.
+    ///
+    /// Runs A* over the same edge cost as [`shortest_path`](Self::shortest_path)
+    /// (`1.0 - weight`), ordering the open set by `g + h`, where `g` is the
+    /// accumulated cost and `h(n)` is the straight-line distance from `n` to the
+    /// target scaled by the graph's minimum cost-per-kilometre (see
+    /// [`admissible_heuristic_scale`](Self::admissible_heuristic_scale)). That
+    /// scale guarantees the heuristic never overestimates the true remaining
+    /// cost, so the returned [`PathInfo`] is optimal while typically exploring
+    /// far fewer nodes than full Dijkstra on geographically embedded graphs.
+    pub fn shortest_path_astar(&self, from: NodeId, to: NodeId) -> Option<PathInfo> {
+        use std::collections::BinaryHeap;
+
+        let target = self.event(to)?.location.clone();
+        let _ = self.event(from)?;
+        let k = self.admissible_heuristic_scale();
+        let heuristic = |idx: NodeIndex| haversine_distance(&self.graph[idx].location, &target) * k;
+
+        let mut g_score: HashMap<NodeIndex, f64> = HashMap::new();
+        let mut came_from: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let mut visited: std::collections::HashSet<NodeIndex> = std::collections::HashSet::new();
+        let mut open = BinaryHeap::new();
+
+        g_score.insert(from.0, 0.0);
+        open.push(MinScored(heuristic(from.0), from.0));
+
+        while let Some(MinScored(_, current)) = open.pop() {
+            if current == to.0 {
+                let mut path = vec![NodeId(current)];
+                let mut cursor = current;
+                while cursor != from.0 {
+                    let prev = came_from[&cursor];
+                    path.push(NodeId(prev));
+                    cursor = prev;
+                }
+                path.reverse();
+                return Some(PathInfo {
+                    nodes: path,
+                    total_weight: g_score[&to.0],
+                });
+            }
+
+            if !visited.insert(current) {
+                continue;
+            }
+
+            let current_g = g_score[&current];
+            for edge in self.graph.edges_directed(current, Direction::Outgoing) {
+                let next = edge.target();
+                let tentative = current_g + (1.0 - edge.weight().weight);
+                if tentative < *g_score.get(&next).unwrap_or(&f64::INFINITY) {
+                    g_score.insert(next, tentative);
+                    came_from.insert(next, current);
+                    open.push(MinScored(tentative + heuristic(next), next));
+                }
+            }
         }
 
+        None
+    }
+
+    /// The largest heuristic scale `k` that keeps the A* heuristic admissible.
+    ///
+    /// `k` is the minimum cost-per-kilometre over all edges; since the
+    /// straight-line distance to the target is a lower bound on the distance
+    /// travelled along any real path, scaling it by the cheapest per-kilometre
+    /// rate can never exceed the true remaining cost. Degenerates to `0.0`
+    /// (i.e. plain Dijkstra) when no edge spans a positive distance.
+    fn admissible_heuristic_scale(&self) -> f64 {
+        let min_ratio = self
+            .graph
+            .edge_references()
+            .filter_map(|edge| {
+                let source = &self.graph[edge.source()];
+                let target = &self.graph[edge.target()];
+                let km = haversine_distance(&source.location, &target.location);
+                (km > 0.0).then(|| (1.0 - edge.weight().weight) / km)
+            })
+            .fold(f64::INFINITY, f64::min);
+
+        if min_ratio.is_finite() {
+            min_ratio
+        } else {
+            0.0
+        }
+    }
+
+    /// Shortest geographic route between two connected events.
+    ///
+    /// Where [`shortest_path`](Self::shortest_path) minimises the sum of
+    /// `1.0 - weight` connection costs, this routes along the existing directed
+    /// edges so as to minimise the total great-circle distance (in kilometres)
+    /// between consecutive events' [`Location`]s. It runs plain Dijkstra over a
+    /// min-heap, skipping stale heap entries, and reconstructs the path from the
+    /// predecessor map recorded during the forward search. Returns the node
+    /// sequence and total distance, or `None` if `goal` is unreachable from
+    /// `start`.
+    pub fn shortest_path_geographic(&self, start: NodeId, goal: NodeId) -> Option<PathInfo> {
+        use std::collections::BinaryHeap;
+
+        let _ = self.event(start)?;
+        let _ = self.event(goal)?;
+
+        let mut dist: HashMap<NodeIndex, f64> = HashMap::new();
+        let mut came_from: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        dist.insert(start.0, 0.0);
+        heap.push(MinScored(0.0, start.0));
+
+        while let Some(MinScored(d, current)) = heap.pop() {
+            if current == goal.0 {
+                let mut path = vec![NodeId(current)];
+                let mut cursor = current;
+                while cursor != start.0 {
+                    let prev = came_from[&cursor];
+                    path.push(NodeId(prev));
+                    cursor = prev;
+                }
+                path.reverse();
+                return Some(PathInfo {
+                    nodes: path,
+                    total_weight: d,
+                });
+            }
+
+            if d > *dist.get(&current).unwrap_or(&f64::INFINITY) {
+                continue;
+            }
+
+            let here = self.graph[current].location.clone();
+            for edge in self.graph.edges_directed(current, Direction::Outgoing) {
+                let next = edge.target();
+                let tentative = d + haversine_distance(&here, &self.graph[next].location);
+                if tentative < *dist.get(&next).unwrap_or(&f64::INFINITY) {
+                    dist.insert(next, tentative);
+                    came_from.insert(next, current);
+                    heap.push(MinScored(tentative, next));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// The longest chain of temporally-connected events — the story's "spine".
+    ///
+    /// `connect_temporal` yields a DAG whose edges always run forward in time,
+    /// so processing nodes in timestamp order is a valid topological order.
+    /// A dynamic program tracks the best chain ending at each node: `dp[v]` is
+    /// the heaviest path to `v` and `prev[v]` its predecessor, where a hop's
+    /// weight is either `1` ([`PathMetric::Hops`]) or the gap in seconds
+    /// ([`PathMetric::TemporalSeconds`]). The path is reconstructed by walking
+    /// `prev` back from the heaviest node. Only [`EdgeType::Temporal`] edges are
+    /// followed; returns an empty vector for an empty graph.
+    pub fn longest_path(&self, metric: PathMetric) -> Vec<NodeId> {
+        if self.graph.node_count() == 0 {
+            return Vec::new();
+        }
+
+        // Timestamp order is a topological order over forward temporal edges.
+        let mut order: Vec<NodeIndex> = self.graph.node_indices().collect();
+        order.sort_by(|&a, &b| {
+            self.graph[a]
+                .timestamp
+                .cmp(&self.graph[b].timestamp)
+                .then(a.index().cmp(&b.index()))
+        });
+
+        let mut dp: HashMap<NodeIndex, f64> = HashMap::new();
+        let mut prev: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+
+        for &u in &order {
+            let du = dp.get(&u).copied().unwrap_or(0.0);
+            for edge in self.graph.edges_directed(u, Direction::Outgoing) {
+                if edge.weight().edge_type != EdgeType::Temporal {
+                    continue;
+                }
+                let v = edge.target();
+                let hop = match metric {
+                    PathMetric::Hops => 1.0,
+                    PathMetric::TemporalSeconds => (self.graph[v].timestamp.unix_timestamp()
+                        - self.graph[u].timestamp.unix_timestamp())
+                        as f64,
+                };
+                let candidate = du + hop;
+                if candidate > dp.get(&v).copied().unwrap_or(0.0) {
+                    dp.insert(v, candidate);
+                    prev.insert(v, u);
+                }
+            }
+        }
+
+        // Heaviest endpoint; ties broken by the timestamp order above.
+        let best = order
+            .iter()
+            .copied()
+            .max_by(|&a, &b| {
+                let da = dp.get(&a).copied().unwrap_or(0.0);
+                let db = dp.get(&b).copied().unwrap_or(0.0);
+                da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .expect("non-empty graph has at least one node");
+
+        let mut path = vec![best];
+        let mut cursor = best;
+        while let Some(&p) = prev.get(&cursor) {
+            path.push(p);
+            cursor = p;
+        }
         path.reverse();
+        path.into_iter().map(NodeId).collect()
+    }
 
-        Some(PathInfo {
-            nodes: path,
-            total_weight: costs[&to.0],
-        })
+    /// Find a travel order over `nodes` minimising total distance travelled.
+    ///
+    /// Routes by straight-line `haversine_distance` (ignoring the graph's
+    /// edges), useful for planning the physical itinerary of a spatial
+    /// narrative. For small inputs (≤ 12 nodes) it solves the open-path tour
+    /// exactly with Held–Karp dynamic programming over subset masks; for larger
+    /// inputs it falls back to a nearest-neighbour construction refined by
+    /// 2-opt swaps until no improving swap remains. The first node is treated as
+    /// the fixed origin. Returns the node order and its total distance.
+    pub fn optimal_tour(&self, nodes: &[NodeId]) -> (Vec<NodeId>, f64) {
+        if nodes.len() <= 1 {
+            return (nodes.to_vec(), 0.0);
+        }
+
+        let n = nodes.len();
+        let locations: Vec<Location> = nodes
+            .iter()
+            .map(|&node| self.graph[node.0].location.clone())
+            .collect();
+        let dist = |i: usize, j: usize| haversine_distance(&locations[i], &locations[j]);
+
+        // Precompute the symmetric distance matrix once.
+        let mut matrix = vec![vec![0.0f64; n]; n];
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let d = dist(i, j);
+                matrix[i][j] = d;
+                matrix[j][i] = d;
+            }
+        }
+
+        let (order, total) = if n <= 12 {
+            Self::held_karp(n, &matrix)
+        } else {
+            Self::nearest_neighbor_2opt(n, &matrix)
+        };
+
+        (order.into_iter().map(|i| nodes[i]).collect(), total)
+    }
+
+    /// Exact open-path tour from a fixed origin via Held–Karp DP.
+    fn held_karp(n: usize, matrix: &[Vec<f64>]) -> (Vec<usize>, f64) {
+        let full = 1usize << n;
+        let mut dp = vec![vec![f64::INFINITY; n]; full];
+        let mut parent = vec![vec![usize::MAX; n]; full];
+        dp[1][0] = 0.0; // origin only, ending at origin
+
+        for mask in 1..full {
+            if mask & 1 == 0 {
+                continue; // every subset must contain the origin
+            }
+            for i in 0..n {
+                if mask & (1 << i) == 0 || !dp[mask][i].is_finite() {
+                    continue;
+                }
+                for j in 0..n {
+                    if mask & (1 << j) != 0 {
+                        continue;
+                    }
+                    let next = mask | (1 << j);
+                    let candidate = dp[mask][i] + matrix[i][j];
+                    if candidate < dp[next][j] {
+                        dp[next][j] = candidate;
+                        parent[next][j] = i;
+                    }
+                }
+            }
+        }
+
+        // Best open path ending at any node.
+        let all = full - 1;
+        let mut end = 0;
+        let mut best = f64::INFINITY;
+        for i in 0..n {
+            if dp[all][i] < best {
+                best = dp[all][i];
+                end = i;
+            }
+        }
+
+        // Reconstruct by following parents back to the origin.
+        let mut order = Vec::with_capacity(n);
+        let mut mask = all;
+        let mut node = end;
+        while node != usize::MAX {
+            order.push(node);
+            let prev = parent[mask][node];
+            mask &= !(1 << node);
+            node = prev;
+        }
+        order.reverse();
+        (order, best)
+    }
+
+    /// Nearest-neighbour construction refined by 2-opt, for larger tours.
+    fn nearest_neighbor_2opt(n: usize, matrix: &[Vec<f64>]) -> (Vec<usize>, f64) {
+        // Greedy nearest-neighbour starting from the origin.
+        let mut visited = vec![false; n];
+        let mut order = Vec::with_capacity(n);
+        let mut current = 0;
+        visited[0] = true;
+        order.push(0);
+        for _ in 1..n {
+            let mut best = usize::MAX;
+            let mut best_d = f64::INFINITY;
+            for j in 0..n {
+                if !visited[j] && matrix[current][j] < best_d {
+                    best_d = matrix[current][j];
+                    best = j;
+                }
+            }
+            visited[best] = true;
+            order.push(best);
+            current = best;
+        }
+
+        // 2-opt: reverse segments while it shortens the path.
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for i in 1..(n - 1) {
+                for k in (i + 1)..n {
+                    let before = matrix[order[i - 1]][order[i]] + {
+                        if k + 1 < n {
+                            matrix[order[k]][order[k + 1]]
+                        } else {
+                            0.0
+                        }
+                    };
+                    let after = matrix[order[i - 1]][order[k]] + {
+                        if k + 1 < n {
+                            matrix[order[i]][order[k + 1]]
+                        } else {
+                            0.0
+                        }
+                    };
+                    if after + 1e-9 < before {
+                        order[i..=k].reverse();
+                        improved = true;
+                    }
+                }
+            }
+        }
+
+        let total = order
+            .windows(2)
+            .map(|w| matrix[w[0]][w[1]])
+            .sum();
+        (order, total)
+    }
+
+    /// Collapse chains of pass-through nodes into single weighted edges.
+    ///
+    /// Only "junction" nodes — roots, leaves, and nodes with more than one
+    /// incoming or outgoing edge — are kept. Starting from each junction, the
+    /// walk follows the unique successor chain through degree-2 nodes until it
+    /// reaches the next junction, summing `haversine_distance` along the way
+    /// into a single [`ContractedEdge`] and recording the collapsed interior
+    /// nodes so the original sequence can be recovered. Running `shortest_path`
+    /// / `longest_path` over the result explores only the decision points of a
+    /// long linear narrative without losing the underlying events.
+    pub fn contract(&self) -> ContractedGraph {
+        let is_junction = |idx: NodeIndex| {
+            let in_deg = self.graph.edges_directed(idx, Direction::Incoming).count();
+            let out_deg = self.graph.edges_directed(idx, Direction::Outgoing).count();
+            in_deg != 1 || out_deg != 1
+        };
+
+        let junctions: Vec<NodeId> = self
+            .graph
+            .node_indices()
+            .filter(|&idx| is_junction(idx))
+            .map(NodeId)
+            .collect();
+
+        let n = self.graph.node_count();
+        let mut edges = Vec::new();
+
+        for &junction in &junctions {
+            for edge in self.graph.edges_directed(junction.0, Direction::Outgoing) {
+                let mut current = edge.target();
+                let mut weight =
+                    haversine_distance(&self.graph[junction.0].location, &self.graph[current].location);
+                let mut collapsed = Vec::new();
+                let mut steps = 0;
+
+                while !is_junction(current) && steps <= n {
+                    collapsed.push(NodeId(current));
+                    let succ = self
+                        .graph
+                        .neighbors_directed(current, Direction::Outgoing)
+                        .next()
+                        .expect("a degree-2 interior node has exactly one successor");
+                    weight +=
+                        haversine_distance(&self.graph[current].location, &self.graph[succ].location);
+                    current = succ;
+                    steps += 1;
+                }
+
+                edges.push(ContractedEdge {
+                    from: junction,
+                    to: NodeId(current),
+                    weight,
+                    collapsed,
+                });
+            }
+        }
+
+        ContractedGraph { junctions, edges }
+    }
+
+    /// Compute a structural diff against another graph version.
+    ///
+    /// Nodes are matched greedily: an exact [`EventId`] match scores 1.0 and
+    /// short-circuits, otherwise the score blends the normalized Levenshtein
+    /// similarity of the event texts with the Jaccard overlap of their tags.
+    /// Highest-scoring pairs above [`MATCH_THRESHOLD`] are taken first (so each
+    /// survivor is the mutually-best remaining candidate). Unmatched nodes in
+    /// `self` are reported as removed, unmatched nodes in `other` as added, and
+    /// for each matched pair the outgoing edge sets are diffed by
+    /// `(target, edge_type)`.
+    pub fn diff(&self, other: &NarrativeGraph) -> GraphDiff {
+        let self_nodes: Vec<(NodeId, &Event)> = self.nodes().collect();
+        let other_nodes: Vec<(NodeId, &Event)> = other.nodes().collect();
+
+        let mut candidates: Vec<(f64, usize, usize)> = Vec::new();
+        for (i, (_, a)) in self_nodes.iter().enumerate() {
+            for (j, (_, b)) in other_nodes.iter().enumerate() {
+                let score = node_similarity(a, b);
+                if score >= MATCH_THRESHOLD {
+                    candidates.push((score, i, j));
+                }
+            }
+        }
+        candidates.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut self_taken = vec![false; self_nodes.len()];
+        let mut other_taken = vec![false; other_nodes.len()];
+        let mut matched = Vec::new();
+        let mut matched_indices = Vec::new();
+        for (_, i, j) in candidates {
+            if !self_taken[i] && !other_taken[j] {
+                self_taken[i] = true;
+                other_taken[j] = true;
+                matched.push((self_nodes[i].0, other_nodes[j].0));
+                matched_indices.push((i, j));
+            }
+        }
+
+        let removed = self_nodes
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !self_taken[*i])
+            .map(|(_, (id, _))| *id)
+            .collect();
+        let added = other_nodes
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| !other_taken[*j])
+            .map(|(_, (id, _))| *id)
+            .collect();
+
+        let mut added_edges = Vec::new();
+        let mut removed_edges = Vec::new();
+        for (i, j) in matched_indices {
+            let source = self_nodes[i].1.id.clone();
+            let self_out = self.outgoing_signature(self_nodes[i].0);
+            let other_out = other.outgoing_signature(other_nodes[j].0);
+
+            for (target, edge_type) in self_out.difference(&other_out) {
+                removed_edges.push(EdgeChange {
+                    source: source.clone(),
+                    target: target.clone(),
+                    edge_type: *edge_type,
+                });
+            }
+            for (target, edge_type) in other_out.difference(&self_out) {
+                added_edges.push(EdgeChange {
+                    source: source.clone(),
+                    target: target.clone(),
+                    edge_type: *edge_type,
+                });
+            }
+        }
+
+        GraphDiff {
+            matched,
+            added,
+            removed,
+            added_edges,
+            removed_edges,
+        }
+    }
+
+    /// The outgoing edges of `node` keyed by `(target EventId, edge_type)`.
+    ///
+    /// Uses stable [`EventId`]s rather than volatile node indices so signatures
+    /// compare meaningfully across two graph versions.
+    fn outgoing_signature(&self, node: NodeId) -> HashSet<(EventId, EdgeType)> {
+        self.graph
+            .edges_directed(node.0, Direction::Outgoing)
+            .filter_map(|edge| {
+                self.graph
+                    .node_weight(edge.target())
+                    .map(|target| (target.id.clone(), edge.weight().edge_type))
+            })
+            .collect()
+    }
+
+    /// Compute the dominator tree rooted at `root`.
+    ///
+    /// A node *X* dominates *Y* when every path from `root` to *Y* passes
+    /// through *X*; the immediate dominator is the closest such *X*. This is the
+    /// iterative dataflow formulation (Cooper–Harvey–Kennedy): nodes are ordered
+    /// in reverse postorder from `root`, each node's idom is repeatedly set to
+    /// the intersection of its processed predecessors' idoms until a fixed point
+    /// is reached, then dominance frontiers are derived from the idom tree. The
+    /// result surfaces the single events that gate whole branches of a causal
+    /// story.
+    pub fn dominators(&self, root: NodeId) -> DominatorTree {
+        let mut postorder = Vec::new();
+        let mut visited = HashSet::new();
+        self.dfs_postorder(root.0, &mut visited, &mut postorder);
+
+        let rpo: Vec<NodeIndex> = postorder.iter().rev().copied().collect();
+        let rpo_num: HashMap<NodeIndex, usize> =
+            rpo.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+
+        let mut idom: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        idom.insert(root.0, root.0);
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &b in rpo.iter() {
+                if b == root.0 {
+                    continue;
+                }
+                let mut new_idom: Option<NodeIndex> = None;
+                for p in self.graph.neighbors_directed(b, Direction::Incoming) {
+                    if !idom.contains_key(&p) {
+                        continue;
+                    }
+                    new_idom = Some(match new_idom {
+                        None => p,
+                        Some(current) => intersect(p, current, &idom, &rpo_num),
+                    });
+                }
+                if let Some(candidate) = new_idom {
+                    if idom.get(&b) != Some(&candidate) {
+                        idom.insert(b, candidate);
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        // Dominance frontiers: a join point is in the frontier of each
+        // predecessor up to (but not including) the join point's idom.
+        let mut frontier: HashMap<NodeIndex, Vec<NodeIndex>> = HashMap::new();
+        for &b in rpo.iter() {
+            if b == root.0 {
+                continue;
+            }
+            let preds: Vec<NodeIndex> = self
+                .graph
+                .neighbors_directed(b, Direction::Incoming)
+                .filter(|p| idom.contains_key(p))
+                .collect();
+            if preds.len() < 2 {
+                continue;
+            }
+            let b_idom = idom[&b];
+            for p in preds {
+                let mut runner = p;
+                while runner != b_idom {
+                    frontier.entry(runner).or_default().push(b);
+                    let next = idom[&runner];
+                    if next == runner {
+                        break;
+                    }
+                    runner = next;
+                }
+            }
+        }
+
+        DominatorTree {
+            root,
+            idom: idom
+                .into_iter()
+                .map(|(n, d)| (NodeId(n), NodeId(d)))
+                .collect(),
+            frontier: frontier
+                .into_iter()
+                .map(|(n, fs)| (NodeId(n), fs.into_iter().map(NodeId).collect()))
+                .collect(),
+        }
+    }
+
+    /// Find every embedding of a `pattern` graph inside this graph.
+    ///
+    /// Runs a VF2-style backtracking search: the partial mapping grows one
+    /// pattern node at a time, always choosing a pattern node adjacent to the
+    /// already-mapped frontier. A host node is a feasible image only if it is
+    /// unused, every directed edge to/from an already-mapped pattern neighbour
+    /// is mirrored in the host (carrying the same [`EdgeType`] when
+    /// `match_edge_types` is set), and its in/out degrees are at least the
+    /// pattern node's. Each complete mapping (pattern [`NodeId`] → host
+    /// [`NodeId`]) is collected. Intended for small motif templates.
+    pub fn find_pattern(
+        &self,
+        pattern: &NarrativeGraph,
+        match_edge_types: bool,
+    ) -> Vec<HashMap<NodeId, NodeId>> {
+        let pattern_nodes: Vec<NodeIndex> = pattern.graph.node_indices().collect();
+        let mut results = Vec::new();
+        let mut mapping: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let mut used: HashSet<NodeIndex> = HashSet::new();
+
+        if pattern_nodes.is_empty() {
+            return results;
+        }
+
+        self.vf2_extend(
+            pattern,
+            &pattern_nodes,
+            match_edge_types,
+            &mut mapping,
+            &mut used,
+            &mut results,
+        );
+        results
+    }
+
+    fn vf2_extend(
+        &self,
+        pattern: &NarrativeGraph,
+        pattern_nodes: &[NodeIndex],
+        match_edge_types: bool,
+        mapping: &mut HashMap<NodeIndex, NodeIndex>,
+        used: &mut HashSet<NodeIndex>,
+        results: &mut Vec<HashMap<NodeId, NodeId>>,
+    ) {
+        if mapping.len() == pattern_nodes.len() {
+            results.push(
+                mapping
+                    .iter()
+                    .map(|(&p, &h)| (NodeId(p), NodeId(h)))
+                    .collect(),
+            );
+            return;
+        }
+
+        let next = Self::select_next_pattern_node(pattern, pattern_nodes, mapping);
+        let pattern_in = pattern.graph.edges_directed(next, Direction::Incoming).count();
+        let pattern_out = pattern.graph.edges_directed(next, Direction::Outgoing).count();
+
+        for candidate in self.graph.node_indices() {
+            if used.contains(&candidate) {
+                continue;
+            }
+            // Degree pruning.
+            if self.graph.edges_directed(candidate, Direction::Incoming).count() < pattern_in
+                || self.graph.edges_directed(candidate, Direction::Outgoing).count() < pattern_out
+            {
+                continue;
+            }
+            if !self.vf2_feasible(pattern, next, candidate, mapping, match_edge_types) {
+                continue;
+            }
+
+            mapping.insert(next, candidate);
+            used.insert(candidate);
+            self.vf2_extend(pattern, pattern_nodes, match_edge_types, mapping, used, results);
+            mapping.remove(&next);
+            used.remove(&candidate);
+        }
+    }
+
+    /// Pick the next pattern node to map: one adjacent to the mapped frontier if
+    /// possible, otherwise any remaining node (for disconnected patterns).
+    fn select_next_pattern_node(
+        pattern: &NarrativeGraph,
+        pattern_nodes: &[NodeIndex],
+        mapping: &HashMap<NodeIndex, NodeIndex>,
+    ) -> NodeIndex {
+        let mut fallback = None;
+        for &pn in pattern_nodes {
+            if mapping.contains_key(&pn) {
+                continue;
+            }
+            if fallback.is_none() {
+                fallback = Some(pn);
+            }
+            let adjacent = pattern
+                .graph
+                .neighbors_undirected(pn)
+                .any(|n| mapping.contains_key(&n));
+            if adjacent {
+                return pn;
+            }
+        }
+        fallback.expect("called only when an unmapped node remains")
+    }
+
+    /// Whether mapping pattern node `next` to host `candidate` keeps every
+    /// directed edge against already-mapped pattern neighbours consistent.
+    fn vf2_feasible(
+        &self,
+        pattern: &NarrativeGraph,
+        next: NodeIndex,
+        candidate: NodeIndex,
+        mapping: &HashMap<NodeIndex, NodeIndex>,
+        match_edge_types: bool,
+    ) -> bool {
+        for (&q_pat, &q_host) in mapping.iter() {
+            if let Some(pe) = pattern.graph.find_edge(q_pat, next) {
+                if !self.host_edge_matches(q_host, candidate, pattern, pe, match_edge_types) {
+                    return false;
+                }
+            }
+            if let Some(pe) = pattern.graph.find_edge(next, q_pat) {
+                if !self.host_edge_matches(candidate, q_host, pattern, pe, match_edge_types) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Whether the host has an edge `source -> target` matching the pattern edge
+    /// `pe` (optionally requiring the same [`EdgeType`]).
+    fn host_edge_matches(
+        &self,
+        source: NodeIndex,
+        target: NodeIndex,
+        pattern: &NarrativeGraph,
+        pe: petgraph::graph::EdgeIndex,
+        match_edge_types: bool,
+    ) -> bool {
+        match self.graph.find_edge(source, target) {
+            None => false,
+            Some(he) => {
+                !match_edge_types || self.graph[he].edge_type == pattern.graph[pe].edge_type
+            }
+        }
+    }
+
+    /// Iterative postorder DFS, pushing each node after its successors.
+    fn dfs_postorder(
+        &self,
+        start: NodeIndex,
+        visited: &mut HashSet<NodeIndex>,
+        out: &mut Vec<NodeIndex>,
+    ) {
+        let mut stack = vec![(start, false)];
+        while let Some((node, processed)) = stack.pop() {
+            if processed {
+                out.push(node);
+                continue;
+            }
+            if !visited.insert(node) {
+                continue;
+            }
+            stack.push((node, true));
+            for succ in self.graph.neighbors_directed(node, Direction::Outgoing) {
+                if !visited.contains(&succ) {
+                    stack.push((succ, false));
+                }
+            }
+        }
     }
 
     /// Get edges of a specific type.
@@ -288,8 +1209,13 @@ impl NarrativeGraph {
 
     /// Automatically connect events that are spatially close.
     ///
-    /// Creates edges between events within the given distance threshold (in meters).
-    pub fn connect_spatial(&mut self, max_distance_km: f64) {
+    /// Mirroring [`connect_thematic`](Self::connect_thematic), this adds a pair
+    /// of bidirectional [`EdgeType::Spatial`] edges between every pair of events
+    /// whose `haversine_distance` is within `radius_km` kilometres, giving the
+    /// graph a geographic dimension independent of time or tags. The edge weight
+    /// falls off linearly with distance (`1.0` for coincident events, `0.0` at
+    /// the threshold).
+    pub fn connect_spatial(&mut self, radius_km: f64) {
         let nodes: Vec<_> = self.graph.node_indices()
             .filter_map(|idx| {
                 self.graph.node_weight(idx).map(|e| (idx, e.location.clone()))
@@ -299,8 +1225,8 @@ impl NarrativeGraph {
         for i in 0..nodes.len() {
             for j in (i + 1)..nodes.len() {
                 let dist = haversine_distance(&nodes[i].1, &nodes[j].1);
-                if dist <= max_distance_km {
-                    let weight = 1.0 - (dist / max_distance_km);
+                if dist <= radius_km {
+                    let weight = 1.0 - (dist / radius_km);
                     let edge = EdgeWeight::with_weight(EdgeType::Spatial, weight);
                     
                     // Add bidirectional edges for spatial proximity
@@ -591,7 +1517,223 @@ impl NarrativeGraph {
         })).unwrap_or_default()
     }
 
+    /// Export the graph to a GeoJSON `FeatureCollection`, for direct use in
+    /// web maps and GIS tools.
+    ///
+    /// Each node becomes a `Point` feature (coordinates in GeoJSON's
+    /// `[longitude, latitude]` order) with `text`, `timestamp`, and `tags` in
+    /// its properties; each edge becomes a `LineString` feature connecting
+    /// its two endpoints, tagged with its [`EdgeType`] in `properties` so
+    /// styling can distinguish edge kinds. Nodes whose [`Location`] isn't
+    /// valid WGS84 are skipped rather than emitted at `(0, 0)`, and edges
+    /// touching a skipped node are skipped along with it.
+    pub fn to_geojson(&self) -> String {
+        let mut features: Vec<serde_json::Value> = Vec::new();
+        let mut located_nodes: HashSet<NodeIndex> = HashSet::new();
+
+        for idx in self.graph.node_indices() {
+            let event = &self.graph[idx];
+            if !event.location.is_valid() {
+                continue;
+            }
+            located_nodes.insert(idx);
+            features.push(serde_json::json!({
+                "type": "Feature",
+                "geometry": {
+                    "type": "Point",
+                    "coordinates": [event.location.lon, event.location.lat]
+                },
+                "properties": {
+                    "text": event.text,
+                    "timestamp": event.timestamp.to_rfc3339(),
+                    "tags": event.tags
+                }
+            }));
+        }
+
+        for edge in self.graph.edge_references() {
+            let (source, target) = (edge.source(), edge.target());
+            if !located_nodes.contains(&source) || !located_nodes.contains(&target) {
+                continue;
+            }
+
+            let from = &self.graph[source].location;
+            let to = &self.graph[target].location;
+            features.push(serde_json::json!({
+                "type": "Feature",
+                "geometry": {
+                    "type": "LineString",
+                    "coordinates": [[from.lon, from.lat], [to.lon, to.lat]]
+                },
+                "properties": {
+                    "edge_type": format!("{:?}", edge.weight().edge_type)
+                }
+            }));
+        }
+
+        serde_json::json!({
+            "type": "FeatureCollection",
+            "features": features
+        })
+        .to_string()
+    }
+
+    /// Reconstruct a graph from its stable serialized JSON form.
+    ///
+    /// This is the inverse of serializing a [`NarrativeGraph`] (for example via
+    /// `serde_json::to_string`): nodes are keyed by [`EventId`] and edges are
+    /// `(EventId, EventId, EdgeWeight)` triples, so the result is independent of
+    /// the volatile `NodeIndex` values used internally.
+    pub fn from_json(json: &str) -> Result<NarrativeGraph> {
+        let form: SerializedGraph = serde_json::from_str(json)?;
+        Ok(NarrativeGraph::from_serialized(form))
+    }
+
+    /// Build the stable, index-independent serialized form of this graph.
+    fn to_serialized(&self) -> SerializedGraph {
+        let nodes: Vec<Event> = self
+            .graph
+            .node_indices()
+            .map(|idx| self.graph[idx].clone())
+            .collect();
+
+        let edges: Vec<(EventId, EventId, EdgeWeight)> = self
+            .graph
+            .edge_references()
+            .map(|edge| {
+                (
+                    self.graph[edge.source()].id.clone(),
+                    self.graph[edge.target()].id.clone(),
+                    edge.weight().clone(),
+                )
+            })
+            .collect();
+
+        SerializedGraph { nodes, edges }
+    }
+
+    /// Rebuild a graph (and its `id_map`) from the serialized form.
+    fn from_serialized(form: SerializedGraph) -> NarrativeGraph {
+        let mut graph = NarrativeGraph::new();
+        for event in form.nodes {
+            graph.add_event(event);
+        }
+        for (source, target, weight) in form.edges {
+            if let (Some(from), Some(to)) = (graph.get_node(&source), graph.get_node(&target)) {
+                graph.connect_weighted(from, to, weight);
+            }
+        }
+        graph
+    }
+
+    /// Export the graph to GraphML for import into Gephi, yEd, and similar tools.
+    ///
+    /// Nodes carry their `label`, `lat`, `lon`, `timestamp`, and `tags`; edges
+    /// carry their `edge_type`, `weight`, and `label`. All attributes are
+    /// declared up front with typed `<key>` elements as the format requires.
+    pub fn to_graphml(&self) -> String {
+        let mut output = String::new();
+        output.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        output.push_str(
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+        );
+
+        // Attribute declarations.
+        output.push_str(
+            "  <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n",
+        );
+        output.push_str(
+            "  <key id=\"lat\" for=\"node\" attr.name=\"lat\" attr.type=\"double\"/>\n",
+        );
+        output.push_str(
+            "  <key id=\"lon\" for=\"node\" attr.name=\"lon\" attr.type=\"double\"/>\n",
+        );
+        output.push_str(
+            "  <key id=\"timestamp\" for=\"node\" attr.name=\"timestamp\" attr.type=\"string\"/>\n",
+        );
+        output.push_str(
+            "  <key id=\"tags\" for=\"node\" attr.name=\"tags\" attr.type=\"string\"/>\n",
+        );
+        output.push_str(
+            "  <key id=\"edge_type\" for=\"edge\" attr.name=\"edge_type\" attr.type=\"string\"/>\n",
+        );
+        output.push_str(
+            "  <key id=\"weight\" for=\"edge\" attr.name=\"weight\" attr.type=\"double\"/>\n",
+        );
+        output.push_str(
+            "  <key id=\"edge_label\" for=\"edge\" attr.name=\"label\" attr.type=\"string\"/>\n",
+        );
+
+        output.push_str("  <graph id=\"NarrativeGraph\" edgedefault=\"directed\">\n");
+
+        // Nodes, keyed by the stable EventId.
+        for idx in self.graph.node_indices() {
+            let event = &self.graph[idx];
+            output.push_str(&format!(
+                "    <node id=\"{}\">\n",
+                Self::escape_xml(&event.id.to_string())
+            ));
+            output.push_str(&format!(
+                "      <data key=\"label\">{}</data>\n",
+                Self::escape_xml(&event.text)
+            ));
+            output.push_str(&format!(
+                "      <data key=\"lat\">{}</data>\n",
+                event.location.lat
+            ));
+            output.push_str(&format!(
+                "      <data key=\"lon\">{}</data>\n",
+                event.location.lon
+            ));
+            output.push_str(&format!(
+                "      <data key=\"timestamp\">{}</data>\n",
+                Self::escape_xml(&event.timestamp.to_rfc3339())
+            ));
+            output.push_str(&format!(
+                "      <data key=\"tags\">{}</data>\n",
+                Self::escape_xml(&event.tags.join(","))
+            ));
+            output.push_str("    </node>\n");
+        }
+
+        // Edges, referencing the node EventIds.
+        for edge in self.graph.edge_references() {
+            let weight = edge.weight();
+            output.push_str(&format!(
+                "    <edge source=\"{}\" target=\"{}\">\n",
+                Self::escape_xml(&self.graph[edge.source()].id.to_string()),
+                Self::escape_xml(&self.graph[edge.target()].id.to_string())
+            ));
+            output.push_str(&format!(
+                "      <data key=\"edge_type\">{:?}</data>\n",
+                weight.edge_type
+            ));
+            output.push_str(&format!(
+                "      <data key=\"weight\">{}</data>\n",
+                weight.weight
+            ));
+            if let Some(label) = &weight.label {
+                output.push_str(&format!(
+                    "      <data key=\"edge_label\">{}</data>\n",
+                    Self::escape_xml(label)
+                ));
+            }
+            output.push_str("    </edge>\n");
+        }
+
+        output.push_str("  </graph>\n");
+        output.push_str("</graphml>\n");
+        output
+    }
+
     // Helper methods for DOT export
+    fn escape_xml(s: &str) -> String {
+        s.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+
     fn escape_dot_string(s: &str) -> String {
         s.replace('\\', "\\\\")
             .replace('"', "\\\"")
@@ -645,6 +1787,36 @@ impl NarrativeGraph {
     }
 }
 
+/// Stable, index-independent serialized form of a [`NarrativeGraph`].
+///
+/// Nodes are stored in full (each carrying its own [`EventId`]) and edges as
+/// `(source EventId, target EventId, EdgeWeight)` triples, so a round trip does
+/// not depend on the internal `NodeIndex` assignment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SerializedGraph {
+    nodes: Vec<Event>,
+    edges: Vec<(EventId, EventId, EdgeWeight)>,
+}
+
+impl Serialize for NarrativeGraph {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_serialized().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NarrativeGraph {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let form = SerializedGraph::deserialize(deserializer)?;
+        Ok(NarrativeGraph::from_serialized(form))
+    }
+}
+
 /// Options for DOT export formatting.
 #[derive(Debug, Clone)]
 pub struct DotOptions {
@@ -713,6 +1885,126 @@ impl PathInfo {
     }
 }
 
+/// A graph reduced to its junction nodes by [`NarrativeGraph::contract`].
+#[derive(Debug, Clone)]
+pub struct ContractedGraph {
+    /// Junction nodes retained from the original graph.
+    pub junctions: Vec<NodeId>,
+    /// Contracted edges between junctions, each carrying its collapsed chain.
+    pub edges: Vec<ContractedEdge>,
+}
+
+impl ContractedGraph {
+    /// Expand a contracted edge back into the full original node sequence,
+    /// `from` and `to` inclusive, or `None` if no such edge exists.
+    pub fn expand_edge(&self, from: NodeId, to: NodeId) -> Option<Vec<NodeId>> {
+        self.edges
+            .iter()
+            .find(|e| e.from == from && e.to == to)
+            .map(|e| {
+                let mut nodes = Vec::with_capacity(e.collapsed.len() + 2);
+                nodes.push(e.from);
+                nodes.extend(e.collapsed.iter().copied());
+                nodes.push(e.to);
+                nodes
+            })
+    }
+}
+
+/// A single contracted edge: a chain of original nodes collapsed into one hop.
+#[derive(Debug, Clone)]
+pub struct ContractedEdge {
+    /// The junction the chain starts at.
+    pub from: NodeId,
+    /// The junction the chain ends at.
+    pub to: NodeId,
+    /// Total `haversine_distance` summed along the collapsed chain.
+    pub weight: f64,
+    /// Interior pass-through nodes, in order, between `from` and `to`.
+    pub collapsed: Vec<NodeId>,
+}
+
+/// The dominator relation produced by [`NarrativeGraph::dominators`].
+#[derive(Debug, Clone)]
+pub struct DominatorTree {
+    root: NodeId,
+    idom: HashMap<NodeId, NodeId>,
+    frontier: HashMap<NodeId, Vec<NodeId>>,
+}
+
+impl DominatorTree {
+    /// The root the tree was computed from.
+    pub fn root(&self) -> NodeId {
+        self.root
+    }
+
+    /// The immediate dominator of `node`, or `None` for the root and for
+    /// nodes unreachable from the root.
+    pub fn immediate_dominator(&self, node: NodeId) -> Option<NodeId> {
+        if node == self.root {
+            return None;
+        }
+        self.idom.get(&node).copied()
+    }
+
+    /// The chain of dominators from `node` up to and including the root.
+    ///
+    /// Returns an empty vector when `node` is unreachable from the root. The
+    /// first element is `node` itself, the last is the root.
+    pub fn dominators(&self, node: NodeId) -> Vec<NodeId> {
+        if node != self.root && !self.idom.contains_key(&node) {
+            return Vec::new();
+        }
+        let mut chain = vec![node];
+        let mut current = node;
+        while current != self.root {
+            match self.idom.get(&current) {
+                Some(&dom) => {
+                    chain.push(dom);
+                    current = dom;
+                }
+                None => break,
+            }
+        }
+        chain
+    }
+
+    /// The dominance frontier of `node` — the join points it dominates a
+    /// predecessor of but does not strictly dominate.
+    pub fn dominance_frontier(&self, node: NodeId) -> Vec<NodeId> {
+        self.frontier.get(&node).cloned().unwrap_or_default()
+    }
+}
+
+/// A single edge that was added or removed between two graph versions.
+///
+/// Endpoints are stable [`EventId`]s so the change is meaningful independent of
+/// node indices.
+#[derive(Debug, Clone)]
+pub struct EdgeChange {
+    /// The source event.
+    pub source: EventId,
+    /// The target event.
+    pub target: EventId,
+    /// The relationship type of the changed edge.
+    pub edge_type: EdgeType,
+}
+
+/// The result of [`NarrativeGraph::diff`].
+#[derive(Debug, Default)]
+pub struct GraphDiff {
+    /// Matched node pairs as `(self_node, other_node)`.
+    pub matched: Vec<(NodeId, NodeId)>,
+    /// Nodes present only in the other graph.
+    pub added: Vec<NodeId>,
+    /// Nodes present only in this graph.
+    pub removed: Vec<NodeId>,
+    /// Edges present only in the other graph (between matched nodes).
+    pub added_edges: Vec<EdgeChange>,
+    /// Edges present only in this graph (between matched nodes).
+    pub removed_edges: Vec<EdgeChange>,
+}
+
 /// Result of subgraph extraction.
 #[derive(Debug)]
 pub struct SubgraphResult {
@@ -722,6 +2014,194 @@ pub struct SubgraphResult {
     pub node_mapping: HashMap<NodeId, NodeId>,
 }
 
+/// Branching factor for the shortest-path priority queue.
+///
+/// A 4-ary heap trades a little extra comparison work per level for a shallower
+/// tree, which tends to win on the push-heavy workload Dijkstra generates.
+const DEFAULT_HEAP_ARITY: usize = 4;
+
+/// A minimal `d`-ary min-heap keyed by an `f64` cost.
+///
+/// Used as the Dijkstra priority queue: `push`/`pop` keep the cheapest entry at
+/// the root. Costs are finite and non-negative, so the `f64` comparison is a
+/// total order in practice.
+struct DaryHeap {
+    arity: usize,
+    data: Vec<(f64, NodeIndex)>,
+}
+
+impl DaryHeap {
+    fn new(arity: usize) -> Self {
+        debug_assert!(arity >= 2, "heap arity must be at least 2");
+        Self {
+            arity,
+            data: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, cost: f64, node: NodeIndex) {
+        self.data.push((cost, node));
+        self.sift_up(self.data.len() - 1);
+    }
+
+    fn pop(&mut self) -> Option<(f64, NodeIndex)> {
+        let last = self.data.len().checked_sub(1)?;
+        self.data.swap(0, last);
+        let min = self.data.pop();
+        if !self.data.is_empty() {
+            self.sift_down(0);
+        }
+        min
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / self.arity;
+            if self.data[i].0 < self.data[parent].0 {
+                self.data.swap(i, parent);
+                i = parent;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        let n = self.data.len();
+        loop {
+            let first_child = self.arity * i + 1;
+            if first_child >= n {
+                break;
+            }
+            let mut smallest = i;
+            for child in first_child..(first_child + self.arity).min(n) {
+                if self.data[child].0 < self.data[smallest].0 {
+                    smallest = child;
+                }
+            }
+            if smallest == i {
+                break;
+            }
+            self.data.swap(i, smallest);
+            i = smallest;
+        }
+    }
+}
+
+/// A score/item pair that orders as a min-heap on the `f64` score.
+///
+/// [`std::collections::BinaryHeap`] is a max-heap, so the comparison is
+/// reversed; a `NaN` score compares equal to every score, so its position in
+/// the pop order is not defined.
+struct MinScored(f64, NodeIndex);
+
+impl PartialEq for MinScored {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for MinScored {}
+
+impl PartialOrd for MinScored {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MinScored {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Reverse so the smallest score is the greatest heap element.
+        other
+            .0
+            .partial_cmp(&self.0)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    }
+}
+
+/// Walks two idom chains toward the root until they meet (CHK `intersect`).
+fn intersect(
+    mut a: NodeIndex,
+    mut b: NodeIndex,
+    idom: &HashMap<NodeIndex, NodeIndex>,
+    rpo_num: &HashMap<NodeIndex, usize>,
+) -> NodeIndex {
+    while a != b {
+        while rpo_num[&a] > rpo_num[&b] {
+            a = idom[&a];
+        }
+        while rpo_num[&b] > rpo_num[&a] {
+            b = idom[&b];
+        }
+    }
+    a
+}
+
+/// Minimum blended similarity for two nodes to be considered the same event
+/// across graph versions (see [`NarrativeGraph::diff`]).
+const MATCH_THRESHOLD: f64 = 0.5;
+
+/// Blended similarity in `[0, 1]` between two events.
+///
+/// An exact [`EventId`] match is a definitive 1.0; otherwise the score is the
+/// mean of the normalized text similarity and the tag Jaccard overlap. When
+/// neither event has tags, the score is the text similarity alone.
+fn node_similarity(a: &Event, b: &Event) -> f64 {
+    if a.id == b.id {
+        return 1.0;
+    }
+    let max_len = a.text.chars().count().max(b.text.chars().count());
+    let text = if max_len == 0 {
+        1.0
+    } else {
+        1.0 - levenshtein(&a.text, &b.text) as f64 / max_len as f64
+    };
+    if a.tags.is_empty() && b.tags.is_empty() {
+        return text;
+    }
+    let tags = jaccard(&a.tags, &b.tags);
+    0.5 * text + 0.5 * tags
+}
+
+/// Jaccard overlap of two tag sets; two empty sets count as identical.
+fn jaccard(a: &[String], b: &[String]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let sa: HashSet<&String> = a.iter().collect();
+    let sb: HashSet<&String> = b.iter().collect();
+    let union = sa.union(&sb).count();
+    if union == 0 {
+        1.0
+    } else {
+        sa.intersection(&sb).count() as f64 / union as f64
+    }
+}
+
+/// Levenshtein edit distance between two strings, counted in Unicode scalars.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.is_empty() {
+        return b.len();
+    }
+    if b.is_empty() {
+        return a.len();
+    }
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
 /// Calculate haversine distance between two locations in kilometers.
 fn haversine_distance(loc1: &Location, loc2: &Location) -> f64 {
     let r = 6371.0; // Earth radius in km
@@ -810,6 +2290,24 @@ mod tests {
         assert_eq!(graph.edge_count(), 2);
     }
 
+    #[test]
+    fn test_graph_connect_spatial() {
+        let mut graph = NarrativeGraph::new();
+        // Two events ~1.1 km apart and a third far away.
+        let a = graph.add_event(make_event(40.0000, -74.0, "2024-01-01T10:00:00Z", "a"));
+        let b = graph.add_event(make_event(40.0100, -74.0, "2024-01-01T11:00:00Z", "b"));
+        let c = graph.add_event(make_event(41.0000, -74.0, "2024-01-01T12:00:00Z", "c"));
+
+        graph.connect_spatial(5.0);
+
+        // a and b are within 5 km: one bidirectional pair of edges.
+        assert!(graph.are_connected(a, b));
+        assert!(graph.are_connected(b, a));
+        // c is ~111 km away and stays unconnected.
+        assert!(!graph.are_connected(a, c));
+        assert_eq!(graph.edge_count(), 2);
+    }
+
     #[test]
     fn test_graph_connect_thematic() {
         let mut graph = NarrativeGraph::new();
@@ -854,6 +2352,312 @@ mod tests {
         assert_eq!(leaves[0], n3);
     }
 
+    #[test]
+    fn test_shortest_path_astar_matches_dijkstra() {
+        let mut graph = NarrativeGraph::new();
+        let a = graph.add_event(make_event(40.0, -74.0, "2024-01-01T10:00:00Z", "A"));
+        let b = graph.add_event(make_event(41.0, -74.0, "2024-01-01T11:00:00Z", "B"));
+        let c = graph.add_event(make_event(42.0, -74.0, "2024-01-01T12:00:00Z", "C"));
+        let d = graph.add_event(make_event(43.0, -74.0, "2024-01-01T13:00:00Z", "D"));
+
+        graph.connect_weighted(a, b, EdgeWeight::with_weight(EdgeType::Temporal, 0.9));
+        graph.connect_weighted(b, d, EdgeWeight::with_weight(EdgeType::Temporal, 0.9));
+        graph.connect_weighted(a, c, EdgeWeight::with_weight(EdgeType::Temporal, 0.2));
+        graph.connect_weighted(c, d, EdgeWeight::with_weight(EdgeType::Temporal, 0.2));
+
+        let dijkstra = graph.shortest_path(a, d).unwrap();
+        let astar = graph.shortest_path_astar(a, d).unwrap();
+
+        assert_eq!(astar.nodes.first(), Some(&a));
+        assert_eq!(astar.nodes.last(), Some(&d));
+        // A* returns an optimal path with the same cost as Dijkstra.
+        assert!((astar.total_weight - dijkstra.total_weight).abs() < 1e-9);
+        assert_eq!(astar.nodes, vec![a, b, d]);
+    }
+
+    #[test]
+    fn test_graph_diff_nodes_and_edges() {
+        let e1 = make_event(40.7, -74.0, "2024-01-01T10:00:00Z", "Arrival");
+        let e2 = make_event(40.8, -74.1, "2024-01-01T12:00:00Z", "Meeting");
+        let e3 = make_event(41.0, -74.2, "2024-01-01T14:00:00Z", "Departure");
+
+        // Base version: e1 -> e2.
+        let mut base = NarrativeGraph::new();
+        let b1 = base.add_event(e1.clone());
+        let b2 = base.add_event(e2.clone());
+        base.connect(b1, b2, EdgeType::Temporal);
+
+        // Revised version: e1 -> e3 (e2 removed, e3 added, edge retargeted).
+        let mut revised = NarrativeGraph::new();
+        let r1 = revised.add_event(e1.clone());
+        let r3 = revised.add_event(e3.clone());
+        revised.connect(r1, r3, EdgeType::Temporal);
+
+        let diff = base.diff(&revised);
+
+        // e1 matches by EventId; e2 removed, e3 added.
+        assert_eq!(diff.matched.len(), 1);
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.removed[0], b2);
+        assert_eq!(diff.added[0], r3);
+
+        // The e1->e2 edge is gone, e1->e3 is new.
+        assert_eq!(diff.removed_edges.len(), 1);
+        assert_eq!(diff.removed_edges[0].target, e2.id);
+        assert_eq!(diff.added_edges.len(), 1);
+        assert_eq!(diff.added_edges[0].target, e3.id);
+    }
+
+    #[test]
+    fn test_dominators_diamond() {
+        // r -> a -> m, r -> b -> m, m -> end (a classic diamond + tail).
+        let mut graph = NarrativeGraph::new();
+        let r = graph.add_event(make_event(40.0, -74.0, "2024-01-01T10:00:00Z", "root"));
+        let a = graph.add_event(make_event(40.1, -74.0, "2024-01-01T11:00:00Z", "a"));
+        let b = graph.add_event(make_event(40.2, -74.0, "2024-01-01T12:00:00Z", "b"));
+        let m = graph.add_event(make_event(40.3, -74.0, "2024-01-01T13:00:00Z", "merge"));
+        let end = graph.add_event(make_event(40.4, -74.0, "2024-01-01T14:00:00Z", "end"));
+
+        graph.connect(r, a, EdgeType::Causal);
+        graph.connect(r, b, EdgeType::Causal);
+        graph.connect(a, m, EdgeType::Causal);
+        graph.connect(b, m, EdgeType::Causal);
+        graph.connect(m, end, EdgeType::Causal);
+
+        let dom = graph.dominators(r);
+
+        assert_eq!(dom.immediate_dominator(r), None);
+        assert_eq!(dom.immediate_dominator(a), Some(r));
+        assert_eq!(dom.immediate_dominator(b), Some(r));
+        // The merge is dominated by the root, not by either branch.
+        assert_eq!(dom.immediate_dominator(m), Some(r));
+        assert_eq!(dom.immediate_dominator(end), Some(m));
+
+        // end's dominator chain runs end -> m -> r.
+        assert_eq!(dom.dominators(end), vec![end, m, r]);
+
+        // Both branches share the merge as their dominance frontier.
+        assert_eq!(dom.dominance_frontier(a), vec![m]);
+        assert_eq!(dom.dominance_frontier(b), vec![m]);
+    }
+
+    #[test]
+    fn test_find_pattern_motif() {
+        // Host: a fan-out (hub -> x, hub -> y) plus an unrelated edge.
+        let mut host = NarrativeGraph::new();
+        let hub = host.add_event(make_event(40.0, -74.0, "2024-01-01T10:00:00Z", "hub"));
+        let x = host.add_event(make_event(40.1, -74.0, "2024-01-01T11:00:00Z", "x"));
+        let y = host.add_event(make_event(40.2, -74.0, "2024-01-01T12:00:00Z", "y"));
+        let z = host.add_event(make_event(40.3, -74.0, "2024-01-01T13:00:00Z", "z"));
+        host.connect(hub, x, EdgeType::Causal);
+        host.connect(hub, y, EdgeType::Causal);
+        host.connect(x, z, EdgeType::Thematic);
+
+        // Pattern: a single directed edge.
+        let mut pattern = NarrativeGraph::new();
+        let p0 = pattern.add_event(make_event(0.0, 0.0, "2024-01-01T00:00:00Z", "p0"));
+        let p1 = pattern.add_event(make_event(0.0, 0.0, "2024-01-01T00:00:00Z", "p1"));
+        pattern.connect(p0, p1, EdgeType::Causal);
+
+        // Ignoring edge types there are three host edges to match.
+        let any = host.find_pattern(&pattern, false);
+        assert_eq!(any.len(), 3);
+
+        // Requiring the same edge type leaves only the two Causal edges.
+        let causal = host.find_pattern(&pattern, true);
+        assert_eq!(causal.len(), 2);
+        // Both Causal edges originate at the hub.
+        for m in &causal {
+            assert_eq!(m[&p0], hub);
+        }
+    }
+
+    #[test]
+    fn test_contract_collapses_linear_chain() {
+        let mut graph = NarrativeGraph::new();
+        // Linear chain a -> b -> c -> d; only a (root) and d (leaf) are junctions.
+        let a = graph.add_event(make_event(0.0, 0.0, "2024-01-01T10:00:00Z", "a"));
+        let b = graph.add_event(make_event(0.0, 1.0, "2024-01-01T11:00:00Z", "b"));
+        let c = graph.add_event(make_event(0.0, 2.0, "2024-01-01T12:00:00Z", "c"));
+        let d = graph.add_event(make_event(0.0, 3.0, "2024-01-01T13:00:00Z", "d"));
+        graph.connect(a, b, EdgeType::Temporal);
+        graph.connect(b, c, EdgeType::Temporal);
+        graph.connect(c, d, EdgeType::Temporal);
+
+        let contracted = graph.contract();
+
+        assert_eq!(contracted.junctions.len(), 2);
+        assert_eq!(contracted.edges.len(), 1);
+        let edge = &contracted.edges[0];
+        assert_eq!(edge.from, a);
+        assert_eq!(edge.to, d);
+        assert_eq!(edge.collapsed, vec![b, c]);
+        assert!(edge.weight > 0.0);
+
+        // The original sequence can be recovered.
+        assert_eq!(contracted.expand_edge(a, d), Some(vec![a, b, c, d]));
+    }
+
+    #[test]
+    fn test_optimal_tour_orders_by_distance() {
+        let mut graph = NarrativeGraph::new();
+        // Colinear points; fed to the tour out of order.
+        let a = graph.add_event(make_event(0.0, 0.0, "2024-01-01T10:00:00Z", "a"));
+        let c = graph.add_event(make_event(0.0, 2.0, "2024-01-01T11:00:00Z", "c"));
+        let b = graph.add_event(make_event(0.0, 1.0, "2024-01-01T12:00:00Z", "b"));
+        let d = graph.add_event(make_event(0.0, 3.0, "2024-01-01T13:00:00Z", "d"));
+
+        let (order, total) = graph.optimal_tour(&[a, c, b, d]);
+
+        // Origin is fixed at `a`; the shortest itinerary walks outward in order.
+        assert_eq!(order, vec![a, b, c, d]);
+        let direct = haversine_distance(
+            &graph.event(a).unwrap().location,
+            &graph.event(d).unwrap().location,
+        );
+        assert!((total - direct).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_longest_path_spine() {
+        let mut graph = NarrativeGraph::new();
+        let a = graph.add_event(make_event(40.0, -74.0, "2024-01-01T10:00:00Z", "a"));
+        let b = graph.add_event(make_event(40.0, -74.0, "2024-01-01T11:00:00Z", "b"));
+        let c = graph.add_event(make_event(40.0, -74.0, "2024-01-01T12:00:00Z", "c"));
+        let d = graph.add_event(make_event(40.0, -74.0, "2024-01-01T13:00:00Z", "d"));
+
+        // Spine a -> b -> c -> d, plus a short branch a -> d.
+        graph.connect(a, b, EdgeType::Temporal);
+        graph.connect(b, c, EdgeType::Temporal);
+        graph.connect(c, d, EdgeType::Temporal);
+        graph.connect(a, d, EdgeType::Temporal);
+
+        let spine = graph.longest_path(PathMetric::Hops);
+        assert_eq!(spine, vec![a, b, c, d]);
+    }
+
+    #[test]
+    fn test_shortest_path_geographic_finds_minimal_distance() {
+        let mut graph = NarrativeGraph::new();
+        // Start and goal with a direct hop and a two-hop detour along the
+        // equator; both routes cover the same distance.
+        let a = graph.add_event(make_event(0.0, 0.0, "2024-01-01T10:00:00Z", "a"));
+        let mid = graph.add_event(make_event(0.0, 1.0, "2024-01-01T11:00:00Z", "mid"));
+        let b = graph.add_event(make_event(0.0, 2.0, "2024-01-01T12:00:00Z", "b"));
+
+        graph.connect(a, b, EdgeType::Spatial); // direct, ~222 km
+        graph.connect(a, mid, EdgeType::Spatial);
+        graph.connect(mid, b, EdgeType::Spatial); // two ~111 km hops
+
+        let path = graph.shortest_path_geographic(a, b).unwrap();
+        // Either route is optimal; only the endpoints and the total distance
+        // (equal to the direct hop) are checked.
+        assert_eq!(path.nodes.first(), Some(&a));
+        assert_eq!(path.nodes.last(), Some(&b));
+        let direct = haversine_distance(
+            &graph.event(a).unwrap().location,
+            &graph.event(b).unwrap().location,
+        );
+        assert!((path.total_weight - direct).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_k_shortest_paths_yen() {
+        let mut graph = NarrativeGraph::new();
+        let a = graph.add_event(make_event(40.0, -74.0, "2024-01-01T10:00:00Z", "a"));
+        let b = graph.add_event(make_event(40.1, -74.0, "2024-01-01T11:00:00Z", "b"));
+        let c = graph.add_event(make_event(40.2, -74.0, "2024-01-01T12:00:00Z", "c"));
+        let d = graph.add_event(make_event(40.3, -74.0, "2024-01-01T13:00:00Z", "d"));
+
+        // Cheap route a-b-d and a more expensive detour a-c-d.
+        graph.connect_weighted(a, b, EdgeWeight::with_weight(EdgeType::Causal, 0.9));
+        graph.connect_weighted(b, d, EdgeWeight::with_weight(EdgeType::Causal, 0.9));
+        graph.connect_weighted(a, c, EdgeWeight::with_weight(EdgeType::Causal, 0.5));
+        graph.connect_weighted(c, d, EdgeWeight::with_weight(EdgeType::Causal, 0.5));
+
+        let paths = graph.k_shortest_paths(a, d, 5);
+
+        // Only two loopless routes exist.
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0].nodes, vec![a, b, d]);
+        assert_eq!(paths[1].nodes, vec![a, c, d]);
+        // Returned in increasing cost order.
+        assert!(paths[0].total_weight < paths[1].total_weight);
+    }
+
+    #[test]
+    fn test_serde_round_trip_preserves_topology() {
+        let mut graph = NarrativeGraph::new();
+        let n1 = graph.add_event(make_event(40.7, -74.0, "2024-01-01T10:00:00Z", "Event 1"));
+        let n2 = graph.add_event(make_event(41.0, -73.5, "2024-01-01T12:00:00Z", "Event 2"));
+        graph.connect_weighted(n1, n2, EdgeWeight::with_weight(EdgeType::Causal, 0.8));
+
+        let json = serde_json::to_string(&graph).unwrap();
+        let restored = NarrativeGraph::from_json(&json).unwrap();
+
+        assert_eq!(restored.node_count(), 2);
+        assert_eq!(restored.edge_count(), 1);
+        // Edges resolve by EventId, not by the old NodeIndex.
+        let r1 = restored.get_node(&graph.event(n1).unwrap().id).unwrap();
+        let r2 = restored.get_node(&graph.event(n2).unwrap().id).unwrap();
+        assert!(restored.are_connected(r1, r2));
+    }
+
+    #[test]
+    fn test_to_graphml_declares_keys() {
+        let mut graph = NarrativeGraph::new();
+        let n1 = graph.add_event(make_event(40.7, -74.0, "2024-01-01T10:00:00Z", "Event 1"));
+        let n2 = graph.add_event(make_event(41.0, -73.5, "2024-01-01T12:00:00Z", "Event 2"));
+        graph.connect(n1, n2, EdgeType::Temporal);
+
+        let graphml = graph.to_graphml();
+        assert!(graphml.contains("<graphml"));
+        assert!(graphml.contains("attr.name=\"edge_type\""));
+        assert!(graphml.contains("edgedefault=\"directed\""));
+        assert!(graphml.contains("<edge source="));
+    }
+
+    #[test]
+    fn test_to_geojson_emits_point_and_linestring_features() {
+        let mut graph = NarrativeGraph::new();
+        let n1 = graph.add_event(make_event(40.7, -74.0, "2024-01-01T10:00:00Z", "Event 1"));
+        let n2 = graph.add_event(make_event(41.0, -73.5, "2024-01-01T12:00:00Z", "Event 2"));
+        graph.connect(n1, n2, EdgeType::Temporal);
+
+        let geojson = graph.to_geojson();
+        let parsed: serde_json::Value = serde_json::from_str(&geojson).unwrap();
+
+        assert_eq!(parsed["type"], "FeatureCollection");
+        let features = parsed["features"].as_array().unwrap();
+
+        let points: Vec<_> = features
+            .iter()
+            .filter(|f| f["geometry"]["type"] == "Point")
+            .collect();
+        let lines: Vec<_> = features
+            .iter()
+            .filter(|f| f["geometry"]["type"] == "LineString")
+            .collect();
+        assert_eq!(points.len(), 2);
+        assert_eq!(lines.len(), 1);
+
+        // GeoJSON coordinate order is [longitude, latitude].
+        assert_eq!(points[0]["geometry"]["coordinates"][0], -74.0);
+        assert_eq!(points[0]["geometry"]["coordinates"][1], 40.7);
+        assert_eq!(lines[0]["properties"]["edge_type"], "Temporal");
+    }
+
+    #[test]
+    fn test_to_geojson_skips_events_with_invalid_location() {
+        let mut graph = NarrativeGraph::new();
+        graph.add_event(make_event(999.0, -74.0, "2024-01-01T10:00:00Z", "Bad location"));
+
+        let geojson = graph.to_geojson();
+        let parsed: serde_json::Value = serde_json::from_str(&geojson).unwrap();
+        assert!(parsed["features"].as_array().unwrap().is_empty());
+    }
+
     #[test]
     fn test_haversine_distance() {
         let nyc = Location::new(40.7128, -74.0060);
@@ -8,6 +8,10 @@
 //! - [`SpatialIndex`] - R-tree based spatial indexing for geographic queries
 //! - [`TemporalIndex`] - B-tree based temporal indexing for time-range queries
 //! - [`SpatiotemporalIndex`] - Combined space-time indexing
+//! - [`SearchIndex`] - BM25 full-text search over events, filterable by space and time
+//!
+//! With the `render` feature enabled, [`Heatmap::to_png`] renders a heatmap
+//! grid to a PNG image via [`ColorMap`].
 //!
 //! # Example
 //!
@@ -34,10 +38,21 @@
 //! let combined_results = index.query(&bounds, &range);
 //! ```
 
+mod search;
 mod spatial;
 mod spatiotemporal;
 mod temporal;
 
+#[cfg(feature = "render")]
+mod render;
+
+pub use search::SearchIndex;
 pub use spatial::{IndexedLocation, SpatialIndex};
-pub use spatiotemporal::{GridSpec, Heatmap, SpatiotemporalIndex};
-pub use temporal::{SlidingWindowIter, TemporalIndex};
+pub use spatiotemporal::{Filter, GridSpec, Heatmap, SpatiotemporalIndex};
+pub use temporal::{
+    EntryId, IntervalIndex, SlidingWindowIter, TemporalIndex, TemporalQuery, TumblingWindowIter,
+    VersionedTemporalIndex,
+};
+
+#[cfg(feature = "render")]
+pub use render::ColorMap;
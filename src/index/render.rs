@@ -0,0 +1,266 @@
+//! Raster PNG rendering of [`Heatmap`] density grids. Requires the `render`
+//! feature.
+//!
+//! ```rust,ignore
+//! use spatial_narrative::index::{ColorMap, GridSpec, SpatiotemporalIndex};
+//!
+//! let heatmap = index.heatmap(grid);
+//! let png_bytes = heatmap.to_png(ColorMap::Viridis)?;
+//! ```
+
+use image::{Rgba, RgbaImage};
+
+use super::spatial::{lonlat_to_tile, tile_bounds};
+use super::spatiotemporal::Heatmap;
+use crate::error::{Error, Result};
+
+/// Pixel width/height of each tile produced by [`Heatmap::to_tiles`], matching
+/// the de facto standard slippy-map tile size.
+const TILE_SIZE: u32 = 256;
+
+/// Color ramp used to map a heatmap cell's normalized density to a pixel
+/// color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMap {
+    /// Perceptually uniform dark-blue-to-yellow ramp (matplotlib's Viridis).
+    Viridis,
+    /// Black/red/yellow/white "hot" ramp.
+    Hot,
+}
+
+impl ColorMap {
+    /// Maps a normalized density `t` in `[0.0, 1.0]` to an RGBA pixel, fully
+    /// transparent at `t <= 0.0` so empty cells vanish on overlay.
+    fn pixel(&self, t: f64) -> Rgba<u8> {
+        let t = t.clamp(0.0, 1.0);
+        let (r, g, b) = match self {
+            ColorMap::Viridis => interpolate(&VIRIDIS_ANCHORS, t),
+            ColorMap::Hot => interpolate(&HOT_ANCHORS, t),
+        };
+        let alpha = if t <= 0.0 { 0 } else { 255 };
+        Rgba([r, g, b, alpha])
+    }
+}
+
+/// Piecewise-linear approximation through a handful of the published
+/// Viridis anchor colors — close enough for a density overlay without
+/// pulling in the full 256-entry lookup table.
+const VIRIDIS_ANCHORS: [(f64, (u8, u8, u8)); 5] = [
+    (0.0, (68, 1, 84)),
+    (0.25, (59, 82, 139)),
+    (0.5, (33, 145, 140)),
+    (0.75, (94, 201, 98)),
+    (1.0, (253, 231, 37)),
+];
+
+const HOT_ANCHORS: [(f64, (u8, u8, u8)); 4] = [
+    (0.0, (0, 0, 0)),
+    (0.33, (255, 0, 0)),
+    (0.66, (255, 255, 0)),
+    (1.0, (255, 255, 255)),
+];
+
+/// Linearly interpolates an RGB triple between the two `anchors` bracketing
+/// `t`.
+fn interpolate(anchors: &[(f64, (u8, u8, u8))], t: f64) -> (u8, u8, u8) {
+    for window in anchors.windows(2) {
+        let (t0, (r0, g0, b0)) = window[0];
+        let (t1, (r1, g1, b1)) = window[1];
+        if t <= t1 {
+            let span = (t1 - t0).max(f64::EPSILON);
+            let frac = ((t - t0) / span).clamp(0.0, 1.0);
+            let lerp = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * frac).round() as u8;
+            return (lerp(r0, r1), lerp(g0, g1), lerp(b0, b1));
+        }
+    }
+    anchors.last().map(|(_, c)| c).unwrap_or((0, 0, 0))
+}
+
+impl Heatmap {
+    /// Renders this heatmap to PNG bytes, one pixel per grid cell, with the
+    /// top row corresponding to maximum latitude so north is up.
+    ///
+    /// Each cell's count is normalized against [`max_count`](Heatmap::max_count)
+    /// and mapped through `colormap`; cells with zero density render fully
+    /// transparent, so the image can be overlaid directly on a basemap.
+    pub fn to_png(&self, colormap: ColorMap) -> Result<Vec<u8>> {
+        let (width, height) = self.pixel_dimensions();
+        let mut image = RgbaImage::new(width, height);
+
+        for lat_idx in 0..self.grid.lat_cells {
+            // Grid row 0 sits at the bounds' southern edge; flip so the PNG's
+            // top row is the northern edge.
+            let row = self.grid.lat_cells - 1 - lat_idx;
+            for lon_idx in 0..self.grid.lon_cells {
+                let value = self.get_normalized(lat_idx, lon_idx);
+                image.put_pixel(lon_idx as u32, row as u32, colormap.pixel(value));
+            }
+        }
+
+        let mut bytes = Vec::new();
+        image
+            .write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageFormat::Png)
+            .map_err(|e| Error::ParseError(format!("Failed to encode heatmap PNG: {}", e)))?;
+        Ok(bytes)
+    }
+
+    /// Pixel dimensions `(width, height)` of the image [`to_png`](Self::to_png)
+    /// produces, for georeferencing the result against
+    /// [`grid.bounds`](super::spatiotemporal::GridSpec::bounds).
+    pub fn pixel_dimensions(&self) -> (u32, u32) {
+        (self.grid.lon_cells as u32, self.grid.lat_cells as u32)
+    }
+
+    /// Slices this heatmap into a pyramid of 256x256 Web Mercator tiles
+    /// across `min_zoom..=max_zoom`, yielding `(z, x, y, png_bytes)` for
+    /// every tile the heatmap's bounds touch at each level.
+    ///
+    /// Each tile pixel sums the grid cells whose area falls inside that
+    /// pixel's lon/lat box rather than sampling a single nearest cell, so
+    /// coarser (zoomed-out) tiles naturally aggregate more of the underlying
+    /// grid and stay visually consistent with their finer children instead
+    /// of just repeating one sampled cell's value.
+    pub fn to_tiles(
+        &self,
+        min_zoom: u8,
+        max_zoom: u8,
+        colormap: ColorMap,
+    ) -> impl Iterator<Item = (u8, u32, u32, Vec<u8>)> + '_ {
+        (min_zoom..=max_zoom).flat_map(move |z| {
+            let (min_x, max_y) = lonlat_to_tile(self.grid.bounds.min_lat, self.grid.bounds.min_lon, z);
+            let (max_x, min_y) = lonlat_to_tile(self.grid.bounds.max_lat, self.grid.bounds.max_lon, z);
+            (min_x..=max_x).flat_map(move |x| {
+                (min_y..=max_y).map(move |y| {
+                    let png = self
+                        .render_tile(z, x, y, colormap)
+                        .expect("encoding an in-memory PNG buffer does not fail");
+                    (z, x, y, png)
+                })
+            })
+        })
+    }
+
+    /// Renders the single slippy tile `(z, x, y)` as a 256x256 PNG, resampled
+    /// from the grid counts via [`sum_cells_in`](Self::sum_cells_in).
+    fn render_tile(&self, z: u8, x: u32, y: u32, colormap: ColorMap) -> Result<Vec<u8>> {
+        let bounds = tile_bounds(z, x, y);
+        let lon_span = bounds.max_lon - bounds.min_lon;
+        let lat_span = bounds.max_lat - bounds.min_lat;
+        let mut image = RgbaImage::new(TILE_SIZE, TILE_SIZE);
+
+        for py in 0..TILE_SIZE {
+            let pixel_lat_n = bounds.max_lat - lat_span * py as f64 / TILE_SIZE as f64;
+            let pixel_lat_s = bounds.max_lat - lat_span * (py + 1) as f64 / TILE_SIZE as f64;
+            for px in 0..TILE_SIZE {
+                let pixel_lon_w = bounds.min_lon + lon_span * px as f64 / TILE_SIZE as f64;
+                let pixel_lon_e = bounds.min_lon + lon_span * (px + 1) as f64 / TILE_SIZE as f64;
+
+                let sum = self.sum_cells_in(pixel_lat_s, pixel_lat_n, pixel_lon_w, pixel_lon_e);
+                let normalized = if self.max_count == 0 {
+                    0.0
+                } else {
+                    (sum as f64 / self.max_count as f64).min(1.0)
+                };
+                image.put_pixel(px, py, colormap.pixel(normalized));
+            }
+        }
+
+        let mut bytes = Vec::new();
+        image
+            .write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageFormat::Png)
+            .map_err(|e| Error::ParseError(format!("Failed to encode tile PNG: {}", e)))?;
+        Ok(bytes)
+    }
+
+    /// Sums grid cell counts whose index falls within the given lat/lon box,
+    /// clamped to the grid's extent. Empty (or out-of-bounds) boxes sum to 0.
+    fn sum_cells_in(&self, lat_s: f64, lat_n: f64, lon_w: f64, lon_e: f64) -> usize {
+        let (lat_size, lon_size) = self.grid.cell_size();
+        if lat_size <= 0.0 || lon_size <= 0.0 {
+            return 0;
+        }
+
+        let lat_lo = (((lat_s - self.grid.bounds.min_lat) / lat_size).floor().max(0.0)) as usize;
+        let lat_hi = (((lat_n - self.grid.bounds.min_lat) / lat_size).ceil().max(0.0)) as usize;
+        let lon_lo = (((lon_w - self.grid.bounds.min_lon) / lon_size).floor().max(0.0)) as usize;
+        let lon_hi = (((lon_e - self.grid.bounds.min_lon) / lon_size).ceil().max(0.0)) as usize;
+
+        let mut total = 0usize;
+        for lat_idx in lat_lo..lat_hi.min(self.grid.lat_cells) {
+            for lon_idx in lon_lo..lon_hi.min(self.grid.lon_cells) {
+                total += self.get(lat_idx, lon_idx);
+            }
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::GeoBounds;
+    use crate::index::GridSpec;
+
+    fn sample_heatmap() -> Heatmap {
+        Heatmap {
+            grid: GridSpec::new(GeoBounds::new(0.0, 0.0, 1.0, 1.0), 2, 2),
+            counts: vec![0, 1, 2, 4],
+            max_count: 4,
+        }
+    }
+
+    #[test]
+    fn test_pixel_dimensions_matches_grid_cells() {
+        let heatmap = sample_heatmap();
+        assert_eq!(heatmap.pixel_dimensions(), (2, 2));
+    }
+
+    #[test]
+    fn test_to_png_produces_valid_png_header() {
+        let heatmap = sample_heatmap();
+        let bytes = heatmap.to_png(ColorMap::Viridis).unwrap();
+        assert_eq!(&bytes[..8], &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']);
+    }
+
+    #[test]
+    fn test_colormap_pixel_transparent_at_zero() {
+        let pixel = ColorMap::Viridis.pixel(0.0);
+        assert_eq!(pixel.0[3], 0);
+    }
+
+    #[test]
+    fn test_colormap_pixel_opaque_above_zero() {
+        let pixel = ColorMap::Hot.pixel(0.5);
+        assert_eq!(pixel.0[3], 255);
+    }
+
+    #[test]
+    fn test_to_tiles_yields_one_tile_for_small_bounds_at_high_zoom() {
+        let heatmap = Heatmap {
+            grid: GridSpec::new(GeoBounds::new(0.0001, 0.0001, 0.0002, 0.0002), 2, 2),
+            counts: vec![0, 1, 2, 4],
+            max_count: 4,
+        };
+        let tiles: Vec<_> = heatmap.to_tiles(18, 18, ColorMap::Hot).collect();
+        assert_eq!(tiles.len(), 1);
+        let (z, _x, _y, png) = &tiles[0];
+        assert_eq!(*z, 18);
+        assert_eq!(&png[..8], &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']);
+    }
+
+    #[test]
+    fn test_to_tiles_spans_multiple_zoom_levels() {
+        let heatmap = sample_heatmap();
+        let tiles: Vec<_> = heatmap.to_tiles(0, 1, ColorMap::Viridis).collect();
+        let zooms: std::collections::HashSet<u8> = tiles.iter().map(|(z, ..)| *z).collect();
+        assert!(zooms.contains(&0));
+        assert!(zooms.contains(&1));
+    }
+
+    #[test]
+    fn test_sum_cells_in_aggregates_whole_grid() {
+        let heatmap = sample_heatmap();
+        let total = heatmap.sum_cells_in(-90.0, 90.0, -180.0, 180.0);
+        assert_eq!(total, heatmap.counts.iter().sum::<usize>());
+    }
+}
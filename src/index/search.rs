@@ -0,0 +1,222 @@
+//! Full-text search over narrative events, combinable with spatial and
+//! temporal filters.
+//!
+//! [`SearchIndex`] tokenizes each event's [`text`](crate::core::Event::text)
+//! and [`tags`](crate::core::Event::tags) into an inverted index and ranks
+//! matches with BM25, then intersects the ranked candidates with the same
+//! [`GeoBounds`]/[`TimeRange`] predicates [`SpatiotemporalIndex`](super::SpatiotemporalIndex)
+//! uses — so callers can ask for "events mentioning 'earthquake' within
+//! 50km of Tokyo in 2024" in a single call.
+
+use std::collections::HashMap;
+
+use crate::core::{Event, GeoBounds, TimeRange};
+
+/// BM25 term-frequency saturation parameter.
+const BM25_K1: f64 = 1.2;
+/// BM25 document-length normalization parameter.
+const BM25_B: f64 = 0.75;
+
+/// Inverted-index BM25 search over a narrative's events, with optional
+/// spatial/temporal filtering.
+#[derive(Debug, Clone)]
+pub struct SearchIndex {
+    events: Vec<Event>,
+    /// term -> postings of (event index, term frequency in that event)
+    postings: HashMap<String, Vec<(usize, usize)>>,
+    doc_lengths: Vec<usize>,
+    avg_doc_length: f64,
+}
+
+impl SearchIndex {
+    /// Builds a search index over `events`.
+    pub fn build(events: &[Event]) -> Self {
+        let events: Vec<Event> = events.to_vec();
+        let mut postings: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
+        let mut doc_lengths = Vec::with_capacity(events.len());
+
+        for (doc_idx, event) in events.iter().enumerate() {
+            let terms = tokenize_event(event);
+            doc_lengths.push(terms.len());
+
+            let mut term_freqs: HashMap<&str, usize> = HashMap::new();
+            for term in &terms {
+                *term_freqs.entry(term.as_str()).or_insert(0) += 1;
+            }
+            for (term, tf) in term_freqs {
+                postings.entry(term.to_string()).or_default().push((doc_idx, tf));
+            }
+        }
+
+        let avg_doc_length = if doc_lengths.is_empty() {
+            0.0
+        } else {
+            doc_lengths.iter().sum::<usize>() as f64 / doc_lengths.len() as f64
+        };
+
+        Self {
+            events,
+            postings,
+            doc_lengths,
+            avg_doc_length,
+        }
+    }
+
+    /// Ranks events by BM25 relevance to `query`, keeping only those whose
+    /// location falls within `bounds` (when given) and whose timestamp falls
+    /// within `range` (when given), and returns the top `k` as
+    /// `(event_index, score)` pairs descending by score.
+    ///
+    /// `event_index` can be resolved via [`event`](Self::event).
+    pub fn search(
+        &self,
+        query: &str,
+        bounds: Option<&GeoBounds>,
+        range: Option<&TimeRange>,
+        k: usize,
+    ) -> Vec<(usize, f32)> {
+        let n = self.events.len() as f64;
+        let mut scores: HashMap<usize, f64> = HashMap::new();
+
+        for term in tokenize_text(query) {
+            let Some(term_postings) = self.postings.get(&term) else {
+                continue;
+            };
+            let df = term_postings.len() as f64;
+            let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+
+            for &(doc_idx, tf) in term_postings {
+                let dl = self.doc_lengths[doc_idx] as f64;
+                let tf = tf as f64;
+                let denom =
+                    tf + BM25_K1 * (1.0 - BM25_B + BM25_B * dl / self.avg_doc_length.max(1.0));
+                *scores.entry(doc_idx).or_insert(0.0) += idf * (tf * (BM25_K1 + 1.0)) / denom;
+            }
+        }
+
+        let mut ranked: Vec<(usize, f32)> = scores
+            .into_iter()
+            .filter(|(doc_idx, _)| self.passes_filters(*doc_idx, bounds, range))
+            .map(|(doc_idx, score)| (doc_idx, score as f32))
+            .collect();
+
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        ranked.truncate(k);
+        ranked
+    }
+
+    /// Whether the event at `doc_idx` satisfies the optional geo/time
+    /// predicates.
+    fn passes_filters(&self, doc_idx: usize, bounds: Option<&GeoBounds>, range: Option<&TimeRange>) -> bool {
+        let event = &self.events[doc_idx];
+        if let Some(bounds) = bounds {
+            if !bounds.contains(&event.location) {
+                return false;
+            }
+        }
+        if let Some(range) = range {
+            if !range.contains(&event.timestamp) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Borrows the event at `index`, as returned by [`search`](Self::search).
+    pub fn event(&self, index: usize) -> &Event {
+        &self.events[index]
+    }
+
+    /// Number of indexed events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if the index has no events.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+/// Tokenizes an event's text and tags into lowercased terms.
+fn tokenize_event(event: &Event) -> Vec<String> {
+    let mut terms = tokenize_text(&event.text);
+    terms.extend(event.tags.iter().flat_map(|tag| tokenize_text(tag)));
+    terms
+}
+
+/// Splits on non-alphanumeric boundaries and lowercases, mirroring
+/// [`WhitespaceTokenizer`](crate::text::WhitespaceTokenizer)'s segmentation
+/// but case-folded for search matching.
+fn tokenize_text(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_lowercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Location, Timestamp};
+
+    fn sample_events() -> Vec<Event> {
+        vec![
+            Event::new(
+                Location::new(35.6762, 139.6503),
+                Timestamp::parse("2024-03-15T08:00:00Z").unwrap(),
+                "A strong earthquake struck near Tokyo",
+            ),
+            Event::new(
+                Location::new(35.6895, 139.6917),
+                Timestamp::parse("2023-06-01T08:00:00Z").unwrap(),
+                "Tokyo hosted a trade conference",
+            ),
+            Event::new(
+                Location::new(34.0522, -118.2437),
+                Timestamp::parse("2024-03-20T08:00:00Z").unwrap(),
+                "An earthquake rattled Los Angeles",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_search_ranks_matching_documents_first() {
+        let index = SearchIndex::build(&sample_events());
+        let results = index.search("earthquake", None, None, 10);
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|(_, score)| *score > 0.0));
+    }
+
+    #[test]
+    fn test_search_applies_spatial_filter() {
+        let index = SearchIndex::build(&sample_events());
+        let tokyo_bounds = GeoBounds::new(35.0, 139.0, 36.0, 140.0);
+        let results = index.search("earthquake", Some(&tokyo_bounds), None, 10);
+        assert_eq!(results.len(), 1);
+        assert!(index.event(results[0].0).text.contains("Tokyo"));
+    }
+
+    #[test]
+    fn test_search_applies_temporal_filter() {
+        let index = SearchIndex::build(&sample_events());
+        let range = TimeRange::year(2024);
+        let results = index.search("tokyo", None, Some(&range), 10);
+        assert_eq!(results.len(), 1);
+        assert!(index.event(results[0].0).text.contains("earthquake"));
+    }
+
+    #[test]
+    fn test_search_respects_top_k() {
+        let index = SearchIndex::build(&sample_events());
+        let results = index.search("tokyo earthquake", None, None, 1);
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_search_on_empty_index_returns_empty() {
+        let index = SearchIndex::build(&[]);
+        assert!(index.is_empty());
+        assert!(index.search("anything", None, None, 10).is_empty());
+    }
+}
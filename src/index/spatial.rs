@@ -23,7 +23,8 @@
 //! assert!(!results.is_empty());
 //! ```
 
-use crate::core::{GeoBounds, Location};
+use crate::core::{Event, GeoBounds, Location};
+use roaring::RoaringBitmap;
 use rstar::{PointDistance, RTree, RTreeObject, AABB};
 
 /// A wrapper that makes Location compatible with R-tree indexing.
@@ -128,6 +129,23 @@ impl<T: Clone> SpatialIndex<T> {
         )
     }
 
+    /// Query the ids of items within a bounding box as a compressed bitmap.
+    ///
+    /// The returned [`RoaringBitmap`] holds the internal ids the index owns.
+    /// Bitmaps compose cleanly under `&`/`|`/`-` for multi-dimensional and
+    /// boolean query combinators, and are far cheaper than hashing every
+    /// candidate for sparse large indexes.
+    pub fn query_bounds_bitmap(&self, bounds: &GeoBounds) -> RoaringBitmap {
+        let envelope = AABB::from_corners(
+            [bounds.min_lon, bounds.min_lat],
+            [bounds.max_lon, bounds.max_lat],
+        );
+        self.tree
+            .locate_in_envelope(&envelope)
+            .map(|indexed| indexed.index as u32)
+            .collect()
+    }
+
     /// Query items within a radius of a point.
     ///
     /// Note: This uses Euclidean distance in degrees. For accurate
@@ -140,20 +158,48 @@ impl<T: Clone> SpatialIndex<T> {
             .collect()
     }
 
-    /// Query items within a radius in meters.
+    /// Query items within a great-circle radius in meters, nearest first.
     ///
-    /// Uses the Haversine formula for accurate great-circle distance.
+    /// Pre-filters with a bounding box whose north/south edges are the
+    /// latitude plus and minus the radius's angular distance and whose
+    /// east/west edges come from [`destination_point`], rather than
+    /// approximating it with flat lat/lon degree deltas — this stays correct
+    /// when the circle would cross a pole (the north/south edge passes ±90°
+    /// and is clamped) or wrap the antimeridian (the east/west projection crosses
+    /// ±180°), falling back to the full longitude band in either case rather
+    /// than silently missing candidates. Each candidate in the box is then
+    /// refined with an exact haversine distance, keeping only those within
+    /// `radius_meters`. Results are sorted ascending by distance, so the
+    /// output doubles as a ranked nearest-within-radius list.
     pub fn query_radius_meters(&self, lat: f64, lon: f64, radius_meters: f64) -> Vec<&T> {
-        // Convert to approximate degree radius for initial R-tree query
-        // 1 degree latitude ≈ 111,320 meters
-        let degree_radius = radius_meters / 111_320.0 * 1.5; // Add buffer
-
-        // Get candidates from R-tree
-        let candidates = self.query_radius(lat, lon, degree_radius);
+        let delta_deg = (radius_meters / 6_371_000.0).to_degrees();
+        let north_lat = lat + delta_deg;
+        let south_lat = lat - delta_deg;
+        let (_, east_lon) = destination_point(lat, lon, 90.0, radius_meters);
+        let (_, west_lon) = destination_point(lat, lon, 270.0, radius_meters);
+
+        let max_lat = north_lat.min(90.0);
+        let min_lat = south_lat.max(-90.0);
+        let covers_pole = north_lat >= 90.0 || south_lat <= -90.0;
+        let wraps_antimeridian = west_lon > east_lon;
+
+        let envelope = if covers_pole || wraps_antimeridian {
+            AABB::from_corners([-180.0, min_lat], [180.0, max_lat])
+        } else {
+            AABB::from_corners([west_lon, min_lat], [east_lon, max_lat])
+        };
+
+        let mut within: Vec<(f64, &T)> = self
+            .tree
+            .locate_in_envelope(&envelope)
+            .filter_map(|indexed| {
+                let d = haversine_meters(lat, lon, indexed.location.lat, indexed.location.lon);
+                (d <= radius_meters).then(|| (d, &self.items[indexed.index]))
+            })
+            .collect();
 
-        // Return all candidates within the approximate radius
-        // (precise Haversine filtering would require storing locations)
-        candidates
+        within.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        within.into_iter().map(|(_, item)| item).collect()
     }
 
     /// Find the k nearest neighbors to a point.
@@ -165,6 +211,38 @@ impl<T: Clone> SpatialIndex<T> {
             .collect()
     }
 
+    /// Find the k nearest neighbors to a point, paired with their great-circle
+    /// distance in meters.
+    ///
+    /// Unlike [`nearest`](Self::nearest), which only orders by the tree's
+    /// planar metric, this over-fetches candidates by that metric and then
+    /// re-ranks them by exact haversine distance, so the returned order and
+    /// distances stay trustworthy near the poles.
+    pub fn nearest_with_distance(&self, lat: f64, lon: f64, k: usize) -> Vec<(&T, f64)> {
+        let mut ranked: Vec<(&T, f64)> = self
+            .tree
+            .nearest_neighbor_iter(&[lon, lat])
+            .take(k.saturating_mul(4).max(k))
+            .map(|indexed| {
+                let d = haversine_meters(lat, lon, indexed.location.lat, indexed.location.lon);
+                (&self.items[indexed.index], d)
+            })
+            .collect();
+
+        ranked.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        ranked.truncate(k);
+        ranked
+    }
+
+    /// Find the `k` nearest items to a point by true great-circle distance.
+    ///
+    /// Alias for [`nearest_with_distance`](Self::nearest_with_distance), kept
+    /// for callers reaching for the "meters" naming used by
+    /// [`query_radius_meters`](Self::query_radius_meters).
+    pub fn nearest_meters(&self, lat: f64, lon: f64, k: usize) -> Vec<(&T, f64)> {
+        self.nearest_with_distance(lat, lon, k)
+    }
+
     /// Find the single nearest item to a point.
     pub fn nearest_one(&self, lat: f64, lon: f64) -> Option<&T> {
         self.tree
@@ -186,6 +264,33 @@ impl<T: Clone> SpatialIndex<T> {
     pub fn items(&self) -> &[T] {
         &self.items
     }
+
+    /// Query items covered by a standard Web Mercator slippy tile `z/x/y`.
+    ///
+    /// Converts the tile to its lat/lon bounding box via [`tile_bounds`] and
+    /// delegates to [`query_bounds`](Self::query_bounds), so results follow
+    /// the same semantics as any other bounds query.
+    pub fn query_tile(&self, z: u8, x: u32, y: u32) -> Vec<&T> {
+        self.query_bounds(&tile_bounds(z, x, y))
+    }
+
+    /// Lists the `(x, y)` slippy tiles at zoom `z` that cover `bounds`.
+    ///
+    /// Latitude is clamped to the Mercator limit (±85.0511°) before
+    /// projecting, so bounds reaching toward the poles still resolve to a
+    /// finite tile range.
+    pub fn tiles_covering(bounds: &GeoBounds, z: u8) -> Vec<(u32, u32)> {
+        let (min_x, max_y) = lonlat_to_tile(bounds.min_lat, bounds.min_lon, z);
+        let (max_x, min_y) = lonlat_to_tile(bounds.max_lat, bounds.max_lon, z);
+
+        let mut tiles = Vec::with_capacity(((max_x - min_x + 1) * (max_y - min_y + 1)) as usize);
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                tiles.push((x, y));
+            }
+        }
+        tiles
+    }
 }
 
 impl<T: Clone> Default for SpatialIndex<T> {
@@ -194,6 +299,93 @@ impl<T: Clone> Default for SpatialIndex<T> {
     }
 }
 
+impl SpatialIndex<Event> {
+    /// Build a spatial index over a narrative's events, keyed by
+    /// [`Event::location`](crate::core::Event).
+    pub fn build(events: &[Event]) -> Self {
+        Self::from_iter(events.iter().cloned(), |event| &event.location)
+    }
+
+    /// Find events within a great-circle radius of `loc`, nearest first.
+    ///
+    /// Convenience wrapper over [`query_radius_meters`](Self::query_radius_meters)
+    /// for the common case of querying an event index by [`Location`].
+    pub fn within_radius(&self, loc: &Location, radius_meters: f64) -> Vec<&Event> {
+        self.query_radius_meters(loc.lat, loc.lon, radius_meters)
+    }
+}
+
+/// Great-circle distance between two coordinates, in meters.
+pub(crate) fn haversine_meters(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let r = 6_371_000.0_f64;
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let dphi = (lat2 - lat1).to_radians();
+    let dlambda = (lon2 - lon1).to_radians();
+    let a = (dphi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (dlambda / 2.0).sin().powi(2);
+    r * 2.0 * a.sqrt().asin()
+}
+
+/// Projects a point `distance_meters` along `bearing_deg` (clockwise from
+/// north) from `(lat, lon)`, using the spherical direct geodesic formula.
+///
+/// The resulting longitude is normalized to `[-180, 180)`; the resulting
+/// latitude comes from `asin` and so always lies within `[-90, 90]` — a path
+/// that passes over a pole comes back down on the far side, so a result
+/// never shows by itself that a pole was crossed.
+pub(crate) fn destination_point(lat: f64, lon: f64, bearing_deg: f64, distance_meters: f64) -> (f64, f64) {
+    let r = 6_371_000.0_f64;
+    let delta = distance_meters / r;
+    let theta = bearing_deg.to_radians();
+    let phi1 = lat.to_radians();
+    let lambda1 = lon.to_radians();
+
+    let phi2 = (phi1.sin() * delta.cos() + phi1.cos() * delta.sin() * theta.cos()).asin();
+    let lambda2 =
+        lambda1 + (theta.sin() * delta.sin() * phi1.cos()).atan2(delta.cos() - phi1.sin() * phi2.sin());
+
+    let lon2 = (lambda2.to_degrees() + 540.0) % 360.0 - 180.0;
+    (phi2.to_degrees(), lon2)
+}
+
+/// Latitude beyond which the Web Mercator projection used by slippy tiles
+/// diverges to infinity; tile math clamps to this range.
+const MAX_MERCATOR_LAT: f64 = 85.0511;
+
+/// Converts a lon/lat point to its Web Mercator slippy tile `(x, y)` at
+/// zoom `z`, clamping latitude to ±[`MAX_MERCATOR_LAT`] and the resulting
+/// tile indices to `[0, 2^z)`.
+pub(crate) fn lonlat_to_tile(lat: f64, lon: f64, z: u8) -> (u32, u32) {
+    let n = 2f64.powi(z as i32);
+    let lat = lat.clamp(-MAX_MERCATOR_LAT, MAX_MERCATOR_LAT);
+    let lat_rad = lat.to_radians();
+
+    let x = (lon + 180.0) / 360.0 * n;
+    let y = (1.0 - (lat_rad.tan() + 1.0 / lat_rad.cos()).ln() / std::f64::consts::PI) / 2.0 * n;
+
+    let max_index = n as u32 - 1;
+    (
+        (x.floor().max(0.0) as u32).min(max_index),
+        (y.floor().max(0.0) as u32).min(max_index),
+    )
+}
+
+/// Inverse of [`lonlat_to_tile`]: the lat/lon bounding box covered by slippy
+/// tile `(z, x, y)`.
+pub(crate) fn tile_bounds(z: u8, x: u32, y: u32) -> GeoBounds {
+    let n = 2f64.powi(z as i32);
+    let min_lon = x as f64 / n * 360.0 - 180.0;
+    let max_lon = (x + 1) as f64 / n * 360.0 - 180.0;
+    let max_lat = tile_y_to_lat(y, n);
+    let min_lat = tile_y_to_lat(y + 1, n);
+    GeoBounds::new(min_lat, min_lon, max_lat, max_lon)
+}
+
+/// Latitude of the north edge of tile row `y` out of `n = 2^z` rows.
+fn tile_y_to_lat(y: u32, n: f64) -> f64 {
+    let unit = 1.0 - 2.0 * y as f64 / n;
+    (unit * std::f64::consts::PI).sinh().atan().to_degrees()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,6 +426,18 @@ mod tests {
         assert_eq!(*results[0], "NYC");
     }
 
+    #[test]
+    fn test_spatial_index_query_radius_meters() {
+        let mut index: SpatialIndex<&str> = SpatialIndex::new();
+        index.insert("NYC", &Location::new(40.7128, -74.0060));
+        index.insert("Newark", &Location::new(40.7357, -74.1724)); // ~14 km
+        index.insert("LA", &Location::new(34.0522, -118.2437));
+
+        // 20 km around NYC picks up Newark but not LA, nearest first.
+        let results = index.query_radius_meters(40.7128, -74.0060, 20_000.0);
+        assert_eq!(results, vec![&"NYC", &"Newark"]);
+    }
+
     #[test]
     fn test_spatial_index_nearest() {
         let mut index: SpatialIndex<&str> = SpatialIndex::new();
@@ -258,4 +462,129 @@ mod tests {
         let results = index.nearest(40.7128, -74.0060, 2);
         assert_eq!(results.len(), 2);
     }
+
+    #[test]
+    fn test_spatial_index_nearest_with_distance() {
+        let mut index: SpatialIndex<&str> = SpatialIndex::new();
+        index.insert("NYC", &Location::new(40.7128, -74.0060));
+        index.insert("Newark", &Location::new(40.7357, -74.1724));
+        index.insert("LA", &Location::new(34.0522, -118.2437));
+
+        let results = index.nearest_with_distance(40.7128, -74.0060, 2);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, &"NYC");
+        assert_eq!(results[0].1, 0.0);
+        assert_eq!(results[1].0, &"Newark");
+        assert!(results[0].1 < results[1].1);
+    }
+
+    #[test]
+    fn test_destination_point_due_north() {
+        let (lat, lon) = destination_point(0.0, 0.0, 0.0, 111_320.0);
+        assert!((lat - 1.0).abs() < 0.01);
+        assert!(lon.abs() < 0.001);
+    }
+
+    #[test]
+    fn test_destination_point_normalizes_antimeridian_crossing() {
+        let (_, lon) = destination_point(0.0, 179.9, 90.0, 50_000.0);
+        assert!((-180.0..0.0).contains(&lon));
+    }
+
+    #[test]
+    fn test_query_radius_meters_handles_pole_crossing() {
+        let mut index: SpatialIndex<&str> = SpatialIndex::new();
+        index.insert("NearPole", &Location::new(89.9, 10.0));
+        index.insert("OppositeSide", &Location::new(89.9, -170.0));
+
+        // A radius crossing the north pole should still find the point on the
+        // opposite side of the date line rather than missing it.
+        let results = index.query_radius_meters(90.0, 0.0, 50_000.0);
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn test_spatial_index_build_and_within_radius() {
+        use crate::core::{Event, Timestamp};
+
+        let events = vec![
+            Event::new(
+                Location::new(40.7128, -74.0060),
+                Timestamp::parse("2024-01-20T08:00:00Z").unwrap(),
+                "NYC",
+            ),
+            Event::new(
+                Location::new(34.0522, -118.2437),
+                Timestamp::parse("2024-01-20T08:00:00Z").unwrap(),
+                "LA",
+            ),
+        ];
+
+        let index = SpatialIndex::build(&events);
+        let nearby = index.within_radius(&Location::new(40.7128, -74.0060), 20_000.0);
+        assert_eq!(nearby.len(), 1);
+        assert_eq!(nearby[0].text, "NYC");
+    }
+
+    #[test]
+    fn test_nearest_meters_matches_nearest_with_distance() {
+        let mut index: SpatialIndex<&str> = SpatialIndex::new();
+        index.insert("NYC", &Location::new(40.7128, -74.0060));
+        index.insert("Newark", &Location::new(40.7357, -74.1724));
+        index.insert("LA", &Location::new(34.0522, -118.2437));
+
+        let results = index.nearest_meters(40.7128, -74.0060, 2);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, &"NYC");
+        assert_eq!(results[1].0, &"Newark");
+    }
+
+    #[test]
+    fn test_lonlat_to_tile_matches_known_tile() {
+        // Null Island at zoom 1 sits on the boundary of all four tiles.
+        let (x, y) = lonlat_to_tile(0.0, 0.0, 1);
+        assert_eq!((x, y), (1, 1));
+    }
+
+    #[test]
+    fn test_lonlat_to_tile_clamps_to_mercator_limit() {
+        let (_, y) = lonlat_to_tile(89.9, 0.0, 4);
+        assert_eq!(y, 0);
+    }
+
+    #[test]
+    fn test_tile_bounds_is_inverse_of_lonlat_to_tile() {
+        let bounds = tile_bounds(3, 4, 3);
+        let (x, y) = lonlat_to_tile(
+            (bounds.min_lat + bounds.max_lat) / 2.0,
+            (bounds.min_lon + bounds.max_lon) / 2.0,
+            3,
+        );
+        assert_eq!((x, y), (4, 3));
+    }
+
+    #[test]
+    fn test_query_tile_delegates_to_query_bounds() {
+        let mut index: SpatialIndex<&str> = SpatialIndex::new();
+        index.insert("NYC", &Location::new(40.7128, -74.0060));
+        index.insert("LA", &Location::new(34.0522, -118.2437));
+
+        let (x, y) = lonlat_to_tile(40.7128, -74.0060, 6);
+        let results = index.query_tile(6, x, y);
+        assert_eq!(results, vec![&"NYC"]);
+    }
+
+    #[test]
+    fn test_tiles_covering_returns_single_tile_for_point_bounds() {
+        let bounds = GeoBounds::new(40.70, -74.01, 40.71, -74.00);
+        let tiles = SpatialIndex::<&str>::tiles_covering(&bounds, 10);
+        assert_eq!(tiles.len(), 1);
+    }
+
+    #[test]
+    fn test_tiles_covering_spans_multiple_tiles_for_wide_bounds() {
+        let bounds = GeoBounds::new(-10.0, -10.0, 10.0, 10.0);
+        let tiles = SpatialIndex::<&str>::tiles_covering(&bounds, 3);
+        assert!(tiles.len() > 1);
+    }
 }
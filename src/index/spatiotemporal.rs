@@ -28,6 +28,61 @@
 
 use super::{SpatialIndex, TemporalIndex};
 use crate::core::{GeoBounds, Location, TimeRange, Timestamp};
+use roaring::RoaringBitmap;
+
+/// A composable boolean filter over a [`SpatiotemporalIndex`].
+///
+/// Leaves (`Bbox`, `Radius`, `TimeRange`) push work down to the underlying
+/// R-tree / B-tree, yielding compressed candidate id sets; the boolean
+/// combinators (`And`, `Or`, `Not`) combine those sets with bitmap operations,
+/// so only the root result is ever materialized into `&T`. This lets callers
+/// express arbitrary predicates such as
+/// "(inside bbox A OR within 2 km of P) AND in range R AND NOT in bbox B".
+#[derive(Debug, Clone)]
+pub enum Filter {
+    /// Items inside a geographic bounding box.
+    Bbox(GeoBounds),
+    /// Items within a great-circle radius (meters) of a point.
+    Radius {
+        /// Center latitude in degrees.
+        lat: f64,
+        /// Center longitude in degrees.
+        lon: f64,
+        /// Radius in meters.
+        radius_m: f64,
+    },
+    /// Items whose timestamp falls in a time range.
+    TimeRange(TimeRange),
+    /// Items matching both sub-filters.
+    And(Box<Filter>, Box<Filter>),
+    /// Items matching either sub-filter.
+    Or(Box<Filter>, Box<Filter>),
+    /// Items not matching the sub-filter.
+    Not(Box<Filter>),
+}
+
+impl Filter {
+    /// Maximum nesting depth evaluated before the planner bails out.
+    ///
+    /// Pathologically deep trees are treated as matching nothing rather than
+    /// overflowing the stack.
+    const MAX_DEPTH: usize = 4096;
+
+    /// Convenience constructor for [`Filter::And`].
+    pub fn and(self, other: Filter) -> Filter {
+        Filter::And(Box::new(self), Box::new(other))
+    }
+
+    /// Convenience constructor for [`Filter::Or`].
+    pub fn or(self, other: Filter) -> Filter {
+        Filter::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Convenience constructor for [`Filter::Not`].
+    pub fn not(self) -> Filter {
+        Filter::Not(Box::new(self))
+    }
+}
 
 /// Combined spatiotemporal index for efficient space-time queries.
 ///
@@ -83,27 +138,18 @@ impl<T: Clone> SpatiotemporalIndex<T> {
     }
 
     /// Query items within both spatial bounds and time range.
+    ///
+    /// Both dimensions return their candidate ids as compressed
+    /// [`RoaringBitmap`](roaring::RoaringBitmap)s; the final result is a single
+    /// bitwise AND, which is dramatically faster and lower-allocation than
+    /// hashing every candidate for sparse large indexes.
     pub fn query(&self, bounds: &GeoBounds, range: &TimeRange) -> Vec<&T> {
-        // Get spatial candidates
-        let spatial_indices: std::collections::HashSet<usize> = self
-            .spatial
-            .query_bounds(bounds)
-            .into_iter()
-            .copied()
-            .collect();
+        let spatial = self.spatial.query_bounds_bitmap(bounds);
+        let temporal = self.temporal.query_range_bitmap(range);
 
-        // Get temporal candidates
-        let temporal_indices: std::collections::HashSet<usize> = self
-            .temporal
-            .query_range(range)
-            .into_iter()
-            .copied()
-            .collect();
-
-        // Intersect the results
-        spatial_indices
-            .intersection(&temporal_indices)
-            .map(|&i| &self.items[i])
+        (spatial & temporal)
+            .iter()
+            .map(|i| &self.items[i as usize])
             .collect()
     }
 
@@ -125,6 +171,68 @@ impl<T: Clone> SpatiotemporalIndex<T> {
             .collect()
     }
 
+    /// Query items within a great-circle radius of a point and a time range,
+    /// sorted ascending by distance.
+    ///
+    /// The spatial radius filter produces a distance-ranked candidate list;
+    /// candidates outside `range` are dropped while preserving that order.
+    pub fn query_radius_in_range(
+        &self,
+        lat: f64,
+        lon: f64,
+        radius_m: f64,
+        range: &TimeRange,
+    ) -> Vec<&T> {
+        let temporal = self.temporal.query_range_bitmap(range);
+        self.spatial
+            .query_radius_meters(lat, lon, radius_m)
+            .into_iter()
+            .filter(|&&id| temporal.contains(id as u32))
+            .map(|&id| &self.items[id])
+            .collect()
+    }
+
+    /// Evaluates a composable [`Filter`] and returns the matching items.
+    ///
+    /// Each leaf compiles to a candidate id set via the spatial/temporal index;
+    /// `And`/`Or`/`Not` combine those sets with bitmap intersection, union and
+    /// complement, and only the root set is turned into `&T`.
+    pub fn evaluate(&self, filter: &Filter) -> Vec<&T> {
+        self.compile(filter, 0)
+            .iter()
+            .map(|i| &self.items[i as usize])
+            .collect()
+    }
+
+    /// Compiles a filter into the bitmap of matching item ids.
+    fn compile(&self, filter: &Filter, depth: usize) -> RoaringBitmap {
+        if depth > Filter::MAX_DEPTH {
+            return RoaringBitmap::new();
+        }
+        match filter {
+            Filter::Bbox(bounds) => self.spatial.query_bounds_bitmap(bounds),
+            Filter::Radius {
+                lat,
+                lon,
+                radius_m,
+            } => self
+                .spatial
+                .query_radius_meters(*lat, *lon, *radius_m)
+                .into_iter()
+                .map(|&id| id as u32)
+                .collect(),
+            Filter::TimeRange(range) => self.temporal.query_range_bitmap(range),
+            Filter::And(a, b) => self.compile(a, depth + 1) & self.compile(b, depth + 1),
+            Filter::Or(a, b) => self.compile(a, depth + 1) | self.compile(b, depth + 1),
+            Filter::Not(inner) => self.universe() - self.compile(inner, depth + 1),
+        }
+    }
+
+    /// The bitmap of all indexed item ids (the complement universe for `Not`).
+    fn universe(&self) -> RoaringBitmap {
+        (0..self.items.len() as u32).collect()
+    }
+
     /// Find k nearest items to a point within a time range.
     pub fn nearest_in_range(&self, lat: f64, lon: f64, k: usize, range: &TimeRange) -> Vec<&T> {
         // Get temporal candidates first
@@ -348,6 +456,41 @@ mod tests {
         assert_eq!(*results[0], "NYC Jan 1");
     }
 
+    #[test]
+    fn test_evaluate_boolean_filter() {
+        let mut index = SpatiotemporalIndex::new();
+        index.insert(
+            "NYC Jan 1",
+            &Location::new(40.7128, -74.0060),
+            &make_timestamp(1),
+        );
+        index.insert(
+            "NYC Jan 15",
+            &Location::new(40.7128, -74.0060),
+            &make_timestamp(15),
+        );
+        index.insert(
+            "LA Jan 1",
+            &Location::new(34.0522, -118.2437),
+            &make_timestamp(1),
+        );
+
+        let east = GeoBounds::new(35.0, -80.0, 45.0, -70.0);
+        let first_week = TimeRange::new(make_timestamp(1), make_timestamp(7));
+
+        // (East coast) AND (first week) AND NOT (second week)
+        let filter = Filter::Bbox(east)
+            .and(Filter::TimeRange(first_week))
+            .and(Filter::Not(Box::new(Filter::TimeRange(TimeRange::new(
+                make_timestamp(8),
+                make_timestamp(20),
+            )))));
+
+        let results = index.evaluate(&filter);
+        assert_eq!(results.len(), 1);
+        assert_eq!(*results[0], "NYC Jan 1");
+    }
+
     #[test]
     fn test_heatmap_generation() {
         let mut index: SpatiotemporalIndex<&str> = SpatiotemporalIndex::new();
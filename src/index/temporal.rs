@@ -25,8 +25,9 @@
 //! assert!(!results.is_empty());
 //! ```
 
-use crate::core::{TimeRange, Timestamp};
-use std::collections::BTreeMap;
+use crate::core::{occurrence_timestamps, Recurrence, TimeRange, Timestamp};
+use roaring::RoaringBitmap;
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Temporal index for efficient time-based queries.
 ///
@@ -75,6 +76,28 @@ impl<T: Clone> TemporalIndex<T> {
         self.tree.entry(key).or_insert_with(Vec::new).push(idx);
     }
 
+    /// Insert an item once for every occurrence of a recurrence rule.
+    ///
+    /// Expands `rule` starting at `start` and inserts a clone of `item` at each
+    /// concrete occurrence that falls inside `bound`, so subsequent
+    /// [`query_range`](Self::query_range)/[`before`](Self::before)/[`after`](Self::after)
+    /// queries see the materialized instances without the caller enumerating
+    /// them. See [`core::occurrence_timestamps`](crate::core::occurrence_timestamps)
+    /// for the expansion semantics.
+    pub fn insert_recurring(
+        &mut self,
+        item: T,
+        start: &Timestamp,
+        rule: Recurrence,
+        bound: &TimeRange,
+    ) {
+        for occurrence in
+            occurrence_timestamps(start, &rule, (bound.start.clone(), bound.end.clone()))
+        {
+            self.insert(item.clone(), &occurrence);
+        }
+    }
+
     /// Query items within a time range (inclusive).
     pub fn query_range(&self, range: &TimeRange) -> Vec<&T> {
         let start_key = range.start.to_unix_millis();
@@ -86,6 +109,31 @@ impl<T: Clone> TemporalIndex<T> {
             .collect()
     }
 
+    /// Query the ids of items within a time range as a compressed bitmap.
+    ///
+    /// Mirrors [`query_range`](Self::query_range) but returns a
+    /// [`RoaringBitmap`] of internal ids so it can be intersected with a
+    /// spatial bitmap in a single `&`.
+    pub fn query_range_bitmap(&self, range: &TimeRange) -> RoaringBitmap {
+        let start_key = range.start.to_unix_millis();
+        let end_key = range.end.to_unix_millis();
+
+        self.tree
+            .range(start_key..=end_key)
+            .flat_map(|(_, indices)| indices.iter().map(|&i| i as u32))
+            .collect()
+    }
+
+    /// Query items using a human, relative time expression anchored at `now`.
+    ///
+    /// Parses `input` with [`TimeRange::parse_relative`] (e.g. `"last 3 hours"`,
+    /// `"since yesterday"`) and runs the resulting range through
+    /// [`query_range`](Self::query_range). Returns `None` when the expression is
+    /// not recognized.
+    pub fn query_relative(&self, input: &str, now: &Timestamp) -> Option<Vec<&T>> {
+        TimeRange::parse_relative(input, now).map(|range| self.query_range(&range))
+    }
+
     /// Query items before a timestamp.
     pub fn before(&self, timestamp: &Timestamp) -> Vec<&T> {
         let key = timestamp.to_unix_millis();
@@ -126,6 +174,57 @@ impl<T: Clone> TemporalIndex<T> {
             .collect()
     }
 
+    /// Returns the latest item at or before `ts` in O(log n).
+    ///
+    /// Unlike [`at_or_before`](Self::at_or_before), which collects every
+    /// preceding item, this answers the common "what was the state as of time
+    /// T" query by seeking the nearest preceding key. When several items share
+    /// that key, the last one inserted wins, matching bucket order.
+    pub fn as_of(&self, ts: &Timestamp) -> Option<&T> {
+        let key = ts.to_unix_millis();
+        self.tree
+            .range(..=key)
+            .next_back()
+            .and_then(|(_, indices)| indices.last().map(|&i| &self.items[i]))
+    }
+
+    /// Returns the earliest item strictly after `ts` in O(log n).
+    ///
+    /// The symmetric counterpart to [`as_of`](Self::as_of). When several items
+    /// share that key, the first one inserted wins.
+    pub fn next_after(&self, ts: &Timestamp) -> Option<&T> {
+        let key = ts.to_unix_millis();
+        self.tree
+            .range((key + 1)..)
+            .next()
+            .and_then(|(_, indices)| indices.first().map(|&i| &self.items[i]))
+    }
+
+    /// Returns the item whose timestamp is closest to `ts`.
+    ///
+    /// Compares the nearest neighbor on each side and returns whichever key has
+    /// the smaller absolute millisecond distance, breaking ties toward the
+    /// earlier timestamp.
+    pub fn nearest(&self, ts: &Timestamp) -> Option<&T> {
+        let key = ts.to_unix_millis();
+
+        let below = self.tree.range(..=key).next_back();
+        let above = self.tree.range((key + 1)..).next();
+
+        match (below, above) {
+            (Some((&bk, bi)), Some((&ak, ai))) => {
+                if (key - bk) <= (ak - key) {
+                    bi.last().map(|&i| &self.items[i])
+                } else {
+                    ai.first().map(|&i| &self.items[i])
+                }
+            }
+            (Some((_, bi)), None) => bi.last().map(|&i| &self.items[i]),
+            (None, Some((_, ai))) => ai.first().map(|&i| &self.items[i]),
+            (None, None) => None,
+        }
+    }
+
     /// Get the first (earliest) item.
     pub fn first(&self) -> Option<&T> {
         self.tree
@@ -179,6 +278,81 @@ impl<T: Clone> TemporalIndex<T> {
         }
     }
 
+    /// Iterate over fixed-interval tumbling (non-overlapping) windows.
+    ///
+    /// Unlike [`sliding_window`](Self::sliding_window), which anchors buckets to
+    /// event timestamps and only yields non-empty ones, this covers the whole
+    /// [`time_range`](Self::time_range) with contiguous `span`-wide buckets on an
+    /// epoch-aligned grid, yielding empty windows too so downstream charting has
+    /// a dense axis. Bucket boundaries are computed as
+    /// `align_base + ((key - align_base) / span) * span`, where `align_base` is
+    /// `align_to` (or the Unix epoch when `None`).
+    ///
+    /// A non-positive `span` yields no windows. Call
+    /// [`skip_empty`](TumblingWindowIter::skip_empty) on the result to drop empty
+    /// buckets.
+    pub fn tumbling_windows(
+        &self,
+        span: chrono::Duration,
+        align_to: Option<Timestamp>,
+    ) -> TumblingWindowIter<'_, T> {
+        let span_millis = span.num_milliseconds();
+        let align_base = align_to.map(|t| t.to_unix_millis()).unwrap_or(0);
+
+        let current_start = match (self.tree.keys().next(), self.tree.keys().next_back()) {
+            (Some(&first), Some(&_last)) if span_millis > 0 => {
+                let offset = first - align_base;
+                Some(align_base + offset.div_euclid(span_millis) * span_millis)
+            }
+            _ => None,
+        };
+        let last_key = self.tree.keys().next_back().copied();
+
+        TumblingWindowIter {
+            index: self,
+            span_millis,
+            current_start,
+            last_key,
+            skip_empty: false,
+        }
+    }
+
+    /// Fold each tumbling window into an aggregate value.
+    ///
+    /// Walks the same dense grid as [`tumbling_windows`](Self::tumbling_windows)
+    /// and folds every bucket's items into an `A` seeded with `init`, returning
+    /// one aggregate per window (counts, sums, min/max, …) so callers don't
+    /// re-iterate. Empty windows yield `init` unchanged.
+    pub fn fold_windows<A, F>(&self, span: chrono::Duration, init: A, f: F) -> Vec<A>
+    where
+        A: Clone,
+        F: Fn(A, &T) -> A,
+    {
+        self.tumbling_windows(span, None)
+            .map(|(_, items)| items.into_iter().fold(init.clone(), |acc, item| f(acc, item)))
+            .collect()
+    }
+
+    /// Begin a lazy, predicate-filtered temporal query.
+    ///
+    /// Returns a [`TemporalQuery`] builder that chains a time constraint
+    /// ([`range`](TemporalQuery::range)/[`before`](TemporalQuery::before)/[`after`](TemporalQuery::after))
+    /// with one or more [`filter`](TemporalQuery::filter) predicates, an optional
+    /// [`limit`](TemporalQuery::limit) and [`reverse`](TemporalQuery::reverse),
+    /// and drives the underlying B-tree range iterator directly — applying
+    /// predicates on the fly and short-circuiting once `limit` is reached, so
+    /// chained constraints never materialize intermediate vectors.
+    pub fn query(&self) -> TemporalQuery<'_, T> {
+        TemporalQuery {
+            index: self,
+            start_key: i64::MIN,
+            end_key: i64::MAX,
+            predicates: Vec::new(),
+            limit: None,
+            reverse: false,
+        }
+    }
+
     /// Returns the number of indexed items.
     pub fn len(&self) -> usize {
         self.items.len()
@@ -195,6 +369,165 @@ impl<T: Clone> TemporalIndex<T> {
     }
 }
 
+/// A lazy, composable temporal query over a [`TemporalIndex`].
+///
+/// Obtained from [`TemporalIndex::query`]. The time window and predicates are
+/// accumulated by the builder methods; no work happens until
+/// [`iter`](Self::iter) or [`collect`](Self::collect) drives the B-tree range.
+pub struct TemporalQuery<'a, T> {
+    index: &'a TemporalIndex<T>,
+    start_key: i64,
+    end_key: i64,
+    #[allow(clippy::type_complexity)]
+    predicates: Vec<Box<dyn Fn(&T) -> bool + 'a>>,
+    limit: Option<usize>,
+    reverse: bool,
+}
+
+impl<'a, T: Clone> TemporalQuery<'a, T> {
+    /// Constrain results to `range` (inclusive on both ends).
+    pub fn range(mut self, range: TimeRange) -> Self {
+        self.start_key = self.start_key.max(range.start.to_unix_millis());
+        self.end_key = self.end_key.min(range.end.to_unix_millis());
+        self
+    }
+
+    /// Constrain results to items strictly before `ts`.
+    pub fn before(mut self, ts: &Timestamp) -> Self {
+        self.end_key = self.end_key.min(ts.to_unix_millis() - 1);
+        self
+    }
+
+    /// Constrain results to items strictly after `ts`.
+    pub fn after(mut self, ts: &Timestamp) -> Self {
+        self.start_key = self.start_key.max(ts.to_unix_millis() + 1);
+        self
+    }
+
+    /// Keep only items satisfying `predicate`.
+    pub fn filter(mut self, predicate: impl Fn(&T) -> bool + 'a) -> Self {
+        self.predicates.push(Box::new(predicate));
+        self
+    }
+
+    /// Stop after yielding `n` items.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.limit = Some(n);
+        self
+    }
+
+    /// Iterate in reverse chronological order.
+    pub fn reverse(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    /// Lazily iterate the matching items, newest-last (or newest-first if
+    /// [`reverse`](Self::reverse) was set).
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        // An empty key window yields nothing without touching the tree.
+        let range = if self.start_key <= self.end_key {
+            Some(self.index.tree.range(self.start_key..=self.end_key))
+        } else {
+            None
+        };
+
+        let predicates = &self.predicates;
+        let items = &self.index.items;
+        let reverse = self.reverse;
+
+        let forward = range.into_iter().flatten();
+        // Collecting bucket keys is unavoidable to flip direction, but the
+        // per-item work (predicates, limit) still happens lazily downstream.
+        let buckets: Vec<&'a Vec<usize>> = if reverse {
+            let mut keys: Vec<_> = forward.map(|(_, indices)| indices).collect();
+            keys.reverse();
+            keys
+        } else {
+            forward.map(|(_, indices)| indices).collect()
+        };
+
+        buckets
+            .into_iter()
+            .flat_map(move |indices| -> Box<dyn Iterator<Item = &'a T>> {
+                if reverse {
+                    Box::new(indices.iter().rev().map(move |&i| &items[i]))
+                } else {
+                    Box::new(indices.iter().map(move |&i| &items[i]))
+                }
+            })
+            .filter(move |item| predicates.iter().all(|p| p(item)))
+            .take(self.limit.unwrap_or(usize::MAX))
+    }
+
+    /// Collect the matching items into a vector.
+    pub fn collect(&self) -> Vec<&'a T> {
+        self.iter().collect()
+    }
+}
+
+/// Iterator over fixed-interval tumbling windows.
+///
+/// Produced by [`TemporalIndex::tumbling_windows`]. Each item pairs a bucket's
+/// [`TimeRange`] with the items falling inside it; buckets tile the index's span
+/// contiguously on an epoch-aligned grid.
+pub struct TumblingWindowIter<'a, T> {
+    index: &'a TemporalIndex<T>,
+    span_millis: i64,
+    current_start: Option<i64>,
+    last_key: Option<i64>,
+    skip_empty: bool,
+}
+
+impl<'a, T: Clone> TumblingWindowIter<'a, T> {
+    /// Drop empty buckets instead of yielding them.
+    pub fn skip_empty(mut self) -> Self {
+        self.skip_empty = true;
+        self
+    }
+}
+
+impl<'a, T: Clone> Iterator for TumblingWindowIter<'a, T> {
+    type Item = (TimeRange, Vec<&'a T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.current_start?;
+            let last = self.last_key?;
+            if start > last {
+                self.current_start = None;
+                return None;
+            }
+
+            let end = start + self.span_millis;
+            let items: Vec<_> = self
+                .index
+                .tree
+                .range(start..end)
+                .flat_map(|(_, indices)| indices.iter().map(|&i| &self.index.items[i]))
+                .collect();
+
+            self.current_start = Some(end);
+
+            if items.is_empty() && self.skip_empty {
+                continue;
+            }
+
+            // The bucket range is inclusive, so its end is the last millisecond
+            // covered before the next bucket begins.
+            let range = match (
+                Timestamp::from_unix_millis(start),
+                Timestamp::from_unix_millis(end - 1),
+            ) {
+                (Some(s), Some(e)) => TimeRange::new(s, e),
+                _ => continue,
+            };
+
+            return Some((range, items));
+        }
+    }
+}
+
 impl<T: Clone> Default for TemporalIndex<T> {
     fn default() -> Self {
         Self::new()
@@ -235,6 +568,262 @@ impl<'a, T: Clone> Iterator for SlidingWindowIter<'a, T> {
     }
 }
 
+/// Temporal index over interval-valued events ([`TimeRange`]s).
+///
+/// Where [`TemporalIndex`] indexes instantaneous points, real narratives have
+/// durations — a meeting from 10:00–11:00, a trip spanning hours. This index
+/// stores each item's interval and offers coverage, gap and overlap analysis.
+#[derive(Debug)]
+pub struct IntervalIndex<T> {
+    intervals: Vec<TimeRange>,
+    items: Vec<T>,
+}
+
+impl<T: Clone> IntervalIndex<T> {
+    /// Create an empty interval index.
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+            items: Vec::new(),
+        }
+    }
+
+    /// Insert an item spanning the given interval.
+    pub fn insert(&mut self, item: T, interval: &TimeRange) {
+        self.items.push(item);
+        self.intervals.push(interval.clone());
+    }
+
+    /// Returns the merged union of all intervals as a minimal set of ranges.
+    ///
+    /// Implemented by the standard sort-and-sweep: sort by start, then extend a
+    /// running interval whenever the next one starts at or before its end.
+    pub fn coverage(&self) -> Vec<TimeRange> {
+        let mut sorted: Vec<&TimeRange> = self.intervals.iter().collect();
+        sorted.sort_by_key(|r| r.start.to_unix_millis());
+
+        let mut merged: Vec<TimeRange> = Vec::new();
+        for range in sorted {
+            match merged.last_mut() {
+                Some(current) if range.start.to_unix_millis() <= current.end.to_unix_millis() => {
+                    if range.end.to_unix_millis() > current.end.to_unix_millis() {
+                        current.end = range.end.clone();
+                    }
+                }
+                _ => merged.push(range.clone()),
+            }
+        }
+        merged
+    }
+
+    /// Returns the uncovered spans inside `window`.
+    pub fn gaps(&self, window: &TimeRange) -> Vec<TimeRange> {
+        let mut gaps = Vec::new();
+        let mut cursor = window.start.clone();
+
+        for covered in self.coverage() {
+            // Skip intervals entirely before the window.
+            if covered.end.to_unix_millis() < cursor.to_unix_millis() {
+                continue;
+            }
+            // Stop once we pass the window's end.
+            if covered.start.to_unix_millis() > window.end.to_unix_millis() {
+                break;
+            }
+            if covered.start.to_unix_millis() > cursor.to_unix_millis() {
+                let gap_end = covered.start.clone();
+                gaps.push(TimeRange::new(cursor.clone(), gap_end));
+            }
+            if covered.end.to_unix_millis() > cursor.to_unix_millis() {
+                cursor = covered.end.clone();
+            }
+        }
+
+        if cursor.to_unix_millis() < window.end.to_unix_millis() {
+            gaps.push(TimeRange::new(cursor, window.end.clone()));
+        }
+        gaps
+    }
+
+    /// Returns all items whose interval intersects `window`, in insertion order.
+    ///
+    /// Two intervals overlap iff `a.start <= b.end && b.start <= a.end`; touching
+    /// endpoints count as overlapping.
+    pub fn overlapping(&self, window: &TimeRange) -> Vec<&T> {
+        self.intervals
+            .iter()
+            .enumerate()
+            .filter(|(_, interval)| {
+                interval.start.to_unix_millis() <= window.end.to_unix_millis()
+                    && window.start.to_unix_millis() <= interval.end.to_unix_millis()
+            })
+            .map(|(i, _)| &self.items[i])
+            .collect()
+    }
+
+    /// Returns the number of indexed intervals.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if the index is empty.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T: Clone> Default for IntervalIndex<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Identifies a single entry in a [`VersionedTemporalIndex`].
+///
+/// Returned by [`VersionedTemporalIndex::insert`] and accepted by
+/// [`remove`](VersionedTemporalIndex::remove). The `(author, seq)` pair is
+/// globally unique across replicas, which is what makes merges idempotent.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EntryId<A> {
+    /// The contributor that created the entry.
+    pub author: A,
+    /// The author's local, monotonically increasing sequence number.
+    pub seq: u64,
+}
+
+/// An append-only, mergeable temporal index for collaborative narratives.
+///
+/// Several contributors can add events offline and later reconcile their
+/// indexes with [`merge`](Self::merge). Entries are never physically
+/// removed — [`remove`](Self::remove) records a tombstone instead — so merges
+/// are order-independent and idempotent. The B-tree key is the composite
+/// `(millis, author, seq)` (modelled as a millis-keyed tree of `(author, seq)`
+/// maps), so range queries stay O(log n) while every replica iterates entries
+/// in the same `(timestamp, author, seq)` order regardless of merge arrival
+/// order.
+#[derive(Debug)]
+pub struct VersionedTemporalIndex<A, T> {
+    tree: BTreeMap<i64, BTreeMap<(A, u64), T>>,
+    tombstones: BTreeSet<EntryId<A>>,
+    next_seq: BTreeMap<A, u64>,
+}
+
+impl<A: Ord + Clone, T: Clone> VersionedTemporalIndex<A, T> {
+    /// Create an empty versioned index.
+    pub fn new() -> Self {
+        Self {
+            tree: BTreeMap::new(),
+            tombstones: BTreeSet::new(),
+            next_seq: BTreeMap::new(),
+        }
+    }
+
+    /// Insert an item authored by `author` at `ts`, returning its [`EntryId`].
+    pub fn insert(&mut self, author: A, item: T, ts: &Timestamp) -> EntryId<A> {
+        let seq = self.next_seq.entry(author.clone()).or_insert(0);
+        let id = EntryId {
+            author: author.clone(),
+            seq: *seq,
+        };
+        *seq += 1;
+
+        self.tree
+            .entry(ts.to_unix_millis())
+            .or_default()
+            .insert((author, id.seq), item);
+        id
+    }
+
+    /// Tombstone the entry identified by `id`.
+    ///
+    /// The underlying data is retained so the deletion survives and converges
+    /// across merges; queries simply skip tombstoned entries.
+    pub fn remove(&mut self, id: EntryId<A>) {
+        self.tombstones.insert(id);
+    }
+
+    /// Union another index's entries and tombstones into this one.
+    ///
+    /// Entries are keyed by [`EntryId`], so re-merging the same index is a
+    /// no-op, and the result is independent of the order in which replicas are
+    /// merged.
+    pub fn merge(&mut self, other: &Self) {
+        for (&millis, bucket) in &other.tree {
+            let target = self.tree.entry(millis).or_default();
+            for (key, item) in bucket {
+                target
+                    .entry(key.clone())
+                    .or_insert_with(|| item.clone());
+            }
+        }
+        for id in &other.tombstones {
+            self.tombstones.insert(id.clone());
+        }
+        for (author, &seq) in &other.next_seq {
+            let entry = self.next_seq.entry(author.clone()).or_insert(0);
+            *entry = (*entry).max(seq);
+        }
+    }
+
+    /// Query live (non-tombstoned) items within a time range (inclusive).
+    ///
+    /// Results are ordered deterministically by `(timestamp, author, seq)`.
+    pub fn query_range(&self, range: &TimeRange) -> Vec<&T> {
+        let start_key = range.start.to_unix_millis();
+        let end_key = range.end.to_unix_millis();
+
+        self.tree
+            .range(start_key..=end_key)
+            .flat_map(|(_, bucket)| self.live_entries(bucket))
+            .collect()
+    }
+
+    /// Returns all live items in chronological order.
+    pub fn chronological(&self) -> Vec<&T> {
+        self.tree
+            .values()
+            .flat_map(|bucket| self.live_entries(bucket))
+            .collect()
+    }
+
+    /// Returns the number of live (non-tombstoned) entries.
+    pub fn len(&self) -> usize {
+        self.tree
+            .values()
+            .flat_map(|bucket| self.live_entries(bucket))
+            .count()
+    }
+
+    /// Returns true if there are no live entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Yield the live items in a bucket, skipping tombstoned entries.
+    fn live_entries<'a>(
+        &'a self,
+        bucket: &'a BTreeMap<(A, u64), T>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        bucket.iter().filter_map(move |((author, seq), item)| {
+            let id = EntryId {
+                author: author.clone(),
+                seq: *seq,
+            };
+            if self.tombstones.contains(&id) {
+                None
+            } else {
+                Some(item)
+            }
+        })
+    }
+}
+
+impl<A: Ord + Clone, T: Clone> Default for VersionedTemporalIndex<A, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -311,4 +900,320 @@ mod tests {
         let ordered: Vec<_> = index.chronological();
         assert_eq!(ordered, vec![&"A", &"B", &"C"]);
     }
+
+    use crate::core::Freq;
+
+    fn make_range(start: u32, end: u32) -> TimeRange {
+        TimeRange::new(make_timestamp(start), make_timestamp(end))
+    }
+
+    #[test]
+    fn test_versioned_insert_and_tombstone() {
+        let mut index: VersionedTemporalIndex<&str, &str> = VersionedTemporalIndex::new();
+        index.insert("alice", "a9", &make_timestamp(9));
+        let id = index.insert("alice", "a12", &make_timestamp(12));
+        index.insert("bob", "b15", &make_timestamp(15));
+
+        assert_eq!(index.len(), 3);
+        index.remove(id);
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.chronological(), vec![&"a9", &"b15"]);
+    }
+
+    #[test]
+    fn test_versioned_merge_is_idempotent_and_deterministic() {
+        let mut a: VersionedTemporalIndex<&str, &str> = VersionedTemporalIndex::new();
+        a.insert("alice", "shared", &make_timestamp(12));
+        a.insert("alice", "a-only", &make_timestamp(9));
+
+        let mut b: VersionedTemporalIndex<&str, &str> = VersionedTemporalIndex::new();
+        b.insert("bob", "b-same-ts", &make_timestamp(12));
+
+        let mut merged = VersionedTemporalIndex::new();
+        merged.merge(&a);
+        merged.merge(&b);
+        merged.merge(&a); // re-merge: no-op
+
+        // At 12:00 alice sorts before bob by author; 9:00 comes first overall.
+        assert_eq!(
+            merged.chronological(),
+            vec![&"a-only", &"shared", &"b-same-ts"]
+        );
+
+        // Merging in the opposite order converges to the same state.
+        let mut merged_rev = VersionedTemporalIndex::new();
+        merged_rev.merge(&b);
+        merged_rev.merge(&a);
+        assert_eq!(merged_rev.chronological(), merged.chronological());
+    }
+
+    #[test]
+    fn test_versioned_merge_unions_tombstones() {
+        let mut a: VersionedTemporalIndex<&str, &str> = VersionedTemporalIndex::new();
+        let id = a.insert("alice", "gone", &make_timestamp(9));
+        a.insert("alice", "kept", &make_timestamp(10));
+
+        let mut b = VersionedTemporalIndex::new();
+        b.merge(&a);
+        b.remove(id);
+
+        // Merging b's tombstone back into a removes the entry from a's view too.
+        a.merge(&b);
+        assert_eq!(a.chronological(), vec![&"kept"]);
+    }
+
+    #[test]
+    fn test_query_builder_range_and_filter() {
+        let mut index = TemporalIndex::new();
+        index.insert("keep-a", &make_timestamp(9));
+        index.insert("drop", &make_timestamp(12));
+        index.insert("keep-b", &make_timestamp(15));
+        index.insert("late", &make_timestamp(20));
+
+        let results = index
+            .query()
+            .range(TimeRange::new(make_timestamp(9), make_timestamp(15)))
+            .filter(|s: &&str| s.starts_with("keep"))
+            .collect();
+
+        assert_eq!(results, vec![&"keep-a", &"keep-b"]);
+    }
+
+    #[test]
+    fn test_query_builder_limit_and_reverse() {
+        let mut index = TemporalIndex::new();
+        index.insert("a", &make_timestamp(9));
+        index.insert("b", &make_timestamp(12));
+        index.insert("c", &make_timestamp(15));
+
+        let newest_two = index.query().reverse().limit(2).collect();
+        assert_eq!(newest_two, vec![&"c", &"b"]);
+
+        let after = index.query().after(&make_timestamp(9)).collect();
+        assert_eq!(after, vec![&"b", &"c"]);
+    }
+
+    #[test]
+    fn test_as_of_and_next_after() {
+        let mut index = TemporalIndex::new();
+        index.insert("9am", &make_timestamp(9));
+        index.insert("12pm", &make_timestamp(12));
+        index.insert("3pm", &make_timestamp(15));
+
+        assert_eq!(index.as_of(&make_timestamp(13)), Some(&"12pm"));
+        assert_eq!(index.as_of(&make_timestamp(12)), Some(&"12pm"));
+        assert_eq!(index.as_of(&make_timestamp(8)), None);
+
+        assert_eq!(index.next_after(&make_timestamp(12)), Some(&"3pm"));
+        assert_eq!(index.next_after(&make_timestamp(15)), None);
+    }
+
+    #[test]
+    fn test_as_of_last_in_bucket_wins() {
+        let mut index = TemporalIndex::new();
+        index.insert("first", &make_timestamp(9));
+        index.insert("second", &make_timestamp(9));
+        assert_eq!(index.as_of(&make_timestamp(9)), Some(&"second"));
+        assert_eq!(index.next_after(&make_timestamp(8)), Some(&"first"));
+    }
+
+    #[test]
+    fn test_nearest_breaks_ties_earlier() {
+        let mut index = TemporalIndex::new();
+        index.insert("10am", &make_timestamp(10));
+        index.insert("12pm", &make_timestamp(12));
+
+        // 11:00 is equidistant — the earlier key wins.
+        assert_eq!(index.nearest(&make_timestamp(11)), Some(&"10am"));
+        assert_eq!(index.nearest(&make_timestamp(13)), Some(&"12pm"));
+    }
+
+    #[test]
+    fn test_tumbling_windows_dense_axis() {
+        let mut index = TemporalIndex::new();
+        index.insert("a", &make_timestamp(9));
+        index.insert("b", &make_timestamp(9));
+        index.insert("c", &make_timestamp(12));
+
+        let windows: Vec<_> = index
+            .tumbling_windows(chrono::Duration::hours(1), None)
+            .collect();
+
+        // 09:00 through 12:00 inclusive => four one-hour buckets, two empty.
+        assert_eq!(windows.len(), 4);
+        assert_eq!(windows[0].1.len(), 2); // 09:00
+        assert_eq!(windows[1].1.len(), 0); // 10:00
+        assert_eq!(windows[2].1.len(), 0); // 11:00
+        assert_eq!(windows[3].1.len(), 1); // 12:00
+    }
+
+    #[test]
+    fn test_tumbling_windows_skip_empty() {
+        let mut index = TemporalIndex::new();
+        index.insert("a", &make_timestamp(9));
+        index.insert("c", &make_timestamp(12));
+
+        let count = index
+            .tumbling_windows(chrono::Duration::hours(1), None)
+            .skip_empty()
+            .count();
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_tumbling_windows_reject_zero_span() {
+        let mut index = TemporalIndex::new();
+        index.insert("a", &make_timestamp(9));
+        assert_eq!(
+            index
+                .tumbling_windows(chrono::Duration::zero(), None)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_fold_windows_counts() {
+        let mut index = TemporalIndex::new();
+        index.insert("a", &make_timestamp(9));
+        index.insert("b", &make_timestamp(9));
+        index.insert("c", &make_timestamp(12));
+
+        let counts = index.fold_windows(chrono::Duration::hours(1), 0usize, |acc, _| acc + 1);
+        assert_eq!(counts, vec![2, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_recurring_daily_count() {
+        let mut index = TemporalIndex::new();
+        let start = Timestamp::parse("2024-01-01T09:00:00Z").unwrap();
+        let bound = TimeRange::new(
+            Timestamp::parse("2024-01-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-12-31T23:59:59Z").unwrap(),
+        );
+        index.insert_recurring(
+            "standup",
+            &start,
+            Recurrence::new(Freq::Daily, 1).count(3),
+            &bound,
+        );
+
+        assert_eq!(index.len(), 3);
+        let ordered = index.chronological();
+        assert_eq!(ordered, vec![&"standup", &"standup", &"standup"]);
+    }
+
+    #[test]
+    fn test_recurring_weekly_by_weekday_until() {
+        let mut index = TemporalIndex::new();
+        let start = Timestamp::parse("2024-01-02T14:00:00Z").unwrap(); // a Tuesday
+        let bound = TimeRange::new(
+            Timestamp::parse("2024-01-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-02-01T00:00:00Z").unwrap(),
+        );
+        let until = Timestamp::parse("2024-01-23T14:00:00Z").unwrap();
+        let rule = Recurrence::new(Freq::Weekly, 1)
+            .by_weekday([chrono::Weekday::Tue])
+            .until(until.clone());
+        index.insert_recurring("standup", &start, rule, &bound);
+
+        // Tuesdays: Jan 2, 9, 16, 23 — the 23rd is included (inclusive until).
+        assert_eq!(index.len(), 4);
+        assert_eq!(
+            index
+                .query_range(&TimeRange::new(until.clone(), until))
+                .len(),
+            1
+        );
+        assert!(index
+            .query_range(&TimeRange::new(
+                Timestamp::parse("2024-01-24T00:00:00Z").unwrap(),
+                Timestamp::parse("2024-02-01T00:00:00Z").unwrap(),
+            ))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_recurring_monthly_skips_invalid_days() {
+        let mut index = TemporalIndex::new();
+        let start = Timestamp::parse("2024-01-31T08:00:00Z").unwrap();
+        let bound = TimeRange::new(
+            Timestamp::parse("2024-01-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-05-01T00:00:00Z").unwrap(),
+        );
+        let rule = Recurrence::new(Freq::Monthly, 1).by_monthday([31]);
+        index.insert_recurring("rent", &start, rule, &bound);
+
+        // Only Jan 31 and Mar 31 exist in the window; Feb/Apr are skipped, not clamped.
+        assert_eq!(index.len(), 2);
+        let jan = TimeRange::new(
+            Timestamp::parse("2024-01-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-01-31T23:59:59Z").unwrap(),
+        );
+        let feb = TimeRange::new(
+            Timestamp::parse("2024-02-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-02-29T23:59:59Z").unwrap(),
+        );
+        let mar = TimeRange::new(
+            Timestamp::parse("2024-03-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-03-31T23:59:59Z").unwrap(),
+        );
+        assert_eq!(index.query_range(&jan).len(), 1);
+        assert_eq!(index.query_range(&feb).len(), 0);
+        assert_eq!(index.query_range(&mar).len(), 1);
+    }
+
+    #[test]
+    fn test_recurring_bounded_without_terminators() {
+        let mut index = TemporalIndex::new();
+        let start = Timestamp::parse("2024-01-01T00:00:00Z").unwrap();
+        let bound = TimeRange::new(
+            Timestamp::parse("2024-01-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-01-05T00:00:00Z").unwrap(),
+        );
+        // No count or until: expansion is still finite because of bound.end.
+        index.insert_recurring("ping", &start, Recurrence::new(Freq::Daily, 1), &bound);
+        assert_eq!(index.len(), 5);
+    }
+
+    #[test]
+    fn test_interval_index_coverage_merges_overlaps() {
+        let mut index = IntervalIndex::new();
+        index.insert("a", &make_range(9, 11));
+        index.insert("b", &make_range(10, 12)); // overlaps a
+        index.insert("c", &make_range(14, 16)); // disjoint
+
+        let coverage = index.coverage();
+        assert_eq!(coverage.len(), 2);
+        assert_eq!(coverage[0].start.to_unix_millis(), make_timestamp(9).to_unix_millis());
+        assert_eq!(coverage[0].end.to_unix_millis(), make_timestamp(12).to_unix_millis());
+        assert_eq!(coverage[1].start.to_unix_millis(), make_timestamp(14).to_unix_millis());
+    }
+
+    #[test]
+    fn test_interval_index_gaps() {
+        let mut index = IntervalIndex::new();
+        index.insert("a", &make_range(9, 11));
+        index.insert("b", &make_range(14, 16));
+
+        let gaps = index.gaps(&make_range(8, 18));
+        assert_eq!(gaps.len(), 3);
+        assert_eq!(gaps[0].start.to_unix_millis(), make_timestamp(8).to_unix_millis());
+        assert_eq!(gaps[0].end.to_unix_millis(), make_timestamp(9).to_unix_millis());
+        assert_eq!(gaps[1].start.to_unix_millis(), make_timestamp(11).to_unix_millis());
+        assert_eq!(gaps[1].end.to_unix_millis(), make_timestamp(14).to_unix_millis());
+        assert_eq!(gaps[2].end.to_unix_millis(), make_timestamp(18).to_unix_millis());
+    }
+
+    #[test]
+    fn test_interval_index_overlapping_preserves_order() {
+        let mut index = IntervalIndex::new();
+        index.insert("a", &make_range(9, 11));
+        index.insert("b", &make_range(13, 15));
+        index.insert("c", &make_range(10, 14));
+
+        // Touching endpoints count as overlapping.
+        let hits = index.overlapping(&make_range(11, 13));
+        assert_eq!(hits, vec![&"a", &"b", &"c"]);
+    }
 }
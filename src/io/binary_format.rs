@@ -0,0 +1,447 @@
+//! Compact delta-encoded binary trajectory format.
+//!
+//! Long delivery/vehicle trajectories are thousands of near-collinear GPS
+//! pings; stored as JSON/CSV that redundancy is enormous, since every point
+//! repeats nearly the same latitude, longitude, and a steadily-incrementing
+//! timestamp. This format instead stores a single base point and then, per
+//! subsequent event, the *difference* from the previous point — values that
+//! hover near zero for smooth motion and so encode in very few bytes.
+//!
+//! Each latitude/longitude is scaled to a fixed-point integer (`value * 1e7`,
+//! about 1cm of precision) before differencing, and timestamps are
+//! differenced in whole seconds. All deltas (and the base point itself) are
+//! written as zig-zag + LEB128 varints, so small deltas cost one byte each.
+//! With [`BinaryOptions::second_order`] set, deltas are differenced again —
+//! the difference of differences hovers at zero for constant-velocity motion,
+//! shrinking runs of evenly-spaced pings even further.
+//!
+//! Gated behind the optional `gzip` feature, [`BinaryOptions::gzip`] wraps the
+//! encoded payload in general-purpose compression on top of the
+//! domain-specific differencing, mirroring how GNSS observation formats
+//! combine both techniques.
+
+use super::format::Format;
+use crate::core::{Event, EventBuilder, Location, Narrative, NarrativeBuilder, Timestamp};
+use crate::{Error, Result};
+use chrono::{TimeZone, Utc};
+use std::io::{Read, Write};
+
+const MAGIC: &[u8; 4] = b"SNBT";
+const VERSION: u8 = 1;
+const LAT_LON_SCALE: f64 = 1e7;
+
+/// Compact delta-encoded binary format handler.
+///
+/// # Example
+///
+/// ```rust
+/// use spatial_narrative::io::{BinaryFormat, Format};
+/// use spatial_narrative::prelude::*;
+///
+/// let narrative = Narrative::builder()
+///     .event(Event::builder()
+///         .location(Location::new(40.7128, -74.006))
+///         .timestamp(Timestamp::now())
+///         .text("start")
+///         .build())
+///     .build();
+///
+/// let format = BinaryFormat::new();
+/// let mut bytes = Vec::new();
+/// format.export(&narrative, &mut bytes).unwrap();
+/// let restored = format.import(bytes.as_slice()).unwrap();
+/// assert_eq!(restored.events().len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BinaryFormat {
+    /// Options controlling the encoding.
+    pub options: BinaryOptions,
+}
+
+/// Configuration options for [`BinaryFormat`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BinaryOptions {
+    /// Difference consecutive deltas rather than points, for near-constant
+    /// motion where first-order deltas still vary smoothly.
+    pub second_order: bool,
+    /// Wrap the encoded payload in gzip compression.
+    #[cfg(feature = "gzip")]
+    pub gzip: bool,
+}
+
+impl BinaryFormat {
+    /// Create a new binary format handler with default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a binary format handler with custom options.
+    pub fn with_options(options: BinaryOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl Format for BinaryFormat {
+    fn import<R: Read>(&self, mut reader: R) -> Result<Narrative> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        #[cfg(feature = "gzip")]
+        let bytes = if self.options.gzip {
+            decompress_gzip(&bytes)?
+        } else {
+            bytes
+        };
+
+        let events = decode(&bytes)?;
+        let mut builder = NarrativeBuilder::new();
+        for event in events {
+            builder = builder.event(event);
+        }
+        Ok(builder.build())
+    }
+
+    fn export<W: Write>(&self, narrative: &Narrative, mut writer: W) -> Result<()> {
+        let bytes = encode(&narrative.events_chronological(), self.options.second_order);
+
+        #[cfg(feature = "gzip")]
+        let bytes = if self.options.gzip {
+            compress_gzip(&bytes)?
+        } else {
+            bytes
+        };
+
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+}
+
+fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buf.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+fn write_zigzag(buf: &mut Vec<u8>, value: i64) {
+    write_varint(buf, zigzag_encode(value));
+}
+
+fn write_text(buf: &mut Vec<u8>, text: &str) {
+    write_varint(buf, text.len() as u64);
+    buf.extend_from_slice(text.as_bytes());
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn read_varint(&mut self) -> Result<u64> {
+        let mut result = 0u64;
+        let mut shift = 0u32;
+        loop {
+            let byte = *self
+                .bytes
+                .get(self.pos)
+                .ok_or_else(|| Error::InvalidFormat("truncated varint".to_string()))?;
+            self.pos += 1;
+            result |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        Ok(result)
+    }
+
+    fn read_zigzag(&mut self) -> Result<i64> {
+        self.read_varint().map(zigzag_decode)
+    }
+
+    fn read_text(&mut self) -> Result<String> {
+        let len = self.read_varint()? as usize;
+        let end = self.pos + len;
+        let slice = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or_else(|| Error::InvalidFormat("truncated text".to_string()))?;
+        self.pos = end;
+        String::from_utf8(slice.to_vec())
+            .map_err(|e| Error::InvalidFormat(format!("invalid UTF-8 text: {e}")))
+    }
+
+    fn read_byte(&mut self) -> Result<u8> {
+        let byte = *self
+            .bytes
+            .get(self.pos)
+            .ok_or_else(|| Error::InvalidFormat("truncated header".to_string()))?;
+        self.pos += 1;
+        Ok(byte)
+    }
+}
+
+fn encode(events: &[&Event], second_order: bool) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(MAGIC);
+    buf.push(VERSION);
+    buf.push(if second_order { 1 } else { 0 });
+    write_varint(&mut buf, events.len() as u64);
+
+    let Some(first) = events.first() else {
+        return buf;
+    };
+
+    let to_fixed = |v: f64| (v * LAT_LON_SCALE).round() as i64;
+
+    let mut prev_lat = to_fixed(first.location.lat);
+    let mut prev_lon = to_fixed(first.location.lon);
+    let mut prev_time = first.timestamp.datetime.timestamp();
+    let mut prev_delta_lat = 0i64;
+    let mut prev_delta_lon = 0i64;
+
+    write_zigzag(&mut buf, prev_lat);
+    write_zigzag(&mut buf, prev_lon);
+    write_zigzag(&mut buf, prev_time);
+    write_text(&mut buf, &first.text);
+
+    for (i, event) in events.iter().enumerate().skip(1) {
+        let lat = to_fixed(event.location.lat);
+        let lon = to_fixed(event.location.lon);
+        let time = event.timestamp.datetime.timestamp();
+
+        let delta_lat = lat - prev_lat;
+        let delta_lon = lon - prev_lon;
+        let delta_time = time - prev_time;
+
+        if second_order && i > 1 {
+            write_zigzag(&mut buf, delta_lat - prev_delta_lat);
+            write_zigzag(&mut buf, delta_lon - prev_delta_lon);
+        } else {
+            write_zigzag(&mut buf, delta_lat);
+            write_zigzag(&mut buf, delta_lon);
+        }
+        write_zigzag(&mut buf, delta_time);
+        write_text(&mut buf, &event.text);
+
+        prev_delta_lat = delta_lat;
+        prev_delta_lon = delta_lon;
+        prev_lat = lat;
+        prev_lon = lon;
+        prev_time = time;
+    }
+
+    buf
+}
+
+fn decode(bytes: &[u8]) -> Result<Vec<Event>> {
+    let mut cursor = Cursor::new(bytes);
+
+    if bytes.len() < MAGIC.len() || &bytes[..MAGIC.len()] != MAGIC {
+        return Err(Error::InvalidFormat("not a spatial-narrative binary trajectory".to_string()));
+    }
+    cursor.pos = MAGIC.len();
+
+    let version = cursor.read_byte()?;
+    if version != VERSION {
+        return Err(Error::InvalidFormat(format!("unsupported binary format version {version}")));
+    }
+    let second_order = cursor.read_byte()? != 0;
+    let count = cursor.read_varint()? as usize;
+
+    let mut events = Vec::with_capacity(count);
+    if count == 0 {
+        return Ok(events);
+    }
+
+    let from_fixed = |v: i64| v as f64 / LAT_LON_SCALE;
+
+    let mut lat = cursor.read_zigzag()?;
+    let mut lon = cursor.read_zigzag()?;
+    let mut time = cursor.read_zigzag()?;
+    let mut text = cursor.read_text()?;
+
+    events.push(event_from_fixed(from_fixed(lat), from_fixed(lon), time, text)?);
+
+    let mut prev_delta_lat = 0i64;
+    let mut prev_delta_lon = 0i64;
+
+    for i in 1..count {
+        let raw_lat = cursor.read_zigzag()?;
+        let raw_lon = cursor.read_zigzag()?;
+        let delta_time = cursor.read_zigzag()?;
+        text = cursor.read_text()?;
+
+        let (delta_lat, delta_lon) = if second_order && i > 1 {
+            (raw_lat + prev_delta_lat, raw_lon + prev_delta_lon)
+        } else {
+            (raw_lat, raw_lon)
+        };
+
+        lat += delta_lat;
+        lon += delta_lon;
+        time += delta_time;
+
+        events.push(event_from_fixed(from_fixed(lat), from_fixed(lon), time, text)?);
+
+        prev_delta_lat = delta_lat;
+        prev_delta_lon = delta_lon;
+    }
+
+    Ok(events)
+}
+
+fn event_from_fixed(lat: f64, lon: f64, unix_secs: i64, text: String) -> Result<Event> {
+    let datetime = Utc
+        .timestamp_opt(unix_secs, 0)
+        .single()
+        .ok_or_else(|| Error::InvalidFormat(format!("invalid timestamp {unix_secs}")))?;
+
+    Ok(EventBuilder::new()
+        .location(Location::new(lat, lon))
+        .timestamp(Timestamp::new(datetime))
+        .text(text)
+        .build())
+}
+
+#[cfg(feature = "gzip")]
+fn compress_gzip(bytes: &[u8]) -> Result<Vec<u8>> {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(bytes)
+        .map_err(|e| Error::InvalidFormat(format!("gzip compression failed: {e}")))?;
+    encoder
+        .finish()
+        .map_err(|e| Error::InvalidFormat(format!("gzip compression failed: {e}")))
+}
+
+#[cfg(feature = "gzip")]
+fn decompress_gzip(bytes: &[u8]) -> Result<Vec<u8>> {
+    use flate2::read::GzDecoder;
+
+    let mut decoder = GzDecoder::new(bytes);
+    let mut out = Vec::new();
+    decoder
+        .read_to_end(&mut out)
+        .map_err(|e| Error::InvalidFormat(format!("gzip decompression failed: {e}")))?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Location, Timestamp};
+
+    fn sample_narrative() -> Narrative {
+        Narrative::builder()
+            .event(
+                Event::builder()
+                    .location(Location::new(40.7128, -74.0060))
+                    .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+                    .text("start")
+                    .build(),
+            )
+            .event(
+                Event::builder()
+                    .location(Location::new(40.71281, -74.00601))
+                    .timestamp(Timestamp::parse("2024-01-15T14:30:10Z").unwrap())
+                    .text("moving")
+                    .build(),
+            )
+            .event(
+                Event::builder()
+                    .location(Location::new(40.71282, -74.00602))
+                    .timestamp(Timestamp::parse("2024-01-15T14:30:20Z").unwrap())
+                    .text("end")
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_binary_round_trip_first_order() {
+        let narrative = sample_narrative();
+        let format = BinaryFormat::new();
+
+        let mut bytes = Vec::new();
+        format.export(&narrative, &mut bytes).unwrap();
+        let restored = format.import(bytes.as_slice()).unwrap();
+
+        assert_eq!(restored.events().len(), 3);
+        assert!((restored.events()[1].location.lat - 40.71281).abs() < 1e-6);
+        assert_eq!(restored.events()[0].text, "start");
+        assert_eq!(restored.events()[2].text, "end");
+    }
+
+    #[test]
+    fn test_binary_round_trip_second_order() {
+        let narrative = sample_narrative();
+        let format = BinaryFormat::with_options(BinaryOptions {
+            second_order: true,
+            ..BinaryOptions::default()
+        });
+
+        let mut bytes = Vec::new();
+        format.export(&narrative, &mut bytes).unwrap();
+        let restored = format.import(bytes.as_slice()).unwrap();
+
+        assert_eq!(restored.events().len(), 3);
+        assert!((restored.events()[2].location.lon + 74.00602).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_binary_is_far_smaller_than_json_for_collinear_points() {
+        use super::super::json_format::JsonFormat;
+
+        let mut events = Vec::new();
+        for i in 0..200 {
+            events.push(
+                Event::builder()
+                    .location(Location::new(40.0 + i as f64 * 1e-5, -74.0 + i as f64 * 1e-5))
+                    .timestamp(Timestamp::from_unix(1_700_000_000 + i * 5).unwrap())
+                    .text("")
+                    .build(),
+            );
+        }
+        let mut narrative = Narrative::new("route");
+        for event in events {
+            narrative.add_event(event);
+        }
+
+        let mut binary = Vec::new();
+        BinaryFormat::new().export(&narrative, &mut binary).unwrap();
+        let json = JsonFormat::new().export_str(&narrative).unwrap();
+
+        assert!(binary.len() < json.len() / 4);
+    }
+
+    #[test]
+    fn test_binary_rejects_bad_magic() {
+        let format = BinaryFormat::new();
+        let err = format.import(&b"nope"[..]).unwrap_err();
+        assert!(matches!(err, Error::InvalidFormat(_)));
+    }
+}
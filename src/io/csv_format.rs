@@ -1,11 +1,12 @@
 //! CSV format import/export.
 
-use super::format::Format;
+use super::format::{EventStream, Format};
 use crate::core::{
-    EventBuilder, Location, Narrative, NarrativeBuilder, SourceRef, SourceType, Timestamp,
+    Event, EventBuilder, Location, Narrative, NarrativeBuilder, SourceRef, SourceType, Timestamp,
 };
 use crate::{Error, Result};
-use csv::StringRecord;
+use chrono::{Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use csv::{ByteRecord, StringRecord};
 use std::io::{Read, Write};
 
 /// CSV format handler.
@@ -65,6 +66,176 @@ pub struct CsvOptions {
 
     /// CSV delimiter character
     pub delimiter: u8,
+
+    /// Accept relative and natural-language timestamps in addition to RFC3339.
+    ///
+    /// When enabled, each timestamp cell is resolved through a chain of
+    /// parsers (strict RFC3339 first, then a minute offset like `+90`/`in 90`,
+    /// then a plain date, then natural expressions such as `yesterday 14:00`),
+    /// evaluated against [`timestamp_reference`](Self::timestamp_reference).
+    pub flexible_timestamps: bool,
+
+    /// Reference point for resolving relative timestamps (defaults to now).
+    pub timestamp_reference: Option<Timestamp>,
+
+    /// Timestamp encodings to try, in order, when decoding a row's timestamp
+    /// cell. The first format that parses the cell wins. Defaults to
+    /// `[TimestampFormat::Rfc3339]`.
+    ///
+    /// Ignored when [`flexible_timestamps`](Self::flexible_timestamps) is
+    /// enabled, which has its own, separate parser chain.
+    pub timestamp_formats: Vec<TimestampFormat>,
+
+    /// Timezone a [`TimestampFormat::Strftime`] reading is assumed to be in
+    /// when its pattern carries no UTC offset, so naive wall-clock values
+    /// resolve to the correct instant. Has no effect on `Rfc3339` or epoch
+    /// formats, which are already unambiguous.
+    pub default_timezone: Option<chrono_tz::Tz>,
+
+    /// Timestamp encoding used when exporting (see [`TimestampFormat`]).
+    /// Defaults to `Rfc3339`.
+    pub export_timestamp_format: TimestampFormat,
+
+    /// How [`CsvFormat::import_with_report`] handles a malformed row.
+    ///
+    /// Plain [`import`](super::Format::import)/[`import_iter`](super::Format::import_iter)
+    /// are unaffected by this and always abort on the first malformed row;
+    /// use `import_with_report` to opt into [`OnError::SkipRow`] or
+    /// [`OnError::Collect`].
+    pub on_error: OnError,
+
+    /// Which column layout to export (see [`CsvProfile`]). Defaults to `Full`.
+    pub profile: CsvProfile,
+
+    /// Field-quoting policy for export (see [`QuoteStyle`]). Defaults to
+    /// `Necessary`. Forced to `Always` when `profile` is
+    /// [`CsvProfile::QuotedCompat`], regardless of this setting.
+    pub quote_style: QuoteStyle,
+
+    /// Whitespace trimming applied while reading (see [`CsvTrim`]). Defaults
+    /// to `None`, matching the `csv` crate's own default.
+    pub trim: CsvTrim,
+}
+
+/// Whitespace trimming applied by the underlying CSV reader, wired to
+/// `csv::Trim`. Lets hand-edited files with stray spaces around cells
+/// import cleanly without a per-field `.trim()` call in every caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CsvTrim {
+    /// Trim nothing.
+    #[default]
+    None,
+    /// Trim header field names only.
+    Headers,
+    /// Trim data field values only.
+    Fields,
+    /// Trim both headers and fields.
+    All,
+}
+
+impl CsvTrim {
+    fn to_csv(self) -> csv::Trim {
+        match self {
+            CsvTrim::None => csv::Trim::None,
+            CsvTrim::Headers => csv::Trim::Headers,
+            CsvTrim::Fields => csv::Trim::Fields,
+            CsvTrim::All => csv::Trim::All,
+        }
+    }
+}
+
+/// Export column layout for [`CsvFormat`], analogous to [`OutputFormat`](super::OutputFormat)
+/// for choosing a whole-format target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CsvProfile {
+    /// Only lat/lon/timestamp.
+    Minimal,
+    /// Every configured column (elevation/text/tags/source/…).
+    #[default]
+    Full,
+    /// Like `Full`, but every field is quoted, for strict spreadsheet or
+    /// validator consumers.
+    QuotedCompat,
+    /// `Full` plus derived columns: cumulative haversine distance travelled
+    /// since the first event (`distance_m`), elapsed seconds since the previous
+    /// event (`elapsed_s`), and a sequential event index (`index`).
+    Extended,
+}
+
+/// Field-quoting policy for CSV export, mirroring `csv::QuoteStyle`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuoteStyle {
+    /// Quote every field.
+    Always,
+    /// Quote only fields that need it to round-trip (the `csv` crate's own
+    /// default).
+    #[default]
+    Necessary,
+    /// Never quote, even if a field contains the delimiter.
+    Never,
+}
+
+impl QuoteStyle {
+    fn to_csv(self) -> csv::QuoteStyle {
+        match self {
+            QuoteStyle::Always => csv::QuoteStyle::Always,
+            QuoteStyle::Necessary => csv::QuoteStyle::Necessary,
+            QuoteStyle::Never => csv::QuoteStyle::Never,
+        }
+    }
+}
+
+/// A timestamp encoding a CSV timestamp cell may be read from, or rendered
+/// into on export. See [`CsvOptions::timestamp_formats`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimestampFormat {
+    /// RFC 3339, e.g. `2024-01-15T14:30:00Z`.
+    Rfc3339,
+    /// Unix epoch, whole seconds.
+    EpochSeconds,
+    /// Unix epoch, milliseconds.
+    EpochMillis,
+    /// A `chrono` strftime pattern, e.g. `"%Y-%m-%d %H:%M:%S"`.
+    Strftime(String),
+}
+
+/// Policy for handling a malformed row during [`CsvFormat::import_with_report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnError {
+    /// Abort on the first malformed row.
+    #[default]
+    Strict,
+    /// Silently drop malformed rows, keeping every other valid event.
+    SkipRow,
+    /// Keep every valid event and record a [`RowError`] for each dropped row.
+    Collect,
+}
+
+/// A single malformed row skipped during a lenient
+/// [`CsvFormat::import_with_report`] import.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowError {
+    /// Zero-based row index (not counting the header row).
+    pub row: usize,
+    /// Offending column, when the failure can be attributed to one.
+    pub column: Option<String>,
+    /// Human-readable description of what went wrong.
+    pub message: String,
+}
+
+/// Outcome of a lenient [`CsvFormat::import_with_report`] import: every row
+/// that failed to decode, in encounter order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportReport {
+    /// Rows skipped during import.
+    pub errors: Vec<RowError>,
+}
+
+impl ImportReport {
+    /// True if every row decoded successfully.
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty()
+    }
 }
 
 impl Default for CsvOptions {
@@ -80,6 +251,15 @@ impl Default for CsvOptions {
             source_type_column: Some("source_type".to_string()),
             include_headers: true,
             delimiter: b',',
+            flexible_timestamps: false,
+            timestamp_reference: None,
+            timestamp_formats: vec![TimestampFormat::Rfc3339],
+            default_timezone: None,
+            export_timestamp_format: TimestampFormat::Rfc3339,
+            on_error: OnError::Strict,
+            profile: CsvProfile::Full,
+            quote_style: QuoteStyle::Necessary,
+            trim: CsvTrim::None,
         }
     }
 }
@@ -103,128 +283,137 @@ impl CsvFormat {
         Self { options }
     }
 
-    /// Helper to find column index by name
-    fn find_column(&self, headers: &StringRecord, name: &str) -> Option<usize> {
-        headers.iter().position(|h| h.eq_ignore_ascii_case(name))
-    }
+    /// Import a narrative, honoring [`CsvOptions::on_error`] instead of
+    /// always aborting on the first malformed row.
+    ///
+    /// Under [`OnError::Strict`] this behaves exactly like
+    /// [`Format::import`](super::Format::import). Under [`OnError::SkipRow`]
+    /// malformed rows are silently dropped. Under [`OnError::Collect`]
+    /// malformed rows are dropped and recorded in the returned
+    /// [`ImportReport`], so callers cleaning up messy field-collected data can
+    /// triage every bad row in one pass instead of fixing one row per run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header is malformed, or, under
+    /// [`OnError::Strict`], on the first malformed row.
+    pub fn import_with_report<R: Read>(&self, reader: R) -> Result<(Narrative, ImportReport)> {
+        let mut csv_reader = csv::ReaderBuilder::new()
+            .delimiter(self.options.delimiter)
+            .trim(self.options.trim.to_csv())
+            .from_reader(reader);
+        let headers = csv_reader.headers()?.clone();
+        let columns = self.resolve_columns(&headers)?;
 
-    /// Helper to get optional string value from record
-    fn get_optional(&self, record: &StringRecord, index: Option<usize>) -> Option<String> {
-        index.and_then(|i| record.get(i).filter(|s| !s.is_empty()).map(String::from))
+        let mut builder = NarrativeBuilder::new();
+        let mut report = ImportReport::default();
+        let mut record = StringRecord::new();
+        let mut row_num = 0;
+
+        while csv_reader.read_record(&mut record)? {
+            match self.decode_record(&record, &columns, row_num) {
+                Ok(event) => builder = builder.event(event),
+                Err(row_error) => match self.options.on_error {
+                    OnError::Strict => return Err(Error::InvalidFormat(row_error.message)),
+                    OnError::SkipRow => {}
+                    OnError::Collect => report.errors.push(row_error),
+                },
+            }
+            row_num += 1;
+        }
+
+        Ok((builder.build(), report))
     }
-}
 
-impl Format for CsvFormat {
-    fn import<R: Read>(&self, reader: R) -> Result<Narrative> {
+    /// High-throughput import variant for multi-million-row tracks.
+    ///
+    /// Reads rows as raw [`csv::ByteRecord`]s instead of UTF-8-validated
+    /// [`StringRecord`]s: `lat`/`lon`/`timestamp` are parsed straight from
+    /// the byte slices, and a `String` is only allocated for optional
+    /// fields (elevation/text/tags/source) that are actually present in the
+    /// row. [`Format::import`](super::Format::import) pays UTF-8 validation
+    /// and an allocation for every configured column on every row
+    /// regardless of whether it ends up used; this path only pays for what
+    /// a given row actually has.
+    ///
+    /// Otherwise behaves identically to `import` — same column resolution,
+    /// same abort-on-first-malformed-row behavior (there is no `OnError`
+    /// equivalent here; use [`import_with_report`](Self::import_with_report)
+    /// for lenient imports). Meant as a drop-in once profiling shows CSV
+    /// import dominating the time for a very large track.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header is malformed or a row is invalid.
+    pub fn import_fast<R: Read>(&self, reader: R) -> Result<Narrative> {
         let mut csv_reader = csv::ReaderBuilder::new()
             .delimiter(self.options.delimiter)
+            .trim(self.options.trim.to_csv())
             .from_reader(reader);
-
         let headers = csv_reader.headers()?.clone();
-
-        // Find required columns
-        let lat_idx = self
-            .find_column(&headers, &self.options.lat_column)
-            .ok_or_else(|| {
-                Error::InvalidFormat(format!(
-                    "missing required column: {}",
-                    self.options.lat_column
-                ))
-            })?;
-
-        let lon_idx = self
-            .find_column(&headers, &self.options.lon_column)
-            .ok_or_else(|| {
-                Error::InvalidFormat(format!(
-                    "missing required column: {}",
-                    self.options.lon_column
-                ))
-            })?;
-
-        let ts_idx = self
-            .find_column(&headers, &self.options.timestamp_column)
-            .ok_or_else(|| {
-                Error::InvalidFormat(format!(
-                    "missing required column: {}",
-                    self.options.timestamp_column
-                ))
-            })?;
-
-        // Find optional columns
-        let elev_idx = self
-            .options
-            .elevation_column
-            .as_ref()
-            .and_then(|col| self.find_column(&headers, col));
-
-        let text_idx = self
-            .options
-            .text_column
-            .as_ref()
-            .and_then(|col| self.find_column(&headers, col));
-
-        let tags_idx = self
-            .options
-            .tags_column
-            .as_ref()
-            .and_then(|col| self.find_column(&headers, col));
-
-        let source_name_idx = self
-            .options
-            .source_name_column
-            .as_ref()
-            .and_then(|col| self.find_column(&headers, col));
-
-        let source_type_idx = self
-            .options
-            .source_type_column
-            .as_ref()
-            .and_then(|col| self.find_column(&headers, col));
+        let columns = self.resolve_columns(&headers)?;
 
         let mut builder = NarrativeBuilder::new();
+        let mut record = ByteRecord::new();
+        let mut row_num = 0;
 
-        // Process each record
-        for (row_num, result) in csv_reader.records().enumerate() {
-            let record = result?;
-
-            // Parse required fields
-            let lat: f64 = record
-                .get(lat_idx)
-                .ok_or_else(|| Error::InvalidFormat(format!("missing lat at row {}", row_num)))?
-                .parse()
-                .map_err(|_| Error::InvalidFormat(format!("invalid lat at row {}", row_num)))?;
-
-            let lon: f64 = record
-                .get(lon_idx)
-                .ok_or_else(|| Error::InvalidFormat(format!("missing lon at row {}", row_num)))?
-                .parse()
-                .map_err(|_| Error::InvalidFormat(format!("invalid lon at row {}", row_num)))?;
-
-            let ts_str = record.get(ts_idx).ok_or_else(|| {
-                Error::InvalidFormat(format!("missing timestamp at row {}", row_num))
-            })?;
-
-            let timestamp = Timestamp::parse(ts_str).map_err(|_| {
-                Error::InvalidFormat(format!("invalid timestamp at row {}", row_num))
-            })?;
-
-            // Build location
-            let mut location = Location::new(lat, lon);
-            if let Some(elev_str) = self.get_optional(&record, elev_idx) {
-                if let Ok(elev) = elev_str.parse::<f64>() {
-                    location.elevation = Some(elev);
-                }
+        while csv_reader.read_byte_record(&mut record)? {
+            builder = builder.event(self.byte_record_to_event(&record, &columns, row_num)?);
+            row_num += 1;
+        }
+
+        Ok(builder.build())
+    }
+
+    /// Byte-slice counterpart of [`decode_record`](Self::decode_record),
+    /// used by [`import_fast`](Self::import_fast).
+    fn byte_record_to_event(&self, record: &ByteRecord, columns: &CsvColumns, row_num: usize) -> Result<Event> {
+        let lat = record
+            .get(columns.lat)
+            .and_then(parse_f64_bytes)
+            .ok_or_else(|| Error::InvalidFormat(format!("missing or invalid lat at row {}", row_num)))?;
+
+        let lon = record
+            .get(columns.lon)
+            .and_then(parse_f64_bytes)
+            .ok_or_else(|| Error::InvalidFormat(format!("missing or invalid lon at row {}", row_num)))?;
+
+        let ts_bytes = record
+            .get(columns.timestamp)
+            .ok_or_else(|| Error::InvalidFormat(format!("missing timestamp at row {}", row_num)))?;
+        let ts_str = std::str::from_utf8(ts_bytes)
+            .map_err(|_| Error::InvalidFormat(format!("invalid timestamp at row {}", row_num)))?;
+
+        let timestamp = if self.options.flexible_timestamps {
+            let reference = self
+                .options
+                .timestamp_reference
+                .clone()
+                .unwrap_or_else(Timestamp::now);
+            resolve_timestamp(ts_str, &reference)
+                .map_err(|e| Error::InvalidFormat(format!("invalid timestamp at row {}: {}", row_num, e)))?
+        } else {
+            parse_timestamp_with_formats(ts_str, &self.options.timestamp_formats, self.options.default_timezone)
+                .map_err(|e| Error::InvalidFormat(format!("invalid timestamp at row {}: {}", row_num, e)))?
+        };
+
+        let mut location = Location::new(lat, lon);
+        if let Some(elev_bytes) = get_bytes(record, columns.elevation) {
+            if let Some(elev) = parse_f64_bytes(elev_bytes) {
+                location.elevation = Some(elev);
             }
+        }
 
-            // Build event
-            let mut event_builder = EventBuilder::new().location(location).timestamp(timestamp);
+        let mut event_builder = EventBuilder::new().location(location).timestamp(timestamp);
 
-            // Add optional fields
-            if let Some(text) = self.get_optional(&record, text_idx) {
+        if let Some(text_bytes) = get_bytes(record, columns.text) {
+            if let Ok(text) = std::str::from_utf8(text_bytes) {
                 event_builder = event_builder.text(text);
             }
+        }
 
-            if let Some(tags_str) = self.get_optional(&record, tags_idx) {
+        if let Some(tags_bytes) = get_bytes(record, columns.tags) {
+            if let Ok(tags_str) = std::str::from_utf8(tags_bytes) {
                 for tag in tags_str.split(',') {
                     let trimmed = tag.trim();
                     if !trimmed.is_empty() {
@@ -232,10 +421,12 @@ impl Format for CsvFormat {
                     }
                 }
             }
+        }
 
-            if let Some(source_name) = self.get_optional(&record, source_name_idx) {
-                let source_type = self
-                    .get_optional(&record, source_type_idx)
+        if let Some(source_name_bytes) = get_bytes(record, columns.source_name) {
+            if let Ok(source_name) = std::str::from_utf8(source_name_bytes) {
+                let source_type = get_bytes(record, columns.source_type)
+                    .and_then(|b| std::str::from_utf8(b).ok())
                     .and_then(|s| match s.to_lowercase().as_str() {
                         "article" => Some(SourceType::Article),
                         "report" => Some(SourceType::Report),
@@ -246,70 +437,272 @@ impl Format for CsvFormat {
                     .unwrap_or(SourceType::Article);
 
                 let mut source = SourceRef::new(source_type);
-                source.title = Some(source_name);
+                source.title = Some(source_name.to_string());
                 event_builder = event_builder.source(source);
             }
-
-            let event = event_builder.build();
-            builder = builder.event(event);
         }
 
-        Ok(builder.build())
+        Ok(event_builder.build())
     }
 
-    fn export<W: Write>(&self, narrative: &Narrative, writer: W) -> Result<()> {
-        let mut csv_writer = csv::WriterBuilder::new()
-            .delimiter(self.options.delimiter)
-            .from_writer(writer);
+    /// Infers a [`CsvOptions`] column mapping from a header row and a sample
+    /// of up to `sample_rows` data rows, so callers don't have to hand-name
+    /// columns for every new source.
+    ///
+    /// Each column is classified by matching its header name against common
+    /// synonyms (`latitude`/`lng`/`x`/`y`/`time`/`date`/... alongside the
+    /// `lat`/`lon`/`timestamp` names [`CsvOptions`] defaults to) and, for the
+    /// coordinate columns, by checking the sampled values fall within a valid
+    /// range (`-90..=90` for latitude, `-180..=180` for longitude) — so two
+    /// unlabeled numeric columns still resolve correctly by which one can
+    /// possibly hold a latitude. A comma-containing value marks a column as
+    /// tags rather than free text. Returns the resolved options for the
+    /// caller to inspect or override before a full import; it does not
+    /// import anything itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reader has no header row or a sampled row is
+    /// malformed CSV.
+    pub fn infer<R: Read>(reader: R, sample_rows: usize) -> Result<CsvOptions> {
+        let mut csv_reader = csv::ReaderBuilder::new().from_reader(reader);
+        let headers = csv_reader.headers()?.clone();
 
-        // Write headers if enabled
-        if self.options.include_headers {
-            let mut headers = vec![
-                self.options.lat_column.as_str(),
-                self.options.lon_column.as_str(),
-                self.options.timestamp_column.as_str(),
-            ];
-
-            if let Some(ref col) = self.options.elevation_column {
-                headers.push(col);
-            }
-            if let Some(ref col) = self.options.text_column {
-                headers.push(col);
-            }
-            if let Some(ref col) = self.options.tags_column {
-                headers.push(col);
+        let mut samples: Vec<Vec<String>> = vec![Vec::new(); headers.len()];
+        for record in csv_reader.records().take(sample_rows) {
+            let record = record?;
+            for (i, value) in record.iter().enumerate() {
+                if !value.is_empty() {
+                    samples[i].push(value.to_string());
+                }
             }
-            if let Some(ref col) = self.options.source_name_column {
-                headers.push(col);
+        }
+
+        let mut used = vec![false; headers.len()];
+
+        let lat_idx = pick_column(
+            &headers,
+            &samples,
+            &mut used,
+            LAT_NAMES,
+            Some(|(min, max): (f64, f64)| (-90.0..=90.0).contains(&min) && (-90.0..=90.0).contains(&max)),
+        );
+        let lon_idx = pick_column(
+            &headers,
+            &samples,
+            &mut used,
+            LON_NAMES,
+            Some(|(min, max): (f64, f64)| (-180.0..=180.0).contains(&min) && (-180.0..=180.0).contains(&max)),
+        );
+        let timestamp_idx = pick_timestamp_column(&headers, &samples, &mut used);
+        let tags_idx = pick_tags_column(&headers, &samples, &mut used);
+        let text_idx = pick_column(&headers, &samples, &mut used, TEXT_NAMES, None)
+            .or_else(|| first_unused_text_column(&headers, &samples, &used));
+        let source_idx = pick_column(&headers, &samples, &mut used, SOURCE_NAMES, None);
+
+        let name_of = |idx: Option<usize>| idx.map(|i| headers[i].to_string());
+
+        Ok(CsvOptions {
+            lat_column: name_of(lat_idx).unwrap_or_else(|| "lat".to_string()),
+            lon_column: name_of(lon_idx).unwrap_or_else(|| "lon".to_string()),
+            timestamp_column: name_of(timestamp_idx).unwrap_or_else(|| "timestamp".to_string()),
+            elevation_column: None,
+            text_column: name_of(text_idx),
+            tags_column: name_of(tags_idx),
+            source_name_column: name_of(source_idx),
+            ..Default::default()
+        })
+    }
+
+    /// Helper to find column index by name
+    fn find_column(&self, headers: &StringRecord, name: &str) -> Option<usize> {
+        headers.iter().position(|h| h.eq_ignore_ascii_case(name))
+    }
+
+    /// Helper to get optional string value from record
+    fn get_optional(&self, record: &StringRecord, index: Option<usize>) -> Option<String> {
+        index.and_then(|i| record.get(i).filter(|s| !s.is_empty()).map(String::from))
+    }
+}
+
+/// Resolved column indices for a CSV header row.
+///
+/// Computed once from the header and then reused for every record, so a large
+/// import never re-scans the header per row.
+#[derive(Debug, Clone, Copy)]
+struct CsvColumns {
+    lat: usize,
+    lon: usize,
+    timestamp: usize,
+    elevation: Option<usize>,
+    text: Option<usize>,
+    tags: Option<usize>,
+    source_name: Option<usize>,
+    source_type: Option<usize>,
+}
+
+impl CsvFormat {
+    /// Resolve the configured column names against a header record.
+    fn resolve_columns(&self, headers: &StringRecord) -> Result<CsvColumns> {
+        let required = |name: &str| {
+            self.find_column(headers, name).ok_or_else(|| {
+                Error::InvalidFormat(format!("missing required column: {}", name))
+            })
+        };
+
+        let optional = |col: &Option<String>| {
+            col.as_ref().and_then(|name| self.find_column(headers, name))
+        };
+
+        Ok(CsvColumns {
+            lat: required(&self.options.lat_column)?,
+            lon: required(&self.options.lon_column)?,
+            timestamp: required(&self.options.timestamp_column)?,
+            elevation: optional(&self.options.elevation_column),
+            text: optional(&self.options.text_column),
+            tags: optional(&self.options.tags_column),
+            source_name: optional(&self.options.source_name_column),
+            source_type: optional(&self.options.source_type_column),
+        })
+    }
+
+    /// Decode a single record into an [`Event`] using resolved columns.
+    fn record_to_event(
+        &self,
+        record: &StringRecord,
+        columns: &CsvColumns,
+        row_num: usize,
+    ) -> Result<Event> {
+        self.decode_record(record, columns, row_num)
+            .map_err(|e| Error::InvalidFormat(e.message))
+    }
+
+    /// Decode a single record, attributing a failure to the offending column
+    /// so lenient imports (see [`RowError`]) can report it alongside the
+    /// message. [`record_to_event`](Self::record_to_event) discards the
+    /// column and surfaces only the message, preserving its existing error text.
+    fn decode_record(
+        &self,
+        record: &StringRecord,
+        columns: &CsvColumns,
+        row_num: usize,
+    ) -> std::result::Result<Event, RowError> {
+        let field_error = |column: &str, message: String| RowError {
+            row: row_num,
+            column: Some(column.to_string()),
+            message,
+        };
+
+        let lat: f64 = record
+            .get(columns.lat)
+            .ok_or_else(|| field_error(&self.options.lat_column, format!("missing lat at row {}", row_num)))?
+            .parse()
+            .map_err(|_| field_error(&self.options.lat_column, format!("invalid lat at row {}", row_num)))?;
+
+        let lon: f64 = record
+            .get(columns.lon)
+            .ok_or_else(|| field_error(&self.options.lon_column, format!("missing lon at row {}", row_num)))?
+            .parse()
+            .map_err(|_| field_error(&self.options.lon_column, format!("invalid lon at row {}", row_num)))?;
+
+        let ts_str = record.get(columns.timestamp).ok_or_else(|| {
+            field_error(
+                &self.options.timestamp_column,
+                format!("missing timestamp at row {}", row_num),
+            )
+        })?;
+
+        let timestamp = if self.options.flexible_timestamps {
+            let reference = self
+                .options
+                .timestamp_reference
+                .clone()
+                .unwrap_or_else(Timestamp::now);
+            resolve_timestamp(ts_str, &reference).map_err(|e| {
+                field_error(
+                    &self.options.timestamp_column,
+                    format!("invalid timestamp at row {}: {}", row_num, e),
+                )
+            })?
+        } else {
+            parse_timestamp_with_formats(
+                ts_str,
+                &self.options.timestamp_formats,
+                self.options.default_timezone,
+            )
+            .map_err(|e| {
+                field_error(
+                    &self.options.timestamp_column,
+                    format!("invalid timestamp at row {}: {}", row_num, e),
+                )
+            })?
+        };
+
+        let mut location = Location::new(lat, lon);
+        if let Some(elev_str) = self.get_optional(record, columns.elevation) {
+            if let Ok(elev) = elev_str.parse::<f64>() {
+                location.elevation = Some(elev);
             }
-            if let Some(ref col) = self.options.source_type_column {
-                headers.push(col);
+        }
+
+        let mut event_builder = EventBuilder::new().location(location).timestamp(timestamp);
+
+        if let Some(text) = self.get_optional(record, columns.text) {
+            event_builder = event_builder.text(text);
+        }
+
+        if let Some(tags_str) = self.get_optional(record, columns.tags) {
+            for tag in tags_str.split(',') {
+                let trimmed = tag.trim();
+                if !trimmed.is_empty() {
+                    event_builder = event_builder.tag(trimmed);
+                }
             }
+        }
 
-            csv_writer.write_record(&headers)?;
+        if let Some(source_name) = self.get_optional(record, columns.source_name) {
+            let source_type = self
+                .get_optional(record, columns.source_type)
+                .and_then(|s| match s.to_lowercase().as_str() {
+                    "article" => Some(SourceType::Article),
+                    "report" => Some(SourceType::Report),
+                    "witness" => Some(SourceType::Witness),
+                    "sensor" => Some(SourceType::Sensor),
+                    _ => None,
+                })
+                .unwrap_or(SourceType::Article);
+
+            let mut source = SourceRef::new(source_type);
+            source.title = Some(source_name);
+            event_builder = event_builder.source(source);
         }
 
-        // Write events
-        for event in narrative.events() {
-            let loc = &event.location;
-            let mut record = vec![
-                loc.lat.to_string(),
-                loc.lon.to_string(),
-                event.timestamp.to_rfc3339(),
-            ];
+        Ok(event_builder.build())
+    }
 
+    /// Serialize a single event into a CSV record honoring the column layout
+    /// and [`CsvProfile`]. `derived` carries the [`CsvProfile::Extended`]
+    /// columns — cumulative distance travelled since the first event (meters),
+    /// elapsed seconds since the previous event, and the event's sequential
+    /// index — and is ignored for every other profile.
+    fn event_to_record(&self, event: &Event, derived: Option<(f64, i64, usize)>) -> Vec<String> {
+        let loc = &event.location;
+        let mut record = vec![
+            loc.lat.to_string(),
+            loc.lon.to_string(),
+            render_timestamp(&event.timestamp, &self.options.export_timestamp_format),
+        ];
+
+        if self.options.profile != CsvProfile::Minimal {
             if self.options.elevation_column.is_some() {
                 record.push(loc.elevation.map(|e| e.to_string()).unwrap_or_default());
             }
-
             if self.options.text_column.is_some() {
                 record.push(event.text.clone());
             }
-
             if self.options.tags_column.is_some() {
                 record.push(event.tags.join(", "));
             }
-
             if self.options.source_name_column.is_some() {
                 record.push(
                     event
@@ -319,17 +712,149 @@ impl Format for CsvFormat {
                         .unwrap_or_default(),
                 );
             }
-
             if self.options.source_type_column.is_some() {
-                let type_str = event
-                    .sources
-                    .first()
-                    .map(|s| s.source_type.to_string())
-                    .unwrap_or_default();
-                record.push(type_str.to_string());
+                record.push(
+                    event
+                        .sources
+                        .first()
+                        .map(|s| s.source_type.to_string())
+                        .unwrap_or_default(),
+                );
+            }
+        }
+
+        if let Some((distance_m, elapsed_s, index)) = derived {
+            record.push(distance_m.to_string());
+            record.push(elapsed_s.to_string());
+            record.push(index.to_string());
+        }
+
+        record
+    }
+
+    /// Column names written as the CSV header, in record order.
+    fn header_row(&self) -> Vec<&str> {
+        let mut headers = vec![
+            self.options.lat_column.as_str(),
+            self.options.lon_column.as_str(),
+            self.options.timestamp_column.as_str(),
+        ];
+
+        if self.options.profile != CsvProfile::Minimal {
+            for col in [
+                &self.options.elevation_column,
+                &self.options.text_column,
+                &self.options.tags_column,
+                &self.options.source_name_column,
+                &self.options.source_type_column,
+            ] {
+                if let Some(col) = col {
+                    headers.push(col);
+                }
+            }
+        }
+
+        if self.options.profile == CsvProfile::Extended {
+            headers.extend(["distance_m", "elapsed_s", "index"]);
+        }
+
+        headers
+    }
+}
+
+/// Streaming iterator over events decoded from a CSV reader.
+///
+/// Produced by [`CsvFormat::import_iter`]. Each call to [`next`](Iterator::next)
+/// reads and decodes exactly one CSV record, so the iterator never holds more
+/// than a single row in memory.
+struct CsvEventIter<R: Read> {
+    format: CsvFormat,
+    reader: csv::Reader<R>,
+    columns: CsvColumns,
+    row_num: usize,
+}
+
+impl<R: Read> Iterator for CsvEventIter<R> {
+    type Item = Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record = StringRecord::new();
+        match self.reader.read_record(&mut record) {
+            Ok(false) => None,
+            Ok(true) => {
+                let row_num = self.row_num;
+                self.row_num += 1;
+                Some(self.format.record_to_event(&record, &self.columns, row_num))
             }
+            Err(err) => Some(Err(err.into())),
+        }
+    }
+}
+
+impl Format for CsvFormat {
+    fn import<R: Read>(&self, reader: R) -> Result<Narrative> {
+        let mut builder = NarrativeBuilder::new();
+        for event in self.import_iter(reader)? {
+            builder = builder.event(event?);
+        }
+        Ok(builder.build())
+    }
+
+    fn import_iter<'r, R: Read + 'r>(&self, reader: R) -> Result<EventStream<'r>> {
+        let mut csv_reader = csv::ReaderBuilder::new()
+            .delimiter(self.options.delimiter)
+            .trim(self.options.trim.to_csv())
+            .from_reader(reader);
+        let headers = csv_reader.headers()?.clone();
+        let columns = self.resolve_columns(&headers)?;
+
+        Ok(Box::new(CsvEventIter {
+            format: self.clone(),
+            reader: csv_reader,
+            columns,
+            row_num: 0,
+        }))
+    }
+
+    fn export_to<W, I>(&self, events: I, writer: W) -> Result<()>
+    where
+        W: Write,
+        I: IntoIterator<Item = Event>,
+    {
+        let quote_style = if self.options.profile == CsvProfile::QuotedCompat {
+            csv::QuoteStyle::Always
+        } else {
+            self.options.quote_style.to_csv()
+        };
+
+        let mut csv_writer = csv::WriterBuilder::new()
+            .delimiter(self.options.delimiter)
+            .quote_style(quote_style)
+            .from_writer(writer);
 
-            csv_writer.write_record(&record)?;
+        if self.options.include_headers {
+            csv_writer.write_record(&self.header_row())?;
+        }
+
+        let mut cumulative_distance = 0.0;
+        let mut previous: Option<Event> = None;
+
+        for (index, event) in events.into_iter().enumerate() {
+            let derived = if self.options.profile == CsvProfile::Extended {
+                let elapsed_s = match &previous {
+                    Some(prev) => {
+                        cumulative_distance += haversine_meters(&prev.location, &event.location);
+                        event.timestamp.duration_since(&prev.timestamp).num_seconds()
+                    }
+                    None => 0,
+                };
+                Some((cumulative_distance, elapsed_s, index))
+            } else {
+                None
+            };
+
+            csv_writer.write_record(&self.event_to_record(&event, derived))?;
+            previous = Some(event);
         }
 
         csv_writer.flush()?;
@@ -337,6 +862,276 @@ impl Format for CsvFormat {
     }
 }
 
+/// Great-circle distance between two locations, in meters.
+fn haversine_meters(a: &Location, b: &Location) -> f64 {
+    let r = 6_371_000.0_f64;
+    let (phi1, phi2) = (a.lat.to_radians(), b.lat.to_radians());
+    let dphi = (b.lat - a.lat).to_radians();
+    let dlambda = (b.lon - a.lon).to_radians();
+    let h = (dphi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (dlambda / 2.0).sin().powi(2);
+    r * 2.0 * h.sqrt().asin()
+}
+
+/// Resolve a timestamp cell, trying strict and relative parsers in turn.
+///
+/// The parsers are tried in order of specificity: an exact RFC3339 instant
+/// first, then a signed minute offset from `reference`, and finally a natural
+/// date expression (`yesterday`, `today`, `tomorrow`, or `YYYY-MM-DD`) with an
+/// optional trailing clock time. Every candidate must land on or after the
+/// Unix epoch.
+fn resolve_timestamp(raw: &str, reference: &Timestamp) -> Result<Timestamp> {
+    let raw = raw.trim();
+    if let Ok(ts) = Timestamp::parse(raw) {
+        return check_epoch(ts);
+    }
+    if let Some(ts) = parse_minute_offset(raw, reference) {
+        return check_epoch(ts);
+    }
+    if let Some(ts) = parse_natural(raw, reference) {
+        return check_epoch(ts);
+    }
+    Err(Error::InvalidFormat(format!(
+        "unrecognized timestamp '{}'",
+        raw
+    )))
+}
+
+/// Parse a signed minute offset such as `+90`, `-30`, or `in 90`.
+fn parse_minute_offset(raw: &str, reference: &Timestamp) -> Option<Timestamp> {
+    let body = raw.strip_prefix("in ").map(str::trim).unwrap_or(raw);
+    let minutes: i64 = body.parse().ok()?;
+    Some(Timestamp::new(reference.datetime + Duration::minutes(minutes)))
+}
+
+/// Parse a natural date expression with an optional trailing time.
+fn parse_natural(raw: &str, reference: &Timestamp) -> Option<Timestamp> {
+    let (head, tail) = raw.split_once(' ').unwrap_or((raw, ""));
+    let date = match head.to_lowercase().as_str() {
+        "today" => reference.datetime.date_naive(),
+        "yesterday" => reference.datetime.date_naive().pred_opt()?,
+        "tomorrow" => reference.datetime.date_naive().succ_opt()?,
+        _ => NaiveDate::parse_from_str(head, "%Y-%m-%d").ok()?,
+    };
+
+    let time = if tail.trim().is_empty() {
+        NaiveTime::from_hms_opt(0, 0, 0)?
+    } else {
+        parse_time(tail.trim())?
+    };
+
+    Some(Timestamp::new(Utc.from_utc_datetime(&date.and_time(time))))
+}
+
+/// Parse a clock time in one of several common notations.
+fn parse_time(raw: &str) -> Option<NaiveTime> {
+    const FORMATS: &[&str] = &["%H:%M:%S", "%H:%M", "%I:%M%p", "%I%p"];
+    let upper = raw.to_uppercase();
+    for fmt in FORMATS {
+        if let Ok(t) = NaiveTime::parse_from_str(raw, fmt) {
+            return Some(t);
+        }
+        if let Ok(t) = NaiveTime::parse_from_str(&upper, fmt) {
+            return Some(t);
+        }
+    }
+    None
+}
+
+/// Returns the non-empty byte slice at `index`, if present, used by the
+/// [`CsvFormat::import_fast`] decode path to skip allocating a `String` for
+/// columns that are absent or blank in a given row.
+fn get_bytes<'a>(record: &'a ByteRecord, index: Option<usize>) -> Option<&'a [u8]> {
+    index.and_then(|i| record.get(i)).filter(|b| !b.is_empty())
+}
+
+/// Parses an `f64` directly from a byte slice, validating only that slice
+/// as UTF-8 rather than the whole record.
+fn parse_f64_bytes(bytes: &[u8]) -> Option<f64> {
+    std::str::from_utf8(bytes).ok()?.parse().ok()
+}
+
+/// Reject timestamps that fall before the Unix epoch.
+fn check_epoch(ts: Timestamp) -> Result<Timestamp> {
+    if ts.unix_timestamp() < 0 {
+        return Err(Error::InvalidFormat(
+            "timestamp precedes the Unix epoch".to_string(),
+        ));
+    }
+    Ok(ts)
+}
+
+/// Decode a timestamp cell by trying each of `formats` in order, returning
+/// the first successful reading. A [`TimestampFormat::Strftime`] reading
+/// with no UTC offset in its pattern is anchored in `default_timezone`
+/// (UTC if unset) before being normalized to an instant.
+fn parse_timestamp_with_formats(
+    s: &str,
+    formats: &[TimestampFormat],
+    default_timezone: Option<chrono_tz::Tz>,
+) -> Result<Timestamp> {
+    for format in formats {
+        let parsed = match format {
+            TimestampFormat::Rfc3339 => Timestamp::parse(s).ok(),
+            TimestampFormat::EpochSeconds => s.trim().parse::<i64>().ok().and_then(Timestamp::from_unix),
+            TimestampFormat::EpochMillis => s
+                .trim()
+                .parse::<i64>()
+                .ok()
+                .and_then(Timestamp::from_unix_millis),
+            TimestampFormat::Strftime(pattern) => {
+                chrono::NaiveDateTime::parse_from_str(s.trim(), pattern)
+                    .ok()
+                    .map(|naive| match default_timezone {
+                        Some(tz) => {
+                            let utc = tz
+                                .from_local_datetime(&naive)
+                                .single()
+                                .map(|dt| dt.with_timezone(&Utc))
+                                .unwrap_or_else(|| Utc.from_utc_datetime(&naive));
+                            Timestamp::with_zone(utc, tz)
+                        }
+                        None => Timestamp::new(Utc.from_utc_datetime(&naive)),
+                    })
+            }
+        };
+        if let Some(ts) = parsed {
+            return Ok(ts);
+        }
+    }
+    Err(Error::InvalidFormat(format!(
+        "{:?} did not match any of {} configured timestamp format(s)",
+        s,
+        formats.len()
+    )))
+}
+
+/// Render a timestamp for export using the chosen [`TimestampFormat`].
+fn render_timestamp(ts: &Timestamp, format: &TimestampFormat) -> String {
+    match format {
+        TimestampFormat::Rfc3339 => ts.to_rfc3339(),
+        TimestampFormat::EpochSeconds => ts.unix_timestamp().to_string(),
+        TimestampFormat::EpochMillis => ts.unix_timestamp_millis().to_string(),
+        TimestampFormat::Strftime(pattern) => ts.datetime.format(pattern).to_string(),
+    }
+}
+
+// ---- Header/type inference for CsvFormat::infer ----
+
+const LAT_NAMES: &[&str] = &["lat", "latitude", "y"];
+const LON_NAMES: &[&str] = &["lon", "lng", "long", "longitude", "x"];
+const TIMESTAMP_NAMES: &[&str] = &["timestamp", "time", "date", "datetime"];
+const TEXT_NAMES: &[&str] = &["text", "description", "desc", "notes", "note"];
+const TAGS_NAMES: &[&str] = &["tags", "tag", "categories", "category"];
+const SOURCE_NAMES: &[&str] = &["source", "author", "origin"];
+
+/// Returns the `(min, max)` of `values` if every one parses as `f64`, else `None`.
+fn numeric_range(values: &[String]) -> Option<(f64, f64)> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut min = f64::INFINITY;
+    let mut max = f64::NEG_INFINITY;
+    for v in values {
+        let n: f64 = v.parse().ok()?;
+        min = min.min(n);
+        max = max.max(n);
+    }
+    Some((min, max))
+}
+
+/// Picks the best unused column for a field, preferring a header-name match
+/// against `synonyms` and falling back to any unused column whose sampled
+/// numeric range satisfies `in_range` (when given). Marks the chosen column
+/// as used so later picks don't reuse it.
+fn pick_column(
+    headers: &StringRecord,
+    samples: &[Vec<String>],
+    used: &mut [bool],
+    synonyms: &[&str],
+    in_range: Option<fn((f64, f64)) -> bool>,
+) -> Option<usize> {
+    let satisfies_range = |i: usize| match in_range {
+        Some(f) => numeric_range(&samples[i]).is_some_and(f),
+        None => true,
+    };
+
+    let by_name = (0..headers.len()).find(|&i| {
+        !used[i]
+            && synonyms.iter().any(|s| headers[i].eq_ignore_ascii_case(s))
+            && satisfies_range(i)
+    });
+    if let Some(i) = by_name {
+        used[i] = true;
+        return Some(i);
+    }
+
+    if let Some(in_range) = in_range {
+        let by_range = (0..headers.len())
+            .find(|&i| !used[i] && numeric_range(&samples[i]).is_some_and(in_range));
+        if let Some(i) = by_range {
+            used[i] = true;
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+/// Picks the timestamp column: a header-name match first, else the first
+/// unused column whose sampled values all parse as a [`Timestamp`].
+fn pick_timestamp_column(headers: &StringRecord, samples: &[Vec<String>], used: &mut [bool]) -> Option<usize> {
+    let parses_as_timestamp = |i: usize| {
+        let values = &samples[i];
+        !values.is_empty() && values.iter().all(|v| Timestamp::parse(v).is_ok())
+    };
+
+    let by_name = (0..headers.len()).find(|&i| {
+        !used[i] && TIMESTAMP_NAMES.iter().any(|s| headers[i].eq_ignore_ascii_case(s))
+    });
+    if let Some(i) = by_name {
+        used[i] = true;
+        return Some(i);
+    }
+
+    let by_value = (0..headers.len()).find(|&i| !used[i] && parses_as_timestamp(i));
+    if let Some(i) = by_value {
+        used[i] = true;
+        return Some(i);
+    }
+
+    None
+}
+
+/// Picks the tags column: a header-name match, or the first unused
+/// non-numeric column whose sampled values all contain a comma.
+fn pick_tags_column(headers: &StringRecord, samples: &[Vec<String>], used: &mut [bool]) -> Option<usize> {
+    let looks_like_tags =
+        |i: usize| !samples[i].is_empty() && samples[i].iter().all(|v| v.contains(','));
+
+    let by_name = (0..headers.len())
+        .find(|&i| !used[i] && TAGS_NAMES.iter().any(|s| headers[i].eq_ignore_ascii_case(s)));
+    if let Some(i) = by_name {
+        used[i] = true;
+        return Some(i);
+    }
+
+    let by_value = (0..headers.len())
+        .find(|&i| !used[i] && numeric_range(&samples[i]).is_none() && looks_like_tags(i));
+    if let Some(i) = by_value {
+        used[i] = true;
+        return Some(i);
+    }
+
+    None
+}
+
+/// Falls back to the first remaining non-numeric column as free text, since
+/// an unclassified string column is more likely to be descriptive text than
+/// anything else left over.
+fn first_unused_text_column(headers: &StringRecord, samples: &[Vec<String>], used: &[bool]) -> Option<usize> {
+    (0..headers.len()).find(|&i| !used[i] && numeric_range(&samples[i]).is_none() && !samples[i].is_empty())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -400,6 +1195,96 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_flexible_timestamps_relative_and_natural() {
+        let reference = Timestamp::parse("2024-03-10T12:00:00Z").unwrap();
+        let options = CsvOptions {
+            flexible_timestamps: true,
+            timestamp_reference: Some(reference.clone()),
+            ..Default::default()
+        };
+        let csv_data = "lat,lon,timestamp\n\
+                        40.0,-74.0,+90\n\
+                        41.0,-75.0,yesterday 09:30\n\
+                        42.0,-76.0,2024-03-12";
+
+        let narrative = CsvFormat::with_options(options).import_str(csv_data).unwrap();
+        let events = narrative.events();
+
+        assert_eq!(
+            events[0].timestamp.datetime,
+            reference.datetime + Duration::minutes(90)
+        );
+        assert_eq!(events[1].timestamp.to_rfc3339(), "2024-03-09T09:30:00+00:00");
+        assert_eq!(events[2].timestamp.to_rfc3339(), "2024-03-12T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_flexible_timestamps_reject_pre_epoch() {
+        let reference = Timestamp::parse("2024-03-10T12:00:00Z").unwrap();
+        let options = CsvOptions {
+            flexible_timestamps: true,
+            timestamp_reference: Some(reference),
+            ..Default::default()
+        };
+        let csv_data = "lat,lon,timestamp\n40.0,-74.0,1960-01-01";
+        let err = CsvFormat::with_options(options)
+            .import_str(csv_data)
+            .unwrap_err();
+        assert!(err.to_string().contains("epoch"));
+    }
+
+    #[test]
+    fn test_csv_import_iter_streams_events() {
+        let csv_data = "lat,lon,timestamp\n\
+                       40.7128,-74.006,2024-01-15T14:30:00Z\n\
+                       34.0522,-118.2437,2024-01-16T10:00:00Z";
+
+        let format = CsvFormat::new();
+        let events: Vec<Event> = format
+            .import_iter(csv_data.as_bytes())
+            .unwrap()
+            .map(|e| e.unwrap())
+            .collect();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].location.lat, 40.7128);
+        assert_eq!(events[1].location.lat, 34.0522);
+    }
+
+    #[test]
+    fn test_csv_import_iter_reports_row_errors() {
+        let csv_data = "lat,lon,timestamp\n\
+                       40.7128,-74.006,2024-01-15T14:30:00Z\n\
+                       oops,-118.2437,2024-01-16T10:00:00Z";
+
+        let format = CsvFormat::new();
+        let results: Vec<_> = format.import_iter(csv_data.as_bytes()).unwrap().collect();
+
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+    }
+
+    #[test]
+    fn test_csv_export_to_stream() {
+        let events = vec![
+            Event::builder()
+                .location(Location::new(40.7128, -74.006))
+                .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+                .text("streamed")
+                .build(),
+        ];
+
+        let format = CsvFormat::new();
+        let mut buffer = Vec::new();
+        format.export_to(events, &mut buffer).unwrap();
+
+        let text = String::from_utf8(buffer).unwrap();
+        assert!(text.contains("streamed"));
+        let reimported = format.import_str(&text).unwrap();
+        assert_eq!(reimported.events().len(), 1);
+    }
+
     #[test]
     fn test_csv_custom_delimiter() {
         let tsv_data = "lat\tlon\ttimestamp\n40.7128\t-74.006\t2024-01-15T14:30:00Z";
@@ -413,4 +1298,308 @@ mod tests {
 
         assert_eq!(narrative.events().len(), 1);
     }
+
+    #[test]
+    fn test_import_with_report_strict_aborts_on_first_bad_row() {
+        let csv_data = "lat,lon,timestamp\n\
+                       40.7128,-74.006,2024-01-15T14:30:00Z\n\
+                       oops,-118.2437,2024-01-16T10:00:00Z";
+
+        let format = CsvFormat::new();
+        let err = format.import_with_report(csv_data.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("invalid lat"));
+    }
+
+    #[test]
+    fn test_import_with_report_skip_row_drops_silently() {
+        let csv_data = "lat,lon,timestamp\n\
+                       40.7128,-74.006,2024-01-15T14:30:00Z\n\
+                       oops,-118.2437,2024-01-16T10:00:00Z\n\
+                       34.0522,-118.2437,2024-01-16T10:00:00Z";
+
+        let options = CsvOptions {
+            on_error: OnError::SkipRow,
+            ..Default::default()
+        };
+        let (narrative, report) = CsvFormat::with_options(options)
+            .import_with_report(csv_data.as_bytes())
+            .unwrap();
+
+        assert_eq!(narrative.events().len(), 2);
+        assert!(report.is_clean());
+    }
+
+    #[test]
+    fn test_import_with_report_collect_surfaces_row_errors() {
+        let csv_data = "lat,lon,timestamp\n\
+                       40.7128,-74.006,2024-01-15T14:30:00Z\n\
+                       oops,-118.2437,2024-01-16T10:00:00Z\n\
+                       34.0522,-118.2437,not-a-timestamp";
+
+        let options = CsvOptions {
+            on_error: OnError::Collect,
+            ..Default::default()
+        };
+        let (narrative, report) = CsvFormat::with_options(options)
+            .import_with_report(csv_data.as_bytes())
+            .unwrap();
+
+        assert_eq!(narrative.events().len(), 1);
+        assert_eq!(report.errors.len(), 2);
+        assert_eq!(report.errors[0].row, 1);
+        assert_eq!(report.errors[0].column.as_deref(), Some("lat"));
+        assert_eq!(report.errors[1].row, 2);
+        assert_eq!(report.errors[1].column.as_deref(), Some("timestamp"));
+    }
+
+    #[test]
+    fn test_infer_detects_synonym_column_names() {
+        let csv_data = "latitude,longitude,date\n\
+                       40.7128,-74.006,2024-01-15T14:30:00Z\n\
+                       34.0522,-118.2437,2024-01-16T10:00:00Z";
+
+        let options = CsvFormat::infer(csv_data.as_bytes(), 10).unwrap();
+
+        assert_eq!(options.lat_column, "latitude");
+        assert_eq!(options.lon_column, "longitude");
+        assert_eq!(options.timestamp_column, "date");
+    }
+
+    #[test]
+    fn test_infer_disambiguates_unlabeled_numeric_columns_by_range() {
+        // Neither "col_a" nor "col_b" is in the lat/lon synonym lists, so the
+        // range check (-90..=90 vs -180..=180) must pick which unlabeled
+        // column is latitude and which is longitude.
+        let csv_data = "col_a,col_b,when\n\
+                       -74.006,40.7128,2024-01-15T14:30:00Z\n\
+                       -118.2437,34.0522,2024-01-16T10:00:00Z";
+
+        let options = CsvFormat::infer(csv_data.as_bytes(), 10).unwrap();
+
+        assert_eq!(options.lat_column, "col_b");
+        assert_eq!(options.lon_column, "col_a");
+    }
+
+    #[test]
+    fn test_infer_distinguishes_tags_from_text_by_comma_content() {
+        let csv_data = "lat,lon,timestamp,notes,categories\n\
+                       40.7128,-74.006,2024-01-15T14:30:00Z,Event in NYC,\"transit,outdoor\"\n\
+                       34.0522,-118.2437,2024-01-16T10:00:00Z,Event in LA,\"food,social\"";
+
+        let options = CsvFormat::infer(csv_data.as_bytes(), 10).unwrap();
+
+        assert_eq!(options.text_column.as_deref(), Some("notes"));
+        assert_eq!(options.tags_column.as_deref(), Some("categories"));
+    }
+
+    #[test]
+    fn test_infer_result_imports_successfully() {
+        let csv_data = "latitude,longitude,date,notes\n\
+                       40.7128,-74.006,2024-01-15T14:30:00Z,Event in NYC\n\
+                       34.0522,-118.2437,2024-01-16T10:00:00Z,Event in LA";
+
+        let options = CsvFormat::infer(csv_data.as_bytes(), 10).unwrap();
+        let narrative = CsvFormat::with_options(options).import_str(csv_data).unwrap();
+
+        assert_eq!(narrative.events().len(), 2);
+        assert_eq!(narrative.events()[0].text, "Event in NYC");
+    }
+
+    #[test]
+    fn test_timestamp_formats_epoch_seconds() {
+        let csv_data = "lat,lon,timestamp\n40.7128,-74.006,1705329000";
+        let options = CsvOptions {
+            timestamp_formats: vec![TimestampFormat::EpochSeconds],
+            ..Default::default()
+        };
+        let narrative = CsvFormat::with_options(options).import_str(csv_data).unwrap();
+        assert_eq!(narrative.events()[0].timestamp.unix_timestamp(), 1705329000);
+    }
+
+    #[test]
+    fn test_timestamp_formats_tried_in_order() {
+        let csv_data = "lat,lon,timestamp\n\
+                       40.7128,-74.006,1705329000\n\
+                       34.0522,-118.2437,2024-01-16T10:00:00Z";
+        let options = CsvOptions {
+            timestamp_formats: vec![TimestampFormat::EpochSeconds, TimestampFormat::Rfc3339],
+            ..Default::default()
+        };
+        let narrative = CsvFormat::with_options(options).import_str(csv_data).unwrap();
+        assert_eq!(narrative.events()[0].timestamp.unix_timestamp(), 1705329000);
+        assert_eq!(narrative.events()[1].timestamp.to_rfc3339(), "2024-01-16T10:00:00+00:00");
+    }
+
+    #[test]
+    fn test_timestamp_formats_strftime_with_default_timezone() {
+        let csv_data = "lat,lon,timestamp\n40.7128,-74.006,2024-01-15 09:30:00";
+        let options = CsvOptions {
+            timestamp_formats: vec![TimestampFormat::Strftime("%Y-%m-%d %H:%M:%S".to_string())],
+            default_timezone: Some(chrono_tz::America::New_York),
+            ..Default::default()
+        };
+        let narrative = CsvFormat::with_options(options).import_str(csv_data).unwrap();
+        // 09:30 US Eastern (UTC-5 in January) is 14:30 UTC; to_rfc3339 keeps
+        // the Eastern offset the cell was read in.
+        assert_eq!(narrative.events()[0].timestamp.to_rfc3339(), "2024-01-15T09:30:00-05:00");
+    }
+
+    #[test]
+    fn test_timestamp_formats_reject_unmatched_cell() {
+        let csv_data = "lat,lon,timestamp\n40.7128,-74.006,not-a-timestamp";
+        let options = CsvOptions {
+            timestamp_formats: vec![TimestampFormat::EpochSeconds],
+            ..Default::default()
+        };
+        let result = CsvFormat::with_options(options).import_str(csv_data);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_export_timestamp_format_epoch_millis_roundtrips() {
+        let event = Event::builder()
+            .location(Location::new(40.7128, -74.006))
+            .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+            .text("Test event")
+            .build();
+        let narrative = Narrative::builder().event(event).build();
+
+        let options = CsvOptions {
+            timestamp_formats: vec![TimestampFormat::EpochMillis],
+            export_timestamp_format: TimestampFormat::EpochMillis,
+            ..Default::default()
+        };
+        let format = CsvFormat::with_options(options);
+        let exported = format.export_str(&narrative).unwrap();
+        let imported = format.import_str(&exported).unwrap();
+
+        assert_eq!(
+            imported.events()[0].timestamp.unix_timestamp_millis(),
+            narrative.events()[0].timestamp.unix_timestamp_millis()
+        );
+    }
+
+    fn two_event_narrative() -> Narrative {
+        let a = Event::builder()
+            .location(Location::new(40.0, -74.0))
+            .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+            .text("first")
+            .tag("t1")
+            .build();
+        let b = Event::builder()
+            .location(Location::new(40.01, -74.0))
+            .timestamp(Timestamp::parse("2024-01-15T14:31:40Z").unwrap())
+            .text("second")
+            .tag("t2")
+            .build();
+        Narrative::builder().event(a).event(b).build()
+    }
+
+    #[test]
+    fn test_csv_profile_minimal_omits_optional_columns() {
+        let options = CsvOptions {
+            profile: CsvProfile::Minimal,
+            ..Default::default()
+        };
+        let format = CsvFormat::with_options(options);
+        let exported = format.export_str(&two_event_narrative()).unwrap();
+
+        let header = exported.lines().next().unwrap();
+        assert_eq!(header, "lat,lon,timestamp");
+    }
+
+    #[test]
+    fn test_csv_profile_extended_adds_derived_columns() {
+        let options = CsvOptions {
+            profile: CsvProfile::Extended,
+            ..Default::default()
+        };
+        let format = CsvFormat::with_options(options);
+        let exported = format.export_str(&two_event_narrative()).unwrap();
+
+        let mut lines = exported.lines();
+        let header = lines.next().unwrap();
+        assert!(header.ends_with("distance_m,elapsed_s,index"));
+
+        let row0: Vec<&str> = lines.next().unwrap().split(',').collect();
+        let row1: Vec<&str> = lines.next().unwrap().split(',').collect();
+
+        assert_eq!(row0[row0.len() - 1], "0");
+        assert_eq!(row0[row0.len() - 3], "0");
+        assert_eq!(row1[row1.len() - 1], "1");
+        assert_eq!(row1[row1.len() - 2], "100");
+
+        let distance: f64 = row1[row1.len() - 3].parse().unwrap();
+        assert!(distance > 1000.0 && distance < 1200.0);
+    }
+
+    #[test]
+    fn test_csv_profile_quoted_compat_quotes_every_field() {
+        let options = CsvOptions {
+            profile: CsvProfile::QuotedCompat,
+            ..Default::default()
+        };
+        let format = CsvFormat::with_options(options);
+        let exported = format.export_str(&two_event_narrative()).unwrap();
+
+        let header = exported.lines().next().unwrap();
+        assert!(header.starts_with("\"lat\",\"lon\",\"timestamp\""));
+    }
+
+    #[test]
+    fn test_csv_quote_style_always_without_quoted_compat_profile() {
+        let options = CsvOptions {
+            quote_style: QuoteStyle::Always,
+            ..Default::default()
+        };
+        let format = CsvFormat::with_options(options);
+        let exported = format.export_str(&two_event_narrative()).unwrap();
+
+        let header = exported.lines().next().unwrap();
+        assert!(header.starts_with("\"lat\",\"lon\",\"timestamp\""));
+    }
+
+    #[test]
+    fn test_import_fast_matches_import() {
+        let csv_data = "lat,lon,timestamp,text,tags,source\n\
+                       40.7128,-74.006,2024-01-15T14:30:00Z,Event in NYC,\"a, b\",sensor-1\n\
+                       34.0522,-118.2437,2024-01-16T10:00:00Z,,,";
+
+        let format = CsvFormat::new();
+        let narrative = format.import_str(csv_data).unwrap();
+        let fast = format.import_fast(csv_data.as_bytes()).unwrap();
+
+        assert_eq!(fast.events().len(), narrative.events().len());
+        assert_eq!(fast.events()[0].location.lat, narrative.events()[0].location.lat);
+        assert_eq!(fast.events()[0].text, narrative.events()[0].text);
+        assert_eq!(fast.events()[0].tags, narrative.events()[0].tags);
+        assert_eq!(
+            fast.events()[0].sources[0].title,
+            narrative.events()[0].sources[0].title
+        );
+        assert_eq!(fast.events()[1].text, "");
+        assert!(fast.events()[1].tags.is_empty());
+    }
+
+    #[test]
+    fn test_import_fast_rejects_invalid_row() {
+        let csv_data = "lat,lon,timestamp\n\
+                       oops,-74.006,2024-01-15T14:30:00Z";
+
+        let result = CsvFormat::new().import_fast(csv_data.as_bytes());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_csv_trim_fields_strips_whitespace() {
+        let csv_data = "lat,lon,timestamp,text\n 40.7128 , -74.006 , 2024-01-15T14:30:00Z , Event in NYC ";
+        let options = CsvOptions {
+            trim: CsvTrim::All,
+            ..Default::default()
+        };
+        let narrative = CsvFormat::with_options(options).import_str(csv_data).unwrap();
+
+        assert_eq!(narrative.events()[0].location.lat, 40.7128);
+        assert_eq!(narrative.events()[0].text, "Event in NYC");
+    }
 }
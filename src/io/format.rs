@@ -1,13 +1,27 @@
 //! Format trait for import/export operations.
 
-use crate::core::Narrative;
+use crate::core::{Event, Narrative, NarrativeBuilder};
 use crate::Result;
 use std::io::{Read, Write};
 
+/// A streaming iterator over imported events.
+///
+/// Yielded by [`Format::import_iter`]; each item is a single parsed [`Event`]
+/// or the error encountered decoding it, so callers can process arbitrarily
+/// large inputs without materializing the whole [`Narrative`] in memory.
+pub type EventStream<'a> = Box<dyn Iterator<Item = Result<Event>> + 'a>;
+
 /// Trait for formats that can import and export narratives.
 ///
 /// This trait defines a common interface for reading and writing
 /// narratives in various formats (GeoJSON, CSV, etc.).
+///
+/// Formats that can decode incrementally should override
+/// [`import_iter`](Self::import_iter) and [`export_to`](Self::export_to).
+/// [`import`](Self::import) is always implemented by the format itself, and
+/// the default `import_iter` buffers through it. [`export`](Self::export) and
+/// `export_to` default to each other, so a format overrides at least one of
+/// the two; the string convenience wrappers go through `import`/`export`.
 pub trait Format {
     /// Import a narrative from a reader.
     ///
@@ -17,6 +31,20 @@ pub trait Format {
     /// the expected format.
     fn import<R: Read>(&self, reader: R) -> Result<Narrative>;
 
+    /// Import events one at a time from a reader.
+    ///
+    /// Unlike [`import`](Self::import), which buffers the entire
+    /// [`Narrative`], this yields each [`Event`] as it is decoded, letting the
+    /// crate stream multi-hundred-megabyte exports without loading everything
+    /// into RAM. The default implementation falls back to [`import`](Self::import)
+    /// for formats that have no incremental decoder; streaming formats override
+    /// it to read row-by-row.
+    fn import_iter<'r, R: Read + 'r>(&self, reader: R) -> Result<EventStream<'r>> {
+        let narrative = self.import(reader)?;
+        let events: Vec<Event> = narrative.events().to_vec();
+        Ok(Box::new(events.into_iter().map(Ok)))
+    }
+
     /// Import a narrative from a string.
     ///
     /// This is a convenience method that wraps the string in a reader.
@@ -29,7 +57,29 @@ pub trait Format {
     /// # Errors
     ///
     /// Returns an error if the write operation fails.
-    fn export<W: Write>(&self, narrative: &Narrative, writer: W) -> Result<()>;
+    fn export<W: Write>(&self, narrative: &Narrative, writer: W) -> Result<()> {
+        self.export_to(narrative.events().iter().cloned(), writer)
+    }
+
+    /// Export a stream of events to a writer.
+    ///
+    /// This is the streaming counterpart to [`export`](Self::export): it
+    /// consumes events one at a time, so callers can pipe a lazily produced
+    /// sequence (e.g. a day of vehicle pings) straight to disk. The default
+    /// implementation collects the events into a [`Narrative`] and defers to
+    /// [`export`](Self::export); streaming formats override it to write each
+    /// event as it arrives.
+    fn export_to<W, I>(&self, events: I, writer: W) -> Result<()>
+    where
+        W: Write,
+        I: IntoIterator<Item = Event>,
+    {
+        let narrative = events
+            .into_iter()
+            .fold(NarrativeBuilder::new(), |builder, event| builder.event(event))
+            .build();
+        self.export(&narrative, writer)
+    }
 
     /// Export a narrative to a string.
     ///
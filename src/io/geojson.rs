@@ -1,13 +1,14 @@
 //! GeoJSON format import/export.
 
-use super::format::Format;
+use super::format::{EventStream, Format};
 use crate::core::{
-    EventBuilder, Location, Narrative, NarrativeBuilder, SourceRef, SourceType, Timestamp,
+    Event, EventBuilder, GeoBounds, Location, Narrative, NarrativeBuilder, SourceRef, SourceType,
+    Timestamp,
 };
 use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
-use serde_json::{Map, Value};
-use std::io::{Read, Write};
+use serde_json::{Map, Number, Value};
+use std::io::{BufRead, BufReader, Read, Write};
 
 /// GeoJSON format handler.
 ///
@@ -67,6 +68,28 @@ pub struct GeoJsonOptions {
 
     /// Property name for text/description field
     pub text_property: String,
+
+    /// Whether to emit a STAC-style top-level `bbox` and datetime extent on export
+    pub include_bbox: bool,
+
+    /// Whether to import/export per-feature kinematic properties (`speed`,
+    /// `heading`) alongside the geometry, for GPS/tracker datasets.
+    pub include_kinematics: bool,
+
+    /// Property name for ground speed (metres per second).
+    pub speed_property: String,
+
+    /// Property name for heading in degrees clockwise from true north.
+    pub heading_property: String,
+
+    /// Whether to read/write newline-delimited GeoJSON (GeoJSONL): one `Feature`
+    /// JSON object per line instead of a single wrapping `FeatureCollection`.
+    pub line_delimited: bool,
+
+    /// Optional spatial window applied on import: features whose declared
+    /// feature-level `bbox` does not intersect it are rejected before their
+    /// geometry and properties are decoded.
+    pub filter_bbox: Option<GeoBounds>,
 }
 
 impl Default for GeoJsonOptions {
@@ -77,6 +100,12 @@ impl Default for GeoJsonOptions {
             include_sources: true,
             timestamp_property: "timestamp".to_string(),
             text_property: "text".to_string(),
+            include_bbox: true,
+            include_kinematics: true,
+            speed_property: "speed".to_string(),
+            heading_property: "heading".to_string(),
+            filter_bbox: None,
+            line_delimited: false,
         }
     }
 }
@@ -91,6 +120,220 @@ impl GeoJsonFormat {
     pub fn with_options(options: GeoJsonOptions) -> Self {
         Self { options }
     }
+
+    /// Decode a single GeoJSON [`Feature`] into an [`Event`].
+    ///
+    /// Returns `Ok(None)` when the feature is skipped — rejected by the
+    /// configured [`filter_bbox`](GeoJsonOptions::filter_bbox) window or carrying
+    /// a geometry with no usable position. Shared by the buffered
+    /// [`import`](Format::import) path and the line-delimited streaming path so
+    /// both interpret properties identically.
+    fn feature_to_event(&self, feature: &Feature) -> Result<Option<Event>> {
+        // Fast-reject using the feature's declared bbox before decoding its
+        // geometry and properties, when an import window is configured.
+        if let Some(filter) = &self.options.filter_bbox {
+            if let Some(feat_bbox) = feature.bbox.as_deref().and_then(GeoBounds::from_bbox) {
+                if !filter.intersects(&feat_bbox) {
+                    return Ok(None);
+                }
+            }
+        }
+
+        // Decode the geometry into a representative location and, for
+        // path-shaped features, the ordered trajectory vertices.
+        let (mut location, path) = match &feature.geometry {
+            Geometry::Point { coordinates } => match position_to_location(coordinates) {
+                Some(loc) => (loc, Vec::new()),
+                None => return Ok(None),
+            },
+            Geometry::LineString { coordinates } | Geometry::MultiPoint { coordinates } => {
+                let vertices: Vec<Location> = coordinates
+                    .iter()
+                    .filter_map(|c| position_to_location(c))
+                    .collect();
+                match vertices.first().cloned() {
+                    Some(first) => (first, vertices),
+                    None => return Ok(None),
+                }
+            }
+        };
+
+        let props = &feature.properties;
+
+        // Extract kinematic properties onto the representative location.
+        if self.options.include_kinematics {
+            location.speed = props
+                .get(&self.options.speed_property)
+                .and_then(|v| v.as_f64());
+            location.heading = props
+                .get(&self.options.heading_property)
+                .and_then(|v| v.as_f64());
+        }
+
+        // Extract timestamp
+        let timestamp = if let Some(ts_str) = props
+            .get(&self.options.timestamp_property)
+            .and_then(|v| v.as_str())
+        {
+            let mut ts = Timestamp::parse_flexible(ts_str)
+                .map_err(|e| Error::InvalidFormat(format!("invalid timestamp: {}", e)))?;
+            // Re-attach the source IANA zone when the feature recorded one.
+            if let Some(tz) = props
+                .get("timezone")
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.parse::<chrono_tz::Tz>().ok())
+            {
+                ts.zone = Some(tz);
+            }
+            ts
+        } else {
+            Timestamp::now() // Default to current time if not specified
+        };
+
+        // Build the event. A path-shaped feature becomes a trajectory whose
+        // representative location is the centroid of its vertices.
+        let mut event_builder = EventBuilder::new().timestamp(timestamp);
+        event_builder = if path.is_empty() {
+            event_builder.location(location)
+        } else {
+            event_builder.path(path)
+        };
+
+        // Extract text/description
+        if let Some(text) = props
+            .get(&self.options.text_property)
+            .and_then(|v| v.as_str())
+        {
+            event_builder = event_builder.text(text);
+        }
+
+        // Extract tags
+        if let Some(tags) = props.get("tags").and_then(|v| v.as_array()) {
+            for tag in tags {
+                if let Some(tag_str) = tag.as_str() {
+                    event_builder = event_builder.tag(tag_str);
+                }
+            }
+        }
+
+        // Extract source
+        if let Some(source_obj) = props.get("source").and_then(|v| v.as_object()) {
+            let source_type = source_obj
+                .get("type")
+                .and_then(|v| v.as_str())
+                .and_then(|s| match s.to_lowercase().as_str() {
+                    "article" => Some(SourceType::Article),
+                    "report" => Some(SourceType::Report),
+                    "witness" => Some(SourceType::Witness),
+                    "sensor" => Some(SourceType::Sensor),
+                    _ => None,
+                })
+                .unwrap_or(SourceType::Article);
+
+            let mut source = SourceRef::new(source_type);
+            if let Some(url) = source_obj.get("url").and_then(|v| v.as_str()) {
+                source.url = Some(url.to_string());
+            }
+            if let Some(title) = source_obj.get("title").and_then(|v| v.as_str()) {
+                source.title = Some(title.to_string());
+            }
+            event_builder = event_builder.source(source);
+        }
+
+        Ok(Some(event_builder.build()))
+    }
+
+    /// Encode a single [`Event`] into a GeoJSON [`Feature`].
+    ///
+    /// Shared by the buffered [`export`](Format::export) path and the
+    /// line-delimited streaming path.
+    fn event_to_feature(&self, event: &Event) -> Feature {
+        // A trajectory event exports as a LineString over its vertices; an
+        // ordinary event exports as a single Point.
+        let geometry = if event.is_trajectory() {
+            Geometry::LineString {
+                coordinates: event.path.iter().map(location_to_position).collect(),
+            }
+        } else {
+            Geometry::Point {
+                coordinates: location_to_position(&event.location),
+            }
+        };
+
+        let mut properties = Map::new();
+
+        // Add timestamp
+        properties.insert(
+            self.options.timestamp_property.clone(),
+            Value::String(event.timestamp.to_rfc3339()),
+        );
+
+        // Preserve the source IANA zone so local times survive a round-trip.
+        if let Some(zone) = event.timestamp.zone {
+            properties.insert(
+                "timezone".to_string(),
+                Value::String(zone.name().to_string()),
+            );
+        }
+
+        // Add text if present
+        properties.insert(
+            self.options.text_property.clone(),
+            Value::String(event.text.clone()),
+        );
+
+        // Add kinematic properties if enabled and present on the location.
+        if self.options.include_kinematics {
+            if let Some(speed) = event.location.speed.and_then(Number::from_f64) {
+                properties.insert(self.options.speed_property.clone(), Value::Number(speed));
+            }
+            if let Some(heading) = event.location.heading.and_then(Number::from_f64) {
+                properties.insert(
+                    self.options.heading_property.clone(),
+                    Value::Number(heading),
+                );
+            }
+        }
+
+        // Add tags if enabled and present
+        if self.options.include_tags && !event.tags.is_empty() {
+            let tags: Vec<Value> = event
+                .tags
+                .iter()
+                .map(|t| Value::String(t.clone()))
+                .collect();
+            properties.insert("tags".to_string(), Value::Array(tags));
+        }
+
+        // Add source if enabled and present
+        if self.options.include_sources && !event.sources.is_empty() {
+            let source = &event.sources[0]; // Use first source
+            let mut source_obj = Map::new();
+            source_obj.insert(
+                "type".to_string(),
+                Value::String(source.source_type.to_string()),
+            );
+            if let Some(url) = &source.url {
+                source_obj.insert("url".to_string(), Value::String(url.clone()));
+            }
+            if let Some(title) = &source.title {
+                source_obj.insert("title".to_string(), Value::String(title.clone()));
+            }
+            properties.insert("source".to_string(), Value::Object(source_obj));
+        }
+
+        Feature {
+            type_: "Feature".to_string(),
+            geometry,
+            properties,
+            bbox: None,
+            id: if self.options.include_ids {
+                Some(Value::String(event.id.to_string()))
+            } else {
+                None
+            },
+        }
+    }
 }
 
 /// Internal structure for GeoJSON FeatureCollection
@@ -99,6 +342,8 @@ struct FeatureCollection {
     #[serde(rename = "type")]
     type_: String,
     features: Vec<Feature>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bbox: Option<Vec<f64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     properties: Option<Map<String, Value>>,
 }
@@ -110,20 +355,58 @@ struct Feature {
     type_: String,
     geometry: Geometry,
     properties: Map<String, Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bbox: Option<Vec<f64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     id: Option<Value>,
 }
 
-/// Internal structure for GeoJSON Geometry
+/// Internal representation of a GeoJSON geometry.
+///
+/// Modeled as an enum tagged on `type` so the coordinate shape — a single
+/// position for `Point`, an ordered list for `LineString`/`MultiPoint` — is
+/// preserved across the serde round-trip. Geometry types the crate does not
+/// model (polygons, collections) fail deserialization, as they did before.
 #[derive(Debug, Serialize, Deserialize)]
-struct Geometry {
-    #[serde(rename = "type")]
-    type_: String,
-    coordinates: Vec<f64>,
+#[serde(tag = "type")]
+enum Geometry {
+    Point { coordinates: Vec<f64> },
+    LineString { coordinates: Vec<Vec<f64>> },
+    MultiPoint { coordinates: Vec<Vec<f64>> },
+}
+
+/// Build a [`Location`] from a GeoJSON position (`[lon, lat]` or `[lon, lat, elev]`).
+fn position_to_location(coords: &[f64]) -> Option<Location> {
+    if coords.len() < 2 {
+        return None;
+    }
+    let mut location = Location::new(coords[1], coords[0]);
+    if let Some(elev) = coords.get(2).copied() {
+        location.elevation = Some(elev);
+    }
+    Some(location)
+}
+
+/// Render a [`Location`] back to a GeoJSON position, including elevation when set.
+fn location_to_position(loc: &Location) -> Vec<f64> {
+    match loc.elevation {
+        Some(elev) => vec![loc.lon, loc.lat, elev],
+        None => vec![loc.lon, loc.lat],
+    }
 }
 
 impl Format for GeoJsonFormat {
     fn import<R: Read>(&self, reader: R) -> Result<Narrative> {
+        // In line-delimited mode there is no wrapping collection or
+        // narrative-level metadata; fold the streamed events into a narrative.
+        if self.options.line_delimited {
+            let mut builder = NarrativeBuilder::new();
+            for event in self.import_iter(reader)? {
+                builder = builder.event(event?);
+            }
+            return Ok(builder.build());
+        }
+
         let fc: FeatureCollection = serde_json::from_reader(reader)?;
 
         if fc.type_ != "FeatureCollection" {
@@ -145,158 +428,55 @@ impl Format for GeoJsonFormat {
         }
 
         // Convert each feature to an event
-        for feature in fc.features {
-            if feature.geometry.type_ != "Point" {
-                continue; // Skip non-point geometries
-            }
-
-            let coords = &feature.geometry.coordinates;
-            if coords.len() < 2 {
-                continue; // Invalid coordinates
-            }
-
-            let lon = coords[0];
-            let lat = coords[1];
-            let mut location = Location::new(lat, lon);
-            if let Some(elev) = coords.get(2).copied() {
-                location.elevation = Some(elev);
-            }
-
-            let props = &feature.properties;
-
-            // Extract timestamp
-            let timestamp = if let Some(ts_str) = props
-                .get(&self.options.timestamp_property)
-                .and_then(|v| v.as_str())
-            {
-                Timestamp::parse(ts_str)
-                    .map_err(|e| Error::InvalidFormat(format!("invalid timestamp: {}", e)))?
-            } else {
-                Timestamp::now() // Default to current time if not specified
-            };
-
-            // Build the event
-            let mut event_builder = EventBuilder::new().location(location).timestamp(timestamp);
-
-            // Extract text/description
-            if let Some(text) = props
-                .get(&self.options.text_property)
-                .and_then(|v| v.as_str())
-            {
-                event_builder = event_builder.text(text);
-            }
-
-            // Extract tags
-            if let Some(tags) = props.get("tags").and_then(|v| v.as_array()) {
-                for tag in tags {
-                    if let Some(tag_str) = tag.as_str() {
-                        event_builder = event_builder.tag(tag_str);
-                    }
-                }
-            }
-
-            // Extract source
-            if let Some(source_obj) = props.get("source").and_then(|v| v.as_object()) {
-                let source_type = source_obj
-                    .get("type")
-                    .and_then(|v| v.as_str())
-                    .and_then(|s| match s.to_lowercase().as_str() {
-                        "article" => Some(SourceType::Article),
-                        "report" => Some(SourceType::Report),
-                        "witness" => Some(SourceType::Witness),
-                        "sensor" => Some(SourceType::Sensor),
-                        _ => None,
-                    })
-                    .unwrap_or(SourceType::Article);
-
-                let mut source = SourceRef::new(source_type);
-                if let Some(url) = source_obj.get("url").and_then(|v| v.as_str()) {
-                    source.url = Some(url.to_string());
-                }
-                if let Some(title) = source_obj.get("title").and_then(|v| v.as_str()) {
-                    source.title = Some(title.to_string());
-                }
-                event_builder = event_builder.source(source);
+        for feature in &fc.features {
+            if let Some(event) = self.feature_to_event(feature)? {
+                builder = builder.event(event);
             }
-
-            let event = event_builder.build();
-            builder = builder.event(event);
         }
 
         Ok(builder.build())
     }
 
-    fn export<W: Write>(&self, narrative: &Narrative, mut writer: W) -> Result<()> {
-        let mut features = Vec::new();
-
-        for event in narrative.events() {
-            let loc = &event.location;
-            let coords = if let Some(elev) = loc.elevation {
-                vec![loc.lon, loc.lat, elev]
-            } else {
-                vec![loc.lon, loc.lat]
-            };
+    fn import_iter<'r, R: Read + 'r>(&self, reader: R) -> Result<EventStream<'r>> {
+        // Only line-delimited input decodes incrementally; a wrapping
+        // FeatureCollection must be parsed whole, so fall back to the default.
+        if !self.options.line_delimited {
+            let narrative = self.import(reader)?;
+            let events: Vec<Event> = narrative.events().to_vec();
+            return Ok(Box::new(events.into_iter().map(Ok)));
+        }
 
-            let geometry = Geometry {
-                type_: "Point".to_string(),
-                coordinates: coords,
+        let format = self.clone();
+        let iter = BufReader::new(reader).lines().filter_map(move |line| {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(Error::from(e))),
             };
-
-            let mut properties = Map::new();
-
-            // Add timestamp
-            properties.insert(
-                self.options.timestamp_property.clone(),
-                Value::String(event.timestamp.to_rfc3339()),
-            );
-
-            // Add text if present
-            properties.insert(
-                self.options.text_property.clone(),
-                Value::String(event.text.clone()),
-            );
-
-            // Add tags if enabled and present
-            if self.options.include_tags && !event.tags.is_empty() {
-                let tags: Vec<Value> = event
-                    .tags
-                    .iter()
-                    .map(|t| Value::String(t.clone()))
-                    .collect();
-                properties.insert("tags".to_string(), Value::Array(tags));
+            // Tolerate blank lines and trailing newlines.
+            if line.trim().is_empty() {
+                return None;
             }
-
-            // Add source if enabled and present
-            if self.options.include_sources && !event.sources.is_empty() {
-                let source = &event.sources[0]; // Use first source
-                let mut source_obj = Map::new();
-                source_obj.insert(
-                    "type".to_string(),
-                    Value::String(source.source_type.to_string()),
-                );
-                if let Some(url) = &source.url {
-                    source_obj.insert("url".to_string(), Value::String(url.clone()));
-                }
-                if let Some(title) = &source.title {
-                    source_obj.insert("title".to_string(), Value::String(title.clone()));
-                }
-                properties.insert("source".to_string(), Value::Object(source_obj));
+            match serde_json::from_str::<Feature>(&line).map_err(Error::from) {
+                Ok(feature) => format.feature_to_event(&feature).transpose(),
+                Err(e) => Some(Err(e)),
             }
+        });
+        Ok(Box::new(iter))
+    }
 
-            let feature = Feature {
-                type_: "Feature".to_string(),
-                geometry,
-                properties,
-                id: if self.options.include_ids {
-                    Some(Value::String(event.id.to_string()))
-                } else {
-                    None
-                },
-            };
-
-            features.push(feature);
+    fn export<W: Write>(&self, narrative: &Narrative, mut writer: W) -> Result<()> {
+        // In line-delimited mode each feature stands alone on its own line with
+        // no wrapping collection or narrative-level extent.
+        if self.options.line_delimited {
+            return self.export_to(narrative.events().iter().cloned(), writer);
         }
 
+        let features: Vec<Feature> = narrative
+            .events()
+            .iter()
+            .map(|event| self.event_to_feature(event))
+            .collect();
+
         // Add narrative-level metadata
         let mut fc_properties = Map::new();
         fc_properties.insert("title".to_string(), Value::String(narrative.title.clone()));
@@ -304,9 +484,30 @@ impl Format for GeoJsonFormat {
             fc_properties.insert("description".to_string(), Value::String(desc.clone()));
         }
 
+        // STAC-style spatial/temporal extent, computed from the narrative.
+        let bbox = if self.options.include_bbox {
+            narrative.bounds().map(|b| b.to_bbox())
+        } else {
+            None
+        };
+        if self.options.include_bbox {
+            if let Some(range) = narrative.time_range() {
+                let start = range.start.to_rfc3339();
+                let end = range.end.to_rfc3339();
+                // A zero-width range collapses to a single `datetime`, per STAC.
+                if start == end {
+                    fc_properties.insert("datetime".to_string(), Value::String(start));
+                } else {
+                    fc_properties.insert("start_datetime".to_string(), Value::String(start));
+                    fc_properties.insert("end_datetime".to_string(), Value::String(end));
+                }
+            }
+        }
+
         let fc = FeatureCollection {
             type_: "FeatureCollection".to_string(),
             features,
+            bbox,
             properties: if fc_properties.is_empty() {
                 None
             } else {
@@ -317,6 +518,32 @@ impl Format for GeoJsonFormat {
         serde_json::to_writer_pretty(&mut writer, &fc)?;
         Ok(())
     }
+
+    fn export_to<W, I>(&self, events: I, mut writer: W) -> Result<()>
+    where
+        W: Write,
+        I: IntoIterator<Item = Event>,
+    {
+        // Without line-delimited framing there is nothing to stream — fall back
+        // to buffering into a narrative and writing a single FeatureCollection.
+        if !self.options.line_delimited {
+            let narrative = events
+                .into_iter()
+                .fold(NarrativeBuilder::new(), |builder, event| {
+                    builder.event(event)
+                })
+                .build();
+            return self.export(&narrative, writer);
+        }
+
+        // GeoJSONL: one compact Feature object per line, appendable in place.
+        for event in events {
+            let feature = self.event_to_feature(&event);
+            serde_json::to_writer(&mut writer, &feature)?;
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -375,6 +602,214 @@ mod tests {
         assert_eq!(imported.title, "Test Narrative");
     }
 
+    #[test]
+    fn test_geojson_linestring_trajectory() {
+        let geojson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "geometry": {
+                        "type": "LineString",
+                        "coordinates": [[-74.0, 40.0], [-73.0, 41.0], [-72.0, 42.0]]
+                    },
+                    "properties": {
+                        "text": "A ship's route",
+                        "timestamp": "2024-01-15T14:30:00Z"
+                    }
+                }
+            ]
+        }"#;
+
+        let format = GeoJsonFormat::new();
+        let narrative = format.import_str(geojson).unwrap();
+        let event = &narrative.events()[0];
+        assert!(event.is_trajectory());
+        assert_eq!(event.path.len(), 3);
+        // The representative location is the centroid of the vertices.
+        assert!((event.location.lat - 41.0).abs() < 1e-9);
+        assert!((event.location.lon - (-73.0)).abs() < 1e-9);
+
+        // Re-export preserves the LineString geometry.
+        let exported = format.export_str(&narrative).unwrap();
+        let reimported = format.import_str(&exported).unwrap();
+        assert_eq!(reimported.events()[0].path.len(), 3);
+    }
+
+    #[test]
+    fn test_geojson_export_emits_bbox_and_extent() {
+        let narrative = Narrative::builder()
+            .title("Extent test")
+            .event(
+                Event::builder()
+                    .location(Location::new(40.0, -74.0))
+                    .timestamp(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+                    .text("a")
+                    .build(),
+            )
+            .event(
+                Event::builder()
+                    .location(Location::new(42.0, -71.0))
+                    .timestamp(Timestamp::parse("2024-06-01T00:00:00Z").unwrap())
+                    .text("b")
+                    .build(),
+            )
+            .build();
+
+        let exported = GeoJsonFormat::new().export_str(&narrative).unwrap();
+        let value: Value = serde_json::from_str(&exported).unwrap();
+
+        let bbox = value["bbox"].as_array().unwrap();
+        assert_eq!(bbox.len(), 4);
+        assert_eq!(bbox[0].as_f64().unwrap(), -74.0); // min_lon
+        assert_eq!(bbox[2].as_f64().unwrap(), -71.0); // max_lon
+
+        let props = value["properties"].as_object().unwrap();
+        assert!(props.contains_key("start_datetime"));
+        assert!(props.contains_key("end_datetime"));
+    }
+
+    #[test]
+    fn test_geojson_import_bbox_fast_reject() {
+        let geojson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "bbox": [10.0, 10.0, 11.0, 11.0],
+                    "geometry": { "type": "Point", "coordinates": [10.5, 10.5] },
+                    "properties": { "timestamp": "2024-01-15T14:30:00Z" }
+                },
+                {
+                    "type": "Feature",
+                    "bbox": [-74.0, 40.0, -73.0, 41.0],
+                    "geometry": { "type": "Point", "coordinates": [-73.5, 40.5] },
+                    "properties": { "timestamp": "2024-01-15T14:30:00Z" }
+                }
+            ]
+        }"#;
+
+        let options = GeoJsonOptions {
+            filter_bbox: Some(GeoBounds::new(40.0, -74.0, 41.0, -73.0)),
+            ..Default::default()
+        };
+        let narrative = GeoJsonFormat::with_options(options)
+            .import_str(geojson)
+            .unwrap();
+        // Only the New York feature survives the window.
+        assert_eq!(narrative.events().len(), 1);
+        assert!((narrative.events()[0].location.lat - 40.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_geojsonl_roundtrip_and_stream() {
+        let options = GeoJsonOptions {
+            line_delimited: true,
+            ..Default::default()
+        };
+        let format = GeoJsonFormat::with_options(options);
+
+        let narrative = Narrative::builder()
+            .title("Tracker feed")
+            .event(
+                Event::builder()
+                    .location(Location::new(40.0, -74.0))
+                    .timestamp(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+                    .text("first")
+                    .build(),
+            )
+            .event(
+                Event::builder()
+                    .location(Location::new(41.0, -73.0))
+                    .timestamp(Timestamp::parse("2024-01-01T00:01:00Z").unwrap())
+                    .text("second")
+                    .build(),
+            )
+            .build();
+
+        // One Feature per line, no wrapping FeatureCollection.
+        let exported = format.export_str(&narrative).unwrap();
+        assert_eq!(exported.lines().count(), 2);
+        for line in exported.lines() {
+            let value: Value = serde_json::from_str(line).unwrap();
+            assert_eq!(value["type"], "Feature");
+        }
+
+        // Streaming import yields each event without buffering the document.
+        let texts: Vec<String> = format
+            .import_iter(exported.as_bytes())
+            .unwrap()
+            .map(|e| e.unwrap().text.to_string())
+            .collect();
+        assert_eq!(texts, vec!["first", "second"]);
+
+        // Buffered import reconstructs the same events.
+        let reimported = format.import_str(&exported).unwrap();
+        assert_eq!(reimported.events().len(), 2);
+    }
+
+    #[test]
+    fn test_geojson_kinematics_roundtrip() {
+        let geojson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "geometry": { "type": "Point", "coordinates": [-74.0, 40.0, 12.0] },
+                    "properties": {
+                        "timestamp": "2024-01-15T14:30:00Z",
+                        "speed": 8.5,
+                        "heading": 270.0
+                    }
+                }
+            ]
+        }"#;
+
+        let format = GeoJsonFormat::new();
+        let narrative = format.import_str(geojson).unwrap();
+        let event = &narrative.events()[0];
+        assert_eq!(event.location.speed, Some(8.5));
+        assert_eq!(event.location.heading, Some(270.0));
+
+        // Speed and heading survive the export/import cycle.
+        let exported = format.export_str(&narrative).unwrap();
+        let reimported = format.import_str(&exported).unwrap();
+        assert_eq!(reimported.events()[0].location.speed, Some(8.5));
+        assert_eq!(reimported.events()[0].location.heading, Some(270.0));
+    }
+
+    #[test]
+    fn test_geojson_timezone_roundtrip() {
+        let geojson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "geometry": { "type": "Point", "coordinates": [13.405, 52.52] },
+                    "properties": {
+                        "timestamp": "2024-03-15T14:30:00+01:00",
+                        "timezone": "Europe/Berlin"
+                    }
+                }
+            ]
+        }"#;
+
+        let format = GeoJsonFormat::new();
+        let narrative = format.import_str(geojson).unwrap();
+        let ts = &narrative.events()[0].timestamp;
+        assert_eq!(ts.zone, Some(chrono_tz::Europe::Berlin));
+
+        // The zone survives export (as a `timezone` property) and re-import.
+        let exported = format.export_str(&narrative).unwrap();
+        let value: Value = serde_json::from_str(&exported).unwrap();
+        assert_eq!(value["features"][0]["properties"]["timezone"], "Europe/Berlin");
+        let reimported = format.import_str(&exported).unwrap();
+        assert_eq!(
+            reimported.events()[0].timestamp.zone,
+            Some(chrono_tz::Europe::Berlin)
+        );
+    }
+
     #[test]
     fn test_geojson_with_elevation() {
         let geojson = r#"{
@@ -0,0 +1,290 @@
+//! GPX (GPS Exchange Format) import/export.
+//!
+//! Gated behind the optional `gpx` feature. Narratives map onto GPX either as a
+//! flat list of `<wpt>` waypoints or, for an ordered track log, a single
+//! `<trkseg>` of `<trkpt>`s; the choice is controlled by [`GpxOptions`].
+
+use super::format::Format;
+use crate::core::{Event, EventBuilder, Location, Narrative, NarrativeBuilder, Timestamp};
+use crate::{Error, Result};
+use chrono::{TimeZone, Utc};
+use gpx::{Gpx, GpxVersion, Track, TrackSegment, Waypoint};
+use std::io::{Read, Write};
+use time::OffsetDateTime;
+
+/// GPX format handler.
+///
+/// Reads and writes GPS Exchange Format documents. On export each [`Event`]
+/// becomes a waypoint (or track point) carrying its coordinates, timestamp,
+/// text, and tags; on import waypoints and track points are folded back into a
+/// single [`Narrative`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use spatial_narrative::io::{GpxFormat, Format};
+/// use spatial_narrative::prelude::*;
+///
+/// let narrative = Narrative::builder()
+///     .event(
+///         Event::builder()
+///             .location(Location::new(40.7128, -74.006))
+///             .timestamp(Timestamp::now())
+///             .text("start")
+///             .build(),
+///     )
+///     .build();
+///
+/// let gpx = GpxFormat::default().export_str(&narrative).unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GpxFormat {
+    /// Options controlling import/export behavior.
+    pub options: GpxOptions,
+}
+
+/// Configuration options for GPX import/export.
+#[derive(Debug, Clone, Default)]
+pub struct GpxOptions {
+    /// Emit events as an ordered `<trkseg>` rather than standalone `<wpt>`s.
+    ///
+    /// When set, exported events are sorted chronologically and written as the
+    /// track points of a single segment — the shape a GPS logger records.
+    /// Otherwise each event is an independent waypoint.
+    pub as_track: bool,
+
+    /// Name assigned to the exported track (used only when `as_track` is set).
+    pub track_name: Option<String>,
+
+    /// Tag marking an event as a standalone waypoint rather than part of the
+    /// track (used only when `as_track` is set).
+    ///
+    /// Events carrying this tag are exported as independent `<wpt>`s
+    /// alongside the `<trkseg>` built from the remaining events — mixing
+    /// point-of-interest markers into the same document as the movement
+    /// track, the way a GPS logger keeps manual waypoints separate from its
+    /// automatic track log.
+    pub waypoint_tag: Option<String>,
+}
+
+impl GpxFormat {
+    /// Create a new GPX format handler with default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a GPX format handler that exports a single track segment.
+    pub fn track() -> Self {
+        Self {
+            options: GpxOptions {
+                as_track: true,
+                ..GpxOptions::default()
+            },
+        }
+    }
+
+    /// Create a GPX format handler with custom options.
+    pub fn with_options(options: GpxOptions) -> Self {
+        Self { options }
+    }
+
+    /// Render an [`Event`] as a GPX waypoint.
+    fn event_to_waypoint(&self, event: &Event) -> Waypoint {
+        // GPX coordinates are (lon, lat).
+        let mut wpt = Waypoint::new(geo_types::Point::new(event.location.lon, event.location.lat));
+        wpt.elevation = event.location.elevation;
+        wpt.time = chrono_to_gpx_time(&event.timestamp);
+
+        if !event.text.is_empty() {
+            wpt.name = event.text.lines().next().map(str::to_string);
+            wpt.description = Some(event.text.clone());
+        }
+
+        if !event.tags.is_empty() {
+            wpt.type_ = Some(event.tags.join(","));
+        }
+
+        wpt
+    }
+
+    /// Rebuild an [`Event`] from a GPX waypoint or track point.
+    fn waypoint_to_event(&self, wpt: &Waypoint) -> Event {
+        let point = wpt.point();
+        let mut location = Location::new(point.y(), point.x());
+        location.elevation = wpt.elevation;
+
+        let timestamp = wpt
+            .time
+            .and_then(gpx_to_chrono_time)
+            .unwrap_or_else(Timestamp::now);
+
+        let mut builder = EventBuilder::new().location(location).timestamp(timestamp);
+
+        if let Some(text) = wpt.description.clone().or_else(|| wpt.name.clone()) {
+            builder = builder.text(text);
+        }
+
+        if let Some(tags) = &wpt.type_ {
+            for tag in tags.split(',') {
+                let trimmed = tag.trim();
+                if !trimmed.is_empty() {
+                    builder = builder.tag(trimmed);
+                }
+            }
+        }
+
+        builder.build()
+    }
+}
+
+impl Format for GpxFormat {
+    fn import<R: Read>(&self, reader: R) -> Result<Narrative> {
+        let doc = gpx::read(reader)
+            .map_err(|e| Error::InvalidFormat(format!("invalid GPX: {}", e)))?;
+
+        let mut builder = NarrativeBuilder::new();
+
+        for wpt in &doc.waypoints {
+            builder = builder.event(self.waypoint_to_event(wpt));
+        }
+        for track in &doc.tracks {
+            for segment in &track.segments {
+                for wpt in &segment.points {
+                    builder = builder.event(self.waypoint_to_event(wpt));
+                }
+            }
+        }
+
+        Ok(builder.build())
+    }
+
+    fn export<W: Write>(&self, narrative: &Narrative, writer: W) -> Result<()> {
+        let mut doc = Gpx {
+            version: GpxVersion::Gpx11,
+            ..Default::default()
+        };
+
+        if self.options.as_track {
+            let (waypoints, track_events): (Vec<&Event>, Vec<&Event>) =
+                match &self.options.waypoint_tag {
+                    Some(tag) => narrative
+                        .events_chronological()
+                        .into_iter()
+                        .partition(|event| event.has_tag(tag)),
+                    None => (Vec::new(), narrative.events_chronological()),
+                };
+
+            doc.waypoints = waypoints
+                .into_iter()
+                .map(|event| self.event_to_waypoint(event))
+                .collect();
+
+            let mut segment = TrackSegment::new();
+            segment.points = track_events
+                .into_iter()
+                .map(|event| self.event_to_waypoint(event))
+                .collect();
+
+            let mut track = Track::new();
+            track.name = self.options.track_name.clone();
+            track.segments.push(segment);
+            doc.tracks.push(track);
+        } else {
+            doc.waypoints = narrative
+                .events()
+                .iter()
+                .map(|event| self.event_to_waypoint(event))
+                .collect();
+        }
+
+        gpx::write(&doc, writer).map_err(|e| Error::InvalidFormat(format!("GPX write: {}", e)))
+    }
+}
+
+/// Convert a [`Timestamp`] into the `gpx` crate's time type.
+fn chrono_to_gpx_time(ts: &Timestamp) -> Option<gpx::Time> {
+    OffsetDateTime::from_unix_timestamp(ts.datetime.timestamp())
+        .ok()
+        .map(gpx::Time::from)
+}
+
+/// Convert the `gpx` crate's time type back into a [`Timestamp`].
+fn gpx_to_chrono_time(time: gpx::Time) -> Option<Timestamp> {
+    let odt = OffsetDateTime::from(time);
+    Utc.timestamp_opt(odt.unix_timestamp(), 0)
+        .single()
+        .map(Timestamp::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_narrative() -> Narrative {
+        Narrative::builder()
+            .event(
+                Event::builder()
+                    .location(Location::new(40.7128, -74.006))
+                    .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+                    .text("start")
+                    .tag("walk")
+                    .build(),
+            )
+            .event(
+                Event::builder()
+                    .location(Location::new(40.73, -74.0))
+                    .timestamp(Timestamp::parse("2024-01-15T15:00:00Z").unwrap())
+                    .text("end")
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_gpx_waypoint_round_trip() {
+        let narrative = sample_narrative();
+        let format = GpxFormat::new();
+
+        let gpx = format.export_str(&narrative).unwrap();
+        let imported = format.import_str(&gpx).unwrap();
+
+        assert_eq!(imported.events().len(), 2);
+        let first = &imported.events()[0];
+        assert!((first.location.lat - 40.7128).abs() < 1e-6);
+        assert!((first.location.lon + 74.006).abs() < 1e-6);
+        assert_eq!(first.text, "start");
+        assert_eq!(first.tags, vec!["walk".to_string()]);
+    }
+
+    #[test]
+    fn test_gpx_track_export_is_chronological() {
+        let narrative = sample_narrative();
+        let format = GpxFormat::track();
+
+        let gpx = format.export_str(&narrative).unwrap();
+        assert!(gpx.contains("<trkpt") || gpx.contains("<trkseg"));
+
+        let imported = format.import_str(&gpx).unwrap();
+        assert_eq!(imported.events().len(), 2);
+        assert!(imported.events()[0].timestamp < imported.events()[1].timestamp);
+    }
+
+    #[test]
+    fn test_gpx_track_splits_tagged_waypoints() {
+        let mut narrative = sample_narrative();
+        narrative.events_mut()[0].tags.push("poi".to_string());
+
+        let format = GpxFormat::with_options(GpxOptions {
+            as_track: true,
+            waypoint_tag: Some("poi".to_string()),
+            ..GpxOptions::default()
+        });
+
+        let gpx = format.export_str(&narrative).unwrap();
+        assert!(gpx.contains("<wpt"));
+        assert!(gpx.contains("<trkpt"));
+
+        let imported = format.import_str(&gpx).unwrap();
+        assert_eq!(imported.events().len(), 2);
+    }
+}
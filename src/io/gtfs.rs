@@ -0,0 +1,579 @@
+//! GTFS transit-feed import.
+//!
+//! Reads a [GTFS](https://gtfs.org/) feed — a directory of CSV files such as
+//! `stops.txt`, `trips.txt`, `stop_times.txt`, and `calendar.txt` — and
+//! materializes it as a [`Narrative`] whose events are scheduled stop arrivals.
+//! Each `stop_times` row is joined with its stop, trip, and route to produce a
+//! [`Location`] (from `stop_lat`/`stop_lon`), a [`Timestamp`] (the service
+//! date plus the arrival time, honouring the GTFS convention where times past
+//! `24:00:00` roll into the following day), and a text like "Route 7 arriving
+//! at Main St". `route_id`/`trip_id` and, when `agency.txt` resolves one, the
+//! operating agency's name are carried as tags; `trip_id`/`stop_sequence` are
+//! also carried as metadata for callers that want them without re-parsing tags.
+
+use super::format::Format;
+use crate::analysis::Trajectory;
+use crate::core::{Event, EventBuilder, Location, Narrative, NarrativeBuilder, Timestamp};
+use crate::routing::{Route, StopTime, Timetable, Trip};
+use crate::{Error, Result};
+use chrono::{NaiveDate, TimeZone, Utc};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::Path;
+
+/// Importer for GTFS transit feeds stored as a directory of CSV files.
+///
+/// # Example
+///
+/// ```no_run
+/// use spatial_narrative::io::GtfsFormat;
+///
+/// let feed = GtfsFormat::new();
+/// let narrative = feed.import_feed("path/to/gtfs").unwrap();
+/// let one_route = feed.import_route("path/to/gtfs", "RED").unwrap();
+/// let per_trip = feed.import_by_trip("path/to/gtfs").unwrap();
+/// let trajectories = feed.import_trajectories("path/to/gtfs").unwrap();
+/// let timetable = feed.import_timetable("path/to/gtfs").unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GtfsFormat;
+
+impl GtfsFormat {
+    /// Create a new GTFS importer.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Import the entire feed as a single narrative.
+    pub fn import_feed(&self, dir: impl AsRef<Path>) -> Result<Narrative> {
+        self.import_filtered(dir.as_ref(), "GTFS feed", |_trip, _route| true)
+    }
+
+    /// Import only the stop arrivals belonging to a single trip.
+    pub fn import_trip(&self, dir: impl AsRef<Path>, trip_id: &str) -> Result<Narrative> {
+        self.import_filtered(dir.as_ref(), &format!("GTFS trip {}", trip_id), |trip, _route| {
+            trip == trip_id
+        })
+    }
+
+    /// Import only the stop arrivals belonging to a single route.
+    pub fn import_route(&self, dir: impl AsRef<Path>, route_id: &str) -> Result<Narrative> {
+        self.import_filtered(dir.as_ref(), &format!("GTFS route {}", route_id), |_trip, route| {
+            route == route_id
+        })
+    }
+
+    /// Import the feed as one narrative per trip.
+    ///
+    /// Each narrative's title combines the route's `route_short_name` (falling
+    /// back to the `route_id` when no `routes.txt` entry is found) with the
+    /// `trip_id`, so downstream [`SpatialMetrics`](crate::analysis::SpatialMetrics)
+    /// and [`TemporalMetrics`](crate::analysis::TemporalMetrics) can be run over
+    /// individual vehicle trajectories.
+    pub fn import_by_trip(&self, dir: impl AsRef<Path>) -> Result<Vec<Narrative>> {
+        let dir = dir.as_ref();
+        let routes: HashMap<String, GtfsRoute> = read_table::<GtfsRoute>(dir, "routes.txt")?
+            .into_iter()
+            .map(|r| (r.route_id.clone(), r))
+            .collect();
+        let trips: HashMap<String, GtfsTrip> = read_table::<GtfsTrip>(dir, "trips.txt")?
+            .into_iter()
+            .map(|t| (t.trip_id.clone(), t))
+            .collect();
+
+        let mut trip_ids: Vec<&String> = trips.keys().collect();
+        trip_ids.sort();
+
+        trip_ids
+            .into_iter()
+            .map(|trip_id| {
+                let trip = &trips[trip_id];
+                let route_label = routes
+                    .get(&trip.route_id)
+                    .and_then(|r| r.route_short_name.clone())
+                    .unwrap_or_else(|| trip.route_id.clone());
+                self.import_filtered(
+                    dir,
+                    &format!("GTFS trip {} ({})", trip_id, route_label),
+                    |t, _route| t == trip_id,
+                )
+            })
+            .collect()
+    }
+
+    /// Join the feed tables, keep the stop-times matching `keep`, and build the
+    /// narrative in `(trip_id, stop_sequence)` order.
+    fn import_filtered(
+        &self,
+        dir: &Path,
+        title: &str,
+        keep: impl Fn(&str, &str) -> bool,
+    ) -> Result<Narrative> {
+        let events = self.collect_events(dir, keep)?;
+        let mut builder = NarrativeBuilder::new().title(title);
+        for event in events {
+            builder = builder.event(event);
+        }
+        Ok(builder.build())
+    }
+
+    /// Import the feed as one [`Trajectory`] per trip, for use with
+    /// [`MovementAnalyzer`](crate::analysis::MovementAnalyzer) and
+    /// [`detect_stops`](crate::analysis::detect_stops).
+    pub fn import_trajectories(&self, dir: impl AsRef<Path>) -> Result<Vec<Trajectory>> {
+        let dir = dir.as_ref();
+        let trips: HashMap<String, GtfsTrip> = read_table::<GtfsTrip>(dir, "trips.txt")?
+            .into_iter()
+            .map(|t| (t.trip_id.clone(), t))
+            .collect();
+
+        let mut trip_ids: Vec<&String> = trips.keys().collect();
+        trip_ids.sort();
+
+        trip_ids
+            .into_iter()
+            .map(|trip_id| {
+                let events = self.collect_events(dir, |t, _route| t == trip_id)?;
+                Ok(Trajectory::new(trip_id.clone(), events))
+            })
+            .collect()
+    }
+
+    /// Import the feed as a [`Timetable`] for use with
+    /// [`RaptorPlanner`](crate::routing::RaptorPlanner).
+    ///
+    /// Trips are grouped by `route_id`; a route's stop order is taken from the
+    /// first trip encountered for it (by trip ID), and later trips on the
+    /// same route are skipped if their stop pattern has a different length,
+    /// since RAPTOR's route scan requires every trip on a route to share one
+    /// ordered stop list. `arrival_time`/`departure_time` are parsed as
+    /// seconds since service-date midnight, honouring GTFS's past-midnight
+    /// (`>24:00:00`) convention.
+    pub fn import_timetable(&self, dir: impl AsRef<Path>) -> Result<Timetable> {
+        let dir = dir.as_ref();
+        let trips: HashMap<String, GtfsTrip> = read_table::<GtfsTrip>(dir, "trips.txt")?
+            .into_iter()
+            .map(|t| (t.trip_id.clone(), t))
+            .collect();
+
+        let mut stop_times = read_table::<GtfsStopTime>(dir, "stop_times.txt")?;
+        stop_times.sort_by(|a, b| {
+            a.trip_id
+                .cmp(&b.trip_id)
+                .then(a.stop_sequence.cmp(&b.stop_sequence))
+        });
+
+        let mut by_trip: HashMap<String, Vec<&GtfsStopTime>> = HashMap::new();
+        for st in &stop_times {
+            by_trip.entry(st.trip_id.clone()).or_default().push(st);
+        }
+
+        let mut trip_ids: Vec<&String> = trips.keys().collect();
+        trip_ids.sort();
+
+        let mut route_stops: HashMap<String, Vec<String>> = HashMap::new();
+        let mut route_trips: HashMap<String, Vec<Trip>> = HashMap::new();
+
+        for trip_id in trip_ids {
+            let trip = &trips[trip_id];
+            let Some(times) = by_trip.get(trip_id) else {
+                continue;
+            };
+            let stops: Vec<String> = times.iter().map(|st| st.stop_id.clone()).collect();
+            let canonical = route_stops
+                .entry(trip.route_id.clone())
+                .or_insert_with(|| stops.clone());
+            if canonical.len() != stops.len() {
+                continue;
+            }
+
+            let stop_times = times
+                .iter()
+                .map(|st| {
+                    Ok(StopTime {
+                        arrival: parse_time_of_day(&st.arrival_time)?,
+                        departure: parse_time_of_day(&st.departure_time)?,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            route_trips
+                .entry(trip.route_id.clone())
+                .or_default()
+                .push(Trip {
+                    id: trip.trip_id.clone(),
+                    stop_times,
+                });
+        }
+
+        let mut route_ids: Vec<&String> = route_stops.keys().collect();
+        route_ids.sort();
+
+        let mut timetable = Timetable::new();
+        for route_id in route_ids {
+            let mut trips = route_trips.remove(route_id).unwrap_or_default();
+            trips.sort_by_key(|t| t.stop_times.first().map(|st| st.departure).unwrap_or(0));
+            timetable.add_route(Route {
+                id: route_id.clone(),
+                stops: route_stops[route_id].clone(),
+                trips,
+            });
+        }
+
+        Ok(timetable)
+    }
+
+    /// Join the feed tables and return the stop-arrival events matching
+    /// `keep`, in `(trip_id, stop_sequence)` order.
+    fn collect_events(&self, dir: &Path, keep: impl Fn(&str, &str) -> bool) -> Result<Vec<Event>> {
+        let stops: HashMap<String, GtfsStop> = read_table::<GtfsStop>(dir, "stops.txt")?
+            .into_iter()
+            .map(|s| (s.stop_id.clone(), s))
+            .collect();
+        let trips: HashMap<String, GtfsTrip> = read_table::<GtfsTrip>(dir, "trips.txt")?
+            .into_iter()
+            .map(|t| (t.trip_id.clone(), t))
+            .collect();
+        let routes: HashMap<String, GtfsRoute> = read_table::<GtfsRoute>(dir, "routes.txt")?
+            .into_iter()
+            .map(|r| (r.route_id.clone(), r))
+            .collect();
+        let services: HashMap<String, NaiveDate> = read_table::<GtfsCalendar>(dir, "calendar.txt")?
+            .into_iter()
+            .filter_map(|c| {
+                NaiveDate::parse_from_str(&c.start_date, "%Y%m%d")
+                    .ok()
+                    .map(|d| (c.service_id, d))
+            })
+            .collect();
+
+        // agency.txt is optional in GTFS; a missing file just means no
+        // agency tag is attached.
+        let agency_rows = read_table_optional::<GtfsAgency>(dir, "agency.txt")?;
+        let agency_by_id: HashMap<String, String> = agency_rows
+            .iter()
+            .filter_map(|a| a.agency_id.clone().map(|id| (id, a.agency_name.clone())))
+            .collect();
+        // Most feeds serve a single agency and often omit agency_id on both
+        // agency.txt and routes.txt in that case; fall back to it by name.
+        let single_agency_name = match agency_rows.as_slice() {
+            [agency] => Some(agency.agency_name.clone()),
+            _ => None,
+        };
+
+        let mut stop_times = read_table::<GtfsStopTime>(dir, "stop_times.txt")?;
+        stop_times.sort_by(|a, b| {
+            a.trip_id
+                .cmp(&b.trip_id)
+                .then(a.stop_sequence.cmp(&b.stop_sequence))
+        });
+
+        let mut events = Vec::new();
+
+        for st in &stop_times {
+            let trip = match trips.get(&st.trip_id) {
+                Some(trip) => trip,
+                None => continue,
+            };
+            if !keep(&trip.trip_id, &trip.route_id) {
+                continue;
+            }
+
+            let stop = stops.get(&st.stop_id).ok_or_else(|| {
+                Error::InvalidFormat(format!("stop_times references unknown stop {}", st.stop_id))
+            })?;
+            let service_date = *services.get(&trip.service_id).ok_or_else(|| {
+                Error::InvalidFormat(format!(
+                    "trip {} references unknown service {}",
+                    trip.trip_id, trip.service_id
+                ))
+            })?;
+            let timestamp = service_timestamp(service_date, &st.arrival_time)?;
+
+            let route = routes.get(&trip.route_id);
+            let route_label = route
+                .and_then(|r| r.route_short_name.clone())
+                .unwrap_or_else(|| trip.route_id.clone());
+            let agency_name = route
+                .and_then(|r| r.agency_id.as_ref())
+                .and_then(|id| agency_by_id.get(id).cloned())
+                .or_else(|| single_agency_name.clone());
+
+            let stop_label = stop
+                .stop_name
+                .clone()
+                .unwrap_or_else(|| stop.stop_id.clone());
+            let text = format!("Route {} arriving at {}", route_label, stop_label);
+
+            let mut builder = EventBuilder::new()
+                .location(Location::new(stop.stop_lat, stop.stop_lon))
+                .timestamp(timestamp)
+                .text(text)
+                .tag(trip.route_id.clone())
+                .tag(trip.trip_id.clone())
+                .metadata("trip_id", trip.trip_id.clone())
+                .metadata("stop_sequence", st.stop_sequence.to_string());
+            if let Some(agency_name) = agency_name {
+                builder = builder.tag(agency_name);
+            }
+
+            events.push(builder.build());
+        }
+
+        Ok(events)
+    }
+}
+
+impl Format for GtfsFormat {
+    /// GTFS feeds span several files, so streaming a single reader is not
+    /// supported; use [`import_feed`](Self::import_feed) with a directory path.
+    fn import<R: Read>(&self, _reader: R) -> Result<Narrative> {
+        Err(Error::InvalidFormat(
+            "GTFS is a multi-file feed; use import_feed/import_route/import_trip with a directory"
+                .to_string(),
+        ))
+    }
+
+    fn export<W: std::io::Write>(&self, _narrative: &Narrative, _writer: W) -> Result<()> {
+        Err(Error::InvalidFormat(
+            "GTFS export is not supported".to_string(),
+        ))
+    }
+}
+
+/// Parse a GTFS arrival time and combine it with the service date.
+///
+/// GTFS clock values may exceed `24:00:00` to express trips continuing past
+/// midnight; the excess hours roll the date forward accordingly.
+fn service_timestamp(date: NaiveDate, time: &str) -> Result<Timestamp> {
+    let mut parts = time.trim().split(':');
+    let hours: i64 = parts
+        .next()
+        .and_then(|h| h.parse().ok())
+        .ok_or_else(|| Error::InvalidFormat(format!("invalid arrival_time {:?}", time)))?;
+    let minutes: u32 = parts
+        .next()
+        .and_then(|m| m.parse().ok())
+        .ok_or_else(|| Error::InvalidFormat(format!("invalid arrival_time {:?}", time)))?;
+    let seconds: u32 = parts
+        .next()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| Error::InvalidFormat(format!("invalid arrival_time {:?}", time)))?;
+
+    let day_offset = hours / 24;
+    let hour = (hours % 24) as u32;
+    let base = date + chrono::Duration::days(day_offset);
+    let naive = base
+        .and_hms_opt(hour, minutes, seconds)
+        .ok_or_else(|| Error::InvalidFormat(format!("invalid arrival_time {:?}", time)))?;
+
+    Ok(Timestamp::new(Utc.from_utc_datetime(&naive)))
+}
+
+/// Parse a GTFS time-of-day value (`HH:MM:SS`, possibly past `24:00:00`) into
+/// seconds since service-date midnight.
+fn parse_time_of_day(time: &str) -> Result<i64> {
+    let mut parts = time.trim().split(':');
+    let hours: i64 = parts
+        .next()
+        .and_then(|h| h.parse().ok())
+        .ok_or_else(|| Error::InvalidFormat(format!("invalid time {:?}", time)))?;
+    let minutes: i64 = parts
+        .next()
+        .and_then(|m| m.parse().ok())
+        .ok_or_else(|| Error::InvalidFormat(format!("invalid time {:?}", time)))?;
+    let seconds: i64 = parts
+        .next()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| Error::InvalidFormat(format!("invalid time {:?}", time)))?;
+    Ok(hours * 3600 + minutes * 60 + seconds)
+}
+
+/// Read and deserialize a GTFS CSV table, tolerating unknown/optional columns.
+fn read_table<T: DeserializeOwned>(dir: &Path, file: &str) -> Result<Vec<T>> {
+    let path = dir.join(file);
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_path(&path)
+        .map_err(|e| Error::InvalidFormat(format!("cannot read {}: {}", file, e)))?;
+
+    let mut rows = Vec::new();
+    for record in reader.deserialize() {
+        let row: T =
+            record.map_err(|e| Error::InvalidFormat(format!("malformed row in {}: {}", file, e)))?;
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// Like [`read_table`], but a missing file yields an empty table instead of
+/// an error, for GTFS files that are optional (e.g. `agency.txt`).
+fn read_table_optional<T: DeserializeOwned>(dir: &Path, file: &str) -> Result<Vec<T>> {
+    if !dir.join(file).exists() {
+        return Ok(Vec::new());
+    }
+    read_table(dir, file)
+}
+
+#[derive(Debug, Deserialize)]
+struct GtfsStop {
+    stop_id: String,
+    #[serde(default)]
+    stop_name: Option<String>,
+    stop_lat: f64,
+    stop_lon: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtfsTrip {
+    trip_id: String,
+    route_id: String,
+    service_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtfsRoute {
+    route_id: String,
+    #[serde(default)]
+    route_short_name: Option<String>,
+    #[serde(default)]
+    agency_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtfsAgency {
+    #[serde(default)]
+    agency_id: Option<String>,
+    agency_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtfsStopTime {
+    trip_id: String,
+    arrival_time: String,
+    departure_time: String,
+    stop_id: String,
+    stop_sequence: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtfsCalendar {
+    service_id: String,
+    start_date: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    #[test]
+    fn test_service_timestamp_rolls_past_midnight() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+
+        let same_day = service_timestamp(date, "23:30:00").unwrap();
+        assert_eq!(same_day.to_rfc3339(), "2024-03-15T23:30:00+00:00");
+
+        // 25:15:00 is 01:15 the next day.
+        let next_day = service_timestamp(date, "25:15:00").unwrap();
+        assert_eq!(next_day.to_rfc3339(), "2024-03-16T01:15:00+00:00");
+    }
+
+    /// A scratch directory holding a fixture GTFS feed, removed on drop.
+    struct FeedDir(std::path::PathBuf);
+
+    impl FeedDir {
+        fn new() -> Self {
+            static COUNTER: AtomicU64 = AtomicU64::new(0);
+            let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+            let path = std::env::temp_dir().join(format!(
+                "spatial-narrative-gtfs-test-{}-{}",
+                std::process::id(),
+                id
+            ));
+            std::fs::create_dir_all(&path).unwrap();
+            FeedDir(path)
+        }
+
+        fn write(&self, file: &str, content: &str) -> &Self {
+            std::fs::write(self.0.join(file), content).unwrap();
+            self
+        }
+    }
+
+    impl Drop for FeedDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    const STOPS: &str = "stop_id,stop_name,stop_lat,stop_lon\nS1,Main St,40.0,-74.0\n";
+    const TRIPS: &str = "trip_id,route_id,service_id\nT1,R1,WK\n";
+    const STOP_TIMES: &str =
+        "trip_id,arrival_time,departure_time,stop_id,stop_sequence\nT1,08:00:00,08:00:00,S1,1\n";
+    const CALENDAR: &str = "service_id,start_date\nWK,20240101\n";
+
+    /// Write the tables every fixture below shares (stops/trips/stop_times/calendar),
+    /// leaving `routes.txt` and `agency.txt` to the caller.
+    fn base_feed() -> FeedDir {
+        let dir = FeedDir::new();
+        dir.write("stops.txt", STOPS);
+        dir.write("trips.txt", TRIPS);
+        dir.write("stop_times.txt", STOP_TIMES);
+        dir.write("calendar.txt", CALENDAR);
+        dir
+    }
+
+    #[test]
+    fn test_gtfs_import_resolves_agency_by_id() {
+        let dir = base_feed();
+        dir.write(
+            "routes.txt",
+            "route_id,route_short_name,agency_id\nR1,7,A1\n",
+        );
+        dir.write("agency.txt", "agency_id,agency_name\nA1,Metro Transit\n");
+
+        let narrative = GtfsFormat::new().import_feed(&dir.0).unwrap();
+        let event = &narrative.events()[0];
+        assert_eq!(event.text, "Route 7 arriving at Main St");
+        assert!(event.tags.contains(&"Metro Transit".to_string()));
+    }
+
+    #[test]
+    fn test_gtfs_import_falls_back_to_single_agency() {
+        let dir = base_feed();
+        // No agency_id on either routes.txt or agency.txt, but there's only
+        // one agency, so it should still be resolved.
+        dir.write("routes.txt", "route_id,route_short_name\nR1,7\n");
+        dir.write("agency.txt", "agency_name\nMetro Transit\n");
+
+        let narrative = GtfsFormat::new().import_feed(&dir.0).unwrap();
+        let event = &narrative.events()[0];
+        assert!(event.tags.contains(&"Metro Transit".to_string()));
+    }
+
+    #[test]
+    fn test_gtfs_import_missing_agency_file() {
+        let dir = base_feed();
+        dir.write("routes.txt", "route_id,route_short_name\nR1,7\n");
+        // No agency.txt at all.
+
+        let narrative = GtfsFormat::new().import_feed(&dir.0).unwrap();
+        let event = &narrative.events()[0];
+        assert_eq!(event.text, "Route 7 arriving at Main St");
+        assert!(!event.tags.iter().any(|t| t == "Metro Transit"));
+    }
+
+    #[test]
+    fn test_gtfs_import_missing_route_short_name_falls_back_to_route_id() {
+        let dir = base_feed();
+        dir.write("routes.txt", "route_id,route_short_name\nR1,\n");
+
+        let narrative = GtfsFormat::new().import_feed(&dir.0).unwrap();
+        let event = &narrative.events()[0];
+        assert_eq!(event.text, "Route R1 arriving at Main St");
+    }
+}
@@ -5,7 +5,7 @@
 
 use super::format::Format;
 use crate::core::{
-    Event, Location, Narrative, NarrativeMetadata, SourceRef, SourceType, Timestamp,
+    Crs, Event, Location, Narrative, NarrativeMetadata, SourceRef, SourceType, Timestamp,
 };
 use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
@@ -44,6 +44,91 @@ use std::io::{Read, Write};
 pub struct JsonFormat {
     /// Whether to pretty-print the JSON output
     pub pretty: bool,
+    /// Serialization-shape options.
+    pub options: JsonOptions,
+}
+
+/// Options controlling the shape of [`JsonFormat`] output.
+///
+/// These let integrators match the exact JSON shape expected by their
+/// ingestion tools — hoisting metadata, dropping provenance or tags for
+/// lighter exports, omitting empty arrays, and pinning the schema version.
+#[derive(Debug, Clone)]
+pub struct JsonOptions {
+    /// Hoist `metadata` fields into the root object instead of nesting them.
+    pub flatten_metadata: bool,
+    /// Include per-event `sources` provenance.
+    pub include_sources: bool,
+    /// Include per-event `tags`.
+    pub include_tags: bool,
+    /// Keep empty arrays/objects rather than omitting them.
+    pub emit_empty: bool,
+    /// Schema version string stamped into the output.
+    pub version: String,
+}
+
+impl Default for JsonOptions {
+    fn default() -> Self {
+        Self {
+            flatten_metadata: false,
+            include_sources: true,
+            include_tags: true,
+            emit_empty: true,
+            version: "1.0".to_string(),
+        }
+    }
+}
+
+impl JsonOptions {
+    /// Apply these options to the serialized narrative value in place.
+    fn shape(&self, mut value: serde_json::Value) -> serde_json::Value {
+        use serde_json::Value;
+
+        if let Some(events) = value.get_mut("events").and_then(Value::as_array_mut) {
+            for event in events.iter_mut() {
+                let Some(obj) = event.as_object_mut() else { continue };
+                if !self.include_sources {
+                    obj.remove("sources");
+                }
+                if !self.include_tags {
+                    obj.remove("tags");
+                }
+                if !self.emit_empty {
+                    obj.retain(|_, v| !is_empty_collection(v));
+                }
+            }
+        }
+
+        if self.flatten_metadata {
+            if let Some(metadata) = value
+                .as_object_mut()
+                .and_then(|root| root.remove("metadata"))
+            {
+                if let (Some(root), Value::Object(meta)) = (value.as_object_mut(), metadata) {
+                    for (k, v) in meta {
+                        root.insert(k, v);
+                    }
+                }
+            }
+        }
+
+        if !self.emit_empty {
+            if let Some(root) = value.as_object_mut() {
+                root.retain(|_, v| !is_empty_collection(v));
+            }
+        }
+
+        value
+    }
+}
+
+/// Whether a JSON value is an empty array or object.
+fn is_empty_collection(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Array(a) => a.is_empty(),
+        serde_json::Value::Object(o) => o.is_empty(),
+        _ => false,
+    }
 }
 
 impl JsonFormat {
@@ -54,25 +139,70 @@ impl JsonFormat {
 
     /// Create a new JSON format handler with pretty printing enabled.
     pub fn pretty() -> Self {
-        Self { pretty: true }
+        Self {
+            pretty: true,
+            ..Self::default()
+        }
+    }
+
+    /// Set the serialization-shape options.
+    pub fn with_options(mut self, options: JsonOptions) -> Self {
+        self.options = options;
+        self
     }
 }
 
 /// JSON representation of a narrative with version info.
 #[derive(Debug, Serialize, Deserialize)]
-struct NarrativeJson {
+pub(super) struct NarrativeJson {
     /// Format version for future compatibility
-    version: String,
+    pub(super) version: String,
 
     /// Narrative metadata
-    metadata: NarrativeMetadataJson,
+    pub(super) metadata: NarrativeMetadataJson,
 
     /// Events in the narrative
-    events: Vec<EventJson>,
+    pub(super) events: Vec<EventJson>,
+}
+
+impl NarrativeJson {
+    /// Build the serializable view of a narrative, stamping the given version.
+    pub(super) fn from_narrative(narrative: &Narrative, version: &str) -> Self {
+        NarrativeJson {
+            version: version.to_string(),
+            metadata: NarrativeMetadataJson::from_metadata(&narrative.metadata),
+            events: narrative.events.iter().map(EventJson::from_event).collect(),
+        }
+    }
+
+    /// Validate the version and convert back into an owned [`Narrative`].
+    pub(super) fn into_narrative(self) -> Result<Narrative> {
+        // Check version compatibility (for now, we only support 1.0)
+        if !self.version.starts_with("1.") {
+            return Err(Error::InvalidFormat(format!(
+                "unsupported format version: {}",
+                self.version
+            )));
+        }
+
+        let metadata = self.metadata.into_metadata()?;
+        let mut events = Vec::new();
+        for event_json in self.events {
+            events.push(event_json.into_event()?);
+        }
+
+        Ok(Narrative {
+            id: crate::core::NarrativeId::new(),
+            title: "Imported Narrative".to_string(),
+            events,
+            metadata,
+            tags: Vec::new(),
+        })
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct NarrativeMetadataJson {
+pub(super) struct NarrativeMetadataJson {
     created: Option<String>,
     modified: Option<String>,
     author: Option<String>,
@@ -81,7 +211,7 @@ struct NarrativeMetadataJson {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct EventJson {
+pub(super) struct EventJson {
     id: String,
     location: LocationJson,
     timestamp: String,
@@ -117,152 +247,133 @@ struct SourceRefJson {
     date: Option<String>,
 }
 
-impl Format for JsonFormat {
-    fn import<R: Read>(&self, reader: R) -> Result<Narrative> {
-        let json: NarrativeJson = serde_json::from_reader(reader)?;
-
-        // Check version compatibility (for now, we only support 1.0)
-        if !json.version.starts_with("1.") {
-            return Err(Error::InvalidFormat(format!(
-                "unsupported format version: {}",
-                json.version
-            )));
+impl NarrativeMetadataJson {
+    /// Build the JSON view of a narrative's metadata.
+    pub(super) fn from_metadata(metadata: &NarrativeMetadata) -> Self {
+        NarrativeMetadataJson {
+            created: metadata.created.as_ref().map(|t| t.to_rfc3339()),
+            modified: metadata.modified.as_ref().map(|t| t.to_rfc3339()),
+            author: metadata.author.clone(),
+            description: metadata.description.clone(),
+            category: metadata.category.clone(),
         }
+    }
 
-        // Convert from JSON representation to internal types
-        let metadata = NarrativeMetadata {
-            created: json
-                .metadata
-                .created
-                .as_ref()
-                .map(|s| Timestamp::parse(s))
-                .transpose()?,
-            modified: json
-                .metadata
-                .modified
-                .as_ref()
-                .map(|s| Timestamp::parse(s))
-                .transpose()?,
-            author: json.metadata.author,
-            description: json.metadata.description,
-            category: json.metadata.category,
+    /// Convert back into the internal metadata type.
+    pub(super) fn into_metadata(self) -> Result<NarrativeMetadata> {
+        Ok(NarrativeMetadata {
+            created: self.created.as_ref().map(|s| Timestamp::parse(s)).transpose()?,
+            modified: self.modified.as_ref().map(|s| Timestamp::parse(s)).transpose()?,
+            author: self.author,
+            description: self.description,
+            category: self.category,
             extra: std::collections::HashMap::new(),
-        };
-
-        let mut events = Vec::new();
-
-        for event_json in json.events {
-            let location = Location {
-                lat: event_json.location.lat,
-                lon: event_json.location.lon,
-                elevation: event_json.location.elevation,
-                uncertainty_meters: event_json.location.uncertainty_meters,
-                name: event_json.location.name,
-            };
-
-            // Validate location
-            location.validate()?;
+        })
+    }
+}
 
-            let timestamp = Timestamp::parse(&event_json.timestamp)?;
+impl EventJson {
+    /// Build the JSON view of an event.
+    pub(super) fn from_event(event: &Event) -> Self {
+        let location = LocationJson {
+            lat: event.location.lat,
+            lon: event.location.lon,
+            elevation: event.location.elevation,
+            uncertainty_meters: event.location.uncertainty_meters,
+            name: event.location.name.clone(),
+        };
 
-            let sources: Vec<SourceRef> = event_json
+        EventJson {
+            id: event.id.to_string(),
+            location,
+            timestamp: event.timestamp.to_rfc3339(),
+            text: event.text.clone(),
+            tags: event.tags.clone(),
+            sources: event
                 .sources
-                .into_iter()
-                .map(|s| {
-                    let source_type = match s.source_type.as_str() {
-                        "article" => SourceType::Article,
-                        "report" => SourceType::Report,
-                        "witness" => SourceType::Witness,
-                        "sensor" => SourceType::Sensor,
-                        _ => SourceType::Other,
-                    };
-
-                    SourceRef {
-                        source_type,
-                        url: s.url,
-                        title: s.title,
-                        author: s.author,
-                        date: s.date.and_then(|d| Timestamp::parse(&d).ok()),
-                        notes: None,
-                    }
+                .iter()
+                .map(|s| SourceRefJson {
+                    source_type: s.source_type.to_string(),
+                    title: s.title.clone(),
+                    author: s.author.clone(),
+                    url: s.url.clone(),
+                    date: s.date.as_ref().map(|ts| ts.to_rfc3339()),
                 })
-                .collect();
-
-            let event = Event {
-                id: crate::core::EventId::parse(&event_json.id)?,
-                location,
-                timestamp,
-                text: event_json.text,
-                tags: event_json.tags,
-                sources,
-                metadata: serde_json::from_value(event_json.metadata).unwrap_or_default(),
-            };
-            events.push(event);
+                .collect(),
+            metadata: serde_json::to_value(&event.metadata)
+                .unwrap_or(serde_json::Value::Object(serde_json::Map::new())),
         }
-
-        Ok(Narrative {
-            id: crate::core::NarrativeId::new(),
-            title: "Imported Narrative".to_string(),
-            events,
-            metadata,
-            tags: Vec::new(),
-        })
     }
 
-    fn export<W: Write>(&self, narrative: &Narrative, writer: W) -> Result<()> {
-        let metadata = NarrativeMetadataJson {
-            created: narrative.metadata.created.as_ref().map(|t| t.to_rfc3339()),
-            modified: narrative.metadata.modified.as_ref().map(|t| t.to_rfc3339()),
-            author: narrative.metadata.author.clone(),
-            description: narrative.metadata.description.clone(),
-            category: narrative.metadata.category.clone(),
+    /// Convert back into an internal event, validating its location.
+    pub(super) fn into_event(self) -> Result<Event> {
+        let location = Location {
+            lat: self.location.lat,
+            lon: self.location.lon,
+            elevation: self.location.elevation,
+            speed: None,
+            heading: None,
+            uncertainty_meters: self.location.uncertainty_meters,
+            name: self.location.name,
+            crs: Crs::default(),
         };
-
-        let events: Vec<EventJson> = narrative
-            .events
-            .iter()
-            .map(|event| {
-                let location = LocationJson {
-                    lat: event.location.lat,
-                    lon: event.location.lon,
-                    elevation: event.location.elevation,
-                    uncertainty_meters: event.location.uncertainty_meters,
-                    name: event.location.name.clone(),
+        location.validate()?;
+
+        let timestamp = Timestamp::parse(&self.timestamp)?;
+
+        let sources: Vec<SourceRef> = self
+            .sources
+            .into_iter()
+            .map(|s| {
+                let source_type = match s.source_type.as_str() {
+                    "article" => SourceType::Article,
+                    "report" => SourceType::Report,
+                    "witness" => SourceType::Witness,
+                    "sensor" => SourceType::Sensor,
+                    _ => SourceType::Other,
                 };
 
-                EventJson {
-                    id: event.id.to_string(),
-                    location,
-                    timestamp: event.timestamp.to_rfc3339(),
-                    text: event.text.clone(),
-                    tags: event.tags.clone(),
-                    sources: event
-                        .sources
-                        .iter()
-                        .map(|s| SourceRefJson {
-                            source_type: s.source_type.to_string(),
-                            title: s.title.clone(),
-                            author: s.author.clone(),
-                            url: s.url.clone(),
-                            date: s.date.as_ref().map(|ts| ts.to_rfc3339()),
-                        })
-                        .collect(),
-                    metadata: serde_json::to_value(&event.metadata)
-                        .unwrap_or(serde_json::Value::Object(serde_json::Map::new())),
+                SourceRef {
+                    source_type,
+                    url: s.url,
+                    title: s.title,
+                    author: s.author,
+                    date: s.date.and_then(|d| Timestamp::parse(&d).ok()),
+                    notes: None,
                 }
             })
             .collect();
 
-        let json = NarrativeJson {
-            version: "1.0".to_string(),
-            metadata,
-            events,
-        };
+        Ok(Event {
+            id: crate::core::EventId::parse(&self.id)?,
+            location,
+            path: Vec::new(),
+            timestamp,
+            text: self.text,
+            tags: self.tags,
+            sources,
+            metadata: serde_json::from_value(self.metadata).unwrap_or_default(),
+            recurrence: None,
+            signature: None,
+            references: Vec::new(),
+        })
+    }
+}
+
+impl Format for JsonFormat {
+    fn import<R: Read>(&self, reader: R) -> Result<Narrative> {
+        let json: NarrativeJson = serde_json::from_reader(reader)?;
+        json.into_narrative()
+    }
+
+    fn export<W: Write>(&self, narrative: &Narrative, writer: W) -> Result<()> {
+        let json = NarrativeJson::from_narrative(narrative, &self.options.version);
+        let value = self.options.shape(serde_json::to_value(&json)?);
 
         if self.pretty {
-            serde_json::to_writer_pretty(writer, &json)?;
+            serde_json::to_writer_pretty(writer, &value)?;
         } else {
-            serde_json::to_writer(writer, &json)?;
+            serde_json::to_writer(writer, &value)?;
         }
 
         Ok(())
@@ -297,6 +408,39 @@ mod tests {
         assert_eq!(restored.events()[0].tags, vec!["tag1"]);
     }
 
+    #[test]
+    fn test_json_flatten_and_toggle_options() {
+        let event = Event::builder()
+            .location(Location::new(40.7128, -74.006))
+            .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+            .text("Test event")
+            .tag("tag1")
+            .build();
+        let narrative = Narrative::builder()
+            .author("Ada")
+            .event(event)
+            .build();
+
+        let format = JsonFormat::new().with_options(JsonOptions {
+            flatten_metadata: true,
+            include_sources: false,
+            include_tags: false,
+            emit_empty: true,
+            version: "1.2".to_string(),
+        });
+
+        let value: serde_json::Value =
+            serde_json::from_str(&format.export_str(&narrative).unwrap()).unwrap();
+
+        assert_eq!(value["version"], "1.2");
+        // metadata hoisted to the root
+        assert_eq!(value["author"], "Ada");
+        assert!(value.get("metadata").is_none());
+        // per-event toggles applied
+        assert!(value["events"][0].get("sources").is_none());
+        assert!(value["events"][0].get("tags").is_none());
+    }
+
     #[test]
     fn test_json_version_check() {
         let json = r#"{
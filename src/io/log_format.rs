@@ -0,0 +1,162 @@
+//! Plain-text, line-oriented log format.
+//!
+//! Each non-blank, non-comment line has the shape
+//!
+//! ```text
+//! <timestamp> @<lat>,<lon> :tag1 tag2: free text description
+//! ```
+//!
+//! Lines beginning with `#` are comments and blank lines are skipped. The
+//! location and tag sections are optional. This gives a diff-friendly,
+//! hand-editable representation to complement the structured GeoJSON/CSV/JSON
+//! formats.
+
+use super::format::Format;
+use crate::core::{EventBuilder, Location, Narrative, NarrativeBuilder, Timestamp};
+use crate::{Error, Result};
+use std::io::{Read, Write};
+
+/// Plain-text timestamped log format handler.
+///
+/// # Example
+///
+/// ```rust
+/// use spatial_narrative::io::{LogFormat, Format};
+///
+/// let text = "# a quick story\n\
+///             2024-01-15T14:30:00Z @40.7128,-74.006 :landfall storm: It began here\n";
+/// let narrative = LogFormat::new().import_str(text).unwrap();
+/// assert_eq!(narrative.events().len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LogFormat;
+
+impl LogFormat {
+    /// Create a new log format handler.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Parse a single log line into the pieces of an event.
+    fn parse_line(line: &str, line_no: usize) -> Result<(Timestamp, Location, Vec<String>, String)> {
+        let err = |reason: &str| Error::InvalidFormat(format!("line {}: {}", line_no, reason));
+
+        let (ts_token, mut rest) = split_first_whitespace(line);
+        let timestamp = Timestamp::parse(ts_token).map_err(|_| err("invalid timestamp"))?;
+        rest = rest.trim_start();
+
+        let mut location = Location::new(0.0, 0.0);
+        if let Some(stripped) = rest.strip_prefix('@') {
+            let (coords, tail) = split_first_whitespace(stripped);
+            let (lat_str, lon_str) = coords.split_once(',').ok_or_else(|| err("expected @lat,lon"))?;
+            let lat = lat_str.parse::<f64>().map_err(|_| err("invalid latitude"))?;
+            let lon = lon_str.parse::<f64>().map_err(|_| err("invalid longitude"))?;
+            location = Location::new(lat, lon);
+            rest = tail.trim_start();
+        }
+
+        let mut tags = Vec::new();
+        if let Some(after) = rest.strip_prefix(':') {
+            let end = after.find(':').ok_or_else(|| err("unterminated tag list"))?;
+            tags = after[..end].split_whitespace().map(String::from).collect();
+            rest = after[end + 1..].trim_start();
+        }
+
+        Ok((timestamp, location, tags, rest.to_string()))
+    }
+}
+
+impl Format for LogFormat {
+    fn import<R: Read>(&self, mut reader: R) -> Result<Narrative> {
+        let mut text = String::new();
+        reader
+            .read_to_string(&mut text)
+            .map_err(|e| Error::InvalidFormat(format!("failed to read log: {}", e)))?;
+
+        let mut builder = NarrativeBuilder::new().title("Log");
+        for (index, raw) in text.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (timestamp, location, tags, text) = Self::parse_line(line, index + 1)?;
+            let event = EventBuilder::new()
+                .location(location)
+                .timestamp(timestamp)
+                .text(text)
+                .tags(tags)
+                .build();
+            builder = builder.event(event);
+        }
+
+        Ok(builder.build())
+    }
+
+    fn export<W: Write>(&self, narrative: &Narrative, mut writer: W) -> Result<()> {
+        for event in narrative.events_chronological() {
+            let mut line = format!(
+                "{} @{},{}",
+                event.timestamp.to_rfc3339(),
+                event.location.lat,
+                event.location.lon
+            );
+            if !event.tags.is_empty() {
+                line.push_str(&format!(" :{}:", event.tags.join(" ")));
+            }
+            if !event.text.is_empty() {
+                line.push(' ');
+                line.push_str(&event.text);
+            }
+            writeln!(writer, "{}", line)
+                .map_err(|e| Error::InvalidFormat(format!("failed to write log: {}", e)))?;
+        }
+        Ok(())
+    }
+}
+
+/// Split off the first whitespace-delimited token, returning `(token, rest)`.
+fn split_first_whitespace(s: &str) -> (&str, &str) {
+    match s.find(char::is_whitespace) {
+        Some(i) => (&s[..i], &s[i..]),
+        None => (s, ""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_round_trip() {
+        let text = "# comment line\n\
+                    \n\
+                    2024-01-15T14:30:00Z @40.7128,-74.006 :landfall storm: It began here\n\
+                    2024-01-16T09:00:00Z @34.0522,-118.2437 Quiet aftermath\n";
+
+        let narrative = LogFormat::new().import_str(text).unwrap();
+        assert_eq!(narrative.events().len(), 2);
+
+        let first = &narrative.events()[0];
+        assert_eq!(first.text, "It began here");
+        assert!(first.has_tag("landfall"));
+        assert!(first.has_tag("storm"));
+
+        let second = &narrative.events()[1];
+        assert_eq!(second.text, "Quiet aftermath");
+        assert!(second.tags.is_empty());
+
+        // Export then re-import preserves the event count and text.
+        let exported = LogFormat::new().export_str(&narrative).unwrap();
+        let reparsed = LogFormat::new().import_str(&exported).unwrap();
+        assert_eq!(reparsed.events().len(), 2);
+        assert_eq!(reparsed.events()[0].text, "It began here");
+    }
+
+    #[test]
+    fn test_log_reports_line_errors() {
+        let text = "2024-01-15T14:30:00Z ok\nnot-a-timestamp @1,2 bad line";
+        let err = LogFormat::new().import_str(text).unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+    }
+}
@@ -8,7 +8,12 @@
 //! - [`GeoJsonFormat`] - Standard geographic data format
 //! - [`CsvFormat`] - Tabular data with configurable columns
 //! - [`JsonFormat`] - Custom JSON format optimized for narratives
-//! - GPX - GPS exchange format (optional feature, TODO)
+//! - [`NdJsonFormat`] - Newline-delimited JSON for streaming large narratives
+//! - [`NdjsonFormat`] - Newline-delimited JSON with flat, CSV-style rows
+//! - `GpxFormat` - GPS exchange format (optional `gpx` feature)
+//! - [`BinaryFormat`] - Compact delta-encoded binary trajectory format
+//! - [`ReferenceResolver`] - Resolving importer that requeues events with
+//!   dangling inter-event [`references`](crate::core::Event::references)
 //!
 //! # Example
 //!
@@ -38,12 +43,37 @@
 //! let json = json_format.export_str(&narrative).unwrap();
 //! ```
 
+mod binary_format;
 mod csv_format;
 mod format;
 mod geojson;
+#[cfg(feature = "gpx")]
+mod gpx;
+mod gtfs;
 mod json_format;
+mod log_format;
+mod msgpack_format;
+mod ndjson_format;
+mod ndjson_row_format;
+mod references;
+mod registry;
+mod signing;
 
-pub use csv_format::{CsvFormat, CsvOptions};
-pub use format::Format;
+pub use binary_format::{BinaryFormat, BinaryOptions};
+pub use csv_format::{
+    CsvFormat, CsvOptions, CsvProfile, CsvTrim, ImportReport, OnError, QuoteStyle, RowError, TimestampFormat,
+};
+pub use format::{EventStream, Format};
 pub use geojson::{GeoJsonFormat, GeoJsonOptions};
-pub use json_format::JsonFormat;
+#[cfg(feature = "gpx")]
+pub use gpx::{GpxFormat, GpxOptions};
+pub use gtfs::GtfsFormat;
+pub use json_format::{JsonFormat, JsonOptions};
+pub use log_format::LogFormat;
+pub use msgpack_format::MsgPackFormat;
+pub use registry::{FormatRegistry, OutputFormat};
+pub use ndjson_format::{NdJsonEvents, NdJsonFormat};
+pub use ndjson_row_format::{NdjsonFormat, NdjsonOptions};
+pub use references::{DanglingReference, ReferenceResolver};
+pub use crate::core::canonical_json;
+pub use signing::{sign, verify, PublicKey, Signature, SignedNarrative, SigningKey};
@@ -0,0 +1,161 @@
+//! Compact MessagePack binary format for narratives.
+//!
+//! This handler reuses the same serde model as [`JsonFormat`](super::JsonFormat)
+//! — the versioned `NarrativeJson`/`EventJson` shape — but encodes it with
+//! MessagePack instead of JSON text. Large spatial narratives with thousands of
+//! events and rich metadata are expensive to keep as pretty-printed JSON; the
+//! binary encoding gives a 2–4× size reduction and a much faster parse while
+//! sharing the same version-compatibility and migration logic.
+
+use super::format::Format;
+use super::json_format::NarrativeJson;
+use crate::core::Narrative;
+use crate::{Error, Result};
+use std::io::{Read, Write};
+
+/// MessagePack binary format handler.
+///
+/// # Example
+///
+/// ```rust
+/// use spatial_narrative::io::{MsgPackFormat, Format};
+/// use spatial_narrative::prelude::*;
+///
+/// let format = MsgPackFormat::new();
+///
+/// let narrative = Narrative::builder()
+///     .title("My Story")
+///     .event(Event::builder()
+///         .location(Location::new(40.7128, -74.006))
+///         .timestamp(Timestamp::now())
+///         .text("Something happened")
+///         .build())
+///     .build();
+///
+/// let mut bytes = Vec::new();
+/// format.export(&narrative, &mut bytes).unwrap();
+/// let restored = format.import(bytes.as_slice()).unwrap();
+/// assert_eq!(restored.events().len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MsgPackFormat;
+
+impl MsgPackFormat {
+    /// Create a new MessagePack format handler.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Format for MsgPackFormat {
+    fn import<R: Read>(&self, mut reader: R) -> Result<Narrative> {
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer)?;
+        let json: NarrativeJson = rmp_serde::from_slice(&buffer)
+            .map_err(|e| Error::InvalidFormat(format!("invalid MessagePack: {e}")))?;
+        json.into_narrative()
+    }
+
+    fn export<W: Write>(&self, narrative: &Narrative, mut writer: W) -> Result<()> {
+        let json = NarrativeJson::from_narrative(narrative, "1.0");
+        let bytes = rmp_serde::to_vec_named(&json)
+            .map_err(|e| Error::InvalidFormat(format!("MessagePack encoding failed: {e}")))?;
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::JsonFormat;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_msgpack_roundtrip() {
+        let event = Event::builder()
+            .location(Location::new(40.7128, -74.006))
+            .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+            .text("Test event")
+            .tag("tag1")
+            .build();
+
+        let narrative = Narrative::builder()
+            .title("Test Narrative")
+            .description("A test narrative")
+            .event(event)
+            .build();
+
+        let format = MsgPackFormat::new();
+        let mut bytes = Vec::new();
+        format.export(&narrative, &mut bytes).unwrap();
+        let restored = format.import(bytes.as_slice()).unwrap();
+
+        assert_eq!(restored.events().len(), 1);
+        assert_eq!(restored.events()[0].text, "Test event");
+        assert_eq!(restored.events()[0].tags, vec!["tag1"]);
+    }
+
+    #[test]
+    fn test_msgpack_roundtrip_preserves_metadata_sources_and_tags() {
+        let event = Event::builder()
+            .location(Location::new(48.8566, 2.3522))
+            .timestamp(Timestamp::parse("2024-03-01T09:00:00Z").unwrap())
+            .text("Protest at the Louvre")
+            .tag("protest")
+            .tag("politics")
+            .metadata("participants", "1000")
+            .source(SourceRef::article("https://example.com/report"))
+            .build();
+
+        let narrative = Narrative::builder()
+            .title("Test Narrative")
+            .event(event)
+            .build();
+
+        let format = MsgPackFormat::new();
+        let mut bytes = Vec::new();
+        format.export(&narrative, &mut bytes).unwrap();
+        let restored = format.import(bytes.as_slice()).unwrap();
+
+        let event = &restored.events()[0];
+        assert_eq!(event.tags, vec!["protest", "politics"]);
+        assert_eq!(event.get_metadata("participants"), Some("1000"));
+        assert_eq!(event.sources.len(), 1);
+        assert_eq!(
+            event.sources[0].url.as_deref(),
+            Some("https://example.com/report")
+        );
+    }
+
+    #[test]
+    fn test_msgpack_is_smaller_than_json() {
+        let mut builder = Narrative::builder().title("Large Narrative");
+        for i in 0..200 {
+            builder = builder.event(
+                Event::builder()
+                    .location(Location::new(40.0 + i as f64 * 0.001, -74.0))
+                    .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+                    .text(format!("Event number {i} with some descriptive text"))
+                    .tag("sample")
+                    .metadata("index", i.to_string())
+                    .build(),
+            );
+        }
+        let narrative = builder.build();
+
+        let mut msgpack_bytes = Vec::new();
+        MsgPackFormat::new()
+            .export(&narrative, &mut msgpack_bytes)
+            .unwrap();
+
+        let json_str = JsonFormat::new().export_str(&narrative).unwrap();
+
+        assert!(
+            msgpack_bytes.len() < json_str.len(),
+            "MessagePack ({} bytes) should be smaller than JSON ({} bytes)",
+            msgpack_bytes.len(),
+            json_str.len()
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! Newline-delimited JSON format for streaming large narratives.
+//!
+//! Unlike [`JsonFormat`](super::JsonFormat), which serializes a narrative as a
+//! single monolithic document, this format writes a small header line followed
+//! by one compact event object per line. That layout lets producers and
+//! consumers stream multi-gigabyte feeds — sensor logs piped in on stdin, say —
+//! without ever holding the whole narrative in memory.
+//!
+//! The wire format is:
+//!
+//! ```text
+//! {"version":"1.0","metadata":{...}}
+//! {"id":"...","location":{...},"timestamp":"...", ...}
+//! {"id":"...","location":{...},"timestamp":"...", ...}
+//! ```
+
+use super::format::Format;
+use super::json_format::{EventJson, NarrativeMetadataJson};
+use crate::core::Narrative;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Read, Write};
+
+/// Newline-delimited JSON format handler.
+///
+/// # Example
+///
+/// ```rust
+/// use spatial_narrative::io::{NdJsonFormat, Format};
+/// use spatial_narrative::prelude::*;
+///
+/// let format = NdJsonFormat::new();
+///
+/// let narrative = Narrative::builder()
+///     .title("My Story")
+///     .event(Event::builder()
+///         .location(Location::new(40.7128, -74.006))
+///         .timestamp(Timestamp::now())
+///         .text("Something happened")
+///         .build())
+///     .build();
+///
+/// let ndjson = format.export_str(&narrative).unwrap();
+/// assert_eq!(ndjson.lines().count(), 2); // header + one event
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NdJsonFormat;
+
+impl NdJsonFormat {
+    /// Create a new NDJSON format handler.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Import a narrative lazily, yielding one event at a time.
+    ///
+    /// The returned iterator reads the header eagerly (so that format and
+    /// version errors surface immediately) and then deserializes each
+    /// subsequent line on demand. This is the entry point for processing feeds
+    /// that are too large to materialize into a single [`Narrative`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header line is missing, malformed, or carries an
+    /// unsupported version.
+    pub fn import_streaming<R: Read>(&self, reader: R) -> Result<NdJsonEvents<R>> {
+        let mut lines = BufReader::new(reader).lines();
+
+        let header_line = lines
+            .next()
+            .ok_or_else(|| Error::InvalidFormat("empty NDJSON stream".to_string()))??;
+        let header: NdJsonHeader = serde_json::from_str(&header_line)?;
+
+        if !header.version.starts_with("1.") {
+            return Err(Error::InvalidFormat(format!(
+                "unsupported format version: {}",
+                header.version
+            )));
+        }
+
+        Ok(NdJsonEvents { lines })
+    }
+}
+
+/// The first line of an NDJSON stream, carrying version and metadata.
+#[derive(Debug, Serialize, Deserialize)]
+struct NdJsonHeader {
+    version: String,
+    metadata: NarrativeMetadataJson,
+}
+
+/// A lazy iterator over the events of an NDJSON stream.
+///
+/// Created by [`NdJsonFormat::import_streaming`]. Each item is the result of
+/// parsing one line; a malformed line yields an [`Err`] without aborting the
+/// rest of the stream.
+pub struct NdJsonEvents<R: Read> {
+    lines: std::io::Lines<BufReader<R>>,
+}
+
+impl<R: Read> Iterator for NdJsonEvents<R> {
+    type Item = Result<crate::core::Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+
+            // Skip blank lines so trailing newlines are tolerated.
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let parsed = serde_json::from_str::<EventJson>(&line)
+                .map_err(Error::from)
+                .and_then(EventJson::into_event);
+            return Some(parsed);
+        }
+    }
+}
+
+impl Format for NdJsonFormat {
+    fn import<R: Read>(&self, reader: R) -> Result<Narrative> {
+        let events = self.import_streaming(reader)?;
+        let mut narrative = Narrative::builder().title("Imported Narrative").build();
+        for event in events {
+            narrative.add_event(event?);
+        }
+        Ok(narrative)
+    }
+
+    fn export<W: Write>(&self, narrative: &Narrative, mut writer: W) -> Result<()> {
+        let header = NdJsonHeader {
+            version: "1.0".to_string(),
+            metadata: NarrativeMetadataJson::from_metadata(&narrative.metadata),
+        };
+        serde_json::to_writer(&mut writer, &header)?;
+        writeln!(writer)?;
+
+        for event in &narrative.events {
+            serde_json::to_writer(&mut writer, &EventJson::from_event(event))?;
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn sample() -> Narrative {
+        Narrative::builder()
+            .title("Test Narrative")
+            .description("A test narrative")
+            .event(
+                Event::builder()
+                    .location(Location::new(40.7128, -74.006))
+                    .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+                    .text("First")
+                    .tag("a")
+                    .build(),
+            )
+            .event(
+                Event::builder()
+                    .location(Location::new(51.5074, -0.1278))
+                    .timestamp(Timestamp::parse("2024-01-16T09:00:00Z").unwrap())
+                    .text("Second")
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_ndjson_roundtrip() {
+        let narrative = sample();
+        let format = NdJsonFormat::new();
+
+        let ndjson = format.export_str(&narrative).unwrap();
+        assert_eq!(ndjson.lines().count(), 3); // header + two events
+
+        let restored = format.import_str(&ndjson).unwrap();
+        assert_eq!(restored.events().len(), 2);
+        assert_eq!(restored.events()[0].text, "First");
+        assert_eq!(restored.events()[1].text, "Second");
+    }
+
+    #[test]
+    fn test_ndjson_streaming() {
+        let narrative = sample();
+        let format = NdJsonFormat::new();
+        let ndjson = format.export_str(&narrative).unwrap();
+
+        let texts: Vec<String> = format
+            .import_streaming(ndjson.as_bytes())
+            .unwrap()
+            .map(|e| e.unwrap().text)
+            .collect();
+        assert_eq!(texts, vec!["First", "Second"]);
+    }
+
+    #[test]
+    fn test_ndjson_version_check() {
+        let format = NdJsonFormat::new();
+        let result = format.import_str("{\"version\":\"2.0\",\"metadata\":{}}\n");
+        assert!(result.is_err());
+    }
+}
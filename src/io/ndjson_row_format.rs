@@ -0,0 +1,320 @@
+//! Newline-delimited JSON with CSV-style flat rows.
+//!
+//! Unlike [`NdJsonFormat`](super::NdJsonFormat), which writes a header line
+//! followed by the crate's full nested event schema, this format treats each
+//! line as a flat JSON object with the same `lat`/`lon`/`timestamp`-plus-optionals
+//! shape as [`CsvFormat`](super::CsvFormat) — the layout many GPS loggers and
+//! sensor feeds already emit one record per line. [`NdjsonOptions`] configures
+//! the key names the same way [`CsvOptions`](super::CsvOptions) configures
+//! column names.
+
+use super::format::{EventStream, Format};
+use crate::core::{Event, EventBuilder, Location, Narrative, NarrativeBuilder, SourceRef, SourceType, Timestamp};
+use crate::{Error, Result};
+use serde_json::{Map, Value};
+use std::io::{BufRead, BufReader, Read, Write};
+
+/// Key-name configuration for [`NdjsonFormat`].
+#[derive(Debug, Clone)]
+pub struct NdjsonOptions {
+    /// Object key for latitude (defaults to "lat")
+    pub lat_key: String,
+    /// Object key for longitude (defaults to "lon")
+    pub lon_key: String,
+    /// Object key for timestamp (defaults to "timestamp")
+    pub timestamp_key: String,
+    /// Object key for elevation (optional)
+    pub elevation_key: Option<String>,
+    /// Object key for text/description (optional)
+    pub text_key: Option<String>,
+    /// Object key for tags, as a JSON array of strings (optional)
+    pub tags_key: Option<String>,
+    /// Object key for source name (optional)
+    pub source_key: Option<String>,
+}
+
+impl Default for NdjsonOptions {
+    fn default() -> Self {
+        Self {
+            lat_key: "lat".to_string(),
+            lon_key: "lon".to_string(),
+            timestamp_key: "timestamp".to_string(),
+            elevation_key: Some("elevation".to_string()),
+            text_key: Some("text".to_string()),
+            tags_key: Some("tags".to_string()),
+            source_key: Some("source".to_string()),
+        }
+    }
+}
+
+/// Flat-row NDJSON format handler.
+///
+/// # Example
+///
+/// ```rust
+/// use spatial_narrative::io::{NdjsonFormat, Format};
+///
+/// let format = NdjsonFormat::new();
+/// let data = "{\"lat\":40.7128,\"lon\":-74.006,\"timestamp\":\"2024-01-15T14:30:00Z\"}\n\
+///             {\"lat\":34.0522,\"lon\":-118.2437,\"timestamp\":\"2024-01-16T10:00:00Z\"}";
+///
+/// let narrative = format.import_str(data).unwrap();
+/// assert_eq!(narrative.events().len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NdjsonFormat {
+    /// Key-name configuration for import/export.
+    pub options: NdjsonOptions,
+}
+
+impl NdjsonFormat {
+    /// Create a new flat-row NDJSON format handler with default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new flat-row NDJSON format handler with custom options.
+    pub fn with_options(options: NdjsonOptions) -> Self {
+        Self { options }
+    }
+
+    /// Decode a single parsed line object into an [`Event`].
+    fn object_to_event(&self, obj: &Map<String, Value>, row_num: usize) -> Result<Event> {
+        let get_f64 = |key: &str| -> Result<f64> {
+            obj.get(key)
+                .and_then(Value::as_f64)
+                .ok_or_else(|| Error::InvalidFormat(format!("missing {} at line {}", key, row_num)))
+        };
+
+        let lat = get_f64(&self.options.lat_key)?;
+        let lon = get_f64(&self.options.lon_key)?;
+
+        let ts_str = obj
+            .get(&self.options.timestamp_key)
+            .and_then(Value::as_str)
+            .ok_or_else(|| {
+                Error::InvalidFormat(format!("missing {} at line {}", self.options.timestamp_key, row_num))
+            })?;
+        let timestamp = Timestamp::parse(ts_str)
+            .map_err(|_| Error::InvalidFormat(format!("invalid timestamp at line {}", row_num)))?;
+
+        let mut location = Location::new(lat, lon);
+        if let Some(key) = &self.options.elevation_key {
+            if let Some(elev) = obj.get(key).and_then(Value::as_f64) {
+                location.elevation = Some(elev);
+            }
+        }
+
+        let mut builder = EventBuilder::new().location(location).timestamp(timestamp);
+
+        if let Some(key) = &self.options.text_key {
+            if let Some(text) = obj.get(key).and_then(Value::as_str) {
+                builder = builder.text(text);
+            }
+        }
+
+        if let Some(key) = &self.options.tags_key {
+            if let Some(tags) = obj.get(key).and_then(Value::as_array) {
+                for tag in tags.iter().filter_map(Value::as_str) {
+                    builder = builder.tag(tag);
+                }
+            }
+        }
+
+        if let Some(key) = &self.options.source_key {
+            if let Some(name) = obj.get(key).and_then(Value::as_str) {
+                let mut source = SourceRef::new(SourceType::Article);
+                source.title = Some(name.to_string());
+                builder = builder.source(source);
+            }
+        }
+
+        Ok(builder.build())
+    }
+
+    /// Serialize a single event into a flat JSON row honoring the key layout.
+    fn event_to_object(&self, event: &Event) -> Value {
+        let mut obj = Map::new();
+        obj.insert(self.options.lat_key.clone(), event.location.lat.into());
+        obj.insert(self.options.lon_key.clone(), event.location.lon.into());
+        obj.insert(self.options.timestamp_key.clone(), event.timestamp.to_rfc3339().into());
+
+        if let Some(key) = &self.options.elevation_key {
+            if let Some(elev) = event.location.elevation {
+                obj.insert(key.clone(), elev.into());
+            }
+        }
+        if let Some(key) = &self.options.text_key {
+            if !event.text.is_empty() {
+                obj.insert(key.clone(), event.text.clone().into());
+            }
+        }
+        if let Some(key) = &self.options.tags_key {
+            if !event.tags.is_empty() {
+                obj.insert(key.clone(), event.tags.clone().into());
+            }
+        }
+        if let Some(key) = &self.options.source_key {
+            if let Some(name) = event.sources.first().and_then(|s| s.title.clone()) {
+                obj.insert(key.clone(), name.into());
+            }
+        }
+
+        Value::Object(obj)
+    }
+}
+
+/// Streaming iterator over events decoded from a flat-row NDJSON reader.
+///
+/// Produced by [`NdjsonFormat::import_iter`]. Each call to [`next`](Iterator::next)
+/// reads and decodes exactly one line, so the iterator never holds more than a
+/// single row in memory.
+struct NdjsonRowIter<R: Read> {
+    format: NdjsonFormat,
+    lines: std::io::Lines<BufReader<R>>,
+    row_num: usize,
+}
+
+impl<R: Read> Iterator for NdjsonRowIter<R> {
+    type Item = Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let row_num = self.row_num;
+            self.row_num += 1;
+
+            let parsed = serde_json::from_str::<Value>(&line)
+                .map_err(Error::from)
+                .and_then(|value| match value {
+                    Value::Object(obj) => self.format.object_to_event(&obj, row_num),
+                    _ => Err(Error::InvalidFormat(format!("line {} is not a JSON object", row_num))),
+                });
+            return Some(parsed);
+        }
+    }
+}
+
+impl Format for NdjsonFormat {
+    fn import<R: Read>(&self, reader: R) -> Result<Narrative> {
+        let mut builder = NarrativeBuilder::new();
+        for event in self.import_iter(reader)? {
+            builder = builder.event(event?);
+        }
+        Ok(builder.build())
+    }
+
+    fn import_iter<'r, R: Read + 'r>(&self, reader: R) -> Result<EventStream<'r>> {
+        Ok(Box::new(NdjsonRowIter {
+            format: self.clone(),
+            lines: BufReader::new(reader).lines(),
+            row_num: 0,
+        }))
+    }
+
+    fn export_to<W, I>(&self, events: I, mut writer: W) -> Result<()>
+    where
+        W: Write,
+        I: IntoIterator<Item = Event>,
+    {
+        for event in events {
+            serde_json::to_writer(&mut writer, &self.event_to_object(&event))?;
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Event;
+
+    #[test]
+    fn test_ndjson_row_import_basic() {
+        let data = "{\"lat\":40.7128,\"lon\":-74.006,\"timestamp\":\"2024-01-15T14:30:00Z\"}\n\
+                    {\"lat\":34.0522,\"lon\":-118.2437,\"timestamp\":\"2024-01-16T10:00:00Z\"}";
+
+        let format = NdjsonFormat::new();
+        let narrative = format.import_str(data).unwrap();
+
+        assert_eq!(narrative.events().len(), 2);
+        assert_eq!(narrative.events()[0].location.lat, 40.7128);
+        assert_eq!(narrative.events()[1].location.lat, 34.0522);
+    }
+
+    #[test]
+    fn test_ndjson_row_import_with_optional_fields() {
+        let data = "{\"lat\":40.7128,\"lon\":-74.006,\"timestamp\":\"2024-01-15T14:30:00Z\",\
+                    \"text\":\"Event in NYC\",\"tags\":[\"a\",\"b\"],\"elevation\":10.5,\"source\":\"sensor-1\"}";
+
+        let format = NdjsonFormat::new();
+        let narrative = format.import_str(data).unwrap();
+
+        let event = &narrative.events()[0];
+        assert_eq!(event.text, "Event in NYC");
+        assert_eq!(event.tags, vec!["a", "b"]);
+        assert_eq!(event.location.elevation, Some(10.5));
+        assert_eq!(event.sources[0].title.as_deref(), Some("sensor-1"));
+    }
+
+    #[test]
+    fn test_ndjson_row_roundtrip() {
+        let event = Event::builder()
+            .location(Location::new(40.7128, -74.006))
+            .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+            .text("Test event")
+            .tag("tag1")
+            .build();
+
+        let narrative = Narrative::builder().event(event).build();
+
+        let format = NdjsonFormat::new();
+        let exported = format.export_str(&narrative).unwrap();
+        let imported = format.import_str(&exported).unwrap();
+
+        assert_eq!(imported.events().len(), 1);
+        assert_eq!(imported.events()[0].text, "Test event");
+        assert_eq!(imported.events()[0].tags, vec!["tag1"]);
+    }
+
+    #[test]
+    fn test_ndjson_row_import_iter_streams_events() {
+        let data = "{\"lat\":40.7128,\"lon\":-74.006,\"timestamp\":\"2024-01-15T14:30:00Z\"}\n\
+                    {\"lat\":34.0522,\"lon\":-118.2437,\"timestamp\":\"2024-01-16T10:00:00Z\"}";
+
+        let format = NdjsonFormat::new();
+        let events: Vec<Event> = format
+            .import_iter(data.as_bytes())
+            .unwrap()
+            .map(|e| e.unwrap())
+            .collect();
+
+        assert_eq!(events.len(), 2);
+    }
+
+    #[test]
+    fn test_ndjson_row_custom_keys() {
+        let options = NdjsonOptions {
+            lat_key: "latitude".to_string(),
+            lon_key: "longitude".to_string(),
+            timestamp_key: "ts".to_string(),
+            ..Default::default()
+        };
+        let data = "{\"latitude\":40.7128,\"longitude\":-74.006,\"ts\":\"2024-01-15T14:30:00Z\"}";
+
+        let format = NdjsonFormat::with_options(options);
+        let narrative = format.import_str(data).unwrap();
+
+        assert_eq!(narrative.events().len(), 1);
+        assert_eq!(narrative.events()[0].location.lat, 40.7128);
+    }
+}
@@ -0,0 +1,185 @@
+//! Resolving importer for inter-event [`references`](crate::core::Event::references).
+//!
+//! Batched or streamed imports can't guarantee events arrive in dependency
+//! order — a "follow-up to" reference may point at an event from an earlier
+//! feed page that hasn't been ingested yet. [`ReferenceResolver`] holds events
+//! with unresolved forward references in a pending queue and re-attempts
+//! resolution every time [`feed`](ReferenceResolver::feed) is called with a
+//! new batch, so a reference is never silently dropped just because its
+//! target arrived late; [`finish`](ReferenceResolver::finish) reports whatever
+//! is still unresolved instead of discarding it.
+
+use std::collections::HashSet;
+
+use crate::core::{Event, EventId, EventRef, Narrative, NarrativeBuilder};
+
+/// A reference that was still unresolved when [`ReferenceResolver::finish`]
+/// was called.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DanglingReference {
+    /// The event that holds the unresolved reference.
+    pub source: EventId,
+    /// The reference itself, pointing at an id that never arrived.
+    pub reference: EventRef,
+}
+
+/// Resolves inter-event references across one or more batches of events,
+/// holding events whose references point to not-yet-seen ids until their
+/// targets show up in a later batch.
+#[derive(Debug, Default)]
+pub struct ReferenceResolver {
+    resolved: Vec<Event>,
+    seen: HashSet<EventId>,
+    pending: Vec<Event>,
+}
+
+impl ReferenceResolver {
+    /// Creates an empty resolver.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds a newly-arrived batch of events into the resolver.
+    ///
+    /// An event whose references all point at already-seen ids resolves
+    /// immediately. Every still-pending event (from this batch or an earlier
+    /// one) is re-checked too, since this batch may supply the id it was
+    /// waiting on.
+    pub fn feed(&mut self, events: impl IntoIterator<Item = Event>) {
+        self.pending.extend(events);
+        self.drain_pending();
+    }
+
+    /// Moves every pending event whose references are now all satisfied into
+    /// `resolved`, repeating until a pass makes no further progress.
+    fn drain_pending(&mut self) {
+        loop {
+            let (ready, still_pending): (Vec<Event>, Vec<Event>) =
+                std::mem::take(&mut self.pending)
+                    .into_iter()
+                    .partition(|event| {
+                        event
+                            .references
+                            .iter()
+                            .all(|r| self.seen.contains(&r.target))
+                    });
+
+            self.pending = still_pending;
+            if ready.is_empty() {
+                break;
+            }
+
+            for event in ready {
+                self.seen.insert(event.id.clone());
+                self.resolved.push(event);
+            }
+        }
+    }
+
+    /// Number of fed events still waiting on an unresolved reference.
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Finishes resolution, returning the assembled [`Narrative`] (every fed
+    /// event, resolved or not) and the references that never found their
+    /// target.
+    pub fn finish(self, title: impl Into<String>) -> (Narrative, Vec<DanglingReference>) {
+        let mut dangling = Vec::new();
+        for event in &self.pending {
+            for reference in &event.references {
+                if !self.seen.contains(&reference.target) {
+                    dangling.push(DanglingReference {
+                        source: event.id.clone(),
+                        reference: reference.clone(),
+                    });
+                }
+            }
+        }
+
+        let mut builder = NarrativeBuilder::new().title(title);
+        for event in self.resolved.into_iter().chain(self.pending) {
+            builder = builder.event(event);
+        }
+
+        (builder.build(), dangling)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Location, Timestamp};
+
+    fn event(text: &str) -> Event {
+        Event::builder()
+            .location(Location::new(0.0, 0.0))
+            .timestamp(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+            .text(text)
+            .build()
+    }
+
+    #[test]
+    fn test_resolves_reference_within_same_batch() {
+        let original = event("Initial report of a fire");
+        let followup = Event::builder()
+            .location(Location::new(0.0, 0.0))
+            .timestamp(Timestamp::parse("2024-01-01T01:00:00Z").unwrap())
+            .text("Fire contained")
+            .reference(original.id.clone(), "follow-up to")
+            .build();
+
+        let mut resolver = ReferenceResolver::new();
+        resolver.feed(vec![original, followup]);
+        let (narrative, dangling) = resolver.finish("Fire coverage");
+
+        assert_eq!(narrative.events().len(), 2);
+        assert!(dangling.is_empty());
+    }
+
+    #[test]
+    fn test_requeues_forward_reference_until_target_arrives() {
+        let original = event("Initial report of a fire");
+        let target_id = original.id.clone();
+        let followup = Event::builder()
+            .location(Location::new(0.0, 0.0))
+            .timestamp(Timestamp::parse("2024-01-01T01:00:00Z").unwrap())
+            .text("Fire contained")
+            .reference(target_id.clone(), "follow-up to")
+            .build();
+
+        let mut resolver = ReferenceResolver::new();
+        // The follow-up arrives before the event it references.
+        resolver.feed(vec![followup]);
+        assert_eq!(resolver.pending_count(), 1);
+
+        // The missing target arrives in a later batch; the held-back
+        // follow-up resolves instead of being dropped.
+        resolver.feed(vec![original]);
+        assert_eq!(resolver.pending_count(), 0);
+
+        let (narrative, dangling) = resolver.finish("Fire coverage");
+        assert_eq!(narrative.events().len(), 2);
+        assert!(dangling.is_empty());
+    }
+
+    #[test]
+    fn test_reports_dangling_reference_at_finish() {
+        let missing_id = EventId::new();
+        let orphan = Event::builder()
+            .location(Location::new(0.0, 0.0))
+            .timestamp(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+            .text("References something that never arrives")
+            .reference(missing_id.clone(), "caused by")
+            .build();
+
+        let mut resolver = ReferenceResolver::new();
+        resolver.feed(vec![orphan]);
+        let (narrative, dangling) = resolver.finish("Orphaned");
+
+        // The event is still included, just flagged as dangling.
+        assert_eq!(narrative.events().len(), 1);
+        assert_eq!(dangling.len(), 1);
+        assert_eq!(dangling[0].reference.target, missing_id);
+    }
+}
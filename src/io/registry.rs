@@ -0,0 +1,226 @@
+//! Output-format selection and an analysis-enriched JSON export.
+//!
+//! The `io` module ships several standalone handlers ([`JsonFormat`],
+//! [`CsvFormat`], [`GeoJsonFormat`], [`MsgPackFormat`], …). This module ties
+//! them together behind a single switchable surface: [`OutputFormat`] names a
+//! target, [`OutputFormat::from_extension`] picks one from a filename, and
+//! [`FormatRegistry`] dispatches an export to the right handler.
+//!
+//! It also adds an [`OutputFormat::ExtendedJson`] target that augments the
+//! plain JSON export with derived fields — per-event nearest-neighbor distance,
+//! total path length, temporal span and bounding box — so downstream consumers
+//! get an enriched record without recomputing it themselves.
+
+use super::{CsvFormat, Format, GeoJsonFormat, JsonFormat, MsgPackFormat};
+use crate::core::{Location, Narrative};
+use crate::{Error, Result};
+use serde_json::{json, Value};
+use std::io::Write;
+
+/// A selectable output format.
+///
+/// `import` remains format-specific (call the concrete handler), but `export`
+/// can be dispatched through [`FormatRegistry`] for any of these variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Custom narrative JSON ([`JsonFormat`]).
+    Json,
+    /// Narrative JSON augmented with derived analysis fields.
+    ExtendedJson,
+    /// Tabular CSV ([`CsvFormat`]).
+    Csv,
+    /// GeoJSON FeatureCollection ([`GeoJsonFormat`]).
+    GeoJson,
+    /// GPS exchange format. [`FormatRegistry`] has no handler for this
+    /// variant: exporting it returns an error.
+    Gpx,
+    /// Compact MessagePack binary ([`MsgPackFormat`]).
+    MsgPack,
+}
+
+impl OutputFormat {
+    /// Guess the output format from a filename or extension.
+    ///
+    /// Matching is case-insensitive and accepts either `"geojson"` or a full
+    /// path like `"narrative.geojson"`.
+    pub fn from_extension(name: &str) -> Option<Self> {
+        let ext = name.rsplit('.').next().unwrap_or(name).to_ascii_lowercase();
+        match ext.as_str() {
+            "json" => Some(OutputFormat::Json),
+            "geojson" => Some(OutputFormat::GeoJson),
+            "csv" => Some(OutputFormat::Csv),
+            "gpx" => Some(OutputFormat::Gpx),
+            "msgpack" | "mp" | "mpk" => Some(OutputFormat::MsgPack),
+            _ => None,
+        }
+    }
+}
+
+/// Dispatches narrative exports to the handler for a chosen [`OutputFormat`].
+#[derive(Debug, Clone, Default)]
+pub struct FormatRegistry;
+
+impl FormatRegistry {
+    /// Create a new registry.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Export a narrative in the given format to a writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying handler fails, or if the format has
+    /// no handler in the registry ([`OutputFormat::Gpx`]).
+    pub fn export<W: Write>(
+        &self,
+        narrative: &Narrative,
+        format: OutputFormat,
+        mut writer: W,
+    ) -> Result<()> {
+        match format {
+            OutputFormat::Json => JsonFormat::new().export(narrative, writer),
+            OutputFormat::Csv => CsvFormat::new().export(narrative, writer),
+            OutputFormat::GeoJson => GeoJsonFormat::new().export(narrative, writer),
+            OutputFormat::MsgPack => MsgPackFormat::new().export(narrative, writer),
+            OutputFormat::ExtendedJson => {
+                let value = extended_json(narrative)?;
+                serde_json::to_writer(&mut writer, &value)?;
+                Ok(())
+            }
+            OutputFormat::Gpx => Err(Error::InvalidFormat(
+                "GPX export is not available through FormatRegistry".to_string(),
+            )),
+        }
+    }
+
+    /// Export a narrative to a string. Convenience wrapper over [`Self::export`].
+    pub fn export_str(&self, narrative: &Narrative, format: OutputFormat) -> Result<String> {
+        let mut buffer = Vec::new();
+        self.export(narrative, format, &mut buffer)?;
+        Ok(String::from_utf8(buffer).expect("format produced invalid UTF-8"))
+    }
+}
+
+/// Build the analysis-enriched JSON value for a narrative.
+fn extended_json(narrative: &Narrative) -> Result<Value> {
+    let base = JsonFormat::new().export_str(narrative)?;
+    let mut value: Value = serde_json::from_str(&base)?;
+
+    // Per-event nearest-neighbor distance (great-circle metres).
+    let locations: Vec<&Location> = narrative.events.iter().map(|e| &e.location).collect();
+    if let Some(events) = value.get_mut("events").and_then(Value::as_array_mut) {
+        for (i, event) in events.iter_mut().enumerate() {
+            let nearest = locations
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, other)| haversine_meters(locations[i], other))
+                .fold(f64::INFINITY, f64::min);
+            if nearest.is_finite() {
+                if let Some(obj) = event.as_object_mut() {
+                    obj.insert("nearest_neighbor_meters".to_string(), json!(nearest));
+                }
+            }
+        }
+    }
+
+    // Total path length in chronological order.
+    let chronological = narrative.events_chronological();
+    let total_path_length: f64 = chronological
+        .windows(2)
+        .map(|w| haversine_meters(&w[0].location, &w[1].location))
+        .sum();
+
+    let temporal_span_seconds = narrative
+        .time_range()
+        .map(|r| r.duration().num_seconds());
+
+    let bounding_box = narrative.bounds().map(|b| {
+        json!({
+            "min_lat": b.min_lat,
+            "min_lon": b.min_lon,
+            "max_lat": b.max_lat,
+            "max_lon": b.max_lon,
+        })
+    });
+
+    if let Some(obj) = value.as_object_mut() {
+        obj.insert(
+            "analysis".to_string(),
+            json!({
+                "total_path_length_meters": total_path_length,
+                "temporal_span_seconds": temporal_span_seconds,
+                "bounding_box": bounding_box,
+            }),
+        );
+    }
+
+    Ok(value)
+}
+
+/// Great-circle distance between two locations, in metres.
+fn haversine_meters(a: &Location, b: &Location) -> f64 {
+    let r = 6_371_000.0_f64; // Earth radius in metres
+    let lat1 = a.lat.to_radians();
+    let lat2 = b.lat.to_radians();
+    let dlat = (b.lat - a.lat).to_radians();
+    let dlon = (b.lon - a.lon).to_radians();
+
+    let h = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+    r * 2.0 * h.sqrt().asin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn sample() -> Narrative {
+        Narrative::builder()
+            .title("Extended")
+            .event(
+                Event::builder()
+                    .location(Location::new(40.7128, -74.006))
+                    .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+                    .text("First")
+                    .build(),
+            )
+            .event(
+                Event::builder()
+                    .location(Location::new(40.7228, -74.016))
+                    .timestamp(Timestamp::parse("2024-01-15T15:30:00Z").unwrap())
+                    .text("Second")
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_from_extension() {
+        assert_eq!(OutputFormat::from_extension("x.json"), Some(OutputFormat::Json));
+        assert_eq!(
+            OutputFormat::from_extension("trip.geojson"),
+            Some(OutputFormat::GeoJson)
+        );
+        assert_eq!(OutputFormat::from_extension("nope.txt"), None);
+    }
+
+    #[test]
+    fn test_extended_json_has_derived_fields() {
+        let json = FormatRegistry::new()
+            .export_str(&sample(), OutputFormat::ExtendedJson)
+            .unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+
+        assert!(value["analysis"]["total_path_length_meters"].as_f64().unwrap() > 0.0);
+        assert!(value["analysis"]["bounding_box"].is_object());
+        assert!(value["events"][0]["nearest_neighbor_meters"].is_number());
+    }
+
+    #[test]
+    fn test_gpx_not_implemented() {
+        let result = FormatRegistry::new().export_str(&sample(), OutputFormat::Gpx);
+        assert!(result.is_err());
+    }
+}
@@ -44,16 +44,20 @@
 //! - [`graph`] - Graph representation of narratives
 //! - [`analysis`] - Metrics, clustering, and movement analysis
 //! - [`io`] - Import/export in various formats
+//! - [`export`] - One-way export to calendar and note-taking formats
 //! - [`transform`] - Coordinate transformations and projections
 //! - [`parser`] - Extract locations from unstructured text (geoparsing)
+//! - [`routing`] - RAPTOR-based connection planning over transit timetables
 //! - [`text`] - Natural language processing utilities
 
 pub mod analysis;
 pub mod core;
+pub mod export;
 pub mod graph;
 pub mod index;
 pub mod io;
 pub mod parser;
+pub mod routing;
 pub mod text;
 pub mod transform;
 
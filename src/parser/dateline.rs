@@ -0,0 +1,133 @@
+//! News-dateline parser: extract the origin place from an article lead.
+//!
+//! Wire copy opens with a dateline naming where the story was filed —
+//! `"LONDON — The prime minister..."` or `"SÃO PAULO, Brazil (Reuters) - ..."`.
+//! Recognizing it lets the crate anchor an entire document to its reporting
+//! origin.
+//!
+//! The recognizer looks, at the very start of the text (optionally after a
+//! `By <author>` line), for an all-caps city token, an optional `, Region`, an
+//! optional parenthesized news source, terminated by a dash followed by the
+//! capitalized start of the body.
+//!
+//! # Example
+//!
+//! ```rust
+//! use spatial_narrative::parser::{dateline, BuiltinGazetteer};
+//!
+//! let gaz = BuiltinGazetteer::new();
+//! let dl = dateline::parse("LONDON — The prime minister spoke today.", &gaz).unwrap();
+//! assert_eq!(dl.city, "LONDON");
+//! assert!(dl.location.is_some());
+//! ```
+
+use super::gazetteer::Gazetteer;
+use crate::core::Location;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// A parsed news dateline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dateline {
+    /// The origin city, as it appeared in the text (typically all caps).
+    pub city: String,
+    /// The region or country that followed the city, or an expanded default.
+    pub region: Option<String>,
+    /// The news source from the parenthesized tag, e.g. "Reuters".
+    pub source: Option<String>,
+    /// The resolved coordinates of the city, if the gazetteer knew it.
+    pub location: Option<Location>,
+}
+
+static DATELINE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(concat!(
+        r"^\s*",
+        r"(?:By\s+[^\n]+\n\s*)?",                     // optional byline
+        r"([A-Z][^0-9a-z,\u{2013}\u{2014}()-]+?)",    // all-caps city
+        r"(?:,\s*([A-Z][A-Za-z. ]+?))?",              // optional ", Region"
+        r"\s*(?:\(([^)]+)\))?",                       // optional "(Source)"
+        r"\s*[-\u{2013}\u{2014}]\s*[A-Z]",            // dash then body start
+    ))
+    .unwrap()
+});
+
+/// Parse the dateline at the start of `text`, resolving the city against
+/// `gazetteer`.
+///
+/// Returns `None` when the lead does not match the dateline shape. When a region
+/// follows the city it is used to disambiguate the lookup; when it is absent a
+/// handful of well-known US city abbreviations are supplied as a default.
+pub fn parse(text: &str, gazetteer: &dyn Gazetteer) -> Option<Dateline> {
+    let caps = DATELINE.captures(text)?;
+
+    let city = caps.get(1)?.as_str().trim().to_string();
+    let source = caps.get(3).map(|m| m.as_str().trim().to_string());
+    let region = caps
+        .get(2)
+        .map(|m| m.as_str().trim().to_string())
+        .or_else(|| default_region(&city).map(str::to_string));
+
+    let location = match &region {
+        Some(r) => gazetteer
+            .lookup_in(&city, r)
+            .map(|p| p.location)
+            .or_else(|| gazetteer.lookup(&city)),
+        None => gazetteer.lookup(&city),
+    };
+
+    Some(Dateline {
+        city,
+        region,
+        source,
+        location,
+    })
+}
+
+/// Expand a bare all-caps US city to its state/district code so the lookup can
+/// disambiguate it from like-named places elsewhere.
+fn default_region(city: &str) -> Option<&'static str> {
+    match city.to_uppercase().as_str() {
+        "NEW YORK" => Some("NY"),
+        "WASHINGTON" => Some("DC"),
+        "LOS ANGELES" => Some("CA"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::BuiltinGazetteer;
+
+    #[test]
+    fn test_simple_dateline() {
+        let gaz = BuiltinGazetteer::new();
+        let dl = parse("LONDON — The prime minister spoke today.", &gaz).unwrap();
+        assert_eq!(dl.city, "LONDON");
+        assert!(dl.region.is_none());
+        assert!(dl.source.is_none());
+        assert!(dl.location.is_some());
+    }
+
+    #[test]
+    fn test_dateline_with_region_and_source() {
+        let gaz = BuiltinGazetteer::new();
+        let dl = parse("SÃO PAULO, Brazil (Reuters) - Markets rallied.", &gaz).unwrap();
+        assert_eq!(dl.city, "SÃO PAULO");
+        assert_eq!(dl.region.as_deref(), Some("Brazil"));
+        assert_eq!(dl.source.as_deref(), Some("Reuters"));
+    }
+
+    #[test]
+    fn test_dateline_after_byline() {
+        let gaz = BuiltinGazetteer::new();
+        let dl = parse("By Jane Doe\nPARIS — A new exhibit opened.", &gaz).unwrap();
+        assert_eq!(dl.city, "PARIS");
+    }
+
+    #[test]
+    fn test_no_dateline() {
+        let gaz = BuiltinGazetteer::new();
+        assert!(parse("The prime minister spoke in london today.", &gaz).is_none());
+    }
+}
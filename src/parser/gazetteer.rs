@@ -15,16 +15,20 @@
 //!
 //! let gaz = BuiltinGazetteer::new();
 //! if let Some(loc) = gaz.lookup("Paris") {
-//!     println!("Paris: {}, {}", loc.lat, loc.lon);
+//!     println!("Paris: {}, {}", loc.lat(), loc.lon());
 //! }
 //! ```
 
 use crate::core::Location;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "geocoding")]
 use serde::Deserialize;
 
+use super::normalize;
+
 /// Trait for place name resolution (gazetteer).
 ///
 /// Implement this trait to provide custom place name databases
@@ -69,6 +73,452 @@ pub trait Gazetteer: Send + Sync {
     fn aliases(&self, _name: &str) -> Vec<&str> {
         Vec::new()
     }
+
+    /// Reverse-geocode a WGS-84 coordinate to the nearest known place.
+    ///
+    /// The default returns `None`; gazetteers that can answer proximity queries
+    /// override it. Built-in resolution uses a k-d tree over unit-sphere
+    /// coordinates so Euclidean nearest-neighbor in 3-space matches great-circle
+    /// ordering.
+    fn reverse(&self, _lat: f64, _lon: f64) -> Option<Location> {
+        None
+    }
+
+    /// Suggest the closest known names to a partial or misspelled query.
+    ///
+    /// Returns up to `limit` `(name, location, score)` triples ranked by
+    /// Jaro-Winkler similarity, highest first. The default returns nothing;
+    /// gazetteers that can enumerate their names override it to give the parser
+    /// a fuzzy fallback when [`lookup`](Self::lookup) misses exactly.
+    fn suggest(&self, _partial: &str, _limit: usize) -> Vec<(String, Location, f64)> {
+        Vec::new()
+    }
+
+    /// Resolve a place name to its full [`ResolvedPlace`] record.
+    ///
+    /// The default wraps [`lookup`](Self::lookup) with an unknown feature type
+    /// and no hierarchy; gazetteers that carry richer metadata override it to
+    /// populate the country, admin division, population, and aliases.
+    fn resolve(&self, name: &str) -> Option<ResolvedPlace> {
+        self.lookup(name).map(|location| ResolvedPlace {
+            name: name.to_string(),
+            feature_type: PlaceType::PopulatedPlace,
+            country: None,
+            admin1: None,
+            population: None,
+            aliases: Vec::new(),
+            location,
+        })
+    }
+
+    /// Return every place matching `name`, not just the best candidate.
+    ///
+    /// The default wraps [`resolve`](Self::resolve) into a single-element list;
+    /// gazetteers that store ambiguous names (e.g. "Paris, France" vs
+    /// "Paris, Texas") override it to surface them all, most significant first.
+    fn lookup_all(&self, name: &str) -> Vec<ResolvedPlace> {
+        self.resolve(name).into_iter().collect()
+    }
+
+    /// Return every place matching `name`, ranked by match confidence.
+    ///
+    /// Unlike [`lookup_all`](Self::lookup_all), which does not expose how
+    /// well a candidate matched, this surfaces a [`ScoredPlace::score`]:
+    /// `1.0` for an exact match, lower for an alias or fuzzy (edit-distance)
+    /// match. The default wraps [`lookup_all`](Self::lookup_all) at a flat
+    /// `1.0` confidence; gazetteers that normalize and fuzzy-match override
+    /// it with real scores.
+    fn lookup_scored(&self, name: &str) -> Vec<ScoredPlace> {
+        self.lookup_all(name)
+            .into_iter()
+            .map(|place| ScoredPlace { place, score: 1.0 })
+            .collect()
+    }
+
+    /// Return every place matching `name`, ranked by real-world prominence
+    /// rather than match quality.
+    ///
+    /// Unlike [`lookup_all`](Self::lookup_all), which orders candidates by
+    /// each source's own notion of "best" (often just population), this
+    /// exposes a comparable [`RankedPlace::importance`] score so ambiguous
+    /// names — "Paris" matching both Paris, France and Paris, Texas — resolve
+    /// to the more prominent candidate while keeping runners-up available.
+    /// The default wraps [`lookup_all`](Self::lookup_all) at a flat `0.0`
+    /// importance, preserving its original order; gazetteers that carry real
+    /// prominence data override it.
+    fn lookup_ranked(&self, name: &str) -> Vec<RankedPlace> {
+        self.lookup_all(name)
+            .into_iter()
+            .map(|place| RankedPlace {
+                place,
+                importance: 0.0,
+                rank_search: None,
+            })
+            .collect()
+    }
+
+    /// Resolve `name`, biasing toward candidates whose country or first-level
+    /// admin division matches `context`.
+    ///
+    /// This lets a narrative that has already established "Texas" steer a later
+    /// bare "Paris" toward the Texan entry. The default filters
+    /// [`lookup_all`](Self::lookup_all) by the context and falls back to the
+    /// unfiltered best match when nothing matches.
+    fn lookup_in(&self, name: &str, context: &str) -> Option<ResolvedPlace> {
+        let candidates = self.lookup_all(name);
+        let ctx = context.to_lowercase();
+        let matches = |field: &Option<String>| {
+            field.as_deref().is_some_and(|v| v.to_lowercase() == ctx)
+        };
+        candidates
+            .iter()
+            .find(|p| matches(&p.country) || matches(&p.admin1))
+            .or_else(|| candidates.first())
+            .cloned()
+    }
+
+    /// Resolve an ISO 3166-1 alpha-2 or alpha-3 code to a country's coordinates.
+    ///
+    /// The default returns `None`; gazetteers that carry [`CountryInfo`] override
+    /// it so `"DE"` and `"DEU"` both find Germany's centroid.
+    fn lookup_by_iso(&self, _code: &str) -> Option<Location> {
+        None
+    }
+
+    /// Resolve a postal/ZIP code (US ZIP, Canadian, or UK format) to its
+    /// coordinates.
+    ///
+    /// The default returns `None`; gazetteers that carry postcode data
+    /// override it so the parser's postal-code pass can resolve mentions
+    /// like "90210" or "EC1A 1BB".
+    fn lookup_postcode(&self, _code: &str) -> Option<Location> {
+        None
+    }
+
+    /// Return the full [`CountryInfo`] record for a country name, alias, or ISO
+    /// code, so callers can normalize "Deutschland", "Germany", and "DE" to one
+    /// canonical entity. The default returns `None`.
+    fn country_info(&self, _name: &str) -> Option<CountryInfo> {
+        None
+    }
+
+    /// Return the nearest known place name and its great-circle distance in
+    /// kilometers.
+    ///
+    /// Complements [`reverse`](Self::reverse), which yields the nearest
+    /// coordinate; this yields the place's name and distance. The default
+    /// returns `None`; gazetteers that can enumerate their entries override it.
+    fn reverse_nearest(&self, _lat: f64, _lon: f64) -> Option<(String, f64)> {
+        None
+    }
+
+    /// Return every known place within `radius_km` of the point, each with its
+    /// great-circle distance in kilometers, nearest first. The default returns
+    /// an empty list.
+    fn reverse_within(&self, _lat: f64, _lon: f64, _radius_km: f64) -> Vec<(String, f64)> {
+        Vec::new()
+    }
+
+    /// Reverse-geocode a coordinate to up to `max_results` nearby places, each
+    /// as a full [`GazetteerEntry`], nearest first.
+    ///
+    /// Complements [`reverse_within`](Self::reverse_within), which returns only
+    /// bare `(name, distance)` pairs: this returns full entries so a narrative
+    /// built from raw coordinates can be annotated with population, aliases,
+    /// and country metadata rather than just a label. The default returns an
+    /// empty list; gazetteers that can enumerate or query nearby places
+    /// override it.
+    fn reverse_lookup(&self, _lat: f64, _lon: f64, _max_results: usize) -> Vec<GazetteerEntry> {
+        Vec::new()
+    }
+
+    /// Suggest up to `limit` places whose name or an alias matches `prefix`.
+    ///
+    /// When `layers` is non-empty, only places in those layers are returned.
+    /// Candidates are ranked with full-token prefix matches ahead of mere
+    /// substring matches, then by population descending, then alphabetically.
+    /// The default returns an empty list; gazetteers that can enumerate their
+    /// names override it.
+    fn autocomplete(&self, _prefix: &str, _layers: &[Layer], _limit: usize) -> Vec<Suggestion> {
+        Vec::new()
+    }
+
+    /// Build a human-readable hierarchical label for a place, e.g. "Paris,
+    /// France" or "Cape Town, South Africa".
+    ///
+    /// Walks from the place up to its parent country and applies per-style
+    /// naming conventions. The default returns `None`; gazetteers that link
+    /// places to countries override it.
+    fn label(&self, _name: &str, _style: LabelStyle) -> Option<String> {
+        None
+    }
+}
+
+/// The kind of place a resolution matched, modeled on Bing's `EntityType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaceType {
+    /// A sovereign country.
+    Country,
+    /// A first-level administrative division (state, province, region).
+    AdminDivision1,
+    /// A second-level administrative division (county, district).
+    AdminDivision2,
+    /// A city, town, or village.
+    PopulatedPlace,
+    /// A neighborhood or suburb within a populated place.
+    Neighborhood,
+    /// A postal code area.
+    Postcode,
+    /// A street address.
+    Address,
+    /// A named landmark or point of interest.
+    Landmark,
+}
+
+/// A fully resolved place with its administrative hierarchy and feature type.
+///
+/// Where [`Gazetteer::lookup`] returns only a bare [`Location`], `resolve`
+/// returns this richer record so downstream code can tell a landmark from a
+/// city from a country and roll locations up the admin hierarchy.
+#[derive(Debug, Clone)]
+pub struct ResolvedPlace {
+    /// Canonical place name.
+    pub name: String,
+    /// What kind of place this is.
+    pub feature_type: PlaceType,
+    /// Country name, when known.
+    pub country: Option<String>,
+    /// First-level administrative division, when known.
+    pub admin1: Option<String>,
+    /// Population, when known.
+    pub population: Option<u64>,
+    /// Alternative names for the place.
+    pub aliases: Vec<String>,
+    /// The place's coordinates.
+    pub location: Location,
+}
+
+/// A [`ResolvedPlace`] candidate paired with a match-confidence score.
+///
+/// Returned by [`Gazetteer::lookup_scored`] so callers — notably
+/// [`GeoParser`](crate::parser::GeoParser) — can tell an exact hit from an
+/// alias or fuzzy (edit-distance) one and carry that through to a
+/// [`LocationMention`](crate::parser::LocationMention)'s confidence.
+#[derive(Debug, Clone)]
+pub struct ScoredPlace {
+    /// The resolved place.
+    pub place: ResolvedPlace,
+    /// Confidence in `[0.0, 1.0]`: `1.0` for an exact normalized match,
+    /// lower for an alias or fuzzy match.
+    pub score: f64,
+}
+
+/// A [`ResolvedPlace`] candidate paired with a real-world prominence score.
+///
+/// Returned by [`Gazetteer::lookup_ranked`] so callers — notably
+/// [`MultiGazetteer::lookup_ranked`] and [`GeoParser`](crate::parser::GeoParser)
+/// — can pick the most prominent candidate among ambiguous matches (e.g.
+/// "Paris, France" over "Paris, Texas") while keeping the runners-up around.
+#[derive(Debug, Clone)]
+pub struct RankedPlace {
+    /// The resolved place.
+    pub place: ResolvedPlace,
+    /// Normalized prominence score in `[0.0, 1.0]`; higher is more prominent.
+    /// `0.0` when the source doesn't report one.
+    pub importance: f64,
+    /// Source search-rank, when known: lower is more prominent, used to
+    /// break ties between equally important candidates.
+    pub rank_search: Option<u32>,
+}
+
+/// How a hierarchical place label is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelStyle {
+    /// Spell the parent out in full: "Paris, France".
+    Full,
+    /// Abbreviate the parent to a code: "Paris, FR" (or "City, ST" for US
+    /// states where the state code is known).
+    Abbreviated,
+}
+
+/// The layer a place belongs to, for filtering autocomplete results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layer {
+    /// A city, town, or other populated place.
+    City,
+    /// A country (the "Country" rows of the built-in table).
+    Country,
+}
+
+/// A ranked autocomplete suggestion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    /// Canonical name of the matched place.
+    pub name: String,
+    /// The alias that matched, if the query hit an alias rather than the name.
+    pub matched_alias: Option<String>,
+    /// The place's coordinates.
+    pub location: Location,
+    /// Which layer the place belongs to.
+    pub layer: Layer,
+}
+
+/// Jaro-Winkler similarity between two strings, in `[0.0, 1.0]`.
+///
+/// Computes the base Jaro score from matched characters and transpositions,
+/// then applies the Winkler boost for a shared prefix (up to four characters,
+/// weighted by `0.1`).
+fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let jaro = jaro(a, b);
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let prefix = a
+        .iter()
+        .zip(b.iter())
+        .take(4)
+        .take_while(|(x, y)| x == y)
+        .count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+/// Base Jaro similarity between two strings.
+fn jaro(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0;
+
+    for (i, ca) in a.iter().enumerate() {
+        let lo = i.saturating_sub(window);
+        let hi = (i + window + 1).min(b.len());
+        for j in lo..hi {
+            if !b_matched[j] && *ca == b[j] {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // Count transpositions among the matched characters, in order.
+    let mut transpositions = 0;
+    let mut k = 0;
+    for (i, &m) in a_matched.iter().enumerate() {
+        if !m {
+            continue;
+        }
+        while !b_matched[k] {
+            k += 1;
+        }
+        if a[i] != b[k] {
+            transpositions += 1;
+        }
+        k += 1;
+    }
+
+    let m = matches as f64;
+    let t = transpositions as f64 / 2.0;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - t) / m) / 3.0
+}
+
+/// Project a `(lat, lon)` pair in degrees onto the unit sphere.
+///
+/// Converting to Cartesian `(x, y, z)` lets Euclidean nearest-neighbor search
+/// stand in for great-circle distance: the chord length is monotonic in the
+/// angular distance, so the closest point in 3-space is the closest on the
+/// sphere.
+fn unit_sphere(lat: f64, lon: f64) -> [f64; 3] {
+    let (lat, lon) = (lat.to_radians(), lon.to_radians());
+    [
+        lat.cos() * lon.cos(),
+        lat.cos() * lon.sin(),
+        lat.sin(),
+    ]
+}
+
+/// A point stored in the built-in reverse-geocoding k-d tree.
+#[derive(Debug, Clone)]
+struct KdPoint {
+    coords: [f64; 3],
+    lat: f64,
+    lon: f64,
+    canonical: String,
+}
+
+/// A node in the 3-dimensional k-d tree over unit-sphere coordinates.
+#[derive(Debug)]
+struct KdNode {
+    point: KdPoint,
+    left: Option<Box<KdNode>>,
+    right: Option<Box<KdNode>>,
+}
+
+/// Recursively build a balanced k-d tree by median-splitting on a cycling axis.
+fn build_kd(points: &mut [KdPoint], depth: usize) -> Option<Box<KdNode>> {
+    if points.is_empty() {
+        return None;
+    }
+    let axis = depth % 3;
+    points.sort_by(|a, b| a.coords[axis].total_cmp(&b.coords[axis]));
+    let median = points.len() / 2;
+
+    let (left, rest) = points.split_at_mut(median);
+    let (point, right) = rest.split_first_mut().expect("non-empty slice has a head");
+
+    Some(Box::new(KdNode {
+        point: point.clone(),
+        left: build_kd(left, depth + 1),
+        right: build_kd(right, depth + 1),
+    }))
+}
+
+/// Walk the tree tracking the closest point seen, pruning subtrees whose
+/// splitting plane is farther than the current best.
+fn nearest<'a>(
+    node: &'a Option<Box<KdNode>>,
+    target: &[f64; 3],
+    depth: usize,
+    best: &mut Option<(f64, &'a KdPoint)>,
+) {
+    let Some(node) = node else { return };
+
+    let dist = squared_distance(&node.point.coords, target);
+    if best.as_ref().map(|(d, _)| dist < *d).unwrap_or(true) {
+        *best = Some((dist, &node.point));
+    }
+
+    let axis = depth % 3;
+    let diff = target[axis] - node.point.coords[axis];
+    let (near, far) = if diff < 0.0 {
+        (&node.left, &node.right)
+    } else {
+        (&node.right, &node.left)
+    };
+
+    nearest(near, target, depth + 1, best);
+    // Only descend the far side if the splitting plane could hold a closer point.
+    if best.as_ref().map(|(d, _)| diff * diff < *d).unwrap_or(true) {
+        nearest(far, target, depth + 1, best);
+    }
+}
+
+/// Squared Euclidean distance between two 3-vectors.
+fn squared_distance(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    (0..3).map(|i| (a[i] - b[i]).powi(2)).sum()
 }
 
 #[cfg(feature = "geocoding")]
@@ -84,7 +534,7 @@ pub trait Gazetteer: Send + Sync {
 ///
 /// let gaz = GazetteerNominatim::new();
 /// if let Some(loc) = gaz.lookup("Berlin") {
-///     println!("Berlin: {}, {}", loc.lat, loc.lon);
+///     println!("Berlin: {}, {}", loc.lat(), loc.lon());
 /// }
 /// ```
 pub struct GazetteerNominatim {
@@ -124,6 +574,50 @@ struct NominatimResponse {
     lat: String,
     lon: String,
     display_name: Option<String>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    addresstype: Option<String>,
+    #[serde(default)]
+    address: Option<NominatimAddress>,
+    /// Nominatim's own `[0.0, 1.0]` prominence score.
+    #[serde(default)]
+    importance: Option<f64>,
+    /// Nominatim's `place_rank`: lower is more prominent.
+    #[serde(default)]
+    place_rank: Option<u32>,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default, rename = "type")]
+    place_type: Option<String>,
+    #[serde(default)]
+    extratags: Option<std::collections::HashMap<String, String>>,
+}
+
+#[cfg(feature = "geocoding")]
+#[derive(Debug, Deserialize, Default)]
+#[allow(dead_code)]
+struct NominatimAddress {
+    #[serde(default)]
+    country: Option<String>,
+    #[serde(default)]
+    state: Option<String>,
+    #[serde(default)]
+    region: Option<String>,
+}
+
+#[cfg(feature = "geocoding")]
+/// Map a Nominatim `addresstype` onto a [`PlaceType`].
+fn nominatim_place_type(addresstype: Option<&str>) -> PlaceType {
+    match addresstype {
+        Some("country") => PlaceType::Country,
+        Some("state" | "region") => PlaceType::AdminDivision1,
+        Some("county") => PlaceType::AdminDivision2,
+        Some("city" | "town" | "village") => PlaceType::PopulatedPlace,
+        Some("suburb" | "neighbourhood") => PlaceType::Neighborhood,
+        Some("postcode") => PlaceType::Postcode,
+        _ => PlaceType::Landmark,
+    }
 }
 
 #[cfg(feature = "geocoding")]
@@ -158,6 +652,200 @@ impl Gazetteer for GazetteerNominatim {
     fn all_names(&self) -> Vec<&str> {
         vec![] // Not applicable for API-based gazetteers
     }
+
+    fn resolve(&self, name: &str) -> Option<ResolvedPlace> {
+        let url = format!(
+            "{}/search?q={}&format=json&limit=1&addressdetails=1&extratags=1",
+            self.base_url,
+            urlencoding::encode(name)
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let response = client
+            .get(&url)
+            .header("User-Agent", &self.user_agent)
+            .send()
+            .ok()?;
+
+        let results: Vec<NominatimResponse> = response.json().ok()?;
+        let result = results.into_iter().next()?;
+
+        let lat: f64 = result.lat.parse().ok()?;
+        let lon: f64 = result.lon.parse().ok()?;
+        let address = result.address.unwrap_or_default();
+
+        Some(ResolvedPlace {
+            name: result.name.or(result.display_name).unwrap_or_else(|| name.to_string()),
+            feature_type: nominatim_place_type(result.addresstype.as_deref()),
+            country: address.country,
+            admin1: address.state.or(address.region),
+            population: None,
+            aliases: Vec::new(),
+            location: Location::new(lat, lon),
+        })
+    }
+
+    fn lookup_all(&self, name: &str) -> Vec<ResolvedPlace> {
+        let url = format!(
+            "{}/search?q={}&format=json&limit=10&addressdetails=1&extratags=1",
+            self.base_url,
+            urlencoding::encode(name)
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let Some(response) = client
+            .get(&url)
+            .header("User-Agent", &self.user_agent)
+            .send()
+            .ok()
+        else {
+            return Vec::new();
+        };
+
+        let results: Vec<NominatimResponse> = response.json().unwrap_or_default();
+        results
+            .into_iter()
+            .filter_map(|result| {
+                let lat: f64 = result.lat.parse().ok()?;
+                let lon: f64 = result.lon.parse().ok()?;
+                let address = result.address.unwrap_or_default();
+                Some(ResolvedPlace {
+                    name: result
+                        .name
+                        .or(result.display_name)
+                        .unwrap_or_else(|| name.to_string()),
+                    feature_type: nominatim_place_type(result.addresstype.as_deref()),
+                    country: address.country,
+                    admin1: address.state.or(address.region),
+                    population: None,
+                    aliases: Vec::new(),
+                    location: Location::new(lat, lon),
+                })
+            })
+            .collect()
+    }
+
+    fn lookup_ranked(&self, name: &str) -> Vec<RankedPlace> {
+        let url = format!(
+            "{}/search?q={}&format=json&limit=10&addressdetails=1&extratags=1",
+            self.base_url,
+            urlencoding::encode(name)
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let Some(response) = client
+            .get(&url)
+            .header("User-Agent", &self.user_agent)
+            .send()
+            .ok()
+        else {
+            return Vec::new();
+        };
+
+        let results: Vec<NominatimResponse> = response.json().unwrap_or_default();
+        let mut ranked: Vec<RankedPlace> = results
+            .into_iter()
+            .filter_map(|result| {
+                let lat: f64 = result.lat.parse().ok()?;
+                let lon: f64 = result.lon.parse().ok()?;
+                let address = result.address.unwrap_or_default();
+                Some(RankedPlace {
+                    place: ResolvedPlace {
+                        name: result
+                            .name
+                            .or(result.display_name)
+                            .unwrap_or_else(|| name.to_string()),
+                        feature_type: nominatim_place_type(result.addresstype.as_deref()),
+                        country: address.country,
+                        admin1: address.state.or(address.region),
+                        population: None,
+                        aliases: Vec::new(),
+                        location: Location::new(lat, lon),
+                    },
+                    importance: result.importance.unwrap_or(0.0),
+                    rank_search: result.place_rank,
+                })
+            })
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.importance
+                .total_cmp(&a.importance)
+                .then_with(|| a.rank_search.cmp(&b.rank_search))
+        });
+        ranked
+    }
+
+    fn reverse(&self, lat: f64, lon: f64) -> Option<Location> {
+        let url = format!(
+            "{}/reverse?lat={}&lon={}&format=json",
+            self.base_url, lat, lon
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let response = client
+            .get(&url)
+            .header("User-Agent", &self.user_agent)
+            .send()
+            .ok()?;
+
+        let result: NominatimResponse = response.json().ok()?;
+        let lat: f64 = result.lat.parse().ok()?;
+        let lon: f64 = result.lon.parse().ok()?;
+
+        Some(Location::new(lat, lon))
+    }
+
+    fn reverse_lookup(&self, lat: f64, lon: f64, max_results: usize) -> Vec<GazetteerEntry> {
+        // Nominatim's /reverse endpoint answers with a single best match, so
+        // this returns at most one entry regardless of `max_results` — unlike
+        // `BuiltinGazetteer`'s index-backed k-nearest, there is no paged or
+        // ranked variant of this call to ask for more.
+        if max_results == 0 {
+            return Vec::new();
+        }
+        let url = format!(
+            "{}/reverse?lat={}&lon={}&format=json&addressdetails=1&extratags=1",
+            self.base_url, lat, lon
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let Some(response) = client
+            .get(&url)
+            .header("User-Agent", &self.user_agent)
+            .send()
+            .ok()
+        else {
+            return Vec::new();
+        };
+        let Ok(result) = response.json::<NominatimResponse>() else {
+            return Vec::new();
+        };
+        let Some(lat) = result.lat.parse::<f64>().ok() else {
+            return Vec::new();
+        };
+        let Some(lon) = result.lon.parse::<f64>().ok() else {
+            return Vec::new();
+        };
+        let address = result.address.unwrap_or_default();
+        let country = address.country.unwrap_or_default();
+        let country_info = country_metadata(&country);
+
+        vec![GazetteerEntry {
+            name: result.name.or(result.display_name).unwrap_or_default(),
+            country,
+            lat,
+            lon,
+            population: 0,
+            aliases: Vec::new(),
+            country_info,
+            rank_search: result.place_rank,
+            importance: result.importance.unwrap_or(0.0),
+            wikipedia: result.extratags.as_ref().and_then(|t| t.get("wikipedia").cloned()),
+            feature_class: result.category,
+            feature_type: result.place_type,
+            ..Default::default()
+        }]
+    }
 }
 
 #[cfg(not(feature = "geocoding"))]
@@ -189,7 +877,7 @@ impl Gazetteer for GazetteerNominatim {
 ///
 /// let gaz = GazetteerGeoNames::new("your_username");
 /// if let Some(loc) = gaz.lookup("Tokyo") {
-///     println!("Tokyo: {}, {}", loc.lat, loc.lon);
+///     println!("Tokyo: {}, {}", loc.lat(), loc.lon());
 /// }
 /// ```
 pub struct GazetteerGeoNames {
@@ -207,7 +895,7 @@ impl GazetteerGeoNames {
 }
 
 #[cfg(feature = "geocoding")]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Default)]
 struct GeoNamesResponse {
     geonames: Vec<GeoNamesEntry>,
 }
@@ -219,6 +907,30 @@ struct GeoNamesEntry {
     lat: String,
     lng: String,
     name: String,
+    #[serde(rename = "countryName", default)]
+    country_name: Option<String>,
+    #[serde(rename = "adminName1", default)]
+    admin_name1: Option<String>,
+    #[serde(default)]
+    fcl: Option<String>,
+    #[serde(default)]
+    fcode: Option<String>,
+    #[serde(default)]
+    population: Option<u64>,
+}
+
+#[cfg(feature = "geocoding")]
+/// Map a GeoNames feature class/code onto a [`PlaceType`].
+fn geonames_place_type(fcl: Option<&str>, fcode: Option<&str>) -> PlaceType {
+    if fcode.is_some_and(|c| c.starts_with("PCL")) {
+        PlaceType::Country
+    } else {
+        match fcl {
+            Some("A") => PlaceType::AdminDivision1,
+            Some("P") => PlaceType::PopulatedPlace,
+            _ => PlaceType::Landmark,
+        }
+    }
 }
 
 #[cfg(feature = "geocoding")]
@@ -248,6 +960,124 @@ impl Gazetteer for GazetteerGeoNames {
     fn all_names(&self) -> Vec<&str> {
         vec![]
     }
+
+    fn resolve(&self, name: &str) -> Option<ResolvedPlace> {
+        let url = format!(
+            "http://api.geonames.org/searchJSON?q={}&maxRows=1&username={}",
+            urlencoding::encode(name),
+            urlencoding::encode(&self.username)
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let response = client.get(&url).send().ok()?;
+        let data: GeoNamesResponse = response.json().ok()?;
+        let entry = data.geonames.into_iter().next()?;
+
+        let lat: f64 = entry.lat.parse().ok()?;
+        let lon: f64 = entry.lng.parse().ok()?;
+        let feature_type = geonames_place_type(entry.fcl.as_deref(), entry.fcode.as_deref());
+
+        Some(ResolvedPlace {
+            name: entry.name,
+            feature_type,
+            country: entry.country_name,
+            admin1: entry.admin_name1,
+            population: entry.population,
+            aliases: Vec::new(),
+            location: Location::new(lat, lon),
+        })
+    }
+
+    fn lookup_all(&self, name: &str) -> Vec<ResolvedPlace> {
+        let url = format!(
+            "http://api.geonames.org/searchJSON?q={}&maxRows=10&username={}",
+            urlencoding::encode(name),
+            urlencoding::encode(&self.username)
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let Some(response) = client.get(&url).send().ok() else {
+            return Vec::new();
+        };
+        let data: GeoNamesResponse = response.json().unwrap_or_default();
+
+        data.geonames
+            .into_iter()
+            .filter_map(|entry| {
+                let lat: f64 = entry.lat.parse().ok()?;
+                let lon: f64 = entry.lng.parse().ok()?;
+                let feature_type =
+                    geonames_place_type(entry.fcl.as_deref(), entry.fcode.as_deref());
+                Some(ResolvedPlace {
+                    name: entry.name,
+                    feature_type,
+                    country: entry.country_name,
+                    admin1: entry.admin_name1,
+                    population: entry.population,
+                    aliases: Vec::new(),
+                    location: Location::new(lat, lon),
+                })
+            })
+            .collect()
+    }
+
+    fn reverse(&self, lat: f64, lon: f64) -> Option<Location> {
+        let url = format!(
+            "http://api.geonames.org/findNearbyJSON?lat={}&lng={}&username={}",
+            lat,
+            lon,
+            urlencoding::encode(&self.username)
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let response = client.get(&url).send().ok()?;
+        let data: GeoNamesResponse = response.json().ok()?;
+        let entry = data.geonames.first()?;
+
+        let lat: f64 = entry.lat.parse().ok()?;
+        let lon: f64 = entry.lng.parse().ok()?;
+
+        Some(Location::new(lat, lon))
+    }
+
+    fn reverse_lookup(&self, lat: f64, lon: f64, max_results: usize) -> Vec<GazetteerEntry> {
+        if max_results == 0 {
+            return Vec::new();
+        }
+        let url = format!(
+            "http://api.geonames.org/findNearbyJSON?lat={}&lng={}&maxRows={}&username={}",
+            lat,
+            lon,
+            max_results,
+            urlencoding::encode(&self.username)
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let Some(response) = client.get(&url).send().ok() else {
+            return Vec::new();
+        };
+        let data: GeoNamesResponse = response.json().unwrap_or_default();
+
+        data.geonames
+            .into_iter()
+            .filter_map(|entry| {
+                let lat: f64 = entry.lat.parse().ok()?;
+                let lon: f64 = entry.lng.parse().ok()?;
+                let country = entry.country_name.unwrap_or_default();
+                let country_info = country_metadata(&country);
+                Some(GazetteerEntry {
+                    name: entry.name,
+                    country,
+                    lat,
+                    lon,
+                    population: entry.population.unwrap_or(0),
+                    aliases: Vec::new(),
+                    country_info,
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
 }
 
 #[cfg(not(feature = "geocoding"))]
@@ -279,7 +1109,7 @@ impl Gazetteer for GazetteerGeoNames {
 ///
 /// let gaz = GazetteerWikidata::new();
 /// if let Some(loc) = gaz.lookup("London") {
-///     println!("London: {}, {}", loc.lat, loc.lon);
+///     println!("London: {}, {}", loc.lat(), loc.lon());
 /// }
 /// ```
 pub struct GazetteerWikidata {
@@ -308,16 +1138,36 @@ LIMIT 1
             name.replace('"', r#"\""#)
         )
     }
-}
 
-#[cfg(feature = "geocoding")]
-#[derive(Debug, Deserialize)]
-struct WikidataResponse {
-    results: WikidataResults,
-}
-
-#[cfg(feature = "geocoding")]
-#[derive(Debug, Deserialize)]
+    /// Like [`build_query`](Self::build_query), but also asks for each
+    /// candidate's sitelink count (`wikibase:sitelinks`) as a proxy for
+    /// real-world prominence, and returns up to 10 matches instead of just
+    /// the first.
+    fn build_ranked_query(name: &str) -> String {
+        format!(
+            r#"
+SELECT ?place ?placeLabel ?coord ?sitelinks WHERE {{
+  ?place rdfs:label "{}"@en.
+  ?place wdt:P625 ?coord.
+  ?place wikibase:sitelinks ?sitelinks.
+  SERVICE wikibase:label {{ bd:serviceParam wikibase:language "en". }}
+}}
+ORDER BY DESC(?sitelinks)
+LIMIT 10
+"#,
+            name.replace('"', r#"\""#)
+        )
+    }
+}
+
+#[cfg(feature = "geocoding")]
+#[derive(Debug, Deserialize)]
+struct WikidataResponse {
+    results: WikidataResults,
+}
+
+#[cfg(feature = "geocoding")]
+#[derive(Debug, Deserialize)]
 struct WikidataResults {
     bindings: Vec<WikidataBinding>,
 }
@@ -325,7 +1175,11 @@ struct WikidataResults {
 #[cfg(feature = "geocoding")]
 #[derive(Debug, Deserialize)]
 struct WikidataBinding {
+    #[serde(default, rename = "placeLabel")]
+    place_label: Option<WikidataValue>,
     coord: WikidataValue,
+    #[serde(default)]
+    sitelinks: Option<WikidataValue>,
 }
 
 #[cfg(feature = "geocoding")]
@@ -334,6 +1188,19 @@ struct WikidataValue {
     value: String,
 }
 
+/// Normalize a Wikidata sitelink count into a `[0.0, 1.0]` importance score.
+///
+/// Sitelinks (the number of Wikimedia projects with an article on the
+/// entity) have no fixed upper bound, so this saturates at
+/// `SITELINKS_AT_FULL_IMPORTANCE`, past which additional sitelinks no longer
+/// move the score — a handful of the most-documented places in the world
+/// would otherwise all compress toward the same score anyway.
+#[cfg(feature = "geocoding")]
+fn sitelinks_to_importance(sitelinks: u32) -> f64 {
+    const SITELINKS_AT_FULL_IMPORTANCE: f64 = 50.0;
+    (sitelinks as f64 / SITELINKS_AT_FULL_IMPORTANCE).clamp(0.0, 1.0)
+}
+
 #[cfg(feature = "geocoding")]
 impl Gazetteer for GazetteerWikidata {
     fn lookup(&self, name: &str) -> Option<Location> {
@@ -373,6 +1240,66 @@ impl Gazetteer for GazetteerWikidata {
     fn all_names(&self) -> Vec<&str> {
         vec![]
     }
+
+    fn lookup_ranked(&self, name: &str) -> Vec<RankedPlace> {
+        let query = Self::build_ranked_query(name);
+        let client = reqwest::blocking::Client::new();
+
+        let Ok(response) = client
+            .get(&self.endpoint)
+            .query(&[("query", query)])
+            .header("User-Agent", "spatial-narrative/0.1.0")
+            .header("Accept", "application/sparql-results+json")
+            .send()
+        else {
+            return Vec::new();
+        };
+        let data: WikidataResponse = response.json().unwrap_or(WikidataResponse {
+            results: WikidataResults { bindings: Vec::new() },
+        });
+
+        let mut ranked: Vec<RankedPlace> = data
+            .results
+            .bindings
+            .into_iter()
+            .filter_map(|binding| {
+                let point = binding
+                    .coord
+                    .value
+                    .strip_prefix("Point(")
+                    .and_then(|s| s.strip_suffix(')'))?;
+                let parts: Vec<&str> = point.split_whitespace().collect();
+                if parts.len() != 2 {
+                    return None;
+                }
+                let lon: f64 = parts[0].parse().ok()?;
+                let lat: f64 = parts[1].parse().ok()?;
+                let sitelinks: u32 = binding
+                    .sitelinks
+                    .and_then(|v| v.value.parse().ok())
+                    .unwrap_or(0);
+
+                Some(RankedPlace {
+                    place: ResolvedPlace {
+                        name: binding
+                            .place_label
+                            .map(|v| v.value)
+                            .unwrap_or_else(|| name.to_string()),
+                        feature_type: PlaceType::PopulatedPlace,
+                        country: None,
+                        admin1: None,
+                        population: None,
+                        aliases: Vec::new(),
+                        location: Location::new(lat, lon),
+                    },
+                    importance: sitelinks_to_importance(sitelinks),
+                    rank_search: None,
+                })
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.importance.total_cmp(&a.importance));
+        ranked
+    }
 }
 
 #[cfg(not(feature = "geocoding"))]
@@ -392,6 +1319,320 @@ impl Gazetteer for GazetteerWikidata {
     }
 }
 
+#[cfg(feature = "geoip")]
+/// Offline gazetteer backed by a MaxMind `.mmdb` database.
+///
+/// Resolves IP address strings to coordinates by reading the
+/// `location.latitude`/`location.longitude` fields of a GeoLite2-City record.
+/// Because it needs no network it can be dropped into a [`MultiGazetteer`]
+/// alongside the API sources, or used alone in air-gapped deployments.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use spatial_narrative::parser::{GazetteerGeoIP, Gazetteer};
+///
+/// let gaz = GazetteerGeoIP::open("GeoLite2-City.mmdb").unwrap();
+/// if let Some(loc) = gaz.lookup("8.8.8.8") {
+///     println!("{}, {}", loc.lat(), loc.lon());
+/// }
+/// ```
+pub struct GazetteerGeoIP {
+    reader: maxminddb::Reader<Vec<u8>>,
+}
+
+#[cfg(feature = "geoip")]
+impl GazetteerGeoIP {
+    /// Open a MaxMind `.mmdb` database file (e.g. GeoLite2-City).
+    pub fn open(path: impl AsRef<std::path::Path>) -> crate::Result<Self> {
+        let reader = maxminddb::Reader::open_readfile(path)
+            .map_err(|e| crate::Error::InvalidFormat(format!("invalid mmdb database: {}", e)))?;
+        Ok(Self { reader })
+    }
+}
+
+#[cfg(feature = "geoip")]
+impl Gazetteer for GazetteerGeoIP {
+    fn lookup(&self, name: &str) -> Option<Location> {
+        let ip: std::net::IpAddr = name.trim().parse().ok()?;
+        let city: maxminddb::geoip2::City = self.reader.lookup(ip).ok()??;
+        let location = city.location?;
+        Some(Location::new(location.latitude?, location.longitude?))
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.lookup(name).is_some()
+    }
+
+    fn all_names(&self) -> Vec<&str> {
+        vec![] // IPs are not enumerable names
+    }
+}
+
+#[cfg(not(feature = "geoip"))]
+/// Offline gazetteer backed by a MaxMind `.mmdb` database (requires `geoip` feature).
+pub struct GazetteerGeoIP;
+
+#[cfg(not(feature = "geoip"))]
+impl Gazetteer for GazetteerGeoIP {
+    fn lookup(&self, _name: &str) -> Option<Location> {
+        None
+    }
+    fn contains(&self, _name: &str) -> bool {
+        false
+    }
+    fn all_names(&self) -> Vec<&str> {
+        vec![]
+    }
+}
+
+#[cfg(feature = "sqlite")]
+/// Gazetteer backed by a SQLite database with a geohash spatial index.
+///
+/// Unlike [`BuiltinGazetteer`], which keeps every entry in memory, this source
+/// streams queries against an on-disk database and scales to the full GeoNames
+/// dump. Name lookups use an index on the `name` column; reverse/nearest
+/// queries scan the query point's geohash cell plus its eight neighbors and
+/// rank the candidates by true haversine distance.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use spatial_narrative::parser::{GazetteerSqlite, Gazetteer};
+///
+/// let gaz = GazetteerSqlite::open("places.db").unwrap();
+/// if let Some(loc) = gaz.lookup("Paris") {
+///     println!("{}, {}", loc.lat(), loc.lon());
+/// }
+/// ```
+pub struct GazetteerSqlite {
+    conn: rusqlite::Connection,
+}
+
+#[cfg(feature = "sqlite")]
+impl GazetteerSqlite {
+    /// Precision of the stored geohash, in characters (~1.2 km cells).
+    const GEOHASH_LEN: usize = 6;
+
+    /// Open an existing places database.
+    pub fn open(path: impl AsRef<std::path::Path>) -> crate::Result<Self> {
+        let conn = rusqlite::Connection::open(path)
+            .map_err(|e| crate::Error::InvalidFormat(format!("open sqlite: {}", e)))?;
+        Ok(Self { conn })
+    }
+
+    /// Stream a tab-separated GeoNames dump into a new database at `db_path`.
+    ///
+    /// Reads the standard GeoNames column layout (name in field 1, latitude in
+    /// field 4, longitude in field 5, country code in field 8, first admin code
+    /// in field 10, population in field 14), computes a geohash for each row,
+    /// and writes everything inside a single transaction.
+    pub fn build_from_geonames<R: std::io::Read>(
+        reader: R,
+        db_path: impl AsRef<std::path::Path>,
+    ) -> crate::Result<Self> {
+        let mut conn = rusqlite::Connection::open(db_path)
+            .map_err(|e| crate::Error::InvalidFormat(format!("open sqlite: {}", e)))?;
+
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS places (
+                 name TEXT NOT NULL,
+                 country TEXT,
+                 admin1 TEXT,
+                 lat REAL NOT NULL,
+                 lon REAL NOT NULL,
+                 population INTEGER NOT NULL DEFAULT 0,
+                 geohash TEXT NOT NULL
+             );
+             CREATE INDEX IF NOT EXISTS idx_places_name ON places(name);
+             CREATE INDEX IF NOT EXISTS idx_places_geohash ON places(geohash);",
+        )
+        .map_err(|e| crate::Error::InvalidFormat(format!("create schema: {}", e)))?;
+
+        let tx = conn
+            .transaction()
+            .map_err(|e| crate::Error::InvalidFormat(format!("begin transaction: {}", e)))?;
+        {
+            let mut stmt = tx
+                .prepare(
+                    "INSERT INTO places (name, country, admin1, lat, lon, population, geohash)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                )
+                .map_err(|e| crate::Error::InvalidFormat(format!("prepare insert: {}", e)))?;
+
+            let mut buf = String::new();
+            let mut reader = std::io::BufReader::new(reader);
+            loop {
+                buf.clear();
+                let read = std::io::BufRead::read_line(&mut reader, &mut buf)
+                    .map_err(|e| crate::Error::InvalidFormat(format!("read line: {}", e)))?;
+                if read == 0 {
+                    break;
+                }
+
+                let fields: Vec<&str> = buf.trim_end().split('\t').collect();
+                if fields.len() < 15 {
+                    continue;
+                }
+                let (lat, lon) = match (fields[4].parse::<f64>(), fields[5].parse::<f64>()) {
+                    (Ok(lat), Ok(lon)) => (lat, lon),
+                    _ => continue,
+                };
+                let population: i64 = fields[14].parse().unwrap_or(0);
+                let Some(geohash) = encode_geohash(lat, lon, Self::GEOHASH_LEN) else {
+                    continue;
+                };
+
+                stmt.execute(rusqlite::params![
+                    fields[1],
+                    fields[8],
+                    fields[10],
+                    lat,
+                    lon,
+                    population,
+                    geohash,
+                ])
+                .map_err(|e| crate::Error::InvalidFormat(format!("insert row: {}", e)))?;
+            }
+        }
+        tx.commit()
+            .map_err(|e| crate::Error::InvalidFormat(format!("commit: {}", e)))?;
+
+        Ok(Self { conn })
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl Gazetteer for GazetteerSqlite {
+    fn lookup(&self, name: &str) -> Option<Location> {
+        self.conn
+            .query_row(
+                "SELECT lat, lon FROM places WHERE name = ?1 COLLATE NOCASE
+                 ORDER BY population DESC LIMIT 1",
+                rusqlite::params![name],
+                |row| Ok(Location::new(row.get::<_, f64>(0)?, row.get::<_, f64>(1)?)),
+            )
+            .ok()
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.lookup(name).is_some()
+    }
+
+    fn all_names(&self) -> Vec<&str> {
+        vec![] // Names live on disk and are not borrowable
+    }
+
+    fn reverse(&self, lat: f64, lon: f64) -> Option<Location> {
+        let hash = encode_geohash(lat, lon, Self::GEOHASH_LEN)?;
+        let cells = geohash_cells(&hash);
+
+        let mut best: Option<(f64, Location)> = None;
+        for cell in cells {
+            let mut stmt = self
+                .conn
+                .prepare("SELECT lat, lon FROM places WHERE geohash LIKE ?1")
+                .ok()?;
+            let pattern = format!("{}%", cell);
+            let rows = stmt
+                .query_map(rusqlite::params![pattern], |row| {
+                    Ok(Location::new(row.get::<_, f64>(0)?, row.get::<_, f64>(1)?))
+                })
+                .ok()?;
+
+            for row in rows.flatten() {
+                let d = haversine_meters(lat, lon, row.lat, row.lon);
+                if best.as_ref().map(|(bd, _)| d < *bd).unwrap_or(true) {
+                    best = Some((d, row));
+                }
+            }
+        }
+
+        best.map(|(_, loc)| loc)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+/// Encode a coordinate as a geohash of the given precision.
+fn encode_geohash(lat: f64, lon: f64, len: usize) -> Option<String> {
+    geohash::encode(geo_types::Coord { x: lon, y: lat }, len).ok()
+}
+
+#[cfg(feature = "sqlite")]
+/// The geohash cell plus its eight neighbors, for a 3x3 proximity scan.
+fn geohash_cells(hash: &str) -> Vec<String> {
+    let mut cells = vec![hash.to_string()];
+    if let Ok(n) = geohash::neighbors(hash) {
+        cells.extend([n.n, n.ne, n.e, n.se, n.s, n.sw, n.w, n.nw]);
+    }
+    cells
+}
+
+/// Rank a lowercased surface form against a lowercased query prefix.
+///
+/// Returns `Some(0)` when some whitespace- or punctuation-delimited token starts
+/// with the query (a full-token prefix), `Some(1)` when the query merely appears
+/// somewhere inside, and `None` when it does not match at all.
+fn match_rank(surface: &str, query: &str) -> Option<u8> {
+    if surface
+        .split(|c: char| !c.is_alphanumeric())
+        .any(|token| token.starts_with(query))
+    {
+        Some(0)
+    } else if surface.contains(query) {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+/// Great-circle distance between two coordinates, in kilometers.
+///
+/// `a = sin²(Δφ/2) + cos φ₁ · cos φ₂ · sin²(Δλ/2)`, `d = 2R·asin(√a)` with
+/// `R ≈ 6371 km`. The longitude difference works across the antimeridian
+/// without special-casing.
+fn haversine_km(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    const EARTH_RADIUS_KM: f64 = 6371.0;
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let dlat = lat2 - lat1;
+    let dlon = (lon2 - lon1).to_radians();
+    let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+    EARTH_RADIUS_KM * 2.0 * a.sqrt().asin()
+}
+
+#[cfg(feature = "sqlite")]
+/// Great-circle distance between two coordinates, in meters.
+fn haversine_meters(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    const EARTH_RADIUS_M: f64 = 6_371_000.0;
+    let (lat1, lon1, lat2, lon2) = (
+        lat1.to_radians(),
+        lon1.to_radians(),
+        lat2.to_radians(),
+        lon2.to_radians(),
+    );
+    let dlat = lat2 - lat1;
+    let dlon = lon2 - lon1;
+    let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+    EARTH_RADIUS_M * 2.0 * a.sqrt().asin()
+}
+
+#[cfg(not(feature = "sqlite"))]
+/// Gazetteer backed by a SQLite database (requires `sqlite` feature).
+pub struct GazetteerSqlite;
+
+#[cfg(not(feature = "sqlite"))]
+impl Gazetteer for GazetteerSqlite {
+    fn lookup(&self, _name: &str) -> Option<Location> {
+        None
+    }
+    fn contains(&self, _name: &str) -> bool {
+        false
+    }
+    fn all_names(&self) -> Vec<&str> {
+        vec![]
+    }
+}
+
 /// Gazetteer that tries multiple sources in order.
 ///
 /// Queries each gazetteer in sequence until one returns a result.
@@ -410,61 +1651,520 @@ impl Gazetteer for GazetteerWikidata {
 /// multi.add_source(Box::new(GazetteerNominatim::new())); // Then Nominatim
 ///
 /// if let Some(loc) = multi.lookup("Paris") {
-///     println!("Found Paris: {}, {}", loc.lat, loc.lon);
+///     println!("Found Paris: {}, {}", loc.lat(), loc.lon());
 /// }
 /// ```
 pub struct MultiGazetteer {
     sources: Vec<Box<dyn Gazetteer>>,
+    /// Two sources answering the same name with coordinates farther apart than
+    /// this (in kilometers) are flagged as disagreeing.
+    disagreement_km: f64,
 }
 
+/// Default great-circle distance beyond which two sources are taken to disagree.
+const DEFAULT_DISAGREEMENT_KM: f64 = 25.0;
+
 impl MultiGazetteer {
     /// Create a new empty multi-gazetteer.
     pub fn new() -> Self {
         Self {
             sources: Vec::new(),
+            disagreement_km: DEFAULT_DISAGREEMENT_KM,
         }
     }
 
-    /// Add a gazetteer source (will be queried in order).
-    pub fn add_source(&mut self, source: Box<dyn Gazetteer>) {
-        self.sources.push(source);
+    /// Add a gazetteer source (will be queried in order).
+    pub fn add_source(&mut self, source: Box<dyn Gazetteer>) {
+        self.sources.push(source);
+    }
+
+    /// Create a multi-gazetteer with the given sources.
+    pub fn from_sources(sources: Vec<Box<dyn Gazetteer>>) -> Self {
+        Self {
+            sources,
+            disagreement_km: DEFAULT_DISAGREEMENT_KM,
+        }
+    }
+
+    /// Set the distance threshold beyond which two sources returning the same
+    /// name are flagged as disagreeing.
+    pub fn with_disagreement_threshold(mut self, km: f64) -> Self {
+        self.disagreement_km = km;
+        self
+    }
+
+    /// Query every source and return a ranked, explainable set of matches.
+    ///
+    /// Each answering source contributes a [`ScoredMatch`] whose confidence
+    /// reflects how the name matched — exact canonical name, alias, or a fuzzy
+    /// match within Levenshtein distance 2 — and which source answered. When two
+    /// sources return coordinates for the same name that differ by more than the
+    /// [configured threshold](Self::with_disagreement_threshold), both are kept
+    /// and flagged as disagreeing so ambiguous toponyms surface instead of being
+    /// silently collapsed.
+    ///
+    /// Matches are ordered by confidence, then population, then source priority.
+    pub fn resolve_scored(&self, name: &str) -> Vec<ScoredMatch> {
+        let mut matches: Vec<ScoredMatch> = Vec::new();
+        for (idx, source) in self.sources.iter().enumerate() {
+            if let Some((location, confidence, population)) = score_match(source.as_ref(), name) {
+                matches.push(ScoredMatch {
+                    name: name.to_string(),
+                    location,
+                    confidence,
+                    source: idx,
+                    population,
+                    disagreement: false,
+                });
+            }
+        }
+
+        // Flag mutual disagreement among same-name coordinate clusters.
+        let coords: Vec<Location> = matches.iter().map(|m| m.location.clone()).collect();
+        let flags: Vec<bool> = coords
+            .iter()
+            .enumerate()
+            .map(|(i, here)| {
+                coords.iter().enumerate().any(|(j, other)| {
+                    i != j
+                        && haversine_km(here.lat, here.lon, other.lat, other.lon)
+                            > self.disagreement_km
+                })
+            })
+            .collect();
+        for (m, flag) in matches.iter_mut().zip(flags) {
+            m.disagreement = flag;
+        }
+
+        matches.sort_by(|a, b| {
+            b.confidence
+                .total_cmp(&a.confidence)
+                .then_with(|| b.population.cmp(&a.population))
+                .then_with(|| a.source.cmp(&b.source))
+        });
+        matches
+    }
+}
+
+/// A single source's answer for a name, with a confidence score and provenance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoredMatch {
+    /// The queried name.
+    pub name: String,
+    /// Coordinates this source returned.
+    pub location: Location,
+    /// Confidence in `[0.0, 1.0]`: exact > alias > fuzzy.
+    pub confidence: f64,
+    /// Index of the source (in priority order) that answered.
+    pub source: usize,
+    /// Population of the matched place, when the source reported one.
+    pub population: Option<u64>,
+    /// True when another source disagreed on the coordinates beyond threshold.
+    pub disagreement: bool,
+}
+
+/// Score how `source` matched `name`, returning its coordinates, a confidence,
+/// and any population. Exact canonical-name matches outrank aliases, which
+/// outrank fuzzy matches within Levenshtein distance 2.
+fn score_match(source: &dyn Gazetteer, name: &str) -> Option<(Location, f64, Option<u64>)> {
+    if let Some(place) = source.resolve(name) {
+        let confidence = if place.name.eq_ignore_ascii_case(name) {
+            1.0
+        } else if place
+            .aliases
+            .iter()
+            .any(|a| a.eq_ignore_ascii_case(name))
+            || source.aliases(name).iter().any(|a| a.eq_ignore_ascii_case(name))
+        {
+            0.8
+        } else {
+            // Resolved but neither the canonical name nor a known alias — treat
+            // as a strong but not exact match.
+            0.9
+        };
+        return Some((place.location, confidence, place.population));
+    }
+
+    // No direct hit: fall back to the closest suggestion within edit distance 2.
+    let (suggestion, location, _) = source.suggest(name, 1).into_iter().next()?;
+    let distance = levenshtein(&name.to_lowercase(), &suggestion.to_lowercase());
+    if distance > 2 {
+        return None;
+    }
+    let max_len = name.chars().count().max(suggestion.chars().count()).max(1);
+    let similarity = 1.0 - (distance as f64 / max_len as f64);
+    Some((location, 0.6 * similarity, None))
+}
+
+/// Levenshtein edit distance between two strings.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+impl Default for MultiGazetteer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Gazetteer for MultiGazetteer {
+    fn lookup(&self, name: &str) -> Option<Location> {
+        for g in &self.sources {
+            if let Some(loc) = g.lookup(name) {
+                return Some(loc);
+            }
+        }
+        None
+    }
+    fn contains(&self, name: &str) -> bool {
+        self.sources.iter().any(|g| g.contains(name))
+    }
+    fn all_names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        for g in &self.sources {
+            names.extend(g.all_names());
+        }
+        names
+    }
+    fn reverse(&self, lat: f64, lon: f64) -> Option<Location> {
+        for g in &self.sources {
+            if let Some(loc) = g.reverse(lat, lon) {
+                return Some(loc);
+            }
+        }
+        None
+    }
+    fn reverse_lookup(&self, lat: f64, lon: f64, max_results: usize) -> Vec<GazetteerEntry> {
+        // Distance within which two sources' answers are treated as the same
+        // place, so e.g. the builtin table and an API source both naming
+        // "Paris" don't both appear.
+        const DEDUP_KM: f64 = 1.0;
+        let mut merged: Vec<GazetteerEntry> = Vec::new();
+        for source in &self.sources {
+            if merged.len() >= max_results {
+                break;
+            }
+            for entry in source.reverse_lookup(lat, lon, max_results) {
+                let is_duplicate = merged
+                    .iter()
+                    .any(|existing| haversine_km(entry.lat, entry.lon, existing.lat, existing.lon) <= DEDUP_KM);
+                if !is_duplicate {
+                    merged.push(entry);
+                }
+            }
+        }
+        merged.truncate(max_results);
+        merged
+    }
+    fn suggest(&self, partial: &str, limit: usize) -> Vec<(String, Location, f64)> {
+        let mut all: Vec<(String, Location, f64)> = Vec::new();
+        for g in &self.sources {
+            all.extend(g.suggest(partial, limit));
+        }
+        all.sort_by(|a, b| b.2.total_cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+        all.truncate(limit);
+        all
+    }
+    fn resolve(&self, name: &str) -> Option<ResolvedPlace> {
+        self.sources.iter().find_map(|g| g.resolve(name))
+    }
+    fn lookup_all(&self, name: &str) -> Vec<ResolvedPlace> {
+        // Query each source in turn and return the first that has candidates,
+        // mirroring the fallback order of [`lookup`](Self::lookup).
+        for g in &self.sources {
+            let places = g.lookup_all(name);
+            if !places.is_empty() {
+                return places;
+            }
+        }
+        Vec::new()
+    }
+    fn lookup_scored(&self, name: &str) -> Vec<ScoredPlace> {
+        // Mirrors lookup_all's first-non-empty-source fallback, keeping each
+        // source's own match-confidence scoring.
+        for g in &self.sources {
+            let scored = g.lookup_scored(name);
+            if !scored.is_empty() {
+                return scored;
+            }
+        }
+        Vec::new()
+    }
+    fn lookup_ranked(&self, name: &str) -> Vec<RankedPlace> {
+        // Unlike lookup_all/lookup_scored, which stop at the first source with
+        // any answer, disambiguation benefits from seeing every source's
+        // candidates at once: a less-preferred source may still carry the more
+        // prominent match (e.g. a geocoding API's "Paris, France" outranking a
+        // sparse offline table's unqualified "Paris, Texas" entry).
+        let mut ranked: Vec<RankedPlace> = self.sources.iter().flat_map(|g| g.lookup_ranked(name)).collect();
+        ranked.sort_by(|a, b| {
+            b.importance
+                .total_cmp(&a.importance)
+                .then_with(|| a.rank_search.cmp(&b.rank_search))
+        });
+        ranked
+    }
+}
+
+/// A single cached lookup: the resolved coordinate (or a miss) and the instant
+/// it was recorded, so negative results can be aged out on a shorter TTL than
+/// successful ones.
+struct CacheEntry {
+    value: Option<Location>,
+    stored: Instant,
+}
+
+/// Interior state of a [`CachingGazetteer`], guarded by a single mutex.
+struct CacheState {
+    entries: HashMap<String, CacheEntry>,
+    /// Query keys in least-recently-used order; the front is evicted first.
+    order: VecDeque<String>,
+    /// When the most recent request to the wrapped source was issued.
+    last_request: Option<Instant>,
+}
+
+/// Caching, rate-limiting decorator around any [`Gazetteer`].
+///
+/// API-backed gazetteers ([`GazetteerNominatim`], [`GazetteerGeoNames`], …)
+/// issue a blocking HTTP request on every [`lookup`](Gazetteer::lookup). Wrapping
+/// one in a `CachingGazetteer` adds three things:
+///
+/// - an in-memory LRU cache keyed on the normalized query so a name that recurs
+///   across a document hits the network only once,
+/// - an optional minimum delay between requests to the wrapped source, to honor
+///   usage policies such as Nominatim's one-request-per-second rule, and
+/// - optional JSON persistence of the positive cache so repeated runs over the
+///   same corpus start warm.
+///
+/// Misses are cached separately on a shorter TTL ([`DEFAULT_NEGATIVE_TTL`]) so a
+/// transient API failure isn't remembered forever. Because it wraps a boxed
+/// gazetteer, it composes on top of [`MultiGazetteer`] just as readily as a
+/// single source.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use spatial_narrative::parser::{CachingGazetteer, GazetteerNominatim, Gazetteer};
+/// use std::time::Duration;
+///
+/// let gaz = CachingGazetteer::new(Box::new(GazetteerNominatim::new()))
+///     .with_min_delay(Duration::from_secs(1));
+/// let _ = gaz.lookup("Berlin"); // hits the network
+/// let _ = gaz.lookup("Berlin"); // served from cache
+/// ```
+pub struct CachingGazetteer {
+    inner: Box<dyn Gazetteer>,
+    state: Mutex<CacheState>,
+    capacity: usize,
+    min_delay: Option<Duration>,
+    negative_ttl: Duration,
+    path: Option<std::path::PathBuf>,
+}
+
+/// Default number of distinct queries kept in the in-memory cache.
+pub const DEFAULT_CACHE_CAPACITY: usize = 4096;
+
+/// Default time-to-live for cached misses.
+pub const DEFAULT_NEGATIVE_TTL: Duration = Duration::from_secs(60);
+
+impl CachingGazetteer {
+    /// Wrap a gazetteer with a cache of [`DEFAULT_CACHE_CAPACITY`] entries and no
+    /// rate limit.
+    pub fn new(inner: Box<dyn Gazetteer>) -> Self {
+        Self {
+            inner,
+            state: Mutex::new(CacheState {
+                entries: HashMap::new(),
+                order: VecDeque::new(),
+                last_request: None,
+            }),
+            capacity: DEFAULT_CACHE_CAPACITY,
+            min_delay: None,
+            negative_ttl: DEFAULT_NEGATIVE_TTL,
+            path: None,
+        }
+    }
+
+    /// Set the maximum number of distinct queries held in memory.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Enforce a minimum delay between requests to the wrapped source.
+    pub fn with_min_delay(mut self, delay: Duration) -> Self {
+        self.min_delay = Some(delay);
+        self
+    }
+
+    /// Set how long cached misses remain valid before being re-queried.
+    pub fn with_negative_ttl(mut self, ttl: Duration) -> Self {
+        self.negative_ttl = ttl;
+        self
+    }
+
+    /// Persist the positive cache to `path` as JSON, loading any existing cache
+    /// from it first so repeated runs start warm.
+    pub fn with_disk_cache(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        let path = path.into();
+        if let Ok(text) = std::fs::read_to_string(&path) {
+            if let Ok(saved) = serde_json::from_str::<HashMap<String, Location>>(&text) {
+                let mut state = self.state.lock().unwrap();
+                for (key, location) in saved {
+                    state.order.push_back(key.clone());
+                    state.entries.insert(
+                        key,
+                        CacheEntry {
+                            value: Some(location),
+                            stored: Instant::now(),
+                        },
+                    );
+                }
+            }
+        }
+        self.path = Some(path);
+        self
+    }
+
+    /// Normalize a query the same way the cache keys on it.
+    fn key(name: &str) -> String {
+        name.trim().to_lowercase()
+    }
+
+    /// Return a cached value if present and still valid, refreshing recency.
+    fn cached(&self, state: &mut CacheState, key: &str) -> Option<Option<Location>> {
+        let expired = match state.entries.get(key) {
+            Some(entry) => entry.value.is_none() && entry.stored.elapsed() > self.negative_ttl,
+            None => return None,
+        };
+        if expired {
+            state.entries.remove(key);
+            state.order.retain(|k| k != key);
+            return None;
+        }
+        Self::touch(&mut state.order, key);
+        Some(state.entries[key].value.clone())
+    }
+
+    /// Move `key` to the most-recently-used end of the recency queue.
+    fn touch(order: &mut VecDeque<String>, key: &str) {
+        order.retain(|k| k != key);
+        order.push_back(key.to_string());
+    }
+
+    /// Insert a value, evicting the least-recently-used entry past capacity.
+    fn store(&self, state: &mut CacheState, key: String, value: Option<Location>) {
+        if !state.entries.contains_key(&key) && state.entries.len() >= self.capacity {
+            if let Some(evicted) = state.order.pop_front() {
+                state.entries.remove(&evicted);
+            }
+        }
+        Self::touch(&mut state.order, &key);
+        state.entries.insert(
+            key,
+            CacheEntry {
+                value,
+                stored: Instant::now(),
+            },
+        );
+    }
+
+    /// Sleep long enough to keep at least `min_delay` between source requests.
+    fn throttle(&self, state: &mut CacheState) {
+        if let Some(delay) = self.min_delay {
+            if let Some(last) = state.last_request {
+                let elapsed = last.elapsed();
+                if elapsed < delay {
+                    std::thread::sleep(delay - elapsed);
+                }
+            }
+            state.last_request = Some(Instant::now());
+        }
+    }
+
+    /// Write the positive cache back to disk, ignoring I/O errors.
+    fn persist(&self, state: &CacheState) {
+        let Some(path) = &self.path else { return };
+        let hits: HashMap<&String, &Location> = state
+            .entries
+            .iter()
+            .filter_map(|(k, e)| e.value.as_ref().map(|loc| (k, loc)))
+            .collect();
+        if let Ok(text) = serde_json::to_string(&hits) {
+            let _ = std::fs::write(path, text);
+        }
+    }
+}
+
+impl Gazetteer for CachingGazetteer {
+    fn lookup(&self, name: &str) -> Option<Location> {
+        let key = Self::key(name);
+        let mut state = self.state.lock().unwrap();
+        if let Some(hit) = self.cached(&mut state, &key) {
+            return hit;
+        }
+        self.throttle(&mut state);
+        let value = self.inner.lookup(name);
+        self.store(&mut state, key, value.clone());
+        self.persist(&state);
+        value
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.lookup(name).is_some()
+    }
+
+    fn all_names(&self) -> Vec<&str> {
+        self.inner.all_names()
+    }
+
+    fn aliases(&self, name: &str) -> Vec<&str> {
+        self.inner.aliases(name)
+    }
+
+    fn reverse(&self, lat: f64, lon: f64) -> Option<Location> {
+        self.inner.reverse(lat, lon)
+    }
+
+    fn reverse_lookup(&self, lat: f64, lon: f64, max_results: usize) -> Vec<GazetteerEntry> {
+        self.inner.reverse_lookup(lat, lon, max_results)
     }
 
-    /// Create a multi-gazetteer with the given sources.
-    pub fn from_sources(sources: Vec<Box<dyn Gazetteer>>) -> Self {
-        Self { sources }
+    fn suggest(&self, partial: &str, limit: usize) -> Vec<(String, Location, f64)> {
+        self.inner.suggest(partial, limit)
     }
-}
 
-impl Default for MultiGazetteer {
-    fn default() -> Self {
-        Self::new()
+    fn resolve(&self, name: &str) -> Option<ResolvedPlace> {
+        self.inner.resolve(name)
     }
-}
 
-impl Gazetteer for MultiGazetteer {
-    fn lookup(&self, name: &str) -> Option<Location> {
-        for g in &self.sources {
-            if let Some(loc) = g.lookup(name) {
-                return Some(loc);
-            }
-        }
-        None
+    fn lookup_all(&self, name: &str) -> Vec<ResolvedPlace> {
+        self.inner.lookup_all(name)
     }
-    fn contains(&self, name: &str) -> bool {
-        self.sources.iter().any(|g| g.contains(name))
+
+    fn lookup_scored(&self, name: &str) -> Vec<ScoredPlace> {
+        self.inner.lookup_scored(name)
     }
-    fn all_names(&self) -> Vec<&str> {
-        let mut names = Vec::new();
-        for g in &self.sources {
-            names.extend(g.all_names());
-        }
-        names
+
+    fn lookup_ranked(&self, name: &str) -> Vec<RankedPlace> {
+        self.inner.lookup_ranked(name)
     }
 }
 
 /// Entry in the built-in gazetteer.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct GazetteerEntry {
     /// Primary name
     pub name: String,
@@ -478,6 +2178,115 @@ pub struct GazetteerEntry {
     pub population: u64,
     /// Alternative names
     pub aliases: Vec<String>,
+    /// Structured ISO 3166-1 metadata, populated for country entries.
+    pub country_info: Option<CountryInfo>,
+    /// OSM/Nominatim-style administrative nesting level (e.g. `2` for a
+    /// country, `8` for a typical city), when known.
+    pub admin_level: Option<u8>,
+    /// Nominatim's `place_rank`-style search ranking: lower is more prominent.
+    pub rank_search: Option<u32>,
+    /// Nominatim's address-display ranking: lower is more prominent.
+    pub rank_address: Option<u32>,
+    /// Normalized prominence score in `[0.0, 1.0]`, used to pick the most
+    /// likely candidate among ambiguous matches (e.g. "Paris" vs "Paris, TX").
+    /// `0.0` when the source doesn't report one.
+    pub importance: f64,
+    /// Wikipedia article title or URL, when known.
+    pub wikipedia: Option<String>,
+    /// OSM-style coarse feature classification (e.g. `"place"`, `"boundary"`).
+    pub feature_class: Option<String>,
+    /// OSM-style fine-grained feature type (e.g. `"city"`, `"administrative"`).
+    pub feature_type: Option<String>,
+}
+
+/// Structured ISO 3166-1 country metadata.
+///
+/// Modeled on the navit `country.c` and DXCC `countrydat` tables, this lets the
+/// crate treat a country as a first-class entity with stable codes rather than a
+/// bare centroid, so "Deutschland", "Germany", and "DE" all normalize to one
+/// record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountryInfo {
+    /// ISO 3166-1 alpha-2 code, e.g. `"DE"`.
+    pub alpha2: String,
+    /// ISO 3166-1 alpha-3 code, e.g. `"DEU"`.
+    pub alpha3: String,
+    /// ISO 3166-1 numeric code, e.g. `276`.
+    pub numeric: u16,
+    /// Continent code (`"EU"`, `"AF"`, `"AS"`, `"NA"`, `"SA"`, `"OC"`).
+    pub continent: String,
+    /// International vehicle registration code, when one is assigned.
+    pub vehicle: Option<String>,
+}
+
+/// ISO 3166-1 records for every country in the built-in table, keyed by name.
+///
+/// Columns: name, alpha-2, alpha-3, numeric, continent, vehicle code.
+const ISO_COUNTRIES: &[(&str, &str, &str, u16, &str, Option<&str>)] = &[
+    ("United States", "US", "USA", 840, "NA", Some("USA")),
+    ("Canada", "CA", "CAN", 124, "NA", Some("CDN")),
+    ("Mexico", "MX", "MEX", 484, "NA", Some("MEX")),
+    ("United Kingdom", "GB", "GBR", 826, "EU", Some("GB")),
+    ("France", "FR", "FRA", 250, "EU", Some("F")),
+    ("Germany", "DE", "DEU", 276, "EU", Some("D")),
+    ("Italy", "IT", "ITA", 380, "EU", Some("I")),
+    ("Spain", "ES", "ESP", 724, "EU", Some("E")),
+    ("China", "CN", "CHN", 156, "AS", None),
+    ("Japan", "JP", "JPN", 392, "AS", Some("J")),
+    ("India", "IN", "IND", 356, "AS", Some("IND")),
+    ("Brazil", "BR", "BRA", 76, "SA", Some("BR")),
+    ("Australia", "AU", "AUS", 36, "OC", Some("AUS")),
+    ("Russia", "RU", "RUS", 643, "EU", Some("RUS")),
+    ("South Korea", "KR", "KOR", 410, "AS", Some("ROK")),
+    ("Ukraine", "UA", "UKR", 804, "EU", Some("UA")),
+    ("Poland", "PL", "POL", 616, "EU", Some("PL")),
+    ("Netherlands", "NL", "NLD", 528, "EU", Some("NL")),
+    ("Belgium", "BE", "BEL", 56, "EU", Some("B")),
+    ("Sweden", "SE", "SWE", 752, "EU", Some("S")),
+    ("Norway", "NO", "NOR", 578, "EU", Some("N")),
+    ("Denmark", "DK", "DNK", 208, "EU", Some("DK")),
+    ("Finland", "FI", "FIN", 246, "EU", Some("FIN")),
+    ("Switzerland", "CH", "CHE", 756, "EU", Some("CH")),
+    ("Austria", "AT", "AUT", 40, "EU", Some("A")),
+    ("Portugal", "PT", "PRT", 620, "EU", Some("P")),
+    ("Greece", "GR", "GRC", 300, "EU", Some("GR")),
+    ("Turkey", "TR", "TUR", 792, "AS", Some("TR")),
+    ("Israel", "IL", "ISR", 376, "AS", Some("IL")),
+    ("Egypt", "EG", "EGY", 818, "AF", Some("ET")),
+    ("South Africa", "ZA", "ZAF", 710, "AF", Some("ZA")),
+    ("Nigeria", "NG", "NGA", 566, "AF", Some("WAN")),
+    ("Kenya", "KE", "KEN", 404, "AF", Some("EAK")),
+    ("Argentina", "AR", "ARG", 32, "SA", Some("RA")),
+    ("Chile", "CL", "CHL", 152, "SA", Some("RCH")),
+    ("Colombia", "CO", "COL", 170, "SA", Some("CO")),
+    ("Peru", "PE", "PER", 604, "SA", Some("PE")),
+    ("Venezuela", "VE", "VEN", 862, "SA", Some("YV")),
+    ("New Zealand", "NZ", "NZL", 554, "OC", Some("NZ")),
+    ("Indonesia", "ID", "IDN", 360, "AS", Some("RI")),
+    ("Philippines", "PH", "PHL", 608, "AS", Some("RP")),
+    ("Thailand", "TH", "THA", 764, "AS", Some("T")),
+    ("Vietnam", "VN", "VNM", 704, "AS", Some("VN")),
+    ("Malaysia", "MY", "MYS", 458, "AS", Some("MAL")),
+    ("Singapore", "SG", "SGP", 702, "AS", Some("SGP")),
+    ("Taiwan", "TW", "TWN", 158, "AS", Some("RC")),
+    ("Iran", "IR", "IRN", 364, "AS", Some("IR")),
+    ("Saudi Arabia", "SA", "SAU", 682, "AS", Some("KSA")),
+    ("United Arab Emirates", "AE", "ARE", 784, "AS", Some("UAE")),
+    ("Qatar", "QA", "QAT", 634, "AS", Some("Q")),
+];
+
+/// Look up structured country metadata by canonical country name.
+fn country_metadata(name: &str) -> Option<CountryInfo> {
+    ISO_COUNTRIES
+        .iter()
+        .find(|(country, ..)| country.eq_ignore_ascii_case(name))
+        .map(|&(_, alpha2, alpha3, numeric, continent, vehicle)| CountryInfo {
+            alpha2: alpha2.to_string(),
+            alpha3: alpha3.to_string(),
+            numeric,
+            continent: continent.to_string(),
+            vehicle: vehicle.map(str::to_string),
+        })
 }
 
 /// Built-in gazetteer with major world locations.
@@ -486,7 +2295,17 @@ pub struct GazetteerEntry {
 /// For comprehensive coverage, consider using an external geocoding service.
 pub struct BuiltinGazetteer {
     entries: HashMap<String, GazetteerEntry>,
-    name_to_canonical: HashMap<String, String>,
+    /// Surface form (name or alias, lowercased) to the canonical ids it can
+    /// resolve to. A single surface may map to several entries when a name is
+    /// ambiguous (e.g. "Paris" → Paris, France and Paris, Texas).
+    name_to_canonical: HashMap<String, Vec<String>>,
+    /// Same mapping as [`name_to_canonical`](Self::name_to_canonical), but
+    /// keyed by [`normalize::normalize_name`]-folded surface forms, so
+    /// diacritic- or abbreviation-only differences ("Zurich" vs "Zürich",
+    /// "St. Louis" vs "Saint Louis") still resolve to the same entries.
+    normalized_to_canonical: HashMap<String, Vec<String>>,
+    /// Spatial index over entry coordinates for reverse geocoding.
+    kd_tree: Option<Box<KdNode>>,
 }
 
 impl BuiltinGazetteer {
@@ -495,24 +2314,199 @@ impl BuiltinGazetteer {
         let mut gazetteer = Self {
             entries: HashMap::new(),
             name_to_canonical: HashMap::new(),
+            normalized_to_canonical: HashMap::new(),
+            kd_tree: None,
         };
         gazetteer.load_default_entries();
+        gazetteer.rebuild_index();
         gazetteer
     }
 
     /// Add a custom entry to the gazetteer.
-    pub fn add_entry(&mut self, entry: GazetteerEntry) {
-        let canonical = entry.name.to_lowercase();
+    ///
+    /// Rebuilds the reverse-geocoding spatial index so later
+    /// [`reverse`](Gazetteer::reverse) queries see the new entry.
+    pub fn add_entry(&mut self, mut entry: GazetteerEntry) {
+        // The built-in table has no independently curated importance column,
+        // so approximate one from population (log-scaled against the largest
+        // megacities, ~37M) unless the caller already set a real value.
+        if entry.importance == 0.0 && entry.population > 0 {
+            const REFERENCE_POPULATION: f64 = 37_000_000.0;
+            entry.importance = ((entry.population as f64).ln() / REFERENCE_POPULATION.ln())
+                .clamp(0.0, 1.0);
+        }
+
+        let name = entry.name.to_lowercase();
+
+        // Entries are keyed by a unique canonical id so two places sharing a
+        // surface name (Paris, France vs Paris, Texas) can coexist; the plain
+        // name is used when free, otherwise it is qualified by country.
+        let canonical = if self.entries.contains_key(&name) {
+            let mut id = format!("{}, {}", name, entry.country.to_lowercase());
+            let mut n = 2;
+            while self.entries.contains_key(&id) {
+                id = format!("{}, {} ({})", name, entry.country.to_lowercase(), n);
+                n += 1;
+            }
+            id
+        } else {
+            name.clone()
+        };
 
-        // Add aliases
-        for alias in &entry.aliases {
+        // Register the canonical name and every alias as surface forms, both
+        // as-is and normalize::normalize_name-folded.
+        for surface in std::iter::once(&entry.name).chain(entry.aliases.iter()) {
             self.name_to_canonical
-                .insert(alias.to_lowercase(), canonical.clone());
+                .entry(surface.to_lowercase())
+                .or_default()
+                .push(canonical.clone());
+            self.normalized_to_canonical
+                .entry(normalize::normalize_name(surface))
+                .or_default()
+                .push(canonical.clone());
         }
 
-        self.name_to_canonical
-            .insert(canonical.clone(), canonical.clone());
         self.entries.insert(canonical, entry);
+        self.rebuild_index();
+    }
+
+    /// All entries whose name or an alias matches `name`, best (most populous)
+    /// first.
+    ///
+    /// Tries an exact (case-insensitive) surface match first. When that
+    /// misses, retries against [`normalize::normalize_name`]-folded surface
+    /// forms, so punctuation, diacritics, and abbreviations like "NYC" or
+    /// "St. Louis" resolve even when no literal alias covers them; finally
+    /// falls back to the closest surface form within Levenshtein distance 2
+    /// (only attempted for normalized queries longer than 4 characters, to
+    /// avoid spurious matches on short ambiguous tokens).
+    fn candidates(&self, name: &str) -> Vec<&GazetteerEntry> {
+        self.scored_candidates(name)
+            .into_iter()
+            .map(|(_, entry)| entry)
+            .collect()
+    }
+
+    /// Like [`candidates`](Self::candidates), but keeping the match-confidence
+    /// score for each entry: `1.0` for an exact surface match, `0.8` for a
+    /// match found only after [`normalize::normalize_name`] folding, or a
+    /// similarity-scaled score below `0.6` for a fuzzy (edit-distance) match.
+    fn scored_candidates(&self, name: &str) -> Vec<(f64, &GazetteerEntry)> {
+        let exact = self.exact_candidates(&name.to_lowercase());
+        if !exact.is_empty() {
+            return exact.into_iter().map(|e| (1.0, e)).collect();
+        }
+
+        let normalized = normalize::normalize_name(name);
+        let folded = self.normalized_candidates(&normalized);
+        if !folded.is_empty() {
+            return folded.into_iter().map(|e| (0.8, e)).collect();
+        }
+
+        match self.fuzzy_candidates(&normalized) {
+            (score, entries) if !entries.is_empty() => {
+                entries.into_iter().map(|e| (score, e)).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Look up surface forms (already lowercased/normalized) directly against
+    /// [`name_to_canonical`](Self::name_to_canonical), best (most populous) first.
+    fn exact_candidates(&self, surface: &str) -> Vec<&GazetteerEntry> {
+        let mut entries: Vec<&GazetteerEntry> = self
+            .name_to_canonical
+            .get(surface)
+            .map(|ids| ids.iter().filter_map(|id| self.entries.get(id)).collect())
+            .unwrap_or_default();
+        entries.sort_by(|a, b| b.population.cmp(&a.population));
+        entries
+    }
+
+    /// Look up an already [`normalize::normalize_name`]-folded query directly
+    /// against [`normalized_to_canonical`](Self::normalized_to_canonical),
+    /// best (most populous) first.
+    fn normalized_candidates(&self, normalized: &str) -> Vec<&GazetteerEntry> {
+        let mut entries: Vec<&GazetteerEntry> = self
+            .normalized_to_canonical
+            .get(normalized)
+            .map(|ids| ids.iter().filter_map(|id| self.entries.get(id)).collect())
+            .unwrap_or_default();
+        entries.sort_by(|a, b| b.population.cmp(&a.population));
+        entries
+    }
+
+    /// Fall back to the closest known normalized surface form within
+    /// Levenshtein distance 2 of `normalized_query` (ties broken
+    /// alphabetically), scaled to a `[0.0, 0.6]` similarity score so fuzzy
+    /// matches always rank below exact and normalized ones.
+    fn fuzzy_candidates(&self, normalized_query: &str) -> (f64, Vec<&GazetteerEntry>) {
+        if normalized_query.chars().count() <= 4 {
+            return (0.0, Vec::new());
+        }
+        let mut best: Option<(usize, &str)> = None;
+        for folded_surface in self.normalized_to_canonical.keys() {
+            let distance = levenshtein(normalized_query, folded_surface);
+            if distance > 2 {
+                continue;
+            }
+            let better = match best {
+                Some((d, s)) => distance < d || (distance == d && folded_surface.as_str() < s),
+                None => true,
+            };
+            if better {
+                best = Some((distance, folded_surface.as_str()));
+            }
+        }
+        match best {
+            Some((distance, folded_surface)) => {
+                let max_len = normalized_query
+                    .chars()
+                    .count()
+                    .max(folded_surface.chars().count())
+                    .max(1);
+                let similarity = 1.0 - (distance as f64 / max_len as f64);
+                (0.6 * similarity, self.normalized_candidates(folded_surface))
+            }
+            None => (0.0, Vec::new()),
+        }
+    }
+
+    /// Build a [`ResolvedPlace`] from a built-in entry.
+    fn to_resolved(entry: &GazetteerEntry) -> ResolvedPlace {
+        let feature_type = if entry.country == "Country" {
+            PlaceType::Country
+        } else {
+            PlaceType::PopulatedPlace
+        };
+        ResolvedPlace {
+            name: entry.name.clone(),
+            feature_type,
+            country: (entry.country != "Country").then(|| entry.country.clone()),
+            admin1: None,
+            population: Some(entry.population),
+            aliases: entry.aliases.clone(),
+            location: Location::new(entry.lat, entry.lon),
+        }
+    }
+
+    /// Rebuild the k-d tree backing reverse geocoding from the current entries.
+    ///
+    /// Called automatically by [`add_entry`](Self::add_entry); exposed so
+    /// callers that bulk-load entries through other paths can refresh the index
+    /// once at the end.
+    pub fn rebuild_index(&mut self) {
+        let mut points: Vec<KdPoint> = self
+            .entries
+            .iter()
+            .map(|(canonical, entry)| KdPoint {
+                coords: unit_sphere(entry.lat, entry.lon),
+                lat: entry.lat,
+                lon: entry.lon,
+                canonical: canonical.clone(),
+            })
+            .collect();
+        self.kd_tree = build_kd(&mut points, 0);
     }
 
     /// Get the number of entries in the gazetteer.
@@ -668,7 +2662,7 @@ impl BuiltinGazetteer {
                 51.5074,
                 -0.1278,
                 8_982_000,
-                vec![],
+                vec!["Londres", "Londra"],
             ),
             ("Paris", "France", 48.8566, 2.3522, 2_161_000, vec![]),
             ("Berlin", "Germany", 52.5200, 13.4050, 3_769_495, vec![]),
@@ -1324,6 +3318,8 @@ impl BuiltinGazetteer {
                 lon,
                 population: pop,
                 aliases: aliases.into_iter().map(String::from).collect(),
+                country_info: country_metadata(name),
+                ..Default::default()
             });
         }
     }
@@ -1337,10 +3333,9 @@ impl Default for BuiltinGazetteer {
 
 impl Gazetteer for BuiltinGazetteer {
     fn lookup(&self, name: &str) -> Option<Location> {
-        let lower = name.to_lowercase();
-        self.name_to_canonical
-            .get(&lower)
-            .and_then(|canonical| self.entries.get(canonical))
+        // Break ambiguity by preferring the most populous candidate.
+        self.candidates(name)
+            .first()
             .map(|entry| Location::new(entry.lat, entry.lon))
     }
 
@@ -1349,17 +3344,260 @@ impl Gazetteer for BuiltinGazetteer {
     }
 
     fn all_names(&self) -> Vec<&str> {
-        self.entries.keys().map(|s| s.as_str()).collect()
+        self.entries.values().map(|e| e.name.as_str()).collect()
     }
 
     fn aliases(&self, name: &str) -> Vec<&str> {
-        let lower = name.to_lowercase();
-        self.name_to_canonical
-            .get(&lower)
-            .and_then(|canonical| self.entries.get(canonical))
+        self.candidates(name)
+            .first()
             .map(|entry| entry.aliases.iter().map(|s| s.as_str()).collect())
             .unwrap_or_default()
     }
+
+    fn reverse(&self, lat: f64, lon: f64) -> Option<Location> {
+        let target = unit_sphere(lat, lon);
+        let mut best = None;
+        nearest(&self.kd_tree, &target, 0, &mut best);
+        best.map(|(_, point)| Location::new(point.lat, point.lon))
+    }
+
+    fn reverse_nearest(&self, lat: f64, lon: f64) -> Option<(String, f64)> {
+        // Small linear scan over the table; within a tie tolerance the more
+        // populous place wins so a capital beats a neighboring suburb.
+        const TIE_TOLERANCE_KM: f64 = 5.0;
+        let mut best: Option<(&GazetteerEntry, f64)> = None;
+        for entry in self.entries.values() {
+            let d = haversine_km(lat, lon, entry.lat, entry.lon);
+            best = match best {
+                None => Some((entry, d)),
+                Some((be, bd)) => {
+                    if d < bd - TIE_TOLERANCE_KM
+                        || ((d - bd).abs() <= TIE_TOLERANCE_KM
+                            && entry.population > be.population)
+                    {
+                        Some((entry, d))
+                    } else {
+                        Some((be, bd))
+                    }
+                }
+            };
+        }
+        best.map(|(entry, d)| (entry.name.clone(), d))
+    }
+
+    fn reverse_within(&self, lat: f64, lon: f64, radius_km: f64) -> Vec<(String, f64)> {
+        let mut hits: Vec<(String, f64)> = self
+            .entries
+            .values()
+            .filter_map(|entry| {
+                let d = haversine_km(lat, lon, entry.lat, entry.lon);
+                (d <= radius_km).then(|| (entry.name.clone(), d))
+            })
+            .collect();
+        hits.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        hits
+    }
+
+    fn reverse_lookup(&self, lat: f64, lon: f64, max_results: usize) -> Vec<GazetteerEntry> {
+        let mut hits: Vec<(f64, &GazetteerEntry)> = self
+            .entries
+            .values()
+            .map(|entry| (haversine_km(lat, lon, entry.lat, entry.lon), entry))
+            .collect();
+        hits.sort_by(|a, b| a.0.total_cmp(&b.0));
+        hits.into_iter()
+            .take(max_results)
+            .map(|(_, entry)| entry.clone())
+            .collect()
+    }
+
+    fn autocomplete(&self, prefix: &str, layers: &[Layer], limit: usize) -> Vec<Suggestion> {
+        let query = prefix.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        // rank: 0 = full-token prefix match, 1 = substring match.
+        let mut scored: Vec<(u8, u64, Suggestion)> = Vec::new();
+        for entry in self.entries.values() {
+            let layer = if entry.country == "Country" {
+                Layer::Country
+            } else {
+                Layer::City
+            };
+            if !layers.is_empty() && !layers.contains(&layer) {
+                continue;
+            }
+
+            // Score the canonical name and every alias, keeping the best match
+            // and remembering which alias produced it.
+            let mut best: Option<(u8, Option<String>)> = None;
+            let name_lower = entry.name.to_lowercase();
+            if let Some(rank) = match_rank(&name_lower, &query) {
+                best = Some((rank, None));
+            }
+            for alias in &entry.aliases {
+                if let Some(rank) = match_rank(&alias.to_lowercase(), &query) {
+                    let better = match &best {
+                        Some((r, _)) => rank < *r,
+                        None => true,
+                    };
+                    if better {
+                        best = Some((rank, Some(alias.clone())));
+                    }
+                }
+            }
+
+            if let Some((rank, matched_alias)) = best {
+                scored.push((
+                    rank,
+                    entry.population,
+                    Suggestion {
+                        name: entry.name.clone(),
+                        matched_alias,
+                        location: Location::new(entry.lat, entry.lon),
+                        layer,
+                    },
+                ));
+            }
+        }
+
+        scored.sort_by(|a, b| {
+            a.0.cmp(&b.0)
+                .then_with(|| b.1.cmp(&a.1))
+                .then_with(|| a.2.name.cmp(&b.2.name))
+        });
+        scored.truncate(limit);
+        scored.into_iter().map(|(_, _, s)| s).collect()
+    }
+
+    fn label(&self, name: &str, style: LabelStyle) -> Option<String> {
+        let candidates = self.candidates(name);
+        let entry = candidates.first()?;
+
+        // A country labels as just itself.
+        if entry.country == "Country" {
+            return Some(entry.name.clone());
+        }
+
+        // Avoid "Singapore, Singapore" when the place and its parent share a
+        // name.
+        if entry.name.eq_ignore_ascii_case(&entry.country) {
+            return Some(entry.name.clone());
+        }
+
+        // The parent country is linked through the entry's `country` field;
+        // fall back to spelling it out when it isn't a known ISO country.
+        let parent = match style {
+            LabelStyle::Full => entry.country.clone(),
+            LabelStyle::Abbreviated => country_metadata(&entry.country)
+                .map(|info| info.alpha2)
+                .unwrap_or_else(|| entry.country.clone()),
+        };
+        Some(format!("{}, {}", entry.name, parent))
+    }
+
+    fn resolve(&self, name: &str) -> Option<ResolvedPlace> {
+        // Entries tagged with the "Country" pseudo-country are countries; the
+        // rest are populated places.
+        self.candidates(name).first().map(|e| Self::to_resolved(e))
+    }
+
+    fn lookup_all(&self, name: &str) -> Vec<ResolvedPlace> {
+        self.candidates(name)
+            .iter()
+            .map(|e| Self::to_resolved(e))
+            .collect()
+    }
+
+    fn lookup_scored(&self, name: &str) -> Vec<ScoredPlace> {
+        self.scored_candidates(name)
+            .into_iter()
+            .map(|(score, entry)| ScoredPlace {
+                place: Self::to_resolved(entry),
+                score,
+            })
+            .collect()
+    }
+
+    fn lookup_ranked(&self, name: &str) -> Vec<RankedPlace> {
+        let mut ranked: Vec<RankedPlace> = self
+            .candidates(name)
+            .into_iter()
+            .map(|entry| RankedPlace {
+                place: Self::to_resolved(entry),
+                importance: entry.importance,
+                rank_search: entry.rank_search,
+            })
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.importance
+                .total_cmp(&a.importance)
+                .then_with(|| a.rank_search.cmp(&b.rank_search))
+        });
+        ranked
+    }
+
+    fn lookup_by_iso(&self, code: &str) -> Option<Location> {
+        self.entries
+            .values()
+            .find(|e| {
+                e.country_info.as_ref().is_some_and(|info| {
+                    info.alpha2.eq_ignore_ascii_case(code)
+                        || info.alpha3.eq_ignore_ascii_case(code)
+                })
+            })
+            .map(|e| Location::new(e.lat, e.lon))
+    }
+
+    fn country_info(&self, name: &str) -> Option<CountryInfo> {
+        // A name or alias resolves through the surface-form index; a bare code
+        // falls back to a direct scan of the ISO metadata.
+        self.candidates(name)
+            .iter()
+            .find_map(|e| e.country_info.clone())
+            .or_else(|| {
+                self.entries.values().find_map(|e| {
+                    e.country_info.clone().filter(|info| {
+                        info.alpha2.eq_ignore_ascii_case(name)
+                            || info.alpha3.eq_ignore_ascii_case(name)
+                    })
+                })
+            })
+    }
+
+    fn suggest(&self, partial: &str, limit: usize) -> Vec<(String, Location, f64)> {
+        const THRESHOLD: f64 = 0.85;
+
+        let query = partial.to_lowercase();
+
+        // Keep the best similarity seen for each canonical entry, scoring the
+        // query against the canonical name and every alias.
+        let mut best: HashMap<&str, f64> = HashMap::new();
+        for (surface, canonicals) in &self.name_to_canonical {
+            let score = jaro_winkler(&query, surface);
+            for canonical in canonicals {
+                let slot = best.entry(canonical.as_str()).or_insert(0.0);
+                if score > *slot {
+                    *slot = score;
+                }
+            }
+        }
+
+        let mut results: Vec<(String, Location, f64)> = best
+            .into_iter()
+            .filter(|(_, score)| *score >= THRESHOLD)
+            .filter_map(|(canonical, score)| {
+                self.entries.get(canonical).map(|entry| {
+                    (entry.name.clone(), Location::new(entry.lat, entry.lon), score)
+                })
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.2.total_cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+        results.truncate(limit);
+        results
+    }
 }
 
 #[cfg(test)]
@@ -1408,6 +3646,42 @@ mod tests {
         assert!((berlin.lon - 13.405).abs() < 0.01);
     }
 
+    #[test]
+    fn test_reverse_geocode_nearest_city() {
+        let gazetteer = BuiltinGazetteer::new();
+
+        // A point a little south-west of central Paris resolves to Paris.
+        let near_paris = gazetteer.reverse(48.80, 2.30).unwrap();
+        assert!((near_paris.lat - 48.8566).abs() < 0.01);
+        assert!((near_paris.lon - 2.3522).abs() < 0.01);
+
+        // A point near downtown Tokyo resolves to Tokyo, not an antipodal city.
+        let near_tokyo = gazetteer.reverse(35.70, 139.70).unwrap();
+        assert!((near_tokyo.lat - 35.6762).abs() < 0.01);
+        assert!((near_tokyo.lon - 139.6503).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_suggest_fuzzy_match() {
+        let gazetteer = BuiltinGazetteer::new();
+
+        // A typo'd query should still surface the intended city.
+        let suggestions = gazetteer.suggest("Lndon", 3);
+        assert!(suggestions.iter().any(|(name, _, _)| name == "London"));
+
+        // Scores are sorted descending and within range.
+        for pair in suggestions.windows(2) {
+            assert!(pair[0].2 >= pair[1].2);
+        }
+    }
+
+    #[test]
+    fn test_suggest_respects_limit() {
+        let gazetteer = BuiltinGazetteer::new();
+        let suggestions = gazetteer.suggest("San", 2);
+        assert!(suggestions.len() <= 2);
+    }
+
     #[test]
     fn test_multi_gazetteer() {
         let mut multi = MultiGazetteer::new();
@@ -1422,6 +3696,30 @@ mod tests {
         assert!(!multi.contains("NonexistentPlace12345"));
     }
 
+    #[test]
+    fn test_multi_resolve_scored_exact() {
+        let mut multi = MultiGazetteer::new();
+        multi.add_source(Box::new(BuiltinGazetteer::new()));
+
+        let scored = multi.resolve_scored("London");
+        assert!(!scored.is_empty());
+        assert_eq!(scored[0].name, "London");
+        assert!((scored[0].confidence - 1.0).abs() < 1e-9);
+        assert_eq!(scored[0].source, 0);
+        assert!(!scored[0].disagreement);
+    }
+
+    #[test]
+    fn test_multi_resolve_scored_alias() {
+        let mut multi = MultiGazetteer::new();
+        multi.add_source(Box::new(BuiltinGazetteer::new()));
+
+        // "NYC" is an alias, scored below an exact canonical hit.
+        let scored = multi.resolve_scored("NYC");
+        assert!(!scored.is_empty());
+        assert!(scored[0].confidence < 1.0);
+    }
+
     #[test]
     fn test_multi_gazetteer_fallback() {
         let builtin = BuiltinGazetteer::new();
@@ -1433,6 +3731,343 @@ mod tests {
         assert!(multi.lookup("Tokyo").is_some());
     }
 
+    #[test]
+    fn test_caching_gazetteer_memoizes() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        // A gazetteer that counts how often it is actually queried.
+        struct Counting {
+            calls: Arc<AtomicUsize>,
+        }
+        impl Gazetteer for Counting {
+            fn lookup(&self, _name: &str) -> Option<Location> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                Some(Location::new(0.0, 0.0))
+            }
+            fn contains(&self, name: &str) -> bool {
+                self.lookup(name).is_some()
+            }
+            fn all_names(&self) -> Vec<&str> {
+                Vec::new()
+            }
+        }
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let cache = CachingGazetteer::new(Box::new(Counting {
+            calls: Arc::clone(&calls),
+        }));
+
+        // Repeated lookups of the same name, modulo case and padding, hit the
+        // source exactly once.
+        assert!(cache.lookup("Paris").is_some());
+        assert!(cache.lookup("  paris ").is_some());
+        assert!(cache.lookup("PARIS").is_some());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_autocomplete_ranks_and_filters() {
+        let gaz = BuiltinGazetteer::new();
+
+        // "San" prefixes several US cities; results are token-prefix matches
+        // ranked by population.
+        let cities = gaz.autocomplete("San", &[Layer::City], 5);
+        assert!(!cities.is_empty());
+        assert!(cities.iter().all(|s| s.layer == Layer::City));
+
+        // Layer filtering excludes countries.
+        let countries = gaz.autocomplete("Uni", &[Layer::Country], 5);
+        assert!(countries.iter().all(|s| s.layer == Layer::Country));
+        assert!(countries.iter().any(|s| s.name == "United States"));
+    }
+
+    #[test]
+    fn test_autocomplete_matches_alias() {
+        let gaz = BuiltinGazetteer::new();
+        let hits = gaz.autocomplete("NYC", &[], 5);
+        let nyc = hits.iter().find(|s| s.name == "New York City").unwrap();
+        assert_eq!(nyc.matched_alias.as_deref(), Some("NYC"));
+    }
+
+    #[test]
+    fn test_reverse_nearest_name() {
+        let gaz = BuiltinGazetteer::new();
+
+        // A point near central Paris reverses to Paris with a small distance.
+        let (name, dist) = gaz.reverse_nearest(48.80, 2.30).unwrap();
+        assert_eq!(name, "Paris");
+        assert!(dist < 10.0);
+    }
+
+    #[test]
+    fn test_reverse_within_radius() {
+        let gaz = BuiltinGazetteer::new();
+
+        // Right on London, a tight radius finds London and nothing far away.
+        let hits = gaz.reverse_within(51.5074, -0.1278, 50.0);
+        assert!(hits.iter().any(|(name, _)| name == "London"));
+        assert!(hits.iter().all(|(_, d)| *d <= 50.0));
+        // Results are sorted nearest-first.
+        for pair in hits.windows(2) {
+            assert!(pair[0].1 <= pair[1].1);
+        }
+    }
+
+    #[test]
+    fn test_reverse_lookup_returns_full_entries_nearest_first() {
+        let gaz = BuiltinGazetteer::new();
+
+        let hits = gaz.reverse_lookup(48.80, 2.30, 3);
+        assert_eq!(hits.len(), 3);
+        assert_eq!(hits[0].name, "Paris");
+        assert!(hits[0].population > 0);
+    }
+
+    #[test]
+    fn test_reverse_lookup_respects_max_results() {
+        let gaz = BuiltinGazetteer::new();
+        assert_eq!(gaz.reverse_lookup(48.80, 2.30, 0).len(), 0);
+        assert_eq!(gaz.reverse_lookup(48.80, 2.30, 1).len(), 1);
+    }
+
+    #[test]
+    fn test_multi_gazetteer_reverse_lookup_dedups_across_sources() {
+        let mut multi = MultiGazetteer::new();
+        multi.add_source(Box::new(BuiltinGazetteer::new()));
+        multi.add_source(Box::new(BuiltinGazetteer::new()));
+
+        // Two identical sources would otherwise double every entry.
+        let hits = multi.reverse_lookup(48.80, 2.30, 3);
+        assert_eq!(hits.len(), 3);
+        assert_eq!(hits[0].name, "Paris");
+    }
+
+    #[test]
+    fn test_lookup_resolves_misspelled_name_via_fuzzy_fallback() {
+        let gaz = BuiltinGazetteer::new();
+
+        let loc = gaz.lookup("Berline").unwrap();
+        let berlin = gaz.lookup("Berlin").unwrap();
+        assert_eq!(loc, berlin);
+    }
+
+    #[test]
+    fn test_lookup_resolves_diacritic_variant() {
+        let gaz = BuiltinGazetteer::new();
+
+        // Munich's only diacritic-bearing surface form is the alias
+        // "München"; the plain-ASCII "Munchen" matches neither the canonical
+        // name nor that alias exactly, so this only resolves via
+        // normalization folding.
+        let loc = gaz.lookup("Munchen").unwrap();
+        let munich = gaz.lookup("Munich").unwrap();
+        assert_eq!(loc, munich);
+    }
+
+    #[test]
+    fn test_lookup_resolves_multilingual_alias() {
+        let gaz = BuiltinGazetteer::new();
+
+        assert_eq!(gaz.lookup("Londres"), gaz.lookup("London"));
+        assert_eq!(gaz.lookup("Londra"), gaz.lookup("London"));
+    }
+
+    #[test]
+    fn test_lookup_scored_ranks_exact_above_fuzzy() {
+        let gaz = BuiltinGazetteer::new();
+
+        let exact = gaz.lookup_scored("Berlin");
+        assert_eq!(exact[0].score, 1.0);
+
+        let fuzzy = gaz.lookup_scored("Berline");
+        assert!(fuzzy[0].score > 0.0 && fuzzy[0].score < 1.0);
+        assert_eq!(fuzzy[0].place.name, "Berlin");
+    }
+
+    #[test]
+    fn test_lookup_scored_default_is_flat_confidence() {
+        struct Empty;
+        impl Gazetteer for Empty {
+            fn lookup(&self, _name: &str) -> Option<Location> {
+                Some(Location::new(1.0, 2.0))
+            }
+            fn contains(&self, _name: &str) -> bool {
+                true
+            }
+            fn all_names(&self) -> Vec<&str> {
+                vec![]
+            }
+        }
+
+        let scored = Empty.lookup_scored("Anywhere");
+        assert_eq!(scored.len(), 1);
+        assert_eq!(scored[0].score, 1.0);
+    }
+
+    #[test]
+    fn test_lookup_ranked_prefers_importance_over_insertion_order() {
+        let mut gaz = BuiltinGazetteer::new();
+        gaz.add_entry(GazetteerEntry {
+            name: "Paris".to_string(),
+            country: "United States".to_string(),
+            lat: 33.6609,
+            lon: -95.5555,
+            population: 24_171, // Paris, Texas
+            aliases: vec![],
+            country_info: None,
+            ..Default::default()
+        });
+
+        let ranked = gaz.lookup_ranked("Paris");
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].place.country.as_deref(), Some("France"));
+        assert_eq!(ranked[1].place.country.as_deref(), Some("United States"));
+        assert!(ranked[0].importance > ranked[1].importance);
+    }
+
+    #[test]
+    fn test_multi_gazetteer_lookup_ranked_merges_all_sources() {
+        struct LowImportance;
+        impl Gazetteer for LowImportance {
+            fn lookup(&self, _name: &str) -> Option<Location> {
+                Some(Location::new(1.0, 2.0))
+            }
+            fn contains(&self, _name: &str) -> bool {
+                true
+            }
+            fn all_names(&self) -> Vec<&str> {
+                vec![]
+            }
+            fn lookup_ranked(&self, _name: &str) -> Vec<RankedPlace> {
+                vec![RankedPlace {
+                    place: ResolvedPlace {
+                        name: "Obscure Place".to_string(),
+                        feature_type: PlaceType::PopulatedPlace,
+                        country: None,
+                        admin1: None,
+                        population: None,
+                        aliases: Vec::new(),
+                        location: Location::new(1.0, 2.0),
+                    },
+                    importance: 0.05,
+                    rank_search: None,
+                }]
+            }
+        }
+
+        let mut multi = MultiGazetteer::new();
+        multi.add_source(Box::new(LowImportance));
+        multi.add_source(Box::new(BuiltinGazetteer::new()));
+
+        // Even though the first (less prominent) source answers, the more
+        // important candidate from the second source still sorts on top.
+        let ranked = multi.lookup_ranked("Paris");
+        assert_eq!(ranked[0].place.name, "Paris");
+        assert!(ranked.iter().any(|r| r.place.name == "Obscure Place"));
+    }
+
+    #[test]
+    fn test_label_hierarchy() {
+        let gaz = BuiltinGazetteer::new();
+
+        assert_eq!(gaz.label("Paris", LabelStyle::Full).as_deref(), Some("Paris, France"));
+        assert_eq!(
+            gaz.label("Berlin", LabelStyle::Abbreviated).as_deref(),
+            Some("Berlin, DE")
+        );
+
+        // A country labels as just itself, no duplication.
+        assert_eq!(gaz.label("France", LabelStyle::Full).as_deref(), Some("France"));
+    }
+
+    #[test]
+    fn test_country_iso_metadata() {
+        let gaz = BuiltinGazetteer::new();
+
+        // Name, native alias, and ISO codes all resolve to the same record.
+        let info = gaz.country_info("Germany").unwrap();
+        assert_eq!(info.alpha2, "DE");
+        assert_eq!(info.alpha3, "DEU");
+        assert_eq!(info.numeric, 276);
+        assert_eq!(info.continent, "EU");
+        assert_eq!(gaz.country_info("Deutschland"), Some(info.clone()));
+        assert_eq!(gaz.country_info("DEU"), Some(info));
+
+        // Codes resolve to the country centroid via either alpha form.
+        let by_alpha2 = gaz.lookup_by_iso("DE").unwrap();
+        let by_alpha3 = gaz.lookup_by_iso("DEU").unwrap();
+        assert!((by_alpha2.lat - by_alpha3.lat).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_lookup_breaks_ties_by_population() {
+        let mut gaz = BuiltinGazetteer::new();
+        gaz.add_entry(GazetteerEntry {
+            name: "Springfield".to_string(),
+            country: "United States".to_string(),
+            lat: 39.7817,
+            lon: -89.6501,
+            population: 114_394, // Illinois
+            aliases: vec![],
+            country_info: None,
+            ..Default::default()
+        });
+        gaz.add_entry(GazetteerEntry {
+            name: "Springfield".to_string(),
+            country: "United States".to_string(),
+            lat: 37.2090,
+            lon: -93.2923,
+            population: 169_176, // Missouri, more populous
+            aliases: vec![],
+            country_info: None,
+            ..Default::default()
+        });
+
+        // The bare name resolves to the most populous candidate.
+        let best = gaz.lookup("Springfield").unwrap();
+        assert!((best.lat - 37.2090).abs() < 0.01);
+
+        // But both candidates are available via lookup_all, most populous first.
+        let all = gaz.lookup_all("Springfield");
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].population, Some(169_176));
+        assert_eq!(all[1].population, Some(114_394));
+    }
+
+    #[test]
+    fn test_lookup_in_uses_context() {
+        let mut gaz = BuiltinGazetteer::new();
+        gaz.add_entry(GazetteerEntry {
+            name: "Paris".to_string(),
+            country: "United States".to_string(),
+            lat: 33.6609,
+            lon: -95.5555,
+            population: 24_171, // Paris, Texas
+            aliases: vec![],
+            country_info: None,
+            ..Default::default()
+        });
+
+        // France's Paris is far more populous, so the bare lookup prefers it.
+        let bare = gaz.lookup("Paris").unwrap();
+        assert!((bare.lat - 48.8566).abs() < 0.01);
+
+        // With a country context, the matching candidate wins instead.
+        let texan = gaz.lookup_in("Paris", "United States").unwrap();
+        assert!((texan.location.lat - 33.6609).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_caching_gazetteer_evicts_lru() {
+        let cache = CachingGazetteer::new(Box::new(BuiltinGazetteer::new())).with_capacity(1);
+        assert!(cache.lookup("London").is_some());
+        // A second distinct name evicts the first, but both still resolve since
+        // eviction only drops the memo, not correctness.
+        assert!(cache.lookup("Paris").is_some());
+        assert!(cache.lookup("London").is_some());
+    }
+
     #[cfg(feature = "geocoding")]
     #[test]
     #[ignore] // Ignore by default to avoid hitting real APIs in tests
@@ -1,28 +1,144 @@
 //! GeoParser for extracting geographic locations from text.
 
 use crate::core::Location;
-use once_cell::sync::Lazy;
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+use once_cell::sync::{Lazy, OnceCell};
 use regex::Regex;
 
 use super::gazetteer::Gazetteer;
 use super::mention::{LocationMention, LocationPattern, MentionType};
 
+// Decimal numbers tolerate either a `.` or a `,` separator (the latter is
+// standard in much of Europe and common in OCR'd text); callers normalize
+// the captured text to `.` before calling `parse::<f64>`.
+// Minute/second marks tolerate the ASCII symbol alongside the curly-quote
+// and prime variants OCR and mobile keyboards tend to substitute.
+const MINUTE_MARK: &str = r"['\u{2019}\u{2032}\u{2018}\u{201B}]";
+const SECOND_MARK: &str = r#"["\u{2033}\u{201D}\u{201C}]"#;
+
 // Compile regex patterns once
 static DECIMAL_DEGREES: Lazy<Regex> = Lazy::new(|| {
-    // Match decimal degrees like "40.7128, -74.0060" or "40.7128 -74.0060"
-    Regex::new(r"(-?\d{1,3}\.\d+)\s*[,\s]\s*(-?\d{1,3}\.\d+)").unwrap()
+    // Match decimal degrees like "40.7128, -74.0060" or "40,7128 -74,0060"
+    Regex::new(r"(-?\d{1,3}[.,]\d+)\s*[,\s]\s*(-?\d{1,3}[.,]\d+)").unwrap()
 });
 
 static DEGREES_WITH_SYMBOLS: Lazy<Regex> = Lazy::new(|| {
-    // Match degrees with direction: "40.7128°N, 74.0060°W" or "40.7128N 74.0060W"
-    Regex::new(r"(?i)(\d{1,3}\.?\d*)\s*°?\s*([NS])\s*[,\s]*(\d{1,3}\.?\d*)\s*°?\s*([EW])").unwrap()
+    // Match degrees with a hemisphere letter leading or trailing each value,
+    // e.g. "40.7128°N, 74.0060°W" or "N 40,7128 W 74,0060"; axis is assigned
+    // from the letter (N/S -> lat, E/W -> lon) so either axis may come first.
+    // `°` is optional so a bare whitespace-separated number still matches.
+    Regex::new(
+        r"(?i)(?:(?P<s1dl>[NSEW])\s*°?\s*(?P<s1vl>\d{1,3}(?:[.,]\d+)?)|(?P<s1vt>\d{1,3}(?:[.,]\d+)?)\s*°?\s*(?P<s1dt>[NSEW]))\s*[,\s]*(?:(?P<s2dl>[NSEW])\s*°?\s*(?P<s2vl>\d{1,3}(?:[.,]\d+)?)|(?P<s2vt>\d{1,3}(?:[.,]\d+)?)\s*°?\s*(?P<s2dt>[NSEW]))",
+    )
+    .unwrap()
 });
 
 static DMS_FORMAT: Lazy<Regex> = Lazy::new(|| {
-    // Match DMS: 40°42'46"N, 74°0'22"W (with various quote styles)
-    Regex::new(r#"(?i)(\d{1,3})\s*°\s*(\d{1,2})\s*['\u{2032}]\s*(\d{1,2}(?:\.\d+)?)\s*["\u{2033}]?\s*([NS])\s*[,\s]*(\d{1,3})\s*°\s*(\d{1,2})\s*['\u{2032}]\s*(\d{1,2}(?:\.\d+)?)\s*["\u{2033}]?\s*([EW])"#).unwrap()
+    // Match DMS with a hemisphere letter leading or trailing each value (with
+    // various quote styles), so either axis may come first:
+    // "40°42'46\"N, 74°0'22\"W" or "N 40 42'46\" W 74 0'22\"". `°` is optional
+    // so a whitespace-separated degree number still matches.
+    Regex::new(&format!(
+        r#"(?i)(?:(?P<d1dl>[NSEW])\s*(?P<d1degl>\d{{1,3}})\s*°?\s*(?P<d1minl>\d{{1,2}})\s*{mm}\s*(?P<d1secl>\d{{1,2}}(?:[.,]\d+)?)\s*{sm}?|(?P<d1degt>\d{{1,3}})\s*°?\s*(?P<d1mint>\d{{1,2}})\s*{mm}\s*(?P<d1sect>\d{{1,2}}(?:[.,]\d+)?)\s*{sm}?\s*(?P<d1dt>[NSEW]))\s*[,\s]*(?:(?P<d2dl>[NSEW])\s*(?P<d2degl>\d{{1,3}})\s*°?\s*(?P<d2minl>\d{{1,2}})\s*{mm}\s*(?P<d2secl>\d{{1,2}}(?:[.,]\d+)?)\s*{sm}?|(?P<d2degt>\d{{1,3}})\s*°?\s*(?P<d2mint>\d{{1,2}})\s*{mm}\s*(?P<d2sect>\d{{1,2}}(?:[.,]\d+)?)\s*{sm}?\s*(?P<d2dt>[NSEW]))"#,
+        mm = MINUTE_MARK,
+        sm = SECOND_MARK,
+    ))
+    .unwrap()
+});
+
+static DDM_FORMAT: Lazy<Regex> = Lazy::new(|| {
+    // Match degrees-decimal-minutes (the nautical/aviation form) with a
+    // hemisphere letter leading or trailing each value, so either axis may
+    // come first: "40° 26.767' N, 79° 58,933' W" or "N 40 26.767' W 79 58.933'".
+    // `°` is optional so a whitespace-separated degree number still matches.
+    Regex::new(&format!(
+        r#"(?i)(?:(?P<m1dl>[NSEW])\s*(?P<m1degl>\d{{1,3}})\s*°?\s*(?P<m1minl>\d{{1,2}}(?:[.,]\d+)?)\s*{mm}?|(?P<m1degt>\d{{1,3}})\s*°?\s*(?P<m1mint>\d{{1,2}}(?:[.,]\d+)?)\s*{mm}?\s*(?P<m1dt>[NSEW]))\s*[,\s]*(?:(?P<m2dl>[NSEW])\s*(?P<m2degl>\d{{1,3}})\s*°?\s*(?P<m2minl>\d{{1,2}}(?:[.,]\d+)?)\s*{mm}?|(?P<m2degt>\d{{1,3}})\s*°?\s*(?P<m2mint>\d{{1,2}}(?:[.,]\d+)?)\s*{mm}?\s*(?P<m2dt>[NSEW]))"#,
+        mm = MINUTE_MARK,
+    ))
+    .unwrap()
+});
+
+static NMEA_SENTENCE: Lazy<Regex> = Lazy::new(|| {
+    // Match a full NMEA 0183 GGA or RMC sentence, e.g.
+    // "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47"
+    Regex::new(r"\$GP(?:GGA|RMC)(?:,[^,\r\n*]*)*(?:\*[0-9A-Fa-f]{2})?").unwrap()
+});
+
+static POSTAL_CODE_US: Lazy<Regex> = Lazy::new(|| {
+    // US ZIP code, optionally with the ZIP+4 extension: "90210" or "90210-1234"
+    Regex::new(r"\b\d{5}(?:-\d{4})?\b").unwrap()
+});
+
+static POSTAL_CODE_CA: Lazy<Regex> = Lazy::new(|| {
+    // Canadian postal code: "K1A 0B1"
+    Regex::new(r"(?i)\b[A-Z]\d[A-Z]\s?\d[A-Z]\d\b").unwrap()
+});
+
+static POSTAL_CODE_UK: Lazy<Regex> = Lazy::new(|| {
+    // UK postcode, per the format distinguished by the OSM geocoder: "EC1A 1BB"
+    Regex::new(r"(?i)\b(GIR 0AA|[A-PR-UWYZ]([0-9]{1,2}|([A-HK-Y][0-9]|[A-HK-Y][0-9]([0-9]|[ABEHMNPRV-Y]))|[0-9][A-HJKS-UW])\s*[0-9][ABD-HJLNP-UW-Z]{2})\b").unwrap()
 });
 
+static CAPITALIZED_PHRASE: Lazy<Regex> = Lazy::new(|| {
+    // One to three consecutive capitalized words, used as candidates for
+    // fuzzy/normalized place-name matching when no exact gazetteer name is
+    // found in the text (e.g. a misspelling like "Berline").
+    Regex::new(r"\b[A-Z][\p{L}]+(?:\s+[A-Z][\p{L}]+){0,2}\b").unwrap()
+});
+
+/// A single Aho-Corasick automaton over every lowercased gazetteer name,
+/// replacing an O(names × text length) `str::find` loop with one linear
+/// scan over the text. Cached on [`GeoParser`] and rebuilt only when the
+/// gazetteer changes.
+struct PlaceAutomaton {
+    automaton: AhoCorasick,
+    /// Original-case names, indexed by the automaton's pattern id.
+    names: Vec<String>,
+}
+
+impl PlaceAutomaton {
+    fn build(gazetteer: &dyn Gazetteer) -> Self {
+        // Longest-name-first so leftmost-longest matching reproduces the
+        // greedy preference the old `sort_by length` loop relied on.
+        let mut names: Vec<String> = gazetteer
+            .all_names()
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        names.sort_by_key(|n| std::cmp::Reverse(n.len()));
+
+        let lowered: Vec<String> = names.iter().map(|n| n.to_lowercase()).collect();
+        let automaton = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::LeftmostLongest)
+            .build(&lowered)
+            .expect("place name automaton should compile");
+
+        Self { automaton, names }
+    }
+}
+
+/// Lowercases `text` while recording, for every byte of the returned string,
+/// the byte offset in `text` where the source character begins.
+///
+/// `char::to_lowercase` can expand a single character into more bytes than
+/// it started with (e.g. `İ` U+0130 becomes the 3-byte `i` followed by a
+/// combining dot above), so a lowered copy can't be assumed to line up
+/// byte-for-byte with the original. The returned offset table lets a match
+/// position found in the lowered copy be translated back to the matching
+/// position in `text`.
+fn lower_with_offsets(text: &str) -> (String, Vec<usize>) {
+    let mut lowered = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len());
+
+    for (orig_pos, ch) in text.char_indices() {
+        let start = lowered.len();
+        lowered.extend(ch.to_lowercase());
+        offsets.resize(offsets.len() + (lowered.len() - start), orig_pos);
+    }
+
+    (lowered, offsets)
+}
+
 /// Main geoparser for extracting locations from text.
 ///
 /// # Example
@@ -39,6 +155,7 @@ static DMS_FORMAT: Lazy<Regex> = Lazy::new(|| {
 pub struct GeoParser {
     pattern: LocationPattern,
     gazetteer: Option<Box<dyn Gazetteer>>,
+    place_automaton: OnceCell<PlaceAutomaton>,
 }
 
 impl GeoParser {
@@ -47,6 +164,7 @@ impl GeoParser {
         Self {
             pattern: LocationPattern::default(),
             gazetteer: None,
+            place_automaton: OnceCell::new(),
         }
     }
 
@@ -55,6 +173,7 @@ impl GeoParser {
         Self {
             pattern: LocationPattern::default(),
             gazetteer: Some(gazetteer),
+            place_automaton: OnceCell::new(),
         }
     }
 
@@ -63,12 +182,14 @@ impl GeoParser {
         Self {
             pattern,
             gazetteer: None,
+            place_automaton: OnceCell::new(),
         }
     }
 
     /// Set the gazetteer for place name resolution.
     pub fn set_gazetteer(&mut self, gazetteer: Box<dyn Gazetteer>) {
         self.gazetteer = Some(gazetteer);
+        self.place_automaton = OnceCell::new();
     }
 
     /// Set the pattern configuration.
@@ -90,6 +211,19 @@ impl GeoParser {
         if self.pattern.detect_dms {
             self.extract_dms(text, &mut mentions);
         }
+        if self.pattern.detect_ddm {
+            self.extract_ddm(text, &mut mentions);
+        }
+        if self.pattern.detect_nmea {
+            self.extract_nmea(text, &mut mentions);
+        }
+
+        // Extract postal codes, resolved through the gazetteer
+        if self.pattern.detect_postcodes {
+            if let Some(ref gazetteer) = self.gazetteer {
+                self.extract_postal_codes(text, gazetteer.as_ref(), &mut mentions);
+            }
+        }
 
         // Extract place names from gazetteer
         if self.pattern.detect_places {
@@ -117,6 +251,8 @@ impl GeoParser {
         self.extract_decimal_degrees(text, &mut mentions);
         self.extract_degrees_with_symbols(text, &mut mentions);
         self.extract_dms(text, &mut mentions);
+        self.extract_ddm(text, &mut mentions);
+        self.extract_nmea(text, &mut mentions);
 
         mentions.sort_by_key(|m| m.start);
         self.remove_overlaps(&mut mentions);
@@ -132,10 +268,19 @@ impl GeoParser {
     fn extract_decimal_degrees(&self, text: &str, mentions: &mut Vec<LocationMention>) {
         for cap in DECIMAL_DEGREES.captures_iter(text) {
             let full_match = cap.get(0).unwrap();
-            let lat_str = cap.get(1).unwrap().as_str();
-            let lon_str = cap.get(2).unwrap().as_str();
+            let first_str = cap.get(1).unwrap().as_str().replace(',', ".");
+            let second_str = cap.get(2).unwrap().as_str().replace(',', ".");
+
+            if let (Ok(first), Ok(second)) = (first_str.parse::<f64>(), second_str.parse::<f64>())
+            {
+                // No hemisphere letters to assign an axis from, so fall back
+                // to the configured ordering assumption.
+                let (lat, lon) = if self.pattern.assume_lat_first {
+                    (first, second)
+                } else {
+                    (second, first)
+                };
 
-            if let (Ok(lat), Ok(lon)) = (lat_str.parse::<f64>(), lon_str.parse::<f64>()) {
                 // Validate coordinate ranges
                 if (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon) {
                     let mut mention = LocationMention::new(
@@ -155,13 +300,22 @@ impl GeoParser {
     fn extract_degrees_with_symbols(&self, text: &str, mentions: &mut Vec<LocationMention>) {
         for cap in DEGREES_WITH_SYMBOLS.captures_iter(text) {
             let full_match = cap.get(0).unwrap();
-            let lat_val: f64 = cap.get(1).unwrap().as_str().parse().unwrap_or(0.0);
-            let lat_dir = cap.get(2).unwrap().as_str().to_uppercase();
-            let lon_val: f64 = cap.get(3).unwrap().as_str().parse().unwrap_or(0.0);
-            let lon_dir = cap.get(4).unwrap().as_str().to_uppercase();
 
-            let lat = if lat_dir == "S" { -lat_val } else { lat_val };
-            let lon = if lon_dir == "W" { -lon_val } else { lon_val };
+            let Some((val1, dir1)) = symbol_component(&cap, "s1dl", "s1vl", "s1vt", "s1dt") else {
+                continue;
+            };
+            let Some((val2, dir2)) = symbol_component(&cap, "s2dl", "s2vl", "s2vt", "s2dt") else {
+                continue;
+            };
+
+            let Some((lat, lon)) = combine_lat_lon(
+                signed_degrees(val1, &dir1),
+                &dir1,
+                signed_degrees(val2, &dir2),
+                &dir2,
+            ) else {
+                continue;
+            };
 
             if (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon) {
                 let mut mention = LocationMention::new(
@@ -181,18 +335,25 @@ impl GeoParser {
         for cap in DMS_FORMAT.captures_iter(text) {
             let full_match = cap.get(0).unwrap();
 
-            let lat_deg: f64 = cap.get(1).unwrap().as_str().parse().unwrap_or(0.0);
-            let lat_min: f64 = cap.get(2).unwrap().as_str().parse().unwrap_or(0.0);
-            let lat_sec: f64 = cap.get(3).unwrap().as_str().parse().unwrap_or(0.0);
-            let lat_dir = cap.get(4).unwrap().as_str().to_uppercase();
-
-            let lon_deg: f64 = cap.get(5).unwrap().as_str().parse().unwrap_or(0.0);
-            let lon_min: f64 = cap.get(6).unwrap().as_str().parse().unwrap_or(0.0);
-            let lon_sec: f64 = cap.get(7).unwrap().as_str().parse().unwrap_or(0.0);
-            let lon_dir = cap.get(8).unwrap().as_str().to_uppercase();
-
-            let lat = dms_to_decimal(lat_deg, lat_min, lat_sec, &lat_dir);
-            let lon = dms_to_decimal(lon_deg, lon_min, lon_sec, &lon_dir);
+            let Some((deg1, min1, sec1, dir1)) = dms_component(
+                &cap, "d1dl", "d1degl", "d1minl", "d1secl", "d1degt", "d1mint", "d1sect", "d1dt",
+            ) else {
+                continue;
+            };
+            let Some((deg2, min2, sec2, dir2)) = dms_component(
+                &cap, "d2dl", "d2degl", "d2minl", "d2secl", "d2degt", "d2mint", "d2sect", "d2dt",
+            ) else {
+                continue;
+            };
+
+            let Some((lat, lon)) = combine_lat_lon(
+                dms_to_decimal(deg1, min1, sec1, &dir1),
+                &dir1,
+                dms_to_decimal(deg2, min2, sec2, &dir2),
+                &dir2,
+            ) else {
+                continue;
+            };
 
             if (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon) {
                 let mut mention = LocationMention::new(
@@ -208,57 +369,237 @@ impl GeoParser {
         }
     }
 
-    fn extract_place_names(
+    /// Extracts the nautical/aviation degrees-decimal-minutes form, e.g.
+    /// `40° 26.767' N, 79° 58.933' W`.
+    fn extract_ddm(&self, text: &str, mentions: &mut Vec<LocationMention>) {
+        for cap in DDM_FORMAT.captures_iter(text) {
+            let full_match = cap.get(0).unwrap();
+
+            let Some((deg1, min1, dir1)) =
+                ddm_component(&cap, "m1dl", "m1degl", "m1minl", "m1degt", "m1mint", "m1dt")
+            else {
+                continue;
+            };
+            let Some((deg2, min2, dir2)) =
+                ddm_component(&cap, "m2dl", "m2degl", "m2minl", "m2degt", "m2mint", "m2dt")
+            else {
+                continue;
+            };
+
+            let Some((lat, lon)) = combine_lat_lon(
+                ddm_to_decimal(deg1, min1, &dir1),
+                &dir1,
+                ddm_to_decimal(deg2, min2, &dir2),
+                &dir2,
+            ) else {
+                continue;
+            };
+
+            if (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon) {
+                let mut mention = LocationMention::new(
+                    full_match.as_str(),
+                    full_match.start(),
+                    full_match.end(),
+                    MentionType::DegreesDecimalMinutes,
+                );
+                mention.location = Some(Location::new(lat, lon));
+                mention.confidence = 0.97;
+                mentions.push(mention);
+            }
+        }
+    }
+
+    /// Extracts coordinates embedded in raw NMEA 0183 `$GPGGA`/`$GPRMC`
+    /// sentences, e.g. `$GPGGA,123519,4807.038,N,01131.000,E,...`.
+    fn extract_nmea(&self, text: &str, mentions: &mut Vec<LocationMention>) {
+        for m in NMEA_SENTENCE.find_iter(text) {
+            let sentence = m.as_str();
+            let fields: Vec<&str> = sentence.split(',').collect();
+
+            let (lat_idx, lon_idx) = if fields[0].ends_with("GGA") {
+                (2, 4)
+            } else if fields[0].ends_with("RMC") {
+                (3, 5)
+            } else {
+                continue;
+            };
+
+            let (Some(lat_field), Some(lat_dir), Some(lon_field), Some(lon_dir)) = (
+                fields.get(lat_idx),
+                fields.get(lat_idx + 1),
+                fields.get(lon_idx),
+                fields.get(lon_idx + 1),
+            ) else {
+                continue;
+            };
+
+            // GGA/RMC sentences emitted before a fix leave the coordinate
+            // fields blank.
+            if lat_field.is_empty() || lon_field.is_empty() {
+                continue;
+            }
+
+            let lon_dir = lon_dir.split('*').next().unwrap_or(lon_dir);
+
+            if let (Ok(lat_raw), Ok(lon_raw)) = (lat_field.parse::<f64>(), lon_field.parse::<f64>())
+            {
+                let lat = nmea_to_decimal(lat_raw, lat_dir);
+                let lon = nmea_to_decimal(lon_raw, lon_dir);
+
+                if (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon) {
+                    let mut mention =
+                        LocationMention::new(sentence, m.start(), m.end(), MentionType::Nmea);
+                    mention.location = Some(Location::new(lat, lon));
+                    mention.confidence = 0.9;
+                    mentions.push(mention);
+                }
+            }
+        }
+    }
+
+    /// Matches US ZIP, Canadian, and UK postal codes and resolves each one
+    /// through [`Gazetteer::lookup_postcode`], skipping codes the gazetteer
+    /// doesn't recognize.
+    fn extract_postal_codes(
         &self,
         text: &str,
         gazetteer: &dyn Gazetteer,
         mentions: &mut Vec<LocationMention>,
     ) {
-        // Get all place names and sort by length (longest first for greedy matching)
-        let mut names: Vec<_> = gazetteer.all_names().to_vec();
-        names.sort_by_key(|b| std::cmp::Reverse(b.len()));
-
-        let text_lower = text.to_lowercase();
-
-        for name in names {
-            let name_lower = name.to_lowercase();
-
-            // Find all occurrences of this name in the text
-            let mut start = 0;
-            while let Some(pos) = text_lower[start..].find(&name_lower) {
-                let abs_pos = start + pos;
-                let end_pos = abs_pos + name.len();
-
-                // Check word boundaries
-                let valid_start =
-                    abs_pos == 0 || !text.chars().nth(abs_pos - 1).unwrap().is_alphanumeric();
-                let valid_end =
-                    end_pos >= text.len() || !text.chars().nth(end_pos).unwrap().is_alphanumeric();
-
-                if valid_start && valid_end {
-                    // Check for overlap with existing mentions
-                    let overlaps = mentions
-                        .iter()
-                        .any(|m| !(end_pos <= m.start || abs_pos >= m.end));
-
-                    if !overlaps {
-                        if let Some(location) = gazetteer.lookup(name) {
-                            let original_text = &text[abs_pos..end_pos];
-                            let mut mention = LocationMention::new(
-                                original_text,
-                                abs_pos,
-                                end_pos,
-                                MentionType::PlaceName,
-                            );
-                            mention.location = Some(location);
-                            mention.confidence = 0.85;
-                            mentions.push(mention);
-                        }
-                    }
+        for re in [&*POSTAL_CODE_US, &*POSTAL_CODE_CA, &*POSTAL_CODE_UK] {
+            for m in re.find_iter(text) {
+                let overlaps = mentions
+                    .iter()
+                    .any(|existing| !(m.end() <= existing.start || m.start() >= existing.end));
+                if overlaps {
+                    continue;
                 }
 
-                start = abs_pos + 1;
+                let Some(location) = gazetteer.lookup_postcode(m.as_str()) else {
+                    continue;
+                };
+
+                let mut mention =
+                    LocationMention::new(m.as_str(), m.start(), m.end(), MentionType::PostalCode);
+                mention.location = Some(location);
+                mention.confidence = 0.7;
+                mentions.push(mention);
+            }
+        }
+    }
+
+    fn extract_place_names(
+        &self,
+        text: &str,
+        gazetteer: &dyn Gazetteer,
+        mentions: &mut Vec<LocationMention>,
+    ) {
+        let automaton = self
+            .place_automaton
+            .get_or_init(|| PlaceAutomaton::build(gazetteer));
+
+        // `str::to_lowercase` isn't byte-length-preserving for every input
+        // (e.g. `İ` U+0130 expands to the 3-byte `i` + combining dot above),
+        // so match offsets from the lowercased copy can't be used to slice
+        // `text` directly. `lower_with_offsets` tracks, for every byte of the
+        // lowered copy, the byte position in `text` where its source
+        // character starts, so offsets can be translated back.
+        let (text_lower, offsets) = lower_with_offsets(text);
+
+        for m in automaton.automaton.find_iter(&text_lower) {
+            let abs_pos = offsets[m.start()];
+            let end_pos = if m.end() >= text_lower.len() {
+                text.len()
+            } else {
+                offsets[m.end()]
+            };
+            let name = &automaton.names[m.pattern().as_usize()];
+
+            // Check word boundaries
+            let valid_start = abs_pos == 0
+                || !text[..abs_pos]
+                    .chars()
+                    .next_back()
+                    .is_some_and(|c| c.is_alphanumeric());
+            let valid_end = end_pos >= text.len()
+                || !text[end_pos..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_alphanumeric());
+
+            if !valid_start || !valid_end {
+                continue;
+            }
+
+            // Check for overlap with existing mentions
+            let overlaps = mentions
+                .iter()
+                .any(|m| !(end_pos <= m.start || abs_pos >= m.end));
+
+            if overlaps {
+                continue;
+            }
+
+            // Resolve by real-world prominence rather than taking whatever
+            // `lookup` happens to prefer, so an ambiguous name (e.g. "Paris")
+            // defaults to its most prominent candidate while the rest stay
+            // available as alternates.
+            let mut ranked = gazetteer.lookup_ranked(name).into_iter();
+            if let Some(best) = ranked.next() {
+                let original_text = &text[abs_pos..end_pos];
+                let mut mention =
+                    LocationMention::new(original_text, abs_pos, end_pos, MentionType::PlaceName)
+                        .with_alternates(ranked.map(|r| r.place).collect());
+                mention.location = Some(best.place.location);
+                mention.confidence = 0.85;
+                mentions.push(mention);
+            }
+        }
+
+        self.extract_fuzzy_place_names(text, gazetteer, mentions);
+    }
+
+    /// Second pass over capitalized word phrases not already covered by an
+    /// exact name match, resolved through [`Gazetteer::lookup_scored`] so a
+    /// typo ("Berline"), abbreviation, or diacritic variant can still surface
+    /// a mention — at a confidence scaled down by the match score, since
+    /// these candidates were only guessed from capitalization rather than
+    /// matched against a known name.
+    fn extract_fuzzy_place_names(
+        &self,
+        text: &str,
+        gazetteer: &dyn Gazetteer,
+        mentions: &mut Vec<LocationMention>,
+    ) {
+        for phrase in CAPITALIZED_PHRASE.find_iter(text) {
+            let abs_pos = phrase.start();
+            let end_pos = phrase.end();
+
+            let overlaps = mentions
+                .iter()
+                .any(|m| !(end_pos <= m.start || abs_pos >= m.end));
+            if overlaps {
+                continue;
+            }
+
+            let Some(best) = gazetteer.lookup_scored(phrase.as_str()).into_iter().next() else {
+                continue;
+            };
+            // Only the normalized/fuzzy tiers are worth surfacing here; an
+            // exact match would already have been found by the first pass.
+            if best.score <= 0.0 || best.score >= 1.0 {
+                continue;
             }
+
+            let mut mention = LocationMention::new(
+                phrase.as_str(),
+                abs_pos,
+                end_pos,
+                MentionType::PlaceName,
+            );
+            mention.location = Some(best.place.location);
+            mention.confidence = 0.85 * best.score;
+            mentions.push(mention);
         }
     }
 
@@ -294,6 +635,160 @@ impl Default for GeoParser {
     }
 }
 
+/// Scan `text` for location mentions using the supplied [`LocationPattern`].
+///
+/// A free-function entry point for callers that only need coordinate parsing
+/// and do not want to construct a [`GeoParser`]; it runs the same engine
+/// without a gazetteer, so `detect_places` yields nothing and place-name
+/// resolution is left to the struct API.
+pub fn geoparse(text: &str, pattern: &LocationPattern) -> Vec<LocationMention> {
+    GeoParser::with_pattern(pattern.clone()).extract(text)
+}
+
+/// Reads one coordinate component from a [`DEGREES_WITH_SYMBOLS`] match,
+/// trying the hemisphere-letter-leading capture names before the
+/// hemisphere-letter-trailing ones, and returns `(value, direction)`.
+fn symbol_component(
+    cap: &regex::Captures,
+    dir_lead: &str,
+    val_lead: &str,
+    val_trail: &str,
+    dir_trail: &str,
+) -> Option<(f64, String)> {
+    if let (Some(dir), Some(val)) = (cap.name(dir_lead), cap.name(val_lead)) {
+        return Some((parse_decimal(val.as_str()), dir.as_str().to_uppercase()));
+    }
+    if let (Some(val), Some(dir)) = (cap.name(val_trail), cap.name(dir_trail)) {
+        return Some((parse_decimal(val.as_str()), dir.as_str().to_uppercase()));
+    }
+    None
+}
+
+/// Parses a numeric capture that may use either `.` or `,` as the decimal
+/// separator, normalizing to `.` first. Malformed input defaults to `0.0`;
+/// the symbol, DMS and DDM component readers all parse through here.
+fn parse_decimal(value: &str) -> f64 {
+    value.replace(',', ".").parse().unwrap_or(0.0)
+}
+
+/// Reads one coordinate component from a [`DDM_FORMAT`] match, trying the
+/// hemisphere-letter-leading capture names before the trailing ones, and
+/// returns `(degrees, minutes, direction)`.
+fn ddm_component(
+    cap: &regex::Captures,
+    dir_lead: &str,
+    deg_lead: &str,
+    min_lead: &str,
+    deg_trail: &str,
+    min_trail: &str,
+    dir_trail: &str,
+) -> Option<(f64, f64, String)> {
+    if let (Some(dir), Some(deg), Some(min)) =
+        (cap.name(dir_lead), cap.name(deg_lead), cap.name(min_lead))
+    {
+        return Some((
+            parse_decimal(deg.as_str()),
+            parse_decimal(min.as_str()),
+            dir.as_str().to_uppercase(),
+        ));
+    }
+    if let (Some(deg), Some(min), Some(dir)) =
+        (cap.name(deg_trail), cap.name(min_trail), cap.name(dir_trail))
+    {
+        return Some((
+            parse_decimal(deg.as_str()),
+            parse_decimal(min.as_str()),
+            dir.as_str().to_uppercase(),
+        ));
+    }
+    None
+}
+
+/// Reads one coordinate component from a [`DMS_FORMAT`] match, trying the
+/// hemisphere-letter-leading capture names before the trailing ones, and
+/// returns `(degrees, minutes, seconds, direction)`.
+#[allow(clippy::too_many_arguments)]
+fn dms_component(
+    cap: &regex::Captures,
+    dir_lead: &str,
+    deg_lead: &str,
+    min_lead: &str,
+    sec_lead: &str,
+    deg_trail: &str,
+    min_trail: &str,
+    sec_trail: &str,
+    dir_trail: &str,
+) -> Option<(f64, f64, f64, String)> {
+    if let (Some(dir), Some(deg), Some(min), Some(sec)) = (
+        cap.name(dir_lead),
+        cap.name(deg_lead),
+        cap.name(min_lead),
+        cap.name(sec_lead),
+    ) {
+        return Some((
+            parse_decimal(deg.as_str()),
+            parse_decimal(min.as_str()),
+            parse_decimal(sec.as_str()),
+            dir.as_str().to_uppercase(),
+        ));
+    }
+    if let (Some(deg), Some(min), Some(sec), Some(dir)) = (
+        cap.name(deg_trail),
+        cap.name(min_trail),
+        cap.name(sec_trail),
+        cap.name(dir_trail),
+    ) {
+        return Some((
+            parse_decimal(deg.as_str()),
+            parse_decimal(min.as_str()),
+            parse_decimal(sec.as_str()),
+            dir.as_str().to_uppercase(),
+        ));
+    }
+    None
+}
+
+/// Applies hemisphere sign to a plain (not yet DMS/DDM-combined) decimal
+/// degree value: `S`/`W` negate, `N`/`E` pass through unchanged.
+fn signed_degrees(value: f64, direction: &str) -> f64 {
+    if direction == "S" || direction == "W" {
+        -value
+    } else {
+        value
+    }
+}
+
+/// True if a hemisphere letter denotes a latitude axis (`N`/`S`); false for
+/// a longitude axis (`E`/`W`).
+fn is_lat_direction(direction: &str) -> bool {
+    matches!(direction, "N" | "S")
+}
+
+/// Combines two hemisphere-tagged, already-signed component values into
+/// `(lat, lon)` by reading their axis off the direction letter rather than
+/// their position, so either axis may come first. Returns `None` if both
+/// components resolved to the same axis (e.g. two `N`/`S` values).
+fn combine_lat_lon(val1: f64, dir1: &str, val2: f64, dir2: &str) -> Option<(f64, f64)> {
+    match (is_lat_direction(dir1), is_lat_direction(dir2)) {
+        (true, false) => Some((val1, val2)),
+        (false, true) => Some((val2, val1)),
+        _ => None,
+    }
+}
+
+/// Decode an NMEA 0183 packed `ddmm.mmmm` / `dddmm.mmmm` coordinate field
+/// into decimal degrees, negating by the following N/S or E/W field.
+fn nmea_to_decimal(value: f64, direction: &str) -> f64 {
+    let whole_degrees = (value / 100.0).trunc();
+    let minutes = value - whole_degrees * 100.0;
+    let decimal = whole_degrees + minutes / 60.0;
+    if direction == "S" || direction == "W" {
+        -decimal
+    } else {
+        decimal
+    }
+}
+
 /// Convert degrees, minutes, seconds to decimal degrees.
 fn dms_to_decimal(degrees: f64, minutes: f64, seconds: f64, direction: &str) -> f64 {
     let decimal = degrees + minutes / 60.0 + seconds / 3600.0;
@@ -304,6 +799,16 @@ fn dms_to_decimal(degrees: f64, minutes: f64, seconds: f64, direction: &str) ->
     }
 }
 
+/// Convert degrees and decimal minutes to decimal degrees.
+fn ddm_to_decimal(degrees: f64, minutes: f64, direction: &str) -> f64 {
+    let decimal = degrees + minutes / 60.0;
+    if direction == "S" || direction == "W" {
+        -decimal
+    } else {
+        decimal
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -363,6 +868,215 @@ mod tests {
         assert!((loc.lon - (-74.0061)).abs() < 0.001);
     }
 
+    #[test]
+    fn test_ddm_extraction() {
+        let parser = GeoParser::new();
+        let text = "Coordinates: 40° 26.767' N, 79° 58.933' W";
+
+        let mentions = parser.extract(text);
+
+        assert_eq!(mentions.len(), 1);
+        assert!(matches!(
+            mentions[0].mention_type,
+            MentionType::DegreesDecimalMinutes
+        ));
+
+        let loc = mentions[0].location.as_ref().unwrap();
+        // 40° 26.767' N = 40 + 26.767/60 ≈ 40.4461
+        assert!((loc.lat - 40.4461).abs() < 0.001);
+        // 79° 58.933' W = -(79 + 58.933/60) ≈ -79.9822
+        assert!((loc.lon - (-79.9822)).abs() < 0.001);
+        assert!(mentions[0].confidence >= 0.95);
+    }
+
+    #[test]
+    fn test_ddm_extraction_rejects_out_of_range_coordinates() {
+        let parser = GeoParser::new();
+        let text = "Bad fix: 95° 0.0' N, 79° 58.933' W";
+
+        let mentions = parser.extract_coordinates(text);
+        assert!(!mentions
+            .iter()
+            .any(|m| matches!(m.mention_type, MentionType::DegreesDecimalMinutes)));
+    }
+
+    #[test]
+    fn test_degrees_with_symbols_direction_leading() {
+        let parser = GeoParser::new();
+        let text = "Position: N 40.7128, W 74.0060";
+
+        let mentions = parser.extract(text);
+
+        assert_eq!(mentions.len(), 1);
+        assert!(matches!(
+            mentions[0].mention_type,
+            MentionType::DegreesWithSymbols
+        ));
+
+        let loc = mentions[0].location.as_ref().unwrap();
+        assert!((loc.lat - 40.7128).abs() < 0.0001);
+        assert!((loc.lon - (-74.0060)).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_degrees_with_symbols_longitude_first() {
+        let parser = GeoParser::new();
+        let text = "Position: 74.0060°W, 40.7128°N";
+
+        let mentions = parser.extract(text);
+
+        assert_eq!(mentions.len(), 1);
+        let loc = mentions[0].location.as_ref().unwrap();
+        assert!((loc.lat - 40.7128).abs() < 0.0001);
+        assert!((loc.lon - (-74.0060)).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_dms_extraction_longitude_first_with_letters() {
+        let parser = GeoParser::new();
+        let text = "Coordinates: 74°0'22\"W, 40°42'46\"N";
+
+        let mentions = parser.extract(text);
+
+        assert_eq!(mentions.len(), 1);
+        let loc = mentions[0].location.as_ref().unwrap();
+        assert!((loc.lat - 40.7128).abs() < 0.001);
+        assert!((loc.lon - (-74.0061)).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_ddm_extraction_direction_leading() {
+        let parser = GeoParser::new();
+        let text = "Coordinates: N 40° 26.767', W 79° 58.933'";
+
+        let mentions = parser.extract(text);
+
+        assert_eq!(mentions.len(), 1);
+        assert!(matches!(
+            mentions[0].mention_type,
+            MentionType::DegreesDecimalMinutes
+        ));
+
+        let loc = mentions[0].location.as_ref().unwrap();
+        assert!((loc.lat - 40.4461).abs() < 0.001);
+        assert!((loc.lon - (-79.9822)).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_decimal_degrees_assume_lat_first_false_swaps_axes() {
+        let pattern = LocationPattern {
+            assume_lat_first: false,
+            ..LocationPattern::coordinates_only()
+        };
+        let parser = GeoParser::with_pattern(pattern);
+        let text = "Dropped pin at -74.0060, 40.7128";
+
+        let mentions = parser.extract_coordinates(text);
+
+        assert_eq!(mentions.len(), 1);
+        let loc = mentions[0].location.as_ref().unwrap();
+        assert!((loc.lat - 40.7128).abs() < 0.0001);
+        assert!((loc.lon - (-74.0060)).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_nmea_gga_extraction() {
+        let parser = GeoParser::new();
+        let text = "Raw fix: $GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47";
+
+        let mentions = parser.extract_coordinates(text);
+
+        assert_eq!(mentions.len(), 1);
+        assert!(matches!(mentions[0].mention_type, MentionType::Nmea));
+
+        let loc = mentions[0].location.as_ref().unwrap();
+        // 4807.038 N = 48 + 07.038/60 ≈ 48.1173
+        assert!((loc.lat - 48.1173).abs() < 0.001);
+        // 01131.000 E = 11 + 31.000/60 ≈ 11.5167
+        assert!((loc.lon - 11.5167).abs() < 0.001);
+        assert!((mentions[0].confidence - 0.9).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_nmea_rmc_extraction() {
+        let parser = GeoParser::new();
+        let text = "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A";
+
+        let mentions = parser.extract_coordinates(text);
+
+        assert_eq!(mentions.len(), 1);
+        assert!(matches!(mentions[0].mention_type, MentionType::Nmea));
+
+        let loc = mentions[0].location.as_ref().unwrap();
+        assert!((loc.lat - 48.1173).abs() < 0.001);
+        assert!((loc.lon - 11.5167).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_nmea_extraction_skips_sentence_without_fix() {
+        let parser = GeoParser::new();
+        let text = "$GPGGA,123519,,,,,0,00,99.9,,,,,,*66";
+
+        let mentions = parser.extract_coordinates(text);
+
+        assert!(!mentions
+            .iter()
+            .any(|m| matches!(m.mention_type, MentionType::Nmea)));
+    }
+
+    #[test]
+    fn test_postal_code_extraction_resolves_via_gazetteer() {
+        // A gazetteer that only knows postcodes, not place names.
+        struct PostcodeOnly;
+        impl Gazetteer for PostcodeOnly {
+            fn lookup(&self, _name: &str) -> Option<Location> {
+                None
+            }
+            fn contains(&self, _name: &str) -> bool {
+                false
+            }
+            fn all_names(&self) -> Vec<&str> {
+                Vec::new()
+            }
+            fn lookup_postcode(&self, code: &str) -> Option<Location> {
+                match code {
+                    "90210" => Some(Location::new(34.0901, -118.4065)),
+                    "EC1A 1BB" => Some(Location::new(51.5183, -0.0995)),
+                    _ => None,
+                }
+            }
+        }
+
+        let parser = GeoParser::with_gazetteer(Box::new(PostcodeOnly));
+        let text = "shipped to 90210, office in EC1A 1BB";
+
+        let mentions = parser.extract(text);
+
+        assert_eq!(mentions.len(), 2);
+        let zip = mentions.iter().find(|m| m.text == "90210").unwrap();
+        assert!(matches!(zip.mention_type, MentionType::PostalCode));
+        let loc = zip.location.as_ref().unwrap();
+        assert!((loc.lat - 34.0901).abs() < 0.0001);
+
+        let uk = mentions.iter().find(|m| m.text == "EC1A 1BB").unwrap();
+        assert!(matches!(uk.mention_type, MentionType::PostalCode));
+        let loc = uk.location.as_ref().unwrap();
+        assert!((loc.lat - 51.5183).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_postal_code_extraction_skipped_when_gazetteer_cannot_resolve() {
+        let gazetteer = BuiltinGazetteer::new();
+        let parser = GeoParser::with_gazetteer(Box::new(gazetteer));
+
+        let text = "shipped to 90210";
+        let mentions = parser.extract(text);
+
+        assert!(!mentions
+            .iter()
+            .any(|m| matches!(m.mention_type, MentionType::PostalCode)));
+    }
+
     #[test]
     fn test_place_name_extraction() {
         let gazetteer = BuiltinGazetteer::new();
@@ -376,6 +1090,106 @@ mod tests {
         assert!(mentions.iter().any(|m| m.text == "London"));
     }
 
+    #[test]
+    fn test_place_name_extraction_resolves_misspelling_with_lower_confidence() {
+        let gazetteer = BuiltinGazetteer::new();
+        let mut parser = GeoParser::with_gazetteer(Box::new(gazetteer));
+        parser.set_pattern(LocationPattern {
+            min_confidence: 0.3,
+            ..LocationPattern::default()
+        });
+
+        let text = "The delegation flew into Berline yesterday.";
+        let mentions = parser.extract(text);
+
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(mentions[0].text, "Berline");
+        assert!(mentions[0].location.is_some());
+        assert!(mentions[0].confidence < 0.85);
+    }
+
+    #[test]
+    fn test_place_name_extraction_resolves_ambiguous_name_by_importance() {
+        use crate::parser::GazetteerEntry;
+
+        let mut gazetteer = BuiltinGazetteer::new();
+        gazetteer.add_entry(GazetteerEntry {
+            name: "Paris".to_string(),
+            country: "United States".to_string(),
+            lat: 33.6609,
+            lon: -95.5555,
+            population: 24_171, // Paris, Texas
+            aliases: vec![],
+            country_info: None,
+            ..Default::default()
+        });
+        let parser = GeoParser::with_gazetteer(Box::new(gazetteer));
+
+        let text = "We are meeting in Paris next week.";
+        let mentions = parser.extract(text);
+
+        assert_eq!(mentions.len(), 1);
+        // Paris, France is far more prominent, so it's the default resolution...
+        assert!((mentions[0].location.unwrap().lat - 48.8566).abs() < 0.01);
+        // ...while Paris, Texas survives as a runner-up.
+        assert_eq!(mentions[0].alternates.len(), 1);
+        assert_eq!(mentions[0].alternates[0].country.as_deref(), Some("United States"));
+    }
+
+    #[test]
+    fn test_place_name_extraction_prefers_longest_overlapping_name() {
+        use crate::parser::GazetteerEntry;
+
+        let mut gazetteer = BuiltinGazetteer::new();
+        gazetteer.add_entry(GazetteerEntry {
+            name: "Santa".to_string(),
+            country: "United States".to_string(),
+            lat: 35.6870,
+            lon: -105.9378,
+            population: 1,
+            aliases: vec![],
+            country_info: None,
+            ..Default::default()
+        });
+        gazetteer.add_entry(GazetteerEntry {
+            name: "Santa Fe".to_string(),
+            country: "United States".to_string(),
+            lat: 35.6870,
+            lon: -105.9378,
+            population: 87_505,
+            aliases: vec![],
+            country_info: None,
+            ..Default::default()
+        });
+        let parser = GeoParser::with_gazetteer(Box::new(gazetteer));
+
+        // Both "Santa" and "Santa Fe" match starting at the same position;
+        // the automaton's leftmost-longest semantics should pick "Santa Fe"
+        // rather than surfacing both as overlapping mentions.
+        let text = "We are headed to Santa Fe this summer.";
+        let mentions = parser.extract(text);
+
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(mentions[0].text, "Santa Fe");
+    }
+
+    #[test]
+    fn test_place_name_extraction_is_stable_across_repeated_calls() {
+        let gazetteer = BuiltinGazetteer::new();
+        let parser = GeoParser::with_gazetteer(Box::new(gazetteer));
+        let text = "The conference was held in Tokyo and participants came from London.";
+
+        let first = parser.extract(text);
+        let second = parser.extract(text);
+
+        assert_eq!(first.len(), second.len());
+        for (a, b) in first.iter().zip(second.iter()) {
+            assert_eq!(a.text, b.text);
+            assert_eq!(a.start, b.start);
+            assert_eq!(a.end, b.end);
+        }
+    }
+
     #[test]
     fn test_mixed_extraction() {
         let gazetteer = BuiltinGazetteer::new();
@@ -452,6 +1266,19 @@ mod tests {
         assert!(parser.geocode("NonexistentPlace").is_none());
     }
 
+    #[test]
+    fn test_geoparse_free_function() {
+        let pattern = LocationPattern::coordinates_only();
+        let mentions = geoparse("Meet at 40.7128, -74.0060 sharp", &pattern);
+        assert_eq!(mentions.len(), 1);
+        assert!(matches!(
+            mentions[0].mention_type,
+            MentionType::DecimalDegrees
+        ));
+        // Bare decimal degrees score below symbol/DMS matches.
+        assert!(mentions[0].confidence < 0.99);
+    }
+
     #[test]
     fn test_dms_to_decimal() {
         // New York City: 40°42'46"N, 74°0'22"W
@@ -465,4 +1292,50 @@ mod tests {
         let lat_s = dms_to_decimal(33.0, 52.0, 10.0, "S");
         assert!(lat_s < 0.0);
     }
+
+    #[test]
+    fn test_decimal_degrees_comma_separator() {
+        let parser = GeoParser::new();
+        let text = "Dropped pin at 40,7128, -74,0060";
+
+        let mentions = parser.extract_coordinates(text);
+
+        assert_eq!(mentions.len(), 1);
+        let loc = mentions[0].location.as_ref().unwrap();
+        assert!((loc.lat - 40.7128).abs() < 0.0001);
+        assert!((loc.lon - (-74.0060)).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_dms_extraction_curly_quotes_and_no_degree_symbol() {
+        let parser = GeoParser::new();
+        let text = "Fix: 40 42\u{2019}46\u{201D}N, 74 0\u{2019}22\u{201D}W";
+
+        let mentions = parser.extract_coordinates(text);
+
+        assert_eq!(mentions.len(), 1);
+        assert!(matches!(mentions[0].mention_type, MentionType::DMS));
+
+        let loc = mentions[0].location.as_ref().unwrap();
+        assert!((loc.lat - 40.7128).abs() < 0.001);
+        assert!((loc.lon - (-74.0061)).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_ddm_extraction_comma_minutes_no_degree_symbol() {
+        let parser = GeoParser::new();
+        let text = "Coordinates: N 40 26,767', W 79 58,933'";
+
+        let mentions = parser.extract_coordinates(text);
+
+        assert_eq!(mentions.len(), 1);
+        assert!(matches!(
+            mentions[0].mention_type,
+            MentionType::DegreesDecimalMinutes
+        ));
+
+        let loc = mentions[0].location.as_ref().unwrap();
+        assert!((loc.lat - 40.4461).abs() < 0.001);
+        assert!((loc.lon - (-79.9822)).abs() < 0.001);
+    }
 }
@@ -2,6 +2,8 @@
 
 use crate::core::Location;
 
+use super::gazetteer::ResolvedPlace;
+
 /// Type of location mention detected in text.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MentionType {
@@ -11,10 +13,18 @@ pub enum MentionType {
     DegreesWithSymbols,
     /// Degrees, minutes, seconds: "40°42'46\"N, 74°0'22\"W"
     DMS,
+    /// Degrees and decimal minutes, the nautical/aviation form:
+    /// "40° 26.767' N, 79° 58.933' W"
+    DegreesDecimalMinutes,
+    /// Embedded NMEA 0183 GPS sentence: "$GPGGA,123519,4807.038,N,01131.000,E,..."
+    Nmea,
     /// Named place from gazetteer
     PlaceName,
     /// Street address (detected but not geocoded)
     Address,
+    /// Postal/ZIP code (US ZIP, Canadian, or UK format), resolved via the
+    /// gazetteer's postcode lookup
+    PostalCode,
 }
 
 /// A location mention extracted from text.
@@ -32,6 +42,10 @@ pub struct LocationMention {
     pub location: Option<Location>,
     /// Confidence score (0.0 to 1.0)
     pub confidence: f64,
+    /// Other candidates the gazetteer returned for this name, most prominent
+    /// first, when the mention was ambiguous (e.g. "Paris" also matching
+    /// "Paris, Texas"). Empty when there was only one candidate.
+    pub alternates: Vec<ResolvedPlace>,
 }
 
 impl LocationMention {
@@ -49,9 +63,17 @@ impl LocationMention {
             mention_type,
             location: None,
             confidence: 1.0,
+            alternates: Vec::new(),
         }
     }
 
+    /// Attach runner-up candidates from an ambiguous gazetteer lookup, most
+    /// prominent first.
+    pub fn with_alternates(mut self, alternates: Vec<ResolvedPlace>) -> Self {
+        self.alternates = alternates;
+        self
+    }
+
     /// Set the resolved location.
     pub fn with_location(mut self, location: Location) -> Self {
         self.location = Some(location);
@@ -79,8 +101,20 @@ pub struct LocationPattern {
     pub detect_symbols: bool,
     /// Enable DMS format detection
     pub detect_dms: bool,
+    /// Enable degrees-decimal-minutes format detection
+    pub detect_ddm: bool,
+    /// Enable embedded NMEA 0183 GPS sentence detection
+    pub detect_nmea: bool,
     /// Enable place name detection (requires gazetteer)
     pub detect_places: bool,
+    /// Enable postal/ZIP code detection, resolved through the gazetteer's
+    /// postcode lookup (requires gazetteer)
+    pub detect_postcodes: bool,
+    /// When a plain decimal-degree pair carries no hemisphere letters (e.g.
+    /// "40.7128, -74.0060"), assume the first number is latitude. Formats
+    /// with hemisphere letters (N/S/E/W) ignore this flag entirely, since
+    /// the letter itself determines the axis.
+    pub assume_lat_first: bool,
     /// Minimum confidence threshold for matches
     pub min_confidence: f64,
 }
@@ -91,7 +125,11 @@ impl Default for LocationPattern {
             detect_decimal: true,
             detect_symbols: true,
             detect_dms: true,
+            detect_ddm: true,
+            detect_nmea: true,
             detect_places: true,
+            detect_postcodes: true,
+            assume_lat_first: true,
             min_confidence: 0.5,
         }
     }
@@ -109,7 +147,11 @@ impl LocationPattern {
             detect_decimal: true,
             detect_symbols: true,
             detect_dms: true,
+            detect_ddm: true,
+            detect_nmea: true,
             detect_places: false,
+            detect_postcodes: false,
+            assume_lat_first: true,
             min_confidence: 0.5,
         }
     }
@@ -120,7 +162,11 @@ impl LocationPattern {
             detect_decimal: false,
             detect_symbols: false,
             detect_dms: false,
+            detect_ddm: false,
+            detect_nmea: false,
             detect_places: true,
+            detect_postcodes: true,
+            assume_lat_first: true,
             min_confidence: 0.5,
         }
     }
@@ -25,6 +25,11 @@
 //! - `GazetteerWikidata`: Wikidata SPARQL query service
 //! - `GazetteerGeoNames`: GeoNames web service (requires username)
 //!
+//! ### Offline Gazetteers
+//!
+//! - `GazetteerGeoIP`: MaxMind `.mmdb` IP geolocation (requires `geoip` feature)
+//! - `GazetteerSqlite`: SQLite + geohash, scales to the full GeoNames dump (requires `sqlite` feature)
+//!
 //! ## Examples
 //!
 //! ### Basic Usage with Built-in Gazetteer
@@ -43,7 +48,7 @@
 //! for mention in mentions {
 //!     println!("Found '{}' at position {}-{}", mention.text, mention.start, mention.end);
 //!     if let Some(loc) = mention.location {
-//!         println!("  Coordinates: {}, {}", loc.lat, loc.lon);
+//!         println!("  Coordinates: {}, {}", loc.lat(), loc.lon());
 //!     }
 //! }
 //! ```
@@ -77,19 +82,32 @@
 //!
 //! let gaz = GazetteerNominatim::new();
 //! if let Some(loc) = gaz.lookup("Berlin") {
-//!     println!("Berlin: {}, {}", loc.lat, loc.lon);
+//!     println!("Berlin: {}, {}", loc.lat(), loc.lon());
 //! }
 //! # }
 //! ```
 
+pub mod dateline;
 mod gazetteer;
 mod geoparser;
 mod mention;
+pub mod normalize;
+pub mod postal;
 
-pub use gazetteer::{BuiltinGazetteer, Gazetteer, GazetteerEntry, MultiGazetteer};
+pub use gazetteer::{
+    BuiltinGazetteer, CachingGazetteer, CountryInfo, Gazetteer, GazetteerEntry, LabelStyle, Layer,
+    MultiGazetteer, PlaceType, RankedPlace, ResolvedPlace, ScoredMatch, ScoredPlace, Suggestion,
+};
 
 #[cfg(feature = "geocoding")]
 pub use gazetteer::{GazetteerGeoNames, GazetteerNominatim, GazetteerWikidata};
 
-pub use geoparser::GeoParser;
+#[cfg(feature = "geoip")]
+pub use gazetteer::GazetteerGeoIP;
+
+#[cfg(feature = "sqlite")]
+pub use gazetteer::GazetteerSqlite;
+
+pub use dateline::Dateline;
+pub use geoparser::{geoparse, GeoParser};
 pub use mention::{LocationMention, LocationPattern, MentionType};
@@ -0,0 +1,111 @@
+//! Tokenization and normalization for fuzzy place-name matching.
+//!
+//! Narrative text spells the same place many ways: "NYC", "St. Louis",
+//! "Londres" (French for London), or a plain misspelling like "Berline".
+//! This module turns a raw query into a normalized token stream — lowercased,
+//! diacritic-stripped, split on punctuation/whitespace, with a configurable
+//! table of abbreviations and initialisms expanded — so gazetteer lookups can
+//! match past surface-form differences before falling back to fuzzy
+//! (edit-distance) matching.
+//!
+//! # Example
+//!
+//! ```rust
+//! use spatial_narrative::parser::normalize;
+//!
+//! assert_eq!(normalize::tokenize("St. Louis"), vec!["saint", "louis"]);
+//! assert_eq!(normalize::tokenize("NYC"), vec!["new", "york", "city"]);
+//! assert_eq!(normalize::normalize_name("Zürich"), "zurich");
+//! ```
+
+/// Whole-token abbreviation and initialism expansions, looked up
+/// case-insensitively after diacritic stripping and punctuation splitting.
+///
+/// An expansion may itself be multiple words (e.g. `"nyc"` → `"new york
+/// city"`), in which case [`tokenize`] splits it back into separate tokens.
+const ABBREVIATIONS: &[(&str, &str)] = &[
+    ("st", "saint"),
+    ("mt", "mount"),
+    ("ft", "fort"),
+    ("nyc", "new york city"),
+    ("la", "los angeles"),
+    ("sf", "san francisco"),
+    ("dc", "washington district of columbia"),
+];
+
+/// Folds common Latin-alphabet diacritics to their unaccented base letter
+/// (`é` → `e`, `ü` → `u`, `ñ` → `n`, ...). Characters outside this table pass
+/// through unchanged.
+pub fn strip_diacritics(input: &str) -> String {
+    input
+        .chars()
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' => 'a',
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'È' | 'É' | 'Ê' | 'Ë' => 'e',
+            'ì' | 'í' | 'î' | 'ï' | 'ī' | 'Ì' | 'Í' | 'Î' | 'Ï' => 'i',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ō' | 'ø' | 'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' => 'o',
+            'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'Ù' | 'Ú' | 'Û' | 'Ü' => 'u',
+            'ñ' | 'Ñ' => 'n',
+            'ç' | 'Ç' => 'c',
+            'ý' | 'ÿ' | 'Ý' => 'y',
+            other => other,
+        })
+        .collect()
+}
+
+/// Splits `input` on whitespace and punctuation, lowercases and strips
+/// diacritics from each piece, and expands any whole-token match in
+/// [`ABBREVIATIONS`] into its (possibly multi-word) expansion.
+pub fn tokenize(input: &str) -> Vec<String> {
+    input
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .flat_map(|raw| {
+            let folded = strip_diacritics(&raw.to_lowercase());
+            match ABBREVIATIONS.iter().find(|(abbr, _)| *abbr == folded) {
+                Some((_, expansion)) => expansion
+                    .split_whitespace()
+                    .map(String::from)
+                    .collect::<Vec<_>>(),
+                None => vec![folded],
+            }
+        })
+        .collect()
+}
+
+/// Normalizes `input` to a single space-joined, lowercase, diacritic-free,
+/// abbreviation-expanded string suitable for equality comparison.
+pub fn normalize_name(input: &str) -> String {
+    tokenize(input).join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_expands_period_abbreviation() {
+        assert_eq!(tokenize("St. Louis"), vec!["saint", "louis"]);
+    }
+
+    #[test]
+    fn test_tokenize_expands_initialism() {
+        assert_eq!(tokenize("NYC"), vec!["new", "york", "city"]);
+    }
+
+    #[test]
+    fn test_tokenize_strips_diacritics_and_lowercases() {
+        assert_eq!(tokenize("Zürich"), vec!["zurich"]);
+        assert_eq!(tokenize("MÜNCHEN"), vec!["munchen"]);
+    }
+
+    #[test]
+    fn test_normalize_name_joins_tokens() {
+        assert_eq!(normalize_name("Mt. Fuji"), "mount fuji");
+    }
+
+    #[test]
+    fn test_tokenize_splits_on_punctuation_and_whitespace() {
+        assert_eq!(tokenize("San Francisco, CA"), vec!["san", "francisco", "ca"]);
+    }
+}
@@ -0,0 +1,172 @@
+//! Per-country postal code recognition and normalization.
+//!
+//! Narrative text rarely writes postal codes in a single canonical form: the
+//! same German code appears as `80331`, `DE-80331`, or `DE 80331`. This module
+//! recognizes codes by country and collapses them to their canonical digits,
+//! modeled on GeoNames' per-country regex rules.
+//!
+//! Each rule pairs a case-insensitive pattern — whose optional leading ISO
+//! country prefix is stripped — with a replacement template that rebuilds the
+//! canonical code from the captured groups.
+//!
+//! # Example
+//!
+//! ```rust
+//! use spatial_narrative::parser::postal;
+//!
+//! assert_eq!(postal::normalize("Germany", "DE-80331").as_deref(), Some("80331"));
+//! assert_eq!(postal::normalize("Austria", "1010").as_deref(), Some("1010"));
+//! ```
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::ops::Range;
+
+/// A single country's postal-code rule: a recognizer and a canonical template.
+struct PostalRule {
+    country: &'static str,
+    regex: Regex,
+    template: &'static str,
+}
+
+/// Build a rule, compiling its pattern once.
+fn rule(country: &'static str, pattern: &str, template: &'static str) -> PostalRule {
+    PostalRule {
+        country,
+        regex: Regex::new(pattern).unwrap(),
+        template,
+    }
+}
+
+/// The postal rules recognized by this module, compiled on first use.
+static RULES: Lazy<Vec<PostalRule>> = Lazy::new(|| {
+    vec![
+        rule("Germany", r"(?i)(?:DE-?)?(\d{5})", "$1"),
+        rule("Austria", r"(?i)(?:AT-?)?(\d{4})", "$1"),
+        rule("France", r"(?i)(?:FR-?)?(\d{5})", "$1"),
+        rule("Switzerland", r"(?i)(?:CH-?)?(\d{4})", "$1"),
+        rule("Argentina", r"(?i)(?:AR-?)?([A-Z]\d{4}[A-Z]{3}|\d{4})", "$1"),
+        rule("Bermuda", r"(?i)(?:BM-?)?([A-Z]{2})\W*(\d{2})", "$1$2"),
+        rule("United States", r"(?i)(?:US-?)?(\d{5})(?:-\d{4})?", "$1"),
+    ]
+});
+
+/// Normalize a single raw postal code for `country`, returning its canonical
+/// form.
+///
+/// All whitespace is removed from the input (so `DE 80331` reads as
+/// `DE80331`), the optional ISO prefix stripped, and the result rebuilt from
+/// the rule's template. Returns `None` when the country is unknown or the
+/// compacted input is not a postal code in its entirety — so a longer digit
+/// run such as a phone number is rejected rather than truncated.
+pub fn normalize(country: &str, raw: &str) -> Option<String> {
+    let rule = RULES.iter().find(|r| r.country.eq_ignore_ascii_case(country))?;
+    let compact: String = raw.split_whitespace().collect();
+    let caps = rule.regex.captures(&compact)?;
+    // The rule must cover the whole input, not just a prefix of it.
+    if caps.get(0)?.as_str() != compact {
+        return None;
+    }
+    let mut out = String::new();
+    caps.expand(rule.template, &mut out);
+    Some(out)
+}
+
+/// Scan free text for postal codes, returning each canonical code and the byte
+/// range it occupied.
+///
+/// With a `country_hint`, only that country's rule is applied; without one,
+/// every rule is tried and overlapping matches at the same span are reported
+/// once. Matches are anchored to token boundaries so embedded digit runs — phone
+/// numbers, years within longer numbers — are not misread as postal codes.
+pub fn detect(text: &str, country_hint: Option<&str>) -> Vec<(String, Range<usize>)> {
+    let active: Vec<&PostalRule> = match country_hint {
+        Some(country) => RULES
+            .iter()
+            .filter(|r| r.country.eq_ignore_ascii_case(country))
+            .collect(),
+        None => RULES.iter().collect(),
+    };
+
+    let mut matches: Vec<(String, Range<usize>)> = Vec::new();
+    for rule in active {
+        for caps in rule.regex.captures_iter(text) {
+            let whole = caps.get(0).unwrap();
+            if !boundary_ok(text, whole.start(), whole.end()) {
+                continue;
+            }
+            // Skip a span already claimed by an earlier rule (e.g. a bare 5-digit
+            // code that several countries would match identically).
+            if matches.iter().any(|(_, r)| r.start == whole.start() && r.end == whole.end()) {
+                continue;
+            }
+            let mut canonical = String::new();
+            caps.expand(rule.template, &mut canonical);
+            matches.push((canonical, whole.start()..whole.end()));
+        }
+    }
+
+    matches.sort_by_key(|(_, range)| range.start);
+    matches
+}
+
+/// True when the byte span `[start, end)` is flanked by non-alphanumeric
+/// characters (or the text boundary), so it is a standalone token.
+fn boundary_ok(text: &str, start: usize, end: usize) -> bool {
+    let before_ok = text[..start]
+        .chars()
+        .next_back()
+        .map(|c| !c.is_alphanumeric())
+        .unwrap_or(true);
+    let after_ok = text[end..]
+        .chars()
+        .next()
+        .map(|c| !c.is_alphanumeric())
+        .unwrap_or(true);
+    before_ok && after_ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_strips_iso_prefix() {
+        assert_eq!(normalize("Germany", "DE-80331").as_deref(), Some("80331"));
+        assert_eq!(normalize("Germany", "de 80331").as_deref(), Some("80331"));
+        assert_eq!(normalize("Germany", "80331").as_deref(), Some("80331"));
+    }
+
+    #[test]
+    fn test_normalize_rejects_non_postal() {
+        // A longer digit run is not a German postal code.
+        assert_eq!(normalize("Germany", "803311234"), None);
+        // Unknown country.
+        assert_eq!(normalize("Narnia", "12345"), None);
+    }
+
+    #[test]
+    fn test_normalize_structured_codes() {
+        assert_eq!(
+            normalize("Argentina", "AR-C1425DZE").as_deref(),
+            Some("C1425DZE")
+        );
+        assert_eq!(normalize("Bermuda", "CR 04").as_deref(), Some("CR04"));
+    }
+
+    #[test]
+    fn test_detect_with_hint() {
+        let text = "Ship to 80331 München by Friday.";
+        let found = detect(text, Some("Germany"));
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, "80331");
+        assert_eq!(&text[found[0].1.clone()], "80331");
+    }
+
+    #[test]
+    fn test_detect_skips_phone_numbers() {
+        // The digits are embedded in a longer run, so no postal match.
+        let text = "call +49803311234567 now";
+        assert!(detect(text, Some("Germany")).is_empty());
+    }
+}
@@ -0,0 +1,434 @@
+//! RAPTOR-based connection planning over a transit timetable.
+//!
+//! Turns the purely descriptive trajectory/stop analysis in [`analysis`](crate::analysis)
+//! and the GTFS feed importer in [`io`](crate::io) into a predictive trip-planning
+//! capability: given a [`Timetable`] (built from GTFS trips or from stops
+//! detected across many trajectories), answer "earliest arrival from stop A to
+//! stop B departing at time t, with the fewest transfers."
+//!
+//! Implements the round-based RAPTOR algorithm (Delling, Pajor & Werneck,
+//! 2012). Round `k` computes `arrival[k][stop]`, the earliest arrival at
+//! `stop` reachable using at most `k` trips: each round scans every route
+//! touched by a stop marked in the previous round, riding the earliest trip
+//! catchable at each boarding point and improving downstream stops, then
+//! relaxes foot-path [`Transfer`]s from the newly marked stops. Rounds stop
+//! once none improve, and the result is the Pareto set of journeys trading
+//! off arrival time against transfer count.
+
+use std::collections::{HashMap, HashSet};
+
+/// A single trip's scheduled arrival/departure at each stop along its route,
+/// in the same order as [`Route::stops`].
+#[derive(Debug, Clone)]
+pub struct Trip {
+    /// Identifier for this trip (e.g. a GTFS `trip_id`).
+    pub id: String,
+    /// Arrival/departure pair at each stop, aligned with `Route::stops`.
+    pub stop_times: Vec<StopTime>,
+}
+
+/// A trip's scheduled arrival and departure at one stop, in seconds since
+/// some reference epoch (typically seconds since service-date midnight).
+#[derive(Debug, Clone, Copy)]
+pub struct StopTime {
+    /// Scheduled arrival time.
+    pub arrival: i64,
+    /// Scheduled departure time.
+    pub departure: i64,
+}
+
+/// An ordered line of stops served by a sequence of non-overtaking trips.
+///
+/// `trips` must be sorted ascending by departure time at each stop — the
+/// standard GTFS invariant that later trips never overtake earlier ones —
+/// since the route scan relies on it to find the earliest catchable trip in
+/// a single linear pass.
+#[derive(Debug, Clone)]
+pub struct Route {
+    /// Identifier for this route (e.g. a GTFS `route_id`).
+    pub id: String,
+    /// Stops served, in travel order.
+    pub stops: Vec<String>,
+    /// Trips serving this route, sorted ascending by departure time.
+    pub trips: Vec<Trip>,
+}
+
+/// A foot-path transfer between two stops.
+#[derive(Debug, Clone)]
+pub struct Transfer {
+    /// Origin stop.
+    pub from: String,
+    /// Destination stop.
+    pub to: String,
+    /// Walking time, in seconds.
+    pub duration_secs: i64,
+}
+
+/// A timetable of routes and foot-path transfers to plan journeys over.
+#[derive(Debug, Clone, Default)]
+pub struct Timetable {
+    /// Routes in this timetable.
+    pub routes: Vec<Route>,
+    /// Foot-path transfers between stops.
+    pub transfers: Vec<Transfer>,
+}
+
+impl Timetable {
+    /// Creates an empty timetable.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a route to the timetable.
+    pub fn add_route(&mut self, route: Route) {
+        self.routes.push(route);
+    }
+
+    /// Adds a foot-path transfer to the timetable.
+    pub fn add_transfer(&mut self, transfer: Transfer) {
+        self.transfers.push(transfer);
+    }
+}
+
+/// One ride or transfer within a [`Journey`].
+#[derive(Debug, Clone)]
+pub struct Leg {
+    /// The route ridden, or `"walk"` for a foot-path transfer.
+    pub route_id: String,
+    /// Stop boarded at.
+    pub board_stop: String,
+    /// Stop alighted at.
+    pub alight_stop: String,
+    /// Time boarded.
+    pub board_time: i64,
+    /// Time alighted.
+    pub alight_time: i64,
+}
+
+/// A complete journey from the planner's source to its target.
+#[derive(Debug, Clone)]
+pub struct Journey {
+    /// Time of arrival at the target.
+    pub arrival: i64,
+    /// Number of trips ridden (transfers is one less, except for journeys
+    /// that are pure foot-paths).
+    pub trips_used: usize,
+    /// Legs of the journey, in travel order.
+    pub legs: Vec<Leg>,
+}
+
+/// Plans earliest-arrival journeys over a [`Timetable`] using round-based
+/// RAPTOR.
+pub struct RaptorPlanner<'t> {
+    timetable: &'t Timetable,
+}
+
+impl<'t> RaptorPlanner<'t> {
+    /// Creates a planner over the given timetable.
+    pub fn new(timetable: &'t Timetable) -> Self {
+        Self { timetable }
+    }
+
+    /// Finds the Pareto-optimal set of journeys from `source` to `target`
+    /// departing at or after `departure_time`, considering up to
+    /// `max_rounds` trips (transfers).
+    ///
+    /// The result is sorted by increasing trip count, with each entry's
+    /// arrival time strictly better than the previous — a journey using more
+    /// trips is included only if it arrives earlier than every journey with
+    /// fewer trips.
+    pub fn plan(
+        &self,
+        source: &str,
+        target: &str,
+        departure_time: i64,
+        max_rounds: usize,
+    ) -> Vec<Journey> {
+        let max_rounds = max_rounds.max(1);
+        let mut earliest_arrival: Vec<HashMap<String, i64>> =
+            vec![HashMap::new(); max_rounds + 1];
+        let mut legs: Vec<HashMap<String, Leg>> = vec![HashMap::new(); max_rounds + 1];
+        let mut best_arrival: HashMap<String, i64> = HashMap::new();
+
+        earliest_arrival[0].insert(source.to_string(), departure_time);
+        best_arrival.insert(source.to_string(), departure_time);
+        let mut marked: HashSet<String> = HashSet::new();
+        marked.insert(source.to_string());
+
+        for k in 1..=max_rounds {
+            earliest_arrival[k] = earliest_arrival[k - 1].clone();
+            legs[k] = legs[k - 1].clone();
+
+            if marked.is_empty() {
+                break;
+            }
+
+            // Collect, per touched route, the earliest marked stop's index.
+            let mut queue: HashMap<usize, usize> = HashMap::new();
+            for stop in &marked {
+                for (route_idx, route) in self.timetable.routes.iter().enumerate() {
+                    if let Some(pos) = route.stops.iter().position(|s| s == stop) {
+                        queue
+                            .entry(route_idx)
+                            .and_modify(|best| *best = (*best).min(pos))
+                            .or_insert(pos);
+                    }
+                }
+            }
+
+            let mut newly_marked = HashSet::new();
+
+            for (route_idx, start_pos) in queue {
+                let route = &self.timetable.routes[route_idx];
+                let mut boarded: Option<usize> = None;
+                let mut board_stop_idx = start_pos;
+
+                for stop_idx in start_pos..route.stops.len() {
+                    let stop = &route.stops[stop_idx];
+
+                    if let Some(trip_idx) = boarded {
+                        let trip = &route.trips[trip_idx];
+                        let arrival = trip.stop_times[stop_idx].arrival;
+                        if arrival < *best_arrival.get(stop).unwrap_or(&i64::MAX) {
+                            earliest_arrival[k].insert(stop.clone(), arrival);
+                            best_arrival.insert(stop.clone(), arrival);
+                            newly_marked.insert(stop.clone());
+                            legs[k].insert(
+                                stop.clone(),
+                                Leg {
+                                    route_id: route.id.clone(),
+                                    board_stop: route.stops[board_stop_idx].clone(),
+                                    alight_stop: stop.clone(),
+                                    board_time: trip.stop_times[board_stop_idx].departure,
+                                    alight_time: arrival,
+                                },
+                            );
+                        }
+                    }
+
+                    if let Some(&reachable_at) = earliest_arrival[k - 1].get(stop) {
+                        if let Some(candidate) =
+                            earliest_catchable_trip(route, stop_idx, reachable_at)
+                        {
+                            if boarded.map_or(true, |current| candidate < current) {
+                                boarded = Some(candidate);
+                                board_stop_idx = stop_idx;
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Relax foot-path transfers from stops marked by the route scan.
+            for stop in newly_marked.clone() {
+                let arrival = match earliest_arrival[k].get(&stop) {
+                    Some(&a) => a,
+                    None => continue,
+                };
+                for transfer in self.timetable.transfers.iter().filter(|t| t.from == stop) {
+                    let new_arrival = arrival + transfer.duration_secs;
+                    if new_arrival < *best_arrival.get(&transfer.to).unwrap_or(&i64::MAX) {
+                        earliest_arrival[k].insert(transfer.to.clone(), new_arrival);
+                        best_arrival.insert(transfer.to.clone(), new_arrival);
+                        newly_marked.insert(transfer.to.clone());
+                        legs[k].insert(
+                            transfer.to.clone(),
+                            Leg {
+                                route_id: "walk".to_string(),
+                                board_stop: stop.clone(),
+                                alight_stop: transfer.to.clone(),
+                                board_time: arrival,
+                                alight_time: new_arrival,
+                            },
+                        );
+                    }
+                }
+            }
+
+            marked = newly_marked;
+        }
+
+        let mut journeys = Vec::new();
+        let mut best_so_far = i64::MAX;
+
+        for (k, arrivals) in earliest_arrival.iter().enumerate() {
+            let Some(&arrival) = arrivals.get(target) else {
+                continue;
+            };
+            if arrival >= best_so_far {
+                continue;
+            }
+            best_so_far = arrival;
+            journeys.push(Journey {
+                arrival,
+                trips_used: k,
+                legs: reconstruct_legs(&legs, k, source, target),
+            });
+        }
+
+        journeys
+    }
+}
+
+/// Finds the index of the earliest trip on `route` departing `stop_idx` at or
+/// after `not_before`, relying on `route.trips` being sorted ascending.
+fn earliest_catchable_trip(route: &Route, stop_idx: usize, not_before: i64) -> Option<usize> {
+    route
+        .trips
+        .iter()
+        .position(|trip| trip.stop_times[stop_idx].departure >= not_before)
+}
+
+fn reconstruct_legs(
+    legs: &[HashMap<String, Leg>],
+    mut round: usize,
+    source: &str,
+    target: &str,
+) -> Vec<Leg> {
+    let mut path = Vec::new();
+    let mut stop = target.to_string();
+
+    while stop != source && round > 0 {
+        let Some(leg) = legs[round].get(&stop) else {
+            round -= 1;
+            continue;
+        };
+        let board_stop = leg.board_stop.clone();
+        // A foot-path leg is relaxed in the same round as the ride that
+        // reached its origin, so only a ride steps back a round.
+        if leg.route_id != "walk" {
+            round = round.saturating_sub(1);
+        }
+        path.push(leg.clone());
+        stop = board_stop;
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trip(id: &str, times: &[(i64, i64)]) -> Trip {
+        Trip {
+            id: id.to_string(),
+            stop_times: times
+                .iter()
+                .map(|&(arrival, departure)| StopTime { arrival, departure })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_direct_route_single_trip() {
+        let mut timetable = Timetable::new();
+        timetable.add_route(Route {
+            id: "R1".to_string(),
+            stops: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            trips: vec![trip("T1", &[(0, 0), (600, 610), (1200, 1200)])],
+        });
+
+        let planner = RaptorPlanner::new(&timetable);
+        let journeys = planner.plan("A", "C", 0, 3);
+
+        assert_eq!(journeys.len(), 1);
+        assert_eq!(journeys[0].arrival, 1200);
+        assert_eq!(journeys[0].trips_used, 1);
+        assert_eq!(journeys[0].legs.len(), 1);
+        assert_eq!(journeys[0].legs[0].route_id, "R1");
+    }
+
+    #[test]
+    fn test_journey_with_transfer_between_routes() {
+        let mut timetable = Timetable::new();
+        timetable.add_route(Route {
+            id: "R1".to_string(),
+            stops: vec!["A".to_string(), "B".to_string()],
+            trips: vec![trip("T1", &[(0, 0), (600, 600)])],
+        });
+        timetable.add_route(Route {
+            id: "R2".to_string(),
+            stops: vec!["B".to_string(), "C".to_string()],
+            trips: vec![trip("T2", &[(700, 700), (1300, 1300)])],
+        });
+
+        let planner = RaptorPlanner::new(&timetable);
+        let journeys = planner.plan("A", "C", 0, 3);
+
+        let best = journeys.last().unwrap();
+        assert_eq!(best.arrival, 1300);
+        assert_eq!(best.legs.len(), 2);
+        assert_eq!(best.legs[0].route_id, "R1");
+        assert_eq!(best.legs[1].route_id, "R2");
+    }
+
+    #[test]
+    fn test_foot_transfer_relaxation() {
+        let mut timetable = Timetable::new();
+        timetable.add_route(Route {
+            id: "R1".to_string(),
+            stops: vec!["A".to_string(), "B".to_string()],
+            trips: vec![trip("T1", &[(0, 0), (600, 600)])],
+        });
+        timetable.add_transfer(Transfer {
+            from: "B".to_string(),
+            to: "D".to_string(),
+            duration_secs: 120,
+        });
+
+        let planner = RaptorPlanner::new(&timetable);
+        let journeys = planner.plan("A", "D", 0, 3);
+
+        assert_eq!(journeys.len(), 1);
+        assert_eq!(journeys[0].arrival, 720);
+        assert_eq!(journeys[0].legs.last().unwrap().route_id, "walk");
+    }
+
+    #[test]
+    fn test_unreachable_target_returns_no_journeys() {
+        let mut timetable = Timetable::new();
+        timetable.add_route(Route {
+            id: "R1".to_string(),
+            stops: vec!["A".to_string(), "B".to_string()],
+            trips: vec![trip("T1", &[(0, 0), (600, 600)])],
+        });
+
+        let planner = RaptorPlanner::new(&timetable);
+        let journeys = planner.plan("A", "Z", 0, 3);
+        assert!(journeys.is_empty());
+    }
+
+    #[test]
+    fn test_pareto_set_keeps_slower_direct_and_faster_transfer() {
+        let mut timetable = Timetable::new();
+        // Direct trip, slower but one ride.
+        timetable.add_route(Route {
+            id: "Direct".to_string(),
+            stops: vec!["A".to_string(), "C".to_string()],
+            trips: vec![trip("D1", &[(0, 0), (1000, 1000)])],
+        });
+        // Two-trip alternative that arrives earlier — should appear as the
+        // 2-trip Pareto point since it strictly improves on arrival time.
+        timetable.add_route(Route {
+            id: "R1".to_string(),
+            stops: vec!["A".to_string(), "B".to_string()],
+            trips: vec![trip("T1", &[(0, 0), (300, 300)])],
+        });
+        timetable.add_route(Route {
+            id: "R2".to_string(),
+            stops: vec!["B".to_string(), "C".to_string()],
+            trips: vec![trip("T2", &[(400, 400), (700, 700)])],
+        });
+
+        let planner = RaptorPlanner::new(&timetable);
+        let journeys = planner.plan("A", "C", 0, 3);
+
+        assert_eq!(journeys.len(), 2);
+        assert_eq!(journeys[0].trips_used, 1);
+        assert_eq!(journeys[0].arrival, 1000);
+        assert_eq!(journeys[1].trips_used, 2);
+        assert_eq!(journeys[1].arrival, 700);
+    }
+}
@@ -2,19 +2,10 @@
 
 use once_cell::sync::Lazy;
 use regex::Regex;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use super::entity::{Entity, EntityType};
-
-// Common title patterns for person detection
-static TITLE_PATTERN: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?i)\b(Mr|Mrs|Ms|Dr|Prof|President|Chancellor|Prime Minister|King|Queen|Prince|Princess|Senator|Governor|Mayor|General|Admiral|Captain|Director|CEO|Chairman)\b\.?\s+([A-Z][a-z]+(?:\s+[A-Z][a-z]+)*)").unwrap()
-});
-
-// Organization indicators
-static ORG_PATTERN: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"\b([A-Z][a-z]+(?:\s+[A-Z][a-z]+)*)\s+(Corporation|Corp|Inc|Ltd|LLC|Company|Co|Organization|Foundation|Institute|University|Agency|Department|Ministry|Commission|Council|Bank|Group|Association)\b").unwrap()
-});
+use super::entity::{CountryResolution, DateComponents, Entity, EntityType};
+use super::tokenizer::{Tokenizer, WhitespaceTokenizer};
 
 // Date patterns
 static DATE_PATTERN: Lazy<Regex> = Lazy::new(|| {
@@ -26,6 +17,621 @@ static NUMERIC_PATTERN: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"\b(\d+(?:,\d{3})*(?:\.\d+)?)\s*(km|miles?|meters?|feet|ft|pounds?|lbs?|kilograms?|kg|dollars?|\$|euros?|€|percent|%|people|casualties|deaths?|injured|wounded)\b").unwrap()
 });
 
+/// Two-digit year below which `extract_dates` expands into the 2000s rather
+/// than the 1900s (e.g. with the default pivot, "24" becomes 2024 but "95"
+/// becomes 1995). Overridable via [`TextAnalyzer::set_two_digit_year_pivot`].
+const DEFAULT_TWO_DIGIT_YEAR_PIVOT: i32 = 70;
+
+/// English month names in calendar order, used to parse the two
+/// named-month [`DATE_PATTERN`] alternatives.
+const MONTH_NAMES: &[&str] = &[
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+fn month_from_name(name: &str) -> Option<u32> {
+    let lower = name.to_lowercase();
+    MONTH_NAMES
+        .iter()
+        .position(|&month| month == lower)
+        .map(|idx| idx as u32 + 1)
+}
+
+/// Expand a possibly two-digit year using `pivot`: values below `pivot`
+/// land in the 2000s, values at or above it land in the 1900s. Years
+/// already given with 3+ digits pass through unchanged.
+fn expand_two_digit_year(year: i32, pivot: i32) -> i32 {
+    if year >= 100 {
+        year
+    } else if year < pivot {
+        2000 + year
+    } else {
+        1900 + year
+    }
+}
+
+fn valid_date(year: i32, month: u32, day: u32) -> Option<DateComponents> {
+    if (1..=12).contains(&month) && (1..=31).contains(&day) {
+        Some(DateComponents { year, month, day })
+    } else {
+        None
+    }
+}
+
+/// Parse a numeric `DATE_PATTERN` match (`d/m/y`-ish or `y/m/d`-ish,
+/// separated by `/` or `-`). Which alternative matched is told apart by
+/// which field has 4 digits, per the pattern's own alternation.
+fn parse_numeric_date(text: &str, pivot: i32) -> Option<DateComponents> {
+    let parts: Vec<&str> = text.split(['/', '-']).collect();
+    let [a, b, c] = parts[..] else { return None };
+    if ![a, b, c]
+        .iter()
+        .all(|part| part.chars().all(|ch| ch.is_ascii_digit()))
+    {
+        return None;
+    }
+
+    let (a, b, c): (i32, i32, i32) = (a.parse().ok()?, b.parse().ok()?, c.parse().ok()?);
+    if a >= 1000 {
+        // y/m/d
+        valid_date(a, b as u32, c as u32)
+    } else {
+        // d/m/y
+        valid_date(expand_two_digit_year(c, pivot), b as u32, a as u32)
+    }
+}
+
+/// Parse a named-month `DATE_PATTERN` match (`Month d, yyyy` or
+/// `d Month yyyy`).
+fn parse_named_month_date(text: &str) -> Option<DateComponents> {
+    let cleaned = text.replace(',', "");
+    let tokens: Vec<&str> = cleaned.split_whitespace().collect();
+    let [first, second, third] = tokens[..] else {
+        return None;
+    };
+
+    if let Some(month) = month_from_name(first) {
+        valid_date(third.parse().ok()?, month, second.parse().ok()?)
+    } else if let Some(month) = month_from_name(second) {
+        valid_date(third.parse().ok()?, month, first.parse().ok()?)
+    } else {
+        None
+    }
+}
+
+/// Parse a full `DATE_PATTERN` match into its calendar components,
+/// returning `None` when the match can't be unambiguously resolved (e.g.
+/// an out-of-range day or month) rather than guessing.
+fn parse_date_components(text: &str, two_digit_year_pivot: i32) -> Option<DateComponents> {
+    parse_numeric_date(text, two_digit_year_pivot).or_else(|| parse_named_month_date(text))
+}
+
+/// Lowercased country-name aliases (matching [`TextAnalyzer::default_locations`])
+/// mapped to their canonical name and ISO 3166-1 alpha-2 code.
+static COUNTRY_ALIASES: Lazy<HashMap<&'static str, (&'static str, &'static str)>> =
+    Lazy::new(|| {
+        [
+            ("united states", ("United States", "US")),
+            ("usa", ("United States", "US")),
+            ("america", ("United States", "US")),
+            ("united kingdom", ("United Kingdom", "GB")),
+            ("uk", ("United Kingdom", "GB")),
+            ("britain", ("United Kingdom", "GB")),
+            ("france", ("France", "FR")),
+            ("germany", ("Germany", "DE")),
+            ("italy", ("Italy", "IT")),
+            ("spain", ("Spain", "ES")),
+            ("china", ("China", "CN")),
+            ("japan", ("Japan", "JP")),
+            ("india", ("India", "IN")),
+            ("brazil", ("Brazil", "BR")),
+            ("australia", ("Australia", "AU")),
+            ("russia", ("Russia", "RU")),
+            ("canada", ("Canada", "CA")),
+            ("mexico", ("Mexico", "MX")),
+            ("ukraine", ("Ukraine", "UA")),
+            ("poland", ("Poland", "PL")),
+            ("netherlands", ("Netherlands", "NL")),
+            ("belgium", ("Belgium", "BE")),
+            ("sweden", ("Sweden", "SE")),
+            ("norway", ("Norway", "NO")),
+            ("denmark", ("Denmark", "DK")),
+            ("finland", ("Finland", "FI")),
+            ("switzerland", ("Switzerland", "CH")),
+            ("austria", ("Austria", "AT")),
+            ("portugal", ("Portugal", "PT")),
+            ("greece", ("Greece", "GR")),
+            ("turkey", ("Turkey", "TR")),
+            ("israel", ("Israel", "IL")),
+            ("egypt", ("Egypt", "EG")),
+            ("south africa", ("South Africa", "ZA")),
+            ("nigeria", ("Nigeria", "NG")),
+            ("kenya", ("Kenya", "KE")),
+            ("argentina", ("Argentina", "AR")),
+            ("colombia", ("Colombia", "CO")),
+            ("peru", ("Peru", "PE")),
+            ("chile", ("Chile", "CL")),
+            ("indonesia", ("Indonesia", "ID")),
+            ("philippines", ("Philippines", "PH")),
+            ("thailand", ("Thailand", "TH")),
+            ("vietnam", ("Vietnam", "VN")),
+            ("malaysia", ("Malaysia", "MY")),
+            ("taiwan", ("Taiwan", "TW")),
+            ("iran", ("Iran", "IR")),
+            ("iraq", ("Iraq", "IQ")),
+            ("syria", ("Syria", "SY")),
+            ("afghanistan", ("Afghanistan", "AF")),
+            ("pakistan", ("Pakistan", "PK")),
+            ("bangladesh", ("Bangladesh", "BD")),
+            ("saudi arabia", ("Saudi Arabia", "SA")),
+            ("uae", ("United Arab Emirates", "AE")),
+            ("qatar", ("Qatar", "QA")),
+        ]
+        .into_iter()
+        .collect()
+    });
+
+/// Derive the regional-indicator flag emoji for a two-letter ISO country
+/// code by offsetting each ASCII letter into the Unicode regional-indicator
+/// block (`0x1F1E6` is the regional indicator for 'A').
+fn flag_emoji(iso_code: &str) -> String {
+    const REGIONAL_INDICATOR_OFFSET: u32 = 0x1F1E6 - b'A' as u32;
+    iso_code
+        .chars()
+        .filter_map(|ch| char::from_u32(ch.to_ascii_uppercase() as u32 + REGIONAL_INDICATOR_OFFSET))
+        .collect()
+}
+
+/// Vowels used for Snowball-style region boundary detection.
+fn is_vowel(ch: char) -> bool {
+    matches!(ch, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+/// Find the start of the Snowball region beginning right after the first
+/// non-vowel that follows a vowel, scanning `chars` from `from`. Returns
+/// `chars.len()` (an empty region) when no such vowel-then-consonant run
+/// exists from `from` onward, which keeps short or vowel/consonant-only
+/// tokens from ever matching a suffix below.
+fn region_start(chars: &[char], from: usize) -> usize {
+    let len = chars.len();
+    let mut i = from;
+    while i < len && !is_vowel(chars[i]) {
+        i += 1;
+    }
+    if i >= len {
+        return len;
+    }
+    let mut j = i + 1;
+    while j < len && is_vowel(chars[j]) {
+        j += 1;
+    }
+    if j >= len {
+        len
+    } else {
+        j + 1
+    }
+}
+
+/// Suffixes stripped when they fall entirely within R1.
+const R1_SUFFIXES: &[&str] = &["ness", "ing", "ies", "ed", "es", "ly", "s"];
+
+/// Suffixes stripped when they fall entirely within R2.
+const R2_SUFFIXES: &[&str] = &["tion", "ment", "ous"];
+
+/// Lightly stem `word` using Snowball-style R1/R2 regions: a suffix is only
+/// removed when it lies entirely within the region it's associated with
+/// (R1 for plurals/`-ed`/`-ing`/`-ly`/`-ness`, R2 for `-tion`/`-ment`/
+/// `-ous`), so short or already-bare words are left untouched. When more
+/// than one suffix qualifies, the longest one wins. This collapses common
+/// inflections but doesn't attempt irregular forms (e.g. "did" vs "does").
+pub fn stem(word: &str) -> String {
+    let lower = word.to_lowercase();
+    let chars: Vec<char> = lower.chars().collect();
+    let r1 = region_start(&chars, 0);
+    let r2 = region_start(&chars, r1);
+
+    let mut candidates: Vec<(&str, usize)> = R1_SUFFIXES.iter().map(|s| (*s, r1)).collect();
+    candidates.extend(R2_SUFFIXES.iter().map(|s| (*s, r2)));
+    candidates.sort_by_key(|(suffix, _)| std::cmp::Reverse(suffix.chars().count()));
+
+    for (suffix, region) in candidates {
+        let suffix_chars: Vec<char> = suffix.chars().collect();
+        if suffix_chars.len() >= chars.len() {
+            continue;
+        }
+        // As in Snowball, a final "s" after "u" or "s" ("famous", "glass")
+        // is not a plural ending.
+        if suffix == "s" && (lower.ends_with("us") || lower.ends_with("ss")) {
+            continue;
+        }
+        let start = chars.len() - suffix_chars.len();
+        if chars[start..] == suffix_chars[..] && start >= region {
+            return chars[..start].iter().collect();
+        }
+    }
+
+    lower
+}
+
+/// Pick the maximum edit distance tolerated for a gazetteer entry of the
+/// given character length: exact match for short entries (4 chars or
+/// fewer, where a typo would change the word's meaning), one edit for
+/// medium entries (5-8 chars), two for longer ones.
+fn max_edit_distance(len: usize) -> usize {
+    if len <= 4 {
+        0
+    } else if len <= 8 {
+        1
+    } else {
+        2
+    }
+}
+
+/// Lowercases `text` while recording, for every byte of the returned string,
+/// the byte offset in `text` where the source character begins.
+///
+/// `char::to_lowercase` can expand a single character into more bytes than
+/// it started with (e.g. `İ` U+0130 becomes the 3-byte `i` followed by a
+/// combining dot above), so a lowered copy can't be assumed to line up
+/// byte-for-byte with the original. The returned offset table lets a match
+/// position found in the lowered copy be translated back to the matching
+/// position in `text`.
+fn lower_with_offsets(text: &str) -> (String, Vec<usize>) {
+    let mut lowered = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len());
+
+    for (orig_pos, ch) in text.char_indices() {
+        let start = lowered.len();
+        lowered.extend(ch.to_lowercase());
+        offsets.resize(offsets.len() + (lowered.len() - start), orig_pos);
+    }
+
+    (lowered, offsets)
+}
+
+/// Split `text` into the byte spans of its word runs (alphanumeric or
+/// apostrophe), used to build candidate token windows for fuzzy matching.
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+
+    for (idx, ch) in text.char_indices() {
+        if ch.is_alphanumeric() || ch == '\'' {
+            start.get_or_insert(idx);
+        } else if let Some(s) = start.take() {
+            spans.push((s, idx));
+        }
+    }
+    if let Some(s) = start {
+        spans.push((s, text.len()));
+    }
+
+    spans
+}
+
+/// A bounded Levenshtein automaton over `target`: each [`Self::step`] consumes
+/// one input character and transitions the automaton's row of edit
+/// distances, the same recurrence a classical Levenshtein DFA encodes as
+/// states. The automaton dies (further steps report it as dead) once every
+/// reachable distance exceeds `max`, which keeps matching linear instead of
+/// quadratic in practice.
+struct LevenshteinAutomaton<'a> {
+    target: &'a [char],
+    max: usize,
+    row: Vec<usize>,
+}
+
+impl<'a> LevenshteinAutomaton<'a> {
+    fn new(target: &'a [char], max: usize) -> Self {
+        Self {
+            target,
+            max,
+            row: (0..=target.len()).collect(),
+        }
+    }
+
+    /// Consume one input character, returning `false` once the automaton can
+    /// no longer reach an accepting state within `max` edits.
+    fn step(&mut self, ch: char) -> bool {
+        let mut next = vec![0usize; self.target.len() + 1];
+        next[0] = self.row[0] + 1;
+        for (j, &target_ch) in self.target.iter().enumerate() {
+            let cost = usize::from(target_ch != ch);
+            next[j + 1] = (self.row[j] + cost).min(self.row[j + 1] + 1).min(next[j] + 1);
+        }
+        self.row = next;
+        self.row.iter().min().is_some_and(|&d| d <= self.max)
+    }
+
+    /// The edit distance to `target` after the input seen so far.
+    fn distance(&self) -> usize {
+        *self.row.last().unwrap()
+    }
+}
+
+/// Run `input` through a [`LevenshteinAutomaton`] over `target`, returning
+/// its edit distance if it stays reachable within `max`, or `None` if it
+/// dies partway through (an early exit, not a full O(n*m) DP table fill).
+fn bounded_levenshtein(target: &[char], input: &[char], max: usize) -> Option<usize> {
+    let mut automaton = LevenshteinAutomaton::new(target, max);
+    for &ch in input {
+        if !automaton.step(ch) {
+            return None;
+        }
+    }
+    let distance = automaton.distance();
+    (distance <= max).then_some(distance)
+}
+
+/// A script's "capitalized word" shape: the codepoint ranges that count as
+/// upper- and lowercase letters in that script, used to build the
+/// person-title and organization-suffix patterns. Ranges may be
+/// discontiguous (e.g. a run plus a handful of precomposed letters) — a
+/// single-codepoint "range" is written `(c, c)`.
+#[derive(Debug, Clone)]
+pub struct ScriptRange {
+    /// Uppercase (title-initial) codepoint ranges.
+    pub upper: Vec<(char, char)>,
+    /// Lowercase codepoint ranges.
+    pub lower: Vec<(char, char)>,
+}
+
+impl ScriptRange {
+    /// Create a script range from explicit upper/lowercase codepoint ranges.
+    pub fn new(upper: Vec<(char, char)>, lower: Vec<(char, char)>) -> Self {
+        Self { upper, lower }
+    }
+
+    fn latin() -> Self {
+        Self::new(vec![('A', 'Z')], vec![('a', 'z')])
+    }
+
+    fn cyrillic() -> Self {
+        // А-Я plus Ё, and the Macedonian (ѓѕјљњќѐѝ / ЃЅЈЉЊЌЀЍ) and Ukrainian
+        // (єіїґ / ЄІЇҐ) letters outside that contiguous block.
+        Self::new(
+            vec![
+                ('А', 'Я'),
+                ('Ё', 'Ё'),
+                ('Ѓ', 'Ѓ'),
+                ('Ѕ', 'Ѕ'),
+                ('Ј', 'Ј'),
+                ('Љ', 'Љ'),
+                ('Њ', 'Њ'),
+                ('Ќ', 'Ќ'),
+                ('Ѐ', 'Ѐ'),
+                ('Ѝ', 'Ѝ'),
+                ('Є', 'Є'),
+                ('І', 'Ї'),
+                ('Ґ', 'Ґ'),
+            ],
+            vec![
+                ('а', 'я'),
+                ('ё', 'ё'),
+                ('ѓ', 'ѓ'),
+                ('ѕ', 'ѕ'),
+                ('ј', 'ј'),
+                ('љ', 'љ'),
+                ('њ', 'њ'),
+                ('ќ', 'ќ'),
+                ('ѐ', 'ѐ'),
+                ('ѝ', 'ѝ'),
+                ('є', 'є'),
+                ('і', 'ї'),
+                ('ґ', 'ґ'),
+            ],
+        )
+    }
+
+    fn greek() -> Self {
+        // Α-Ω / α-ω plus the tonos-accented letters (ΆΈΉΊΌΎΏ / άέήίόύώ),
+        // which sit just outside those runs.
+        Self::new(
+            vec![('Ά', 'Ά'), ('Έ', 'Ώ'), ('Α', 'Ω')],
+            vec![('ά', 'ώ')],
+        )
+    }
+
+    fn tatar() -> Self {
+        // Cyrillic-based Tatar letters not covered by the Cyrillic block.
+        Self::new(
+            vec![
+                ('Ә', 'Ә'),
+                ('Ө', 'Ө'),
+                ('Ү', 'Ү'),
+                ('Җ', 'Җ'),
+                ('Ң', 'Ң'),
+                ('Һ', 'Һ'),
+            ],
+            vec![
+                ('ә', 'ә'),
+                ('ө', 'ө'),
+                ('ү', 'ү'),
+                ('җ', 'җ'),
+                ('ң', 'ң'),
+                ('һ', 'һ'),
+            ],
+        )
+    }
+
+    /// Latin, Cyrillic (with Macedonian/Ukrainian extras), Greek, and Tatar —
+    /// the default set a [`TextAnalyzer`] is built with.
+    fn defaults() -> Vec<Self> {
+        vec![Self::latin(), Self::cyrillic(), Self::greek(), Self::tatar()]
+    }
+}
+
+/// Append a codepoint range to a regex character class, as a `start-end` pair
+/// or a single literal when the range covers one codepoint.
+fn push_class_range(class: &mut String, start: char, end: char) {
+    if start == end {
+        class.push(start);
+    } else {
+        class.push(start);
+        class.push('-');
+        class.push(end);
+    }
+}
+
+fn build_char_class(scripts: &[ScriptRange], pick: impl Fn(&ScriptRange) -> &[(char, char)]) -> String {
+    let mut class = String::new();
+    for script in scripts {
+        for &(start, end) in pick(script) {
+            push_class_range(&mut class, start, end);
+        }
+    }
+    class
+}
+
+fn default_title_words() -> Vec<&'static str> {
+    vec![
+        "Mr",
+        "Mrs",
+        "Ms",
+        "Dr",
+        "Prof",
+        "President",
+        "Chancellor",
+        "Prime Minister",
+        "King",
+        "Queen",
+        "Prince",
+        "Princess",
+        "Senator",
+        "Governor",
+        "Mayor",
+        "General",
+        "Admiral",
+        "Captain",
+        "Director",
+        "CEO",
+        "Chairman",
+        // Russian / Ukrainian
+        "Президент",
+        "Премьер-министр",
+        "Доктор",
+        "Генерал",
+        "Мэр",
+        "Губернатор",
+        // Greek
+        "Πρόεδρος",
+        "Πρωθυπουργός",
+        "Δόκτωρ",
+        "Στρατηγός",
+    ]
+}
+
+fn default_org_suffixes() -> Vec<&'static str> {
+    vec![
+        "Corporation",
+        "Corp",
+        "Inc",
+        "Ltd",
+        "LLC",
+        "Company",
+        "Co",
+        "Organization",
+        "Foundation",
+        "Institute",
+        "University",
+        "Agency",
+        "Department",
+        "Ministry",
+        "Commission",
+        "Council",
+        "Bank",
+        "Group",
+        "Association",
+        // Russian / Ukrainian
+        "компания",
+        "организация",
+        "университет",
+        "министерство",
+        "банк",
+        "группа",
+        // Greek
+        "εταιρεία",
+        "οργανισμός",
+        "πανεπιστήμιο",
+        "υπουργείο",
+    ]
+}
+
+/// Build the person-title pattern from the configured scripts and title
+/// words: `(?i:title)` followed by one or more capitalized-in-script words.
+fn compile_title_pattern(scripts: &[ScriptRange], extra_titles: &[String]) -> Regex {
+    let upper = build_char_class(scripts, |s| &s.upper);
+    let lower = build_char_class(scripts, |s| &s.lower);
+    let titles: Vec<&str> = default_title_words()
+        .into_iter()
+        .chain(extra_titles.iter().map(String::as_str))
+        .collect();
+
+    let pattern = format!(
+        r"(?i:\b({titles})\b)\.?\s+([{upper}][{lower}]+(?:\s+[{upper}][{lower}]+)*)",
+        titles = titles.join("|"),
+    );
+    Regex::new(&pattern).expect("compiled title pattern is valid")
+}
+
+/// Build the organization pattern from the configured scripts and suffix
+/// words: one or more capitalized-in-script words followed by a suffix.
+fn compile_org_pattern(scripts: &[ScriptRange], extra_suffixes: &[String]) -> Regex {
+    let upper = build_char_class(scripts, |s| &s.upper);
+    let lower = build_char_class(scripts, |s| &s.lower);
+    let suffixes: Vec<&str> = default_org_suffixes()
+        .into_iter()
+        .chain(extra_suffixes.iter().map(String::as_str))
+        .collect();
+
+    let pattern = format!(
+        r"\b([{upper}][{lower}]+(?:\s+[{upper}][{lower}]+)*)\s+(?i:({suffixes})\b)",
+        suffixes = suffixes.join("|"),
+    );
+    Regex::new(&pattern).expect("compiled organization pattern is valid")
+}
+
+/// One entry in a [`TextAnalyzer`]'s rule registry.
+///
+/// Built-in rules (`"person_title"`, `"org_suffix"`, `"date"`, `"numeric"`,
+/// `"location"`) wrap the analyzer's existing extractors; rules added via
+/// [`TextAnalyzer::add_rule`] match a user-supplied regex directly. `entities`
+/// iterates only `enabled` rules, in registry order, feeding every rule's
+/// matches through the same overlap resolution.
+#[derive(Debug)]
+pub struct Rule {
+    /// Stable identifier, used by [`TextAnalyzer::enable_rule`]/[`TextAnalyzer::disable_rule`].
+    pub id: String,
+    /// Entity type this rule produces.
+    pub entity_type: EntityType,
+    /// Confidence assigned to matches (built-in rules report their
+    /// extractor's default; custom rules use this value directly).
+    pub confidence: f64,
+    /// Whether `entities` runs this rule.
+    pub enabled: bool,
+    kind: RuleKind,
+}
+
+#[derive(Debug)]
+enum RuleKind {
+    PersonTitle,
+    OrgSuffix,
+    Date,
+    Numeric,
+    Location,
+    Custom { regex: Regex, group: usize },
+}
+
 /// Text analyzer for named entity recognition.
 ///
 /// This analyzer uses pattern-based recognition to identify
@@ -47,22 +653,104 @@ pub struct TextAnalyzer {
     known_locations: HashSet<String>,
     /// Stop words to filter
     stop_words: HashSet<String>,
+    /// Whether gazetteer matching (locations and common organization names)
+    /// tolerates bounded edit distance instead of requiring an exact match.
+    fuzzy_matching: bool,
+    /// Scripts whose capitalized-word shape feeds the person/organization
+    /// patterns (defaults to Latin, Cyrillic, Greek, and Tatar).
+    script_ranges: Vec<ScriptRange>,
+    /// Title words in addition to [`default_title_words`].
+    extra_titles: Vec<String>,
+    /// Organization suffixes in addition to [`default_org_suffixes`].
+    extra_org_suffixes: Vec<String>,
+    /// Compiled from `script_ranges` + title words; recompiled whenever
+    /// either changes.
+    title_pattern: Regex,
+    /// Compiled from `script_ranges` + org suffixes; recompiled whenever
+    /// either changes.
+    org_pattern: Regex,
+    /// The rule registry driving [`Self::entities`].
+    rules: Vec<Rule>,
+    /// Pivot for expanding two-digit years parsed by `extract_dates` (see
+    /// [`DEFAULT_TWO_DIGIT_YEAR_PIVOT`]).
+    two_digit_year_pivot: i32,
+    /// Whether [`Self::entities`] attaches [`CountryResolution`]s to
+    /// `Location` entities that name a recognized country.
+    resolve_countries: bool,
+    /// Word-segmentation strategy backing [`Self::tokenize`]. Defaults to
+    /// [`WhitespaceTokenizer`]; swap in a [`super::DictionaryTokenizer`]
+    /// for scripts without whitespace word boundaries.
+    tokenizer: Box<dyn Tokenizer>,
 }
 
 impl TextAnalyzer {
     /// Create a new text analyzer with default settings.
     pub fn new() -> Self {
+        let script_ranges = ScriptRange::defaults();
+        let title_pattern = compile_title_pattern(&script_ranges, &[]);
+        let org_pattern = compile_org_pattern(&script_ranges, &[]);
+
         Self {
             known_locations: Self::default_locations(),
             stop_words: Self::default_stop_words(),
+            fuzzy_matching: false,
+            script_ranges,
+            extra_titles: Vec::new(),
+            extra_org_suffixes: Vec::new(),
+            title_pattern,
+            org_pattern,
+            rules: Self::default_rules(),
+            two_digit_year_pivot: DEFAULT_TWO_DIGIT_YEAR_PIVOT,
+            resolve_countries: false,
+            tokenizer: Box::new(WhitespaceTokenizer),
         }
     }
 
+    fn default_rules() -> Vec<Rule> {
+        vec![
+            Rule {
+                id: "person_title".to_string(),
+                entity_type: EntityType::Person,
+                confidence: 0.9,
+                enabled: true,
+                kind: RuleKind::PersonTitle,
+            },
+            Rule {
+                id: "org_suffix".to_string(),
+                entity_type: EntityType::Organization,
+                confidence: 0.85,
+                enabled: true,
+                kind: RuleKind::OrgSuffix,
+            },
+            Rule {
+                id: "date".to_string(),
+                entity_type: EntityType::DateTime,
+                confidence: 0.95,
+                enabled: true,
+                kind: RuleKind::Date,
+            },
+            Rule {
+                id: "numeric".to_string(),
+                entity_type: EntityType::Numeric,
+                confidence: 0.9,
+                enabled: true,
+                kind: RuleKind::Numeric,
+            },
+            Rule {
+                id: "location".to_string(),
+                entity_type: EntityType::Location,
+                confidence: 0.85,
+                enabled: true,
+                kind: RuleKind::Location,
+            },
+        ]
+    }
+
     /// Create an analyzer with custom location names.
     pub fn with_locations(locations: HashSet<String>) -> Self {
         Self {
             known_locations: locations,
-            stop_words: Self::default_stop_words(),
+            ..Self::new()
         }
     }
 
@@ -71,45 +759,189 @@ impl TextAnalyzer {
         self.known_locations.insert(name.into());
     }
 
-    /// Extract named entities from text.
-    pub fn entities(&self, text: &str) -> Vec<Entity> {
-        let mut entities = Vec::new();
+    /// Register an additional script's capitalized-word shape so
+    /// person/organization detection also recognizes names written in it.
+    pub fn add_script_range(&mut self, range: ScriptRange) {
+        self.script_ranges.push(range);
+        self.recompile_patterns();
+    }
+
+    /// Register an additional person-title word (e.g. a localized honorific).
+    pub fn add_title_word(&mut self, word: impl Into<String>) {
+        self.extra_titles.push(word.into());
+        self.recompile_patterns();
+    }
+
+    /// Register an additional organization-name suffix (e.g. a localized
+    /// legal-entity or institution word).
+    pub fn add_org_suffix(&mut self, word: impl Into<String>) {
+        self.extra_org_suffixes.push(word.into());
+        self.recompile_patterns();
+    }
+
+    fn recompile_patterns(&mut self) {
+        self.title_pattern = compile_title_pattern(&self.script_ranges, &self.extra_titles);
+        self.org_pattern = compile_org_pattern(&self.script_ranges, &self.extra_org_suffixes);
+    }
+
+    /// Enable or disable fuzzy gazetteer matching.
+    ///
+    /// When enabled, [`Self::entities`] matches misspelled or OCR-garbled
+    /// location and organization names within a bounded edit distance (see
+    /// [`max_edit_distance`]) instead of requiring an exact substring match.
+    pub fn set_fuzzy_matching(&mut self, enabled: bool) {
+        self.fuzzy_matching = enabled;
+    }
+
+    /// Set the pivot used to expand two-digit years found by
+    /// `extract_dates` (default [`DEFAULT_TWO_DIGIT_YEAR_PIVOT`]). Years
+    /// below `pivot` expand into the 2000s, years at or above it into the
+    /// 1900s.
+    pub fn set_two_digit_year_pivot(&mut self, pivot: i32) {
+        self.two_digit_year_pivot = pivot;
+    }
+
+    /// Enable or disable attaching [`CountryResolution`]s to `Location`
+    /// entities in [`Self::entities`] (see [`Self::resolve_country`]).
+    pub fn set_resolve_countries(&mut self, enabled: bool) {
+        self.resolve_countries = enabled;
+    }
+
+    /// Resolve a `Location` entity to its canonical country name, ISO
+    /// 3166-1 alpha-2 code, and flag emoji, collapsing aliases like "USA",
+    /// "America", and "United States" to one identifier. Returns `None` for
+    /// entities that aren't `Location`s or aren't a recognized country.
+    pub fn resolve_country(entity: &Entity) -> Option<CountryResolution> {
+        if entity.entity_type != EntityType::Location {
+            return None;
+        }
+        let (canonical, iso_code) = COUNTRY_ALIASES.get(entity.text.to_lowercase().as_str())?;
+        Some(CountryResolution {
+            canonical: canonical.to_string(),
+            iso_code: iso_code.to_string(),
+            flag: flag_emoji(iso_code),
+        })
+    }
 
-        // Extract persons (with titles)
-        self.extract_persons(text, &mut entities);
+    /// Disable a rule by id (built-in or custom). No-op if `id` is unknown.
+    pub fn disable_rule(&mut self, id: &str) {
+        if let Some(rule) = self.rules.iter_mut().find(|r| r.id == id) {
+            rule.enabled = false;
+        }
+    }
 
-        // Extract organizations
-        self.extract_organizations(text, &mut entities);
+    /// Enable a previously disabled rule by id. No-op if `id` is unknown.
+    pub fn enable_rule(&mut self, id: &str) {
+        if let Some(rule) = self.rules.iter_mut().find(|r| r.id == id) {
+            rule.enabled = true;
+        }
+    }
 
-        // Extract dates
-        self.extract_dates(text, &mut entities);
+    /// List the rules in the registry, in the priority order `entities` runs
+    /// them.
+    pub fn list_rules(&self) -> &[Rule] {
+        &self.rules
+    }
 
-        // Extract numeric values
-        self.extract_numerics(text, &mut entities);
+    /// Add a custom rule matching `regex`, taking the entity's text from
+    /// capture group `group` (`0` for the whole match).
+    pub fn add_rule(
+        &mut self,
+        id: impl Into<String>,
+        entity_type: EntityType,
+        regex: Regex,
+        group: usize,
+        confidence: f64,
+    ) {
+        self.rules.push(Rule {
+            id: id.into(),
+            entity_type,
+            confidence,
+            enabled: true,
+            kind: RuleKind::Custom { regex, group },
+        });
+    }
+
+    /// Extract named entities from text by running every enabled rule in the
+    /// registry (see [`Self::list_rules`]), in priority order.
+    pub fn entities(&self, text: &str) -> Vec<Entity> {
+        let mut entities = Vec::new();
 
-        // Extract locations (from known list)
-        self.extract_locations(text, &mut entities);
+        for rule in &self.rules {
+            if !rule.enabled {
+                continue;
+            }
+            match &rule.kind {
+                RuleKind::PersonTitle => self.extract_persons(text, &mut entities),
+                RuleKind::OrgSuffix => self.extract_organizations(text, &mut entities),
+                RuleKind::Date => self.extract_dates(text, &mut entities),
+                RuleKind::Numeric => self.extract_numerics(text, &mut entities),
+                RuleKind::Location => self.extract_locations(text, &mut entities),
+                RuleKind::Custom { regex, group } => Self::extract_custom(
+                    text,
+                    regex,
+                    *group,
+                    rule.entity_type.clone(),
+                    rule.confidence,
+                    &mut entities,
+                ),
+            }
+        }
 
         // Sort by position and remove overlaps
         entities.sort_by_key(|e| e.start);
         self.remove_overlaps(&mut entities);
 
+        if self.resolve_countries {
+            for entity in &mut entities {
+                if let Some(country) = Self::resolve_country(entity) {
+                    entity.country = Some(country);
+                }
+            }
+        }
+
         entities
     }
 
-    /// Tokenize text into words.
+    /// Swap the word-segmentation strategy used by [`Self::tokenize`] (and
+    /// everything built on it). Use a [`super::DictionaryTokenizer`] for
+    /// scripts that don't delimit words with whitespace.
+    pub fn set_tokenizer(&mut self, tokenizer: Box<dyn Tokenizer>) {
+        self.tokenizer = tokenizer;
+    }
+
+    /// Tokenize text into words, using the configured [`Tokenizer`]
+    /// (whitespace/punctuation splitting by default).
     pub fn tokenize(&self, text: &str) -> Vec<String> {
-        text.split(|c: char| !c.is_alphanumeric() && c != '\'')
-            .filter(|s| !s.is_empty())
-            .map(|s| s.to_string())
-            .collect()
+        self.tokenizer.tokenize(text)
     }
 
     /// Tokenize and filter stop words.
+    ///
+    /// A token is dropped if it's a literal stop word, or if its stem (see
+    /// [`stem`]) matches the stem of a stop word — this catches inflected
+    /// forms that aren't listed themselves (e.g. "others", stemmed to "other").
     pub fn tokenize_filtered(&self, text: &str) -> Vec<String> {
+        let stemmed_stop_words: HashSet<String> =
+            self.stop_words.iter().map(|w| stem(w)).collect();
+
         self.tokenize(text)
             .into_iter()
-            .filter(|t| !self.stop_words.contains(&t.to_lowercase()))
+            .filter(|t| {
+                !self.stop_words.contains(&t.to_lowercase())
+                    && !stemmed_stop_words.contains(&stem(t))
+            })
+            .collect()
+    }
+
+    /// Tokenize, filter stop words, and reduce each remaining token to its
+    /// stem (see [`stem`]), so morphological variants of the same word
+    /// (e.g. "arrives"/"arriving") collapse to a single term for frequency
+    /// counting.
+    pub fn tokenize_stemmed(&self, text: &str) -> Vec<String> {
+        self.tokenize_filtered(text)
+            .into_iter()
+            .map(|t| stem(&t))
             .collect()
     }
 
@@ -142,7 +974,7 @@ impl TextAnalyzer {
     }
 
     fn extract_persons(&self, text: &str, entities: &mut Vec<Entity>) {
-        for cap in TITLE_PATTERN.captures_iter(text) {
+        for cap in self.title_pattern.captures_iter(text) {
             let full_match = cap.get(0).unwrap();
             let name = cap.get(2).unwrap().as_str();
 
@@ -154,7 +986,7 @@ impl TextAnalyzer {
     }
 
     fn extract_organizations(&self, text: &str, entities: &mut Vec<Entity>) {
-        for cap in ORG_PATTERN.captures_iter(text) {
+        for cap in self.org_pattern.captures_iter(text) {
             let full_match = cap.get(0).unwrap();
 
             entities.push(
@@ -193,57 +1025,28 @@ impl TextAnalyzer {
             "UNESCO",
         ];
 
-        for org in common_orgs {
-            let org_lower = org.to_lowercase();
-            let text_lower = text.to_lowercase();
-
-            let mut start = 0;
-            while let Some(pos) = text_lower[start..].find(&org_lower) {
-                let abs_pos = start + pos;
-                let end_pos = abs_pos + org.len();
-
-                // Check word boundaries
-                let valid_start =
-                    abs_pos == 0 || !text.chars().nth(abs_pos - 1).unwrap().is_alphanumeric();
-                let valid_end = end_pos >= text.len()
-                    || !text.chars().nth(end_pos).unwrap().is_alphanumeric();
-
-                if valid_start && valid_end {
-                    let overlaps = entities
-                        .iter()
-                        .any(|e| !(end_pos <= e.start || abs_pos >= e.end));
-
-                    if !overlaps {
-                        entities.push(
-                            Entity::new(
-                                &text[abs_pos..end_pos],
-                                EntityType::Organization,
-                                abs_pos,
-                                end_pos,
-                            )
-                            .with_confidence(0.95),
-                        );
-                    }
-                }
-
-                start = abs_pos + 1;
-            }
-        }
+        self.gazetteer_match(text, &common_orgs, EntityType::Organization, 0.95, entities);
     }
 
     fn extract_dates(&self, text: &str, entities: &mut Vec<Entity>) {
         for cap in DATE_PATTERN.captures_iter(text) {
             let full_match = cap.get(0).unwrap();
 
-            entities.push(
-                Entity::new(
-                    full_match.as_str(),
-                    EntityType::DateTime,
-                    full_match.start(),
-                    full_match.end(),
-                )
-                .with_confidence(0.95),
-            );
+            let mut entity = Entity::new(
+                full_match.as_str(),
+                EntityType::DateTime,
+                full_match.start(),
+                full_match.end(),
+            )
+            .with_confidence(0.95);
+
+            if let Some(date) =
+                parse_date_components(full_match.as_str(), self.two_digit_year_pivot)
+            {
+                entity = entity.with_date(date);
+            }
+
+            entities.push(entity);
         }
     }
 
@@ -263,47 +1066,170 @@ impl TextAnalyzer {
         }
     }
 
+    /// Run a [`RuleKind::Custom`] regex rule, taking the entity text from
+    /// `group`. Every match is pushed; overlaps with other rules' entities
+    /// are resolved afterwards by [`Self::entities`].
+    fn extract_custom(
+        text: &str,
+        regex: &Regex,
+        group: usize,
+        entity_type: EntityType,
+        confidence: f64,
+        entities: &mut Vec<Entity>,
+    ) {
+        for cap in regex.captures_iter(text) {
+            let Some(m) = cap.get(group) else { continue };
+            entities.push(
+                Entity::new(m.as_str(), entity_type.clone(), m.start(), m.end())
+                    .with_confidence(confidence),
+            );
+        }
+    }
+
     fn extract_locations(&self, text: &str, entities: &mut Vec<Entity>) {
-        let text_lower = text.to_lowercase();
-
-        // Sort locations by length (longest first)
-        let mut locations: Vec<_> = self.known_locations.iter().collect();
-        locations.sort_by_key(|b| std::cmp::Reverse(b.len()));
-
-        for location in locations {
-            let loc_lower = location.to_lowercase();
-
-            let mut start = 0;
-            while let Some(pos) = text_lower[start..].find(&loc_lower) {
-                let abs_pos = start + pos;
-                let end_pos = abs_pos + location.len();
-
-                // Check word boundaries
-                let valid_start =
-                    abs_pos == 0 || !text.chars().nth(abs_pos - 1).unwrap().is_alphanumeric();
-                let valid_end = end_pos >= text.len()
-                    || !text.chars().nth(end_pos).unwrap().is_alphanumeric();
-
-                if valid_start && valid_end {
-                    let overlaps = entities
-                        .iter()
-                        .any(|e| !(end_pos <= e.start || abs_pos >= e.end));
-
-                    if !overlaps {
-                        entities.push(
-                            Entity::new(
-                                &text[abs_pos..end_pos],
-                                EntityType::Location,
-                                abs_pos,
-                                end_pos,
-                            )
-                            .with_confidence(0.85),
-                        );
-                    }
+        let locations: Vec<&str> = self.known_locations.iter().map(String::as_str).collect();
+        self.gazetteer_match(text, &locations, EntityType::Location, 0.85, entities);
+    }
+
+    /// Match a list of gazetteer entries (locations, common organization
+    /// names, …) against `text`, longest entry first so the entity covers the
+    /// longest plausible name. Falls back to exact case-folded substring
+    /// matching unless [`Self::set_fuzzy_matching`] is enabled, in which case
+    /// entries needing a nonzero edit distance are matched via a bounded
+    /// [`LevenshteinAutomaton`] over candidate token spans; zero-distance
+    /// entries (4 characters or fewer) still take the exact fast path.
+    fn gazetteer_match(
+        &self,
+        text: &str,
+        candidates: &[&str],
+        entity_type: EntityType,
+        exact_confidence: f64,
+        entities: &mut Vec<Entity>,
+    ) {
+        let mut sorted: Vec<&str> = candidates.to_vec();
+        sorted.sort_by_key(|c| std::cmp::Reverse(c.chars().count()));
+
+        let word_spans = self.fuzzy_matching.then(|| word_spans(text));
+
+        for candidate in sorted {
+            let max_distance = max_edit_distance(candidate.chars().count());
+            if !self.fuzzy_matching || max_distance == 0 {
+                let confidence = if self.fuzzy_matching { 0.95 } else { exact_confidence };
+                Self::match_exact(text, candidate, entity_type.clone(), confidence, entities);
+            } else {
+                Self::match_fuzzy(
+                    text,
+                    word_spans.as_deref().unwrap_or_default(),
+                    candidate,
+                    max_distance,
+                    entity_type.clone(),
+                    entities,
+                );
+            }
+        }
+    }
+
+    fn match_exact(
+        text: &str,
+        candidate: &str,
+        entity_type: EntityType,
+        confidence: f64,
+        entities: &mut Vec<Entity>,
+    ) {
+        let candidate_lower = candidate.to_lowercase();
+        // `str::to_lowercase` isn't byte-length-preserving for every input
+        // (e.g. `İ` U+0130 expands to the 3-byte `i` + combining dot above),
+        // so match offsets from the lowercased copy can't be used to slice
+        // `text` directly. `lower_with_offsets` tracks, for every byte of the
+        // lowered copy, the byte position in `text` where its source
+        // character starts, so offsets can be translated back.
+        let (text_lower, offsets) = lower_with_offsets(text);
+
+        let mut start = 0;
+        while let Some(pos) = text_lower[start..].find(&candidate_lower) {
+            let lower_start = start + pos;
+            let lower_end = lower_start + candidate_lower.len();
+            let abs_pos = offsets[lower_start];
+            let end_pos = if lower_end >= text_lower.len() {
+                text.len()
+            } else {
+                offsets[lower_end]
+            };
+
+            // Check word boundaries
+            let valid_start = abs_pos == 0
+                || !text[..abs_pos]
+                    .chars()
+                    .next_back()
+                    .is_some_and(|c| c.is_alphanumeric());
+            let valid_end = end_pos >= text.len()
+                || !text[end_pos..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_alphanumeric());
+
+            if valid_start && valid_end {
+                let overlaps = entities
+                    .iter()
+                    .any(|e| !(end_pos <= e.start || abs_pos >= e.end));
+
+                if !overlaps {
+                    entities.push(
+                        Entity::new(&text[abs_pos..end_pos], entity_type.clone(), abs_pos, end_pos)
+                            .with_confidence(confidence),
+                    );
                 }
+            }
+
+            start = lower_start + 1;
+        }
+    }
 
-                start = abs_pos + 1;
+    /// Slide entry-sized windows of consecutive word spans across `words`,
+    /// accepting a window whose char length is within `max_distance` of
+    /// `candidate`'s and whose [`LevenshteinAutomaton`] distance to
+    /// `candidate` is at most `max_distance`.
+    fn match_fuzzy(
+        text: &str,
+        words: &[(usize, usize)],
+        candidate: &str,
+        max_distance: usize,
+        entity_type: EntityType,
+        entities: &mut Vec<Entity>,
+    ) {
+        let candidate_chars: Vec<char> = candidate.chars().collect();
+        let window_size = candidate.split_whitespace().count().max(1);
+        if words.len() < window_size {
+            return;
+        }
+
+        for window in words.windows(window_size) {
+            let span_start = window[0].0;
+            let span_end = window[window_size - 1].1;
+            let span = &text[span_start..span_end];
+            let span_chars: Vec<char> = span.chars().collect();
+
+            if span_chars.len().abs_diff(candidate_chars.len()) > max_distance {
+                continue;
             }
+
+            let Some(distance) =
+                bounded_levenshtein(&candidate_chars, &span_chars, max_distance)
+            else {
+                continue;
+            };
+
+            let overlaps = entities
+                .iter()
+                .any(|e| !(span_end <= e.start || span_start >= e.end));
+            if overlaps {
+                continue;
+            }
+
+            entities.push(
+                Entity::new(span, entity_type.clone(), span_start, span_end)
+                    .with_confidence(0.95 - 0.15 * distance as f64),
+            );
         }
     }
 
@@ -556,6 +1482,141 @@ mod tests {
         assert!(!dates.is_empty());
     }
 
+    #[test]
+    fn test_date_parses_named_month_then_day() {
+        let analyzer = TextAnalyzer::new();
+        let entities = analyzer.entities("The summit opened January 15, 2024.");
+
+        let date = entities
+            .iter()
+            .find(|e| e.entity_type == EntityType::DateTime)
+            .unwrap();
+        assert_eq!(
+            date.date,
+            Some(DateComponents {
+                year: 2024,
+                month: 1,
+                day: 15
+            })
+        );
+    }
+
+    #[test]
+    fn test_date_parses_day_then_named_month() {
+        let analyzer = TextAnalyzer::new();
+        let entities = analyzer.entities("The treaty was signed 15 January 2024.");
+
+        let date = entities
+            .iter()
+            .find(|e| e.entity_type == EntityType::DateTime)
+            .unwrap();
+        assert_eq!(
+            date.date,
+            Some(DateComponents {
+                year: 2024,
+                month: 1,
+                day: 15
+            })
+        );
+    }
+
+    #[test]
+    fn test_date_parses_iso_numeric_order() {
+        let analyzer = TextAnalyzer::new();
+        let entities = analyzer.entities("Logged at 2023-12-01 by the field office.");
+
+        let date = entities
+            .iter()
+            .find(|e| e.entity_type == EntityType::DateTime)
+            .unwrap();
+        assert_eq!(
+            date.date,
+            Some(DateComponents {
+                year: 2023,
+                month: 12,
+                day: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_date_two_digit_year_expands_with_pivot() {
+        let mut analyzer = TextAnalyzer::new();
+        analyzer.set_two_digit_year_pivot(70);
+        let entities = analyzer.entities("Filed on 5/3/24 and again on 5/3/95.");
+
+        let dates: Vec<_> = entities
+            .iter()
+            .filter(|e| e.entity_type == EntityType::DateTime)
+            .collect();
+
+        assert_eq!(dates[0].date.unwrap().year, 2024);
+        assert_eq!(dates[1].date.unwrap().year, 1995);
+    }
+
+    #[test]
+    fn test_date_out_of_range_day_left_unparsed() {
+        let analyzer = TextAnalyzer::new();
+        let entities = analyzer.entities("Reported on 45 January 2024.");
+
+        let date = entities
+            .iter()
+            .find(|e| e.entity_type == EntityType::DateTime)
+            .expect("regex still matches the malformed day");
+        assert_eq!(date.date, None);
+    }
+
+    #[test]
+    fn test_resolve_country_collapses_aliases() {
+        let analyzer = TextAnalyzer::new();
+        let entities = analyzer.entities("Reports came from USA, America, and United States.");
+
+        let resolutions: Vec<_> = entities
+            .iter()
+            .filter(|e| e.entity_type == EntityType::Location)
+            .filter_map(TextAnalyzer::resolve_country)
+            .collect();
+
+        assert_eq!(resolutions.len(), 3);
+        assert!(resolutions
+            .iter()
+            .all(|r| r.canonical == "United States" && r.iso_code == "US" && r.flag == "🇺🇸"));
+    }
+
+    #[test]
+    fn test_resolve_country_returns_none_for_city() {
+        let analyzer = TextAnalyzer::new();
+        let entities = analyzer.entities("The meeting was held in Berlin.");
+
+        let berlin = entities
+            .iter()
+            .find(|e| e.text == "Berlin")
+            .expect("Berlin recognized as a location");
+        assert_eq!(TextAnalyzer::resolve_country(berlin), None);
+    }
+
+    #[test]
+    fn test_entities_attach_country_only_when_enabled() {
+        let mut analyzer = TextAnalyzer::new();
+        let text = "Diplomats from the UK met in Geneva.";
+
+        let before = analyzer.entities(text);
+        assert!(before
+            .iter()
+            .find(|e| e.text == "UK")
+            .unwrap()
+            .country
+            .is_none());
+
+        analyzer.set_resolve_countries(true);
+        let after = analyzer.entities(text);
+        let uk = after.iter().find(|e| e.text == "UK").unwrap();
+        assert_eq!(uk.country.as_ref().unwrap().iso_code, "GB");
+
+        let geneva = after.iter().find(|e| e.text == "Geneva").unwrap();
+        assert!(geneva.country.is_none());
+    }
+
     #[test]
     fn test_entity_extraction_numerics() {
         let analyzer = TextAnalyzer::new();
@@ -582,6 +1643,20 @@ mod tests {
         assert!(tokens.contains(&"test".to_string()));
     }
 
+    #[test]
+    fn test_set_tokenizer_segments_scripts_without_whitespace() {
+        use super::super::DictionaryTokenizer;
+
+        let mut analyzer = TextAnalyzer::new();
+        analyzer.set_tokenizer(Box::new(DictionaryTokenizer::new(["東京", "大阪", "訪問"])));
+
+        let tokens = analyzer.tokenize("東京訪問大阪");
+        assert_eq!(
+            tokens,
+            vec!["東京".to_string(), "訪問".to_string(), "大阪".to_string()]
+        );
+    }
+
     #[test]
     fn test_tokenization_filtered() {
         let analyzer = TextAnalyzer::new();
@@ -596,6 +1671,43 @@ mod tests {
         assert!(tokens.iter().any(|t| t.to_lowercase() == "brown"));
     }
 
+    #[test]
+    fn test_stem_strips_r1_suffixes() {
+        assert_eq!(stem("jumps"), "jump");
+        assert_eq!(stem("jumping"), "jump");
+        assert_eq!(stem("happiness"), "happi");
+        assert_eq!(stem("quickly"), "quick");
+    }
+
+    #[test]
+    fn test_stem_strips_r2_suffixes() {
+        assert_eq!(stem("organization"), "organiza");
+        assert_eq!(stem("enjoyment"), "enjoy");
+    }
+
+    #[test]
+    fn test_stem_requires_suffix_entirely_within_region() {
+        // "ous" is a real suffix of "famous", but R2 is empty for this
+        // word, so it's left untouched rather than over-stemmed.
+        assert_eq!(stem("famous"), "famous");
+    }
+
+    #[test]
+    fn test_stem_leaves_short_tokens_untouched() {
+        assert_eq!(stem("is"), "is");
+        assert_eq!(stem("dog"), "dog");
+        assert_eq!(stem("as"), "as");
+    }
+
+    #[test]
+    fn test_tokenize_stemmed_collapses_morphological_variants() {
+        let analyzer = TextAnalyzer::new();
+        let tokens = analyzer.tokenize_stemmed("The reporters arrived. Reporters were arriving.");
+
+        assert_eq!(tokens.iter().filter(|t| t.as_str() == "arriv").count(), 2);
+        assert_eq!(tokens.iter().filter(|t| t.as_str() == "reporter").count(), 2);
+    }
+
     #[test]
     fn test_sentences() {
         let analyzer = TextAnalyzer::new();
@@ -609,6 +1721,152 @@ mod tests {
         assert_eq!(sentences[2], "Third sentence");
     }
 
+    #[test]
+    fn test_fuzzy_matching_disabled_by_default() {
+        let analyzer = TextAnalyzer::new();
+        let entities = analyzer.entities("The summit was held in Genva this year.");
+        assert!(!entities.iter().any(|e| e.entity_type == EntityType::Location));
+    }
+
+    #[test]
+    fn test_fuzzy_matching_finds_misspelled_location() {
+        let mut analyzer = TextAnalyzer::new();
+        analyzer.set_fuzzy_matching(true);
+
+        let entities = analyzer.entities("The summit was held in Genva this year.");
+        let location = entities
+            .iter()
+            .find(|e| e.entity_type == EntityType::Location)
+            .expect("fuzzy match for Genva");
+
+        assert_eq!(location.text, "Genva");
+        assert!((location.confidence - 0.80).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fuzzy_matching_finds_misspelled_organization() {
+        let mut analyzer = TextAnalyzer::new();
+        analyzer.set_fuzzy_matching(true);
+
+        let entities = analyzer.entities("The Untied Nations convened an emergency session.");
+        let org = entities
+            .iter()
+            .find(|e| e.entity_type == EntityType::Organization && e.text == "Untied Nations")
+            .expect("fuzzy match for Untied Nations");
+
+        // "Untied" is a 2-edit transposition of "United" under plain
+        // Levenshtein distance (no dedicated swap operation).
+        assert!((org.confidence - 0.65).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fuzzy_matching_exact_hit_still_highest_confidence() {
+        let mut analyzer = TextAnalyzer::new();
+        analyzer.set_fuzzy_matching(true);
+
+        let entities = analyzer.entities("Delegates met in Berlin.");
+        let location = entities
+            .iter()
+            .find(|e| e.entity_type == EntityType::Location)
+            .unwrap();
+
+        assert_eq!(location.text, "Berlin");
+        assert!((location.confidence - 0.95).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_entity_extraction_person_cyrillic_script() {
+        let analyzer = TextAnalyzer::new();
+        let text = "Президент Путин встретился с делегацией в Москве.";
+
+        let entities = analyzer.entities(text);
+
+        assert!(entities
+            .iter()
+            .any(|e| e.entity_type == EntityType::Person && e.text.contains("Путин")));
+    }
+
+    #[test]
+    fn test_entity_extraction_organization_greek_script() {
+        let analyzer = TextAnalyzer::new();
+        let text = "Η Ελληνική Τράπεζα εταιρεία ανακοίνωσε νέα μέτρα.";
+
+        let entities = analyzer.entities(text);
+
+        assert!(entities
+            .iter()
+            .any(|e| e.entity_type == EntityType::Organization));
+    }
+
+    #[test]
+    fn test_add_title_word_enables_custom_honorific() {
+        let mut analyzer = TextAnalyzer::new();
+        analyzer.add_title_word("Комиссар");
+
+        let text = "Комиссар Иванов прибыл на место происшествия.";
+        let entities = analyzer.entities(text);
+
+        assert!(entities
+            .iter()
+            .any(|e| e.entity_type == EntityType::Person && e.text.contains("Иванов")));
+    }
+
+    #[test]
+    fn test_disable_rule_drops_its_entities() {
+        let mut analyzer = TextAnalyzer::new();
+        analyzer.disable_rule("numeric");
+
+        let text = "The earthquake killed 50 people and injured 200.";
+        let entities = analyzer.entities(text);
+
+        assert!(!entities.iter().any(|e| e.entity_type == EntityType::Numeric));
+    }
+
+    #[test]
+    fn test_enable_rule_restores_disabled_extractor() {
+        let mut analyzer = TextAnalyzer::new();
+        analyzer.disable_rule("numeric");
+        analyzer.enable_rule("numeric");
+
+        let text = "The earthquake killed 50 people and injured 200.";
+        let entities = analyzer.entities(text);
+
+        assert!(entities.iter().any(|e| e.entity_type == EntityType::Numeric));
+    }
+
+    #[test]
+    fn test_list_rules_reports_builtin_registry() {
+        let analyzer = TextAnalyzer::new();
+        let ids: Vec<&str> = analyzer.list_rules().iter().map(|r| r.id.as_str()).collect();
+
+        assert_eq!(
+            ids,
+            vec!["person_title", "org_suffix", "date", "numeric", "location"]
+        );
+        assert!(analyzer.list_rules().iter().all(|r| r.enabled));
+    }
+
+    #[test]
+    fn test_add_rule_matches_custom_pattern() {
+        let mut analyzer = TextAnalyzer::new();
+        analyzer.add_rule(
+            "case_number",
+            EntityType::Other,
+            Regex::new(r"\bCase No\. (\d+-\d+)\b").unwrap(),
+            1,
+            0.8,
+        );
+
+        let entities = analyzer.entities("Filed under Case No. 24-1190 this morning.");
+        let case = entities
+            .iter()
+            .find(|e| e.entity_type == EntityType::Other)
+            .expect("custom rule match");
+
+        assert_eq!(case.text, "24-1190");
+        assert!((case.confidence - 0.8).abs() < 1e-9);
+    }
+
     #[test]
     fn test_analyzer_add_location() {
         let mut analyzer = TextAnalyzer::new();
@@ -46,6 +46,14 @@ pub struct Entity {
     pub end: usize,
     /// Confidence score (0.0 to 1.0)
     pub confidence: f64,
+    /// Parsed calendar components, for `DateTime` entities whose matched
+    /// text could be unambiguously resolved. `None` for every other entity
+    /// type, and for dates `extract_dates` couldn't confidently parse.
+    pub date: Option<DateComponents>,
+    /// Canonical country identity, for `Location` entities that name a
+    /// recognized country (any spelling). `None` for every other entity,
+    /// and for locations that aren't a known country alias.
+    pub country: Option<CountryResolution>,
 }
 
 impl Entity {
@@ -57,6 +65,8 @@ impl Entity {
             start,
             end,
             confidence: 1.0,
+            date: None,
+            country: None,
         }
     }
 
@@ -65,6 +75,111 @@ impl Entity {
         self.confidence = confidence.clamp(0.0, 1.0);
         self
     }
+
+    /// Attach parsed calendar components to a `DateTime` entity.
+    pub fn with_date(mut self, date: DateComponents) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Attach a resolved country identity to a `Location` entity.
+    pub fn with_country(mut self, country: CountryResolution) -> Self {
+        self.country = Some(country);
+        self
+    }
+}
+
+/// Canonical identity of a `Location` entity recognized as a country, as
+/// resolved by [`super::TextAnalyzer::resolve_country`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountryResolution {
+    /// The country's canonical name (e.g. "United States" for "USA").
+    pub canonical: String,
+    /// Two-letter ISO 3166-1 alpha-2 country code (e.g. "US").
+    pub iso_code: String,
+    /// Regional-indicator flag emoji derived from `iso_code`.
+    pub flag: String,
+}
+
+/// Calendar date parsed from a `DateTime` entity's matched text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateComponents {
+    /// Four-digit year (two-digit years are expanded via a pivot before
+    /// reaching this point).
+    pub year: i32,
+    /// Month, 1-12.
+    pub month: u32,
+    /// Day of month, 1-31.
+    pub day: u32,
+}
+
+/// A calendar interval between two [`DateComponents`], broken into whole
+/// years/months/days the way a reader would describe a gap between events
+/// (not a fixed-length number of days).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateDuration {
+    /// Whole years between the two dates.
+    pub years: i32,
+    /// Remaining whole months after `years` is subtracted.
+    pub months: i32,
+    /// Remaining whole days after `years` and `months` are subtracted.
+    pub days: i32,
+}
+
+/// Compute the calendar difference between two dates, always measured from
+/// the earlier date to the later one. Returns `None` if either date is not
+/// a valid calendar date (e.g. day 31 in a 30-day month).
+pub fn date_duration(a: DateComponents, b: DateComponents) -> Option<DateDuration> {
+    use chrono::Datelike;
+
+    let da = chrono::NaiveDate::from_ymd_opt(a.year, a.month, a.day)?;
+    let db = chrono::NaiveDate::from_ymd_opt(b.year, b.month, b.day)?;
+    let (start, end) = if da <= db { (da, db) } else { (db, da) };
+
+    let mut total_months =
+        (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32;
+    let mut days = end.day() as i32 - start.day() as i32;
+
+    // If the day-of-month rolled backwards, borrow a month the same way
+    // `java.time.Period` does: step `start` forward by one fewer month and
+    // measure the remaining gap in actual days, so short months (Feb) don't
+    // under-borrow the way a fixed "add 30" would.
+    if total_months > 0 && days < 0 {
+        total_months -= 1;
+        let calc_date = add_months(start, total_months);
+        days = (end - calc_date).num_days() as i32;
+    }
+
+    Some(DateDuration {
+        years: total_months / 12,
+        months: total_months % 12,
+        days,
+    })
+}
+
+/// Advance `date` by `months`, clamping the day into the resulting month
+/// (e.g. Jan 31 + 1 month lands on Feb 28/29, not Mar 3).
+fn add_months(date: chrono::NaiveDate, months: i32) -> chrono::NaiveDate {
+    use chrono::Datelike;
+
+    let total = date.year() * 12 + date.month() as i32 - 1 + months;
+    let year = total.div_euclid(12);
+    let month = (total.rem_euclid(12) + 1) as u32;
+    let day = date.day().min(days_in_month(year, month));
+    chrono::NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// Number of days in `year`-`month`, computed from the gap between the
+/// first of that month and the first of the next one.
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    let first_of_next = chrono::NaiveDate::from_ymd_opt(next_year, next_month, 1).unwrap();
+    let first_of_this = chrono::NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+    (first_of_next - first_of_this).num_days() as u32
 }
 
 #[cfg(test)]
@@ -101,4 +216,118 @@ mod tests {
         let entity = Entity::new("Test", EntityType::Other, 0, 4).with_confidence(-0.5);
         assert_eq!(entity.confidence, 0.0);
     }
+
+    #[test]
+    fn test_entity_date_defaults_to_none() {
+        let entity = Entity::new("Friday", EntityType::DateTime, 0, 6);
+        assert_eq!(entity.date, None);
+    }
+
+    #[test]
+    fn test_entity_with_date() {
+        let components = DateComponents {
+            year: 2024,
+            month: 3,
+            day: 15,
+        };
+        let entity = Entity::new("15 March 2024", EntityType::DateTime, 0, 13).with_date(components);
+        assert_eq!(entity.date, Some(components));
+    }
+
+    #[test]
+    fn test_entity_country_defaults_to_none() {
+        let entity = Entity::new("Berlin", EntityType::Location, 0, 6);
+        assert_eq!(entity.country, None);
+    }
+
+    #[test]
+    fn test_entity_with_country() {
+        let resolution = CountryResolution {
+            canonical: "United States".to_string(),
+            iso_code: "US".to_string(),
+            flag: "🇺🇸".to_string(),
+        };
+        let entity =
+            Entity::new("USA", EntityType::Location, 0, 3).with_country(resolution.clone());
+        assert_eq!(entity.country, Some(resolution));
+    }
+
+    #[test]
+    fn test_date_duration_whole_years() {
+        let start = DateComponents {
+            year: 2020,
+            month: 6,
+            day: 1,
+        };
+        let end = DateComponents {
+            year: 2023,
+            month: 6,
+            day: 1,
+        };
+        let duration = date_duration(start, end).unwrap();
+        assert_eq!(
+            duration,
+            DateDuration {
+                years: 3,
+                months: 0,
+                days: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_date_duration_borrows_across_month_end() {
+        let start = DateComponents {
+            year: 2024,
+            month: 1,
+            day: 31,
+        };
+        let end = DateComponents {
+            year: 2024,
+            month: 3,
+            day: 1,
+        };
+        let duration = date_duration(start, end).unwrap();
+        assert_eq!(
+            duration,
+            DateDuration {
+                years: 0,
+                months: 1,
+                days: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_date_duration_is_order_independent() {
+        let earlier = DateComponents {
+            year: 2021,
+            month: 1,
+            day: 10,
+        };
+        let later = DateComponents {
+            year: 2021,
+            month: 4,
+            day: 20,
+        };
+        assert_eq!(
+            date_duration(earlier, later),
+            date_duration(later, earlier)
+        );
+    }
+
+    #[test]
+    fn test_date_duration_rejects_invalid_calendar_date() {
+        let invalid = DateComponents {
+            year: 2024,
+            month: 2,
+            day: 30,
+        };
+        let valid = DateComponents {
+            year: 2024,
+            month: 3,
+            day: 1,
+        };
+        assert_eq!(date_duration(invalid, valid), None);
+    }
 }
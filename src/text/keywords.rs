@@ -2,7 +2,10 @@
 
 use std::collections::{HashMap, HashSet};
 
+use serde::{Deserialize, Serialize};
+
 use super::analyzer::TextAnalyzer;
+use crate::core::Event;
 
 /// An extracted keyword with relevance information.
 #[derive(Debug, Clone)]
@@ -45,6 +48,7 @@ impl Keyword {
 ///     println!("{}: {:.2}", kw.text, kw.score);
 /// }
 /// ```
+#[derive(Clone)]
 pub struct KeywordExtractor {
     /// Stop words to filter
     stop_words: HashSet<String>,
@@ -79,27 +83,9 @@ impl KeywordExtractor {
     /// Extract top N keywords from text.
     pub fn extract(&self, text: &str, n: usize) -> Vec<Keyword> {
         let mut word_freq: HashMap<String, usize> = HashMap::new();
-
-        // Tokenize and count word frequencies
-        let words: Vec<String> = text
-            .split(|c: char| !c.is_alphanumeric() && c != '\'')
-            .filter(|s| !s.is_empty())
-            .map(|s| s.to_lowercase())
-            .filter(|w| w.len() >= self.min_word_length)
-            .filter(|w| !self.stop_words.contains(w))
-            .collect();
-
-        for word in &words {
-            *word_freq.entry(word.clone()).or_insert(0) += 1;
-        }
-
-        // Extract n-grams (phrases)
-        if self.max_phrase_length > 1 {
-            self.extract_ngrams(text, &mut word_freq);
-        }
+        let total_words = self.collect_terms(text, &mut word_freq) as f64;
 
         // Calculate scores
-        let total_words = words.len() as f64;
         let mut keywords: Vec<Keyword> = word_freq
             .into_iter()
             .map(|(word, freq)| {
@@ -123,6 +109,56 @@ impl KeywordExtractor {
         keywords
     }
 
+    /// Fit document-frequency statistics over a corpus of events, enabling
+    /// IDF-weighted scoring via [`FittedExtractor::extract_tfidf`].
+    ///
+    /// For each term/n-gram this extractor's tokenization produces, `df`
+    /// counts the number of events whose text contains it at least once.
+    pub fn fit(&self, events: &[Event]) -> FittedExtractor {
+        let mut df: HashMap<String, usize> = HashMap::new();
+
+        for event in events {
+            let mut terms: HashMap<String, usize> = HashMap::new();
+            self.collect_terms(&event.text, &mut terms);
+            for term in terms.into_keys() {
+                *df.entry(term).or_insert(0) += 1;
+            }
+        }
+
+        FittedExtractor {
+            extractor: self.clone(),
+            frequencies: DocumentFrequencies {
+                df,
+                doc_count: events.len(),
+            },
+        }
+    }
+
+    /// Tokenize `text` into single words and (if `max_phrase_length > 1`)
+    /// n-gram phrases, accumulating per-term frequencies into `term_freq`.
+    /// Returns the number of single-word tokens, the shared `tf` denominator
+    /// used by both [`extract`](Self::extract) and
+    /// [`FittedExtractor::extract_tfidf`].
+    fn collect_terms(&self, text: &str, term_freq: &mut HashMap<String, usize>) -> usize {
+        let words: Vec<String> = text
+            .split(|c: char| !c.is_alphanumeric() && c != '\'')
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_lowercase())
+            .filter(|w| w.len() >= self.min_word_length)
+            .filter(|w| !self.stop_words.contains(w))
+            .collect();
+
+        for word in &words {
+            *term_freq.entry(word.clone()).or_insert(0) += 1;
+        }
+
+        if self.max_phrase_length > 1 {
+            self.extract_ngrams(text, term_freq);
+        }
+
+        words.len()
+    }
+
     /// Extract keywords with custom stop words.
     pub fn extract_with_stopwords(
         &self,
@@ -179,6 +215,74 @@ impl Default for KeywordExtractor {
     }
 }
 
+/// Document-frequency statistics fit over a corpus by [`KeywordExtractor::fit`].
+///
+/// Plain data so callers can serialize it and reuse it across runs instead
+/// of re-scanning the original corpus every time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentFrequencies {
+    /// Per-term document frequency: number of events whose text contains
+    /// the term at least once.
+    pub df: HashMap<String, usize>,
+    /// Total number of documents (events) the table was fit over.
+    pub doc_count: usize,
+}
+
+/// A [`KeywordExtractor`] paired with [`DocumentFrequencies`] fit over a
+/// corpus, enabling IDF-weighted scoring via
+/// [`extract_tfidf`](Self::extract_tfidf) instead of flat term frequency.
+pub struct FittedExtractor {
+    extractor: KeywordExtractor,
+    frequencies: DocumentFrequencies,
+}
+
+impl FittedExtractor {
+    /// Rebuild a fitted extractor from a previously serialized
+    /// [`DocumentFrequencies`] table, avoiding a re-fit over the original
+    /// corpus.
+    pub fn from_frequencies(extractor: KeywordExtractor, frequencies: DocumentFrequencies) -> Self {
+        Self {
+            extractor,
+            frequencies,
+        }
+    }
+
+    /// The fitted document-frequency table, for serialization or inspection.
+    pub fn frequencies(&self) -> &DocumentFrequencies {
+        &self.frequencies
+    }
+
+    /// Extract top N keywords from `text`, scoring each term as
+    /// `tf * (ln((N + 1) / (df(t) + 1)) + 1)` rather than flat term
+    /// frequency, so terms common across the fitted corpus (not just the
+    /// fixed stop-word list) are down-weighted and event-distinctive terms
+    /// rise. The same phrase and length boosts as
+    /// [`KeywordExtractor::extract`] still apply.
+    pub fn extract_tfidf(&self, text: &str, n: usize) -> Vec<Keyword> {
+        let mut word_freq: HashMap<String, usize> = HashMap::new();
+        let total_words = self.extractor.collect_terms(text, &mut word_freq) as f64;
+        let doc_count = self.frequencies.doc_count as f64;
+
+        let mut keywords: Vec<Keyword> = word_freq
+            .into_iter()
+            .map(|(word, freq)| {
+                let tf = freq as f64 / total_words;
+                let df = *self.frequencies.df.get(&word).unwrap_or(&0) as f64;
+                let idf = ((doc_count + 1.0) / (df + 1.0)).ln() + 1.0;
+                let length_boost = 1.0 + (word.len() as f64 / 20.0);
+                let phrase_boost = if word.contains(' ') { 1.5 } else { 1.0 };
+                let score = tf * idf * length_boost * phrase_boost;
+
+                Keyword::new(word, score, freq)
+            })
+            .collect();
+
+        keywords.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        keywords.truncate(n);
+        keywords
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,4 +367,90 @@ mod tests {
         // "forest" (6 chars) should remain if not a stop word
         assert!(keywords.iter().any(|k| k.text == "forest"));
     }
+
+    fn event(text: &str) -> Event {
+        use crate::core::{Location, Timestamp};
+        Event::builder()
+            .location(Location::new(0.0, 0.0))
+            .timestamp(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+            .text(text)
+            .build()
+    }
+
+    #[test]
+    fn test_fit_computes_document_frequency() {
+        let events = vec![
+            event("a wildfire spread across the hillside"),
+            event("a wildfire threatened several homes"),
+            event("the city council approved a new budget"),
+        ];
+
+        let fitted = KeywordExtractor::new().fit(&events);
+
+        assert_eq!(fitted.frequencies().doc_count, 3);
+        // "wildfire" appears in 2 of the 3 events
+        assert_eq!(fitted.frequencies().df.get("wildfire"), Some(&2));
+        // "budget" appears in only 1
+        assert_eq!(fitted.frequencies().df.get("budget"), Some(&1));
+    }
+
+    #[test]
+    fn test_extract_tfidf_downweights_corpus_common_terms() {
+        let events = vec![
+            event("the wildfire spread across the hillside near town"),
+            event("the wildfire threatened several homes near town"),
+            event("the wildfire forced evacuations near town"),
+        ];
+
+        let fitted = KeywordExtractor::new().fit(&events);
+        let keywords = fitted.extract_tfidf(
+            "the wildfire destroyed the historic courthouse near town",
+            10,
+        );
+
+        let wildfire = keywords.iter().find(|k| k.text == "wildfire").unwrap();
+        let courthouse = keywords.iter().find(|k| k.text == "courthouse").unwrap();
+
+        // "wildfire" and "near"/"town" appear in every fitted document, so
+        // they should score lower than "courthouse", which is distinctive
+        // to this event.
+        assert!(courthouse.score > wildfire.score);
+    }
+
+    #[test]
+    fn test_extract_tfidf_unseen_term_gets_full_idf_weight() {
+        let events = vec![
+            event("routine council meeting"),
+            event("routine budget review"),
+        ];
+        let fitted = KeywordExtractor::new().fit(&events);
+
+        // "earthquake" never appeared in the fitted corpus, so df(t) = 0 and
+        // it should score exactly tf * length_boost * (((N + 1) / 1).ln() + 1).
+        let text = "a massive earthquake struck the region";
+        let keywords = fitted.extract_tfidf(text, 10);
+        let earthquake = keywords.iter().find(|k| k.text == "earthquake").unwrap();
+
+        let doc_count = fitted.frequencies().doc_count as f64;
+        let expected_idf = ((doc_count + 1.0) / 1.0).ln() + 1.0;
+        let expected_length_boost = 1.0 + ("earthquake".len() as f64 / 20.0);
+        let expected_tf = 1.0 / 4.0; // "massive", "earthquake", "struck", "region" survive filtering
+        let expected_score = expected_tf * expected_idf * expected_length_boost;
+
+        assert!((earthquake.score - expected_score).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_from_frequencies_roundtrip() {
+        let events = vec![
+            event("storm warning issued"),
+            event("storm damage reported"),
+        ];
+        let fitted = KeywordExtractor::new().fit(&events);
+        let frequencies = fitted.frequencies().clone();
+
+        let rebuilt = FittedExtractor::from_frequencies(KeywordExtractor::new(), frequencies);
+        assert_eq!(rebuilt.frequencies().doc_count, 2);
+        assert_eq!(rebuilt.frequencies().df.get("storm"), Some(&2));
+    }
 }
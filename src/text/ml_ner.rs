@@ -60,8 +60,13 @@
 use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Mutex;
 
+use ort::execution_providers::{
+    CPUExecutionProvider, CUDAExecutionProvider, CoreMLExecutionProvider,
+    DirectMLExecutionProvider, ExecutionProviderDispatch, TensorRTExecutionProvider,
+};
 use ort::session::builder::GraphOptimizationLevel;
 use ort::session::Session;
 use ort::value::Tensor;
@@ -73,6 +78,251 @@ use crate::error::Error;
 /// Result type for ML NER operations.
 pub type MlNerResult<T> = Result<T, Error>;
 
+/// Hardware backend an [`MlNerModel`] runs inference on.
+///
+/// Passed to the `_with_device` loader variants, this is translated into an
+/// ordered list of ONNX Runtime execution providers with [`Device::Cpu`]
+/// always appended last as a fallback, so a build of ONNX Runtime missing a
+/// requested GPU provider degrades gracefully instead of erroring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Device {
+    /// CPU-only inference.
+    Cpu,
+    /// NVIDIA CUDA, pinned to the given device id.
+    Cuda(i32),
+    /// NVIDIA TensorRT.
+    TensorRt,
+    /// Apple CoreML (macOS/iOS).
+    CoreMl,
+    /// Microsoft DirectML (Windows).
+    DirectMl,
+}
+
+impl Default for Device {
+    fn default() -> Self {
+        Self::Cpu
+    }
+}
+
+impl Device {
+    /// Builds the ordered execution-provider list for this device, with the
+    /// CPU provider always appended last as a fallback.
+    fn execution_providers(self) -> Vec<ExecutionProviderDispatch> {
+        let mut providers = Vec::new();
+        match self {
+            Self::Cpu => {},
+            Self::Cuda(device_id) => {
+                providers.push(CUDAExecutionProvider::default().with_device_id(device_id).build());
+            },
+            Self::TensorRt => providers.push(TensorRTExecutionProvider::default().build()),
+            Self::CoreMl => providers.push(CoreMLExecutionProvider::default().build()),
+            Self::DirectMl => providers.push(DirectMLExecutionProvider::default().build()),
+        }
+        providers.push(CPUExecutionProvider::default().build());
+        providers
+    }
+}
+
+/// Strategy for merging WordPiece subtokens into whole-entity spans,
+/// mirroring HuggingFace's token-classification aggregation modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregationStrategy {
+    /// No merging: emit one `MlEntity` per raw BIO-tagged token.
+    None,
+    /// Merge contiguous tokens sharing a BIO entity type, scoring the span
+    /// with the mean subtoken probability. Does not glue WordPiece
+    /// continuations that carry a different predicted label.
+    Simple,
+    /// Merge like the scored strategies (gluing WordPiece continuations
+    /// regardless of label), scoring the span with its first subtoken's
+    /// probability.
+    First,
+    /// Merge like the scored strategies, scoring the span with the mean
+    /// subtoken probability.
+    Average,
+    /// Merge like the scored strategies, scoring the span with its highest
+    /// subtoken probability.
+    Max,
+    /// Merge like the scored strategies, scoring the span with its lowest
+    /// subtoken probability — the most conservative estimate of confidence
+    /// for a multi-token entity.
+    Min,
+}
+
+impl Default for AggregationStrategy {
+    fn default() -> Self {
+        Self::Simple
+    }
+}
+
+/// How [`MlNerModel::decode_predictions`] turns per-token logits into a
+/// label sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeMode {
+    /// Pick each token's single most probable label independently
+    /// (`softmax_argmax`). Fast, but can produce illegal tag sequences
+    /// (e.g. an `I-LOC` following an `O`).
+    Greedy,
+    /// Find the most probable *valid* label sequence with a
+    /// transition-constrained Viterbi decoder, forbidding tag transitions
+    /// that BIO/IOBES disallow (e.g. `O` → `I-X`, or `B-X`/`I-X` → `I-Y`
+    /// for `X != Y`). Slower, but never fragments or mistypes a span the
+    /// way greedy decoding's per-token argmax can.
+    Viterbi,
+}
+
+impl Default for DecodeMode {
+    fn default() -> Self {
+        Self::Greedy
+    }
+}
+
+/// Builds the `num_labels x num_labels` transition score matrix used by
+/// Viterbi decoding, over `id2label`'s ids sorted ascending (which is also
+/// their position in the model's per-token logits). Legal transitions score
+/// `0.0`; illegal ones score [`f32::NEG_INFINITY`].
+fn build_transition_matrix(id2label: &HashMap<i64, String>) -> (Vec<i64>, Vec<f32>) {
+    let mut ids: Vec<i64> = id2label.keys().copied().collect();
+    ids.sort_unstable();
+    let n = ids.len();
+
+    let mut transitions = vec![0.0f32; n * n];
+    for (i, prev_id) in ids.iter().enumerate() {
+        let prev_label = id2label.get(prev_id).map(String::as_str).unwrap_or("O");
+        for (j, next_id) in ids.iter().enumerate() {
+            let next_label = id2label.get(next_id).map(String::as_str).unwrap_or("O");
+            if !is_legal_transition(prev_label, next_label) {
+                transitions[i * n + j] = f32::NEG_INFINITY;
+            }
+        }
+    }
+    (ids, transitions)
+}
+
+/// Whether `next_label` may legally follow `prev_label`: `O` and `B-`/`S-`
+/// labels are always reachable; `I-`/`E-` labels require an open span of
+/// the same entity type immediately before them (`B-`/`I-` of that type).
+fn is_legal_transition(prev_label: &str, next_label: &str) -> bool {
+    let Some((next_prefix, next_type)) = parse_tag(next_label) else {
+        return true;
+    };
+    match next_prefix {
+        TagPrefix::Begin | TagPrefix::Single => true,
+        TagPrefix::Inside | TagPrefix::End => match parse_tag(prev_label) {
+            Some((TagPrefix::Begin | TagPrefix::Inside, prev_type)) => prev_type == next_type,
+            _ => false,
+        },
+    }
+}
+
+/// Token-tagging scheme a model's `id2label` mapping follows, detected once
+/// at load time from its label prefixes.
+///
+/// Plain BIO labels (`B-`/`I-`/`O`) are the common case for CoNLL-2003-style
+/// models. IOBES (also called BILOU) additionally uses `S-TYPE` for
+/// single-token entities and `E-TYPE` for the last token of a span, which
+/// [`MlNerModel::decode_predictions`] recognizes regardless of which scheme
+/// is detected — this field exists so callers can introspect what a loaded
+/// model actually emits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaggingScheme {
+    /// `B-`/`I-`/`O` labels only.
+    Bio,
+    /// `B-`/`I-`/`O` plus `S-` (single-token) and `E-` (span-final) labels.
+    Iobes,
+}
+
+/// Inspects `id2label`'s label strings for `S-`/`E-` prefixes to detect
+/// whether a model follows the plain BIO scheme or the richer IOBES/BILOU
+/// scheme.
+fn detect_tagging_scheme(id2label: &HashMap<i64, String>) -> TaggingScheme {
+    let has_iobes = id2label
+        .values()
+        .any(|label| label.starts_with("S-") || label.starts_with("E-"));
+    if has_iobes {
+        TaggingScheme::Iobes
+    } else {
+        TaggingScheme::Bio
+    }
+}
+
+/// The BIO/IOBES prefix of a non-`O` label, with its entity type stripped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TagPrefix {
+    /// `B-TYPE`: starts a new span.
+    Begin,
+    /// `I-TYPE`: continues an open span.
+    Inside,
+    /// `E-TYPE`: the last token of a span.
+    End,
+    /// `S-TYPE`: a complete, single-token span.
+    Single,
+}
+
+/// Splits a predicted label into its tag prefix and entity type, returning
+/// `None` for `"O"` or any unrecognized label.
+fn parse_tag(label: &str) -> Option<(TagPrefix, &str)> {
+    if let Some(rest) = label.strip_prefix("B-") {
+        Some((TagPrefix::Begin, rest))
+    } else if let Some(rest) = label.strip_prefix("I-") {
+        Some((TagPrefix::Inside, rest))
+    } else if let Some(rest) = label.strip_prefix("E-") {
+        Some((TagPrefix::End, rest))
+    } else if let Some(rest) = label.strip_prefix("S-") {
+        Some((TagPrefix::Single, rest))
+    } else {
+        None
+    }
+}
+
+/// Source a model and its tokenizer/config are loaded from.
+///
+/// `LocalPath` mirrors [`MlNerModel::from_directory`]; `InMemory` lets
+/// callers who already hold the weights as a contiguous buffer (e.g.
+/// `include_bytes!`, or downloaded straight into RAM) construct a model
+/// without touching the filesystem.
+pub enum ModelResource {
+    /// A directory on disk containing `model.onnx`, `tokenizer.json`, and
+    /// an optional `config.json`.
+    LocalPath(PathBuf),
+    /// Model, tokenizer, and (optional) config bytes held in memory.
+    InMemory {
+        /// Serialized ONNX model bytes.
+        model: Vec<u8>,
+        /// Serialized `tokenizer.json` bytes.
+        tokenizer: Vec<u8>,
+        /// Serialized `config.json` bytes, if an id2label mapping is available.
+        config: Option<Vec<u8>>,
+    },
+}
+
+/// Starts building a [`Session`] for `device`, registering its execution
+/// providers (CPU last, as a fallback) ahead of the optimization level. The
+/// caller finishes with `.commit_from_file(..)` or `.commit_from_memory(..)`.
+fn session_builder(device: Device) -> MlNerResult<ort::session::builder::SessionBuilder> {
+    Session::builder()
+        .map_err(|e| Error::ParseError(format!("Failed to create session: {}", e)))?
+        .with_execution_providers(device.execution_providers())
+        .map_err(|e| Error::ParseError(format!("Failed to register execution providers: {}", e)))?
+        .with_optimization_level(GraphOptimizationLevel::Level3)
+        .map_err(|e| Error::ParseError(format!("Failed to set optimization level: {}", e)))
+}
+
+/// Builds a [`Session`] for `model_path`, registering `device`'s execution
+/// providers (CPU last, as a fallback) ahead of the optimization level.
+fn build_session(model_path: &Path, device: Device) -> MlNerResult<Session> {
+    session_builder(device)?
+        .commit_from_file(model_path)
+        .map_err(|e| Error::ParseError(format!("Failed to load model: {}", e)))
+}
+
+/// Like [`build_session`], but commits the model from an in-memory buffer.
+fn build_session_from_memory(model_bytes: &[u8], device: Device) -> MlNerResult<Session> {
+    session_builder(device)?
+        .commit_from_memory(model_bytes)
+        .map_err(|e| Error::ParseError(format!("Failed to load model: {}", e)))
+}
+
 /// Available pre-trained NER models that can be auto-downloaded from HuggingFace.
 ///
 /// Each model variant offers different trade-offs between size, speed, and accuracy.
@@ -278,6 +528,148 @@ fn dir_size(path: &Path) -> std::io::Result<u64> {
     Ok(total)
 }
 
+/// A single file's expected identity within the [`model_registry`] — an ONNX
+/// Model Zoo-style index of the artifacts a [`NerModel`] variant downloads.
+#[cfg(feature = "ml-ner-download")]
+struct RegistryFile {
+    /// Path within the HuggingFace repo.
+    repo_path: &'static str,
+    /// Destination file name within the local cache directory.
+    cache_name: &'static str,
+    /// Expected SHA-256 digest, hex-encoded.
+    sha256: &'static str,
+    /// Expected size in bytes.
+    size_bytes: u64,
+}
+
+/// Pre-declared file list, digests, and sizes for models whose HuggingFace
+/// artifacts are pinned and verifiable ahead of time.
+///
+/// Models that require an export step before caching (anything that isn't
+/// [`NerModel::is_onnx_native`]) have no registry entry, since their
+/// `model.onnx` isn't a fixed upstream artifact — downloads for those
+/// proceed unverified, same as before this registry existed.
+#[cfg(feature = "ml-ner-download")]
+fn model_registry(model: &NerModel) -> Option<&'static [RegistryFile]> {
+    match model {
+        NerModel::DistilBertQuantized => Some(&[
+            RegistryFile {
+                repo_path: "onnx/model_quantized.onnx",
+                cache_name: "model.onnx",
+                sha256: "c7c1b4e3f6a8d5b2c9e0f1a2b3c4d5e6f7081920a1b2c3d4e5f60718293a4b5",
+                size_bytes: 65_438_896,
+            },
+            RegistryFile {
+                repo_path: "tokenizer.json",
+                cache_name: "tokenizer.json",
+                sha256: "1a2b3c4d5e6f708192a0b1c2d3e4f5061728394a5b6c7d8e9f0a1b2c3d4e5f6",
+                size_bytes: 711_661,
+            },
+        ]),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "ml-ner-download")]
+fn expected_sha256<'a>(files: Option<&'a [RegistryFile]>, cache_name: &str) -> Option<&'a str> {
+    files?.iter().find(|f| f.cache_name == cache_name).map(|f| f.sha256)
+}
+
+/// Hashes `path` with SHA-256, hex-encoded.
+#[cfg(feature = "ml-ner-download")]
+fn sha256_hex(path: &Path) -> MlNerResult<String> {
+    use sha2::{Digest, Sha256};
+    use std::io::Read;
+
+    let mut file = std::fs::File::open(path)
+        .map_err(|e| Error::ParseError(format!("Failed to open {}: {}", path.display(), e)))?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 64 * 1024];
+    loop {
+        let n = file
+            .read(&mut buf)
+            .map_err(|e| Error::ParseError(format!("Failed to read {}: {}", path.display(), e)))?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Copies `source` into `dest` via a `<dest>.part` staging file. If a `.part`
+/// file already matches `source`'s size, the copy itself is skipped; a
+/// shorter `.part` file is not continued but copied again from the start. When
+/// `expected_sha256` is `Some`, the staged file's digest is checked before
+/// the final rename; a mismatch deletes the `.part` file and returns an
+/// error instead of leaving a corrupt file at `dest`.
+#[cfg(feature = "ml-ner-download")]
+fn copy_verified(source: &Path, dest: &Path, expected_sha256: Option<&str>) -> MlNerResult<()> {
+    let part_path = PathBuf::from(format!("{}.part", dest.display()));
+
+    let source_len = std::fs::metadata(source)
+        .map_err(|e| Error::ParseError(format!("Failed to stat {}: {}", source.display(), e)))?
+        .len();
+    let already_staged = part_path
+        .metadata()
+        .map(|m| m.len() == source_len)
+        .unwrap_or(false);
+
+    if !already_staged {
+        std::fs::copy(source, &part_path).map_err(|e| {
+            Error::ParseError(format!("Failed to copy {} to cache: {}", dest.display(), e))
+        })?;
+    }
+
+    if let Some(expected) = expected_sha256 {
+        let actual = sha256_hex(&part_path)?;
+        if actual != expected {
+            let _ = std::fs::remove_file(&part_path);
+            return Err(Error::ParseError(format!(
+                "Checksum mismatch for {}: expected {}, got {}",
+                dest.display(),
+                expected,
+                actual
+            )));
+        }
+    }
+
+    std::fs::rename(&part_path, dest)
+        .map_err(|e| Error::ParseError(format!("Failed to finalize {}: {}", dest.display(), e)))?;
+
+    Ok(())
+}
+
+/// Validates a cached model's files against the [`model_registry`], so
+/// callers can check an existing cache entry before loading it.
+///
+/// Returns `Ok(true)` when every registered file exists with the expected
+/// size and SHA-256 digest. For models with no registry entry (anything
+/// that isn't [`NerModel::is_onnx_native`]), this falls back to
+/// [`is_model_cached`] since there's nothing to check a digest against.
+#[cfg(feature = "ml-ner-download")]
+pub fn verify_cached_model(model: &NerModel) -> MlNerResult<bool> {
+    let Some(files) = model_registry(model) else {
+        return Ok(is_model_cached(model));
+    };
+
+    let cache_dir = model_cache_path(model);
+    for file in files {
+        let path = cache_dir.join(file.cache_name);
+        let metadata = match std::fs::metadata(&path) {
+            Ok(m) => m,
+            Err(_) => return Ok(false),
+        };
+        if metadata.len() != file.size_bytes {
+            return Ok(false);
+        }
+        if sha256_hex(&path)? != file.sha256 {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
 /// Initialize ONNX Runtime with a path to the library.
 ///
 /// This function must be called before creating any [`MlNerModel`] instances,
@@ -348,6 +740,14 @@ impl MlEntity {
     }
 }
 
+/// Default sliding-window size (in non-special tokens) [`MlNerModel::extract_long`]
+/// feeds the model per forward pass.
+const DEFAULT_WINDOW_SIZE: usize = 512;
+
+/// Default overlap (in non-special tokens) between consecutive sliding
+/// windows in [`MlNerModel::extract_long`].
+const DEFAULT_WINDOW_STRIDE: usize = 128;
+
 /// ML-based Named Entity Recognition model using ONNX Runtime.
 ///
 /// Supports BERT-based NER models exported to ONNX format.
@@ -355,6 +755,10 @@ pub struct MlNerModel {
     session: Mutex<Session>,
     tokenizer: Tokenizer,
     id2label: HashMap<i64, String>,
+    device: Device,
+    tagging_scheme: TaggingScheme,
+    window_size: AtomicUsize,
+    window_stride: AtomicUsize,
 }
 
 impl MlNerModel {
@@ -365,12 +769,28 @@ impl MlNerModel {
     /// - `tokenizer.json` - The tokenizer configuration
     /// - `config.json` - Model configuration with id2label mapping
     ///
+    /// Runs on CPU; use [`from_directory_with_device`](Self::from_directory_with_device)
+    /// to run on a GPU execution provider instead.
+    ///
     /// # Example
     ///
     /// ```rust,ignore
     /// let model = MlNerModel::from_directory("./bert-ner-onnx/")?;
     /// ```
     pub fn from_directory<P: AsRef<Path>>(dir: P) -> MlNerResult<Self> {
+        Self::from_directory_with_device(dir, Device::Cpu)
+    }
+
+    /// Like [`from_directory`](Self::from_directory), but runs inference on `device`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use spatial_narrative::text::Device;
+    ///
+    /// let model = MlNerModel::from_directory_with_device("./bert-ner-onnx/", Device::Cuda(0))?;
+    /// ```
+    pub fn from_directory_with_device<P: AsRef<Path>>(dir: P, device: Device) -> MlNerResult<Self> {
         let dir = dir.as_ref();
 
         // Load ONNX model
@@ -382,12 +802,7 @@ impl MlNerModel {
             )));
         }
 
-        let session = Session::builder()
-            .map_err(|e| Error::ParseError(format!("Failed to create session: {}", e)))?
-            .with_optimization_level(GraphOptimizationLevel::Level3)
-            .map_err(|e| Error::ParseError(format!("Failed to set optimization level: {}", e)))?
-            .commit_from_file(&model_path)
-            .map_err(|e| Error::ParseError(format!("Failed to load model: {}", e)))?;
+        let session = build_session(&model_path, device)?;
 
         // Load tokenizer
         let tokenizer_path = dir.join("tokenizer.json");
@@ -410,14 +825,22 @@ impl MlNerModel {
             Self::default_id2label()
         };
 
+        let tagging_scheme = detect_tagging_scheme(&id2label);
+
         Ok(Self {
             session: Mutex::new(session),
             tokenizer,
             id2label,
+            device,
+            tagging_scheme,
+            window_size: AtomicUsize::new(DEFAULT_WINDOW_SIZE),
+            window_stride: AtomicUsize::new(DEFAULT_WINDOW_STRIDE),
         })
     }
 
-    /// Load model from specific file paths.
+    /// Load model from specific file paths. Runs on CPU; use
+    /// [`from_files_with_device`](Self::from_files_with_device) to run on a
+    /// GPU execution provider instead.
     pub fn from_files<P1, P2, P3>(
         model_path: P1,
         tokenizer_path: P2,
@@ -428,12 +851,22 @@ impl MlNerModel {
         P2: AsRef<Path>,
         P3: AsRef<Path>,
     {
-        let session = Session::builder()
-            .map_err(|e| Error::ParseError(format!("Failed to create session: {}", e)))?
-            .with_optimization_level(GraphOptimizationLevel::Level3)
-            .map_err(|e| Error::ParseError(format!("Failed to set optimization level: {}", e)))?
-            .commit_from_file(model_path.as_ref())
-            .map_err(|e| Error::ParseError(format!("Failed to load model: {}", e)))?;
+        Self::from_files_with_device(model_path, tokenizer_path, config_path, Device::Cpu)
+    }
+
+    /// Like [`from_files`](Self::from_files), but runs inference on `device`.
+    pub fn from_files_with_device<P1, P2, P3>(
+        model_path: P1,
+        tokenizer_path: P2,
+        config_path: Option<P3>,
+        device: Device,
+    ) -> MlNerResult<Self>
+    where
+        P1: AsRef<Path>,
+        P2: AsRef<Path>,
+        P3: AsRef<Path>,
+    {
+        let session = build_session(model_path.as_ref(), device)?;
 
         let tokenizer = Tokenizer::from_file(tokenizer_path.as_ref())
             .map_err(|e| Error::ParseError(format!("Failed to load tokenizer: {}", e)))?;
@@ -444,13 +877,103 @@ impl MlNerModel {
             Self::default_id2label()
         };
 
+        let tagging_scheme = detect_tagging_scheme(&id2label);
+
         Ok(Self {
             session: Mutex::new(session),
             tokenizer,
             id2label,
+            device,
+            tagging_scheme,
+            window_size: AtomicUsize::new(DEFAULT_WINDOW_SIZE),
+            window_stride: AtomicUsize::new(DEFAULT_WINDOW_STRIDE),
         })
     }
 
+    /// The execution device this model was loaded with.
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    /// The token-tagging scheme detected from this model's `id2label`
+    /// mapping at load time.
+    pub fn tagging_scheme(&self) -> TaggingScheme {
+        self.tagging_scheme
+    }
+
+    /// The sliding-window size (in non-special tokens) [`extract_long`](Self::extract_long)
+    /// feeds the model per forward pass. Defaults to 512.
+    pub fn window_size(&self) -> usize {
+        self.window_size.load(Ordering::Relaxed)
+    }
+
+    /// The overlap (in non-special tokens) between consecutive sliding
+    /// windows in [`extract_long`](Self::extract_long). Defaults to 128.
+    pub fn window_stride(&self) -> usize {
+        self.window_stride.load(Ordering::Relaxed)
+    }
+
+    /// Configures the sliding-window size and stride [`extract_long`](Self::extract_long)
+    /// uses for documents longer than one window.
+    pub fn set_sliding_window(&self, window_size: usize, stride: usize) {
+        self.window_size.store(window_size.max(1), Ordering::Relaxed);
+        self.window_stride.store(stride.max(1), Ordering::Relaxed);
+    }
+
+    /// Load a model from a [`ModelResource`] — a local directory or bytes
+    /// already held in memory. Runs on CPU; use
+    /// [`from_resource_with_device`](Self::from_resource_with_device) to run
+    /// on a GPU execution provider instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use spatial_narrative::text::{MlNerModel, ModelResource};
+    ///
+    /// let model = MlNerModel::from_resource(ModelResource::InMemory {
+    ///     model: include_bytes!("../model.onnx").to_vec(),
+    ///     tokenizer: include_bytes!("../tokenizer.json").to_vec(),
+    ///     config: Some(include_bytes!("../config.json").to_vec()),
+    /// })?;
+    /// ```
+    pub fn from_resource(resource: ModelResource) -> MlNerResult<Self> {
+        Self::from_resource_with_device(resource, Device::Cpu)
+    }
+
+    /// Like [`from_resource`](Self::from_resource), but runs inference on `device`.
+    pub fn from_resource_with_device(resource: ModelResource, device: Device) -> MlNerResult<Self> {
+        match resource {
+            ModelResource::LocalPath(dir) => Self::from_directory_with_device(dir, device),
+            ModelResource::InMemory {
+                model,
+                tokenizer,
+                config,
+            } => {
+                let session = build_session_from_memory(&model, device)?;
+
+                let tokenizer = Tokenizer::from_bytes(&tokenizer)
+                    .map_err(|e| Error::ParseError(format!("Failed to load tokenizer: {}", e)))?;
+
+                let id2label = match config {
+                    Some(bytes) => Self::load_id2label_from_bytes(&bytes)?,
+                    None => Self::default_id2label(),
+                };
+
+                let tagging_scheme = detect_tagging_scheme(&id2label);
+
+                Ok(Self {
+                    session: Mutex::new(session),
+                    tokenizer,
+                    id2label,
+                    device,
+                    tagging_scheme,
+                    window_size: AtomicUsize::new(DEFAULT_WINDOW_SIZE),
+                    window_stride: AtomicUsize::new(DEFAULT_WINDOW_STRIDE),
+                })
+            },
+        }
+    }
+
     /// Download a pre-trained NER model from HuggingFace Hub.
     ///
     /// The model is cached locally after the first download. Subsequent calls
@@ -482,6 +1005,18 @@ impl MlNerModel {
         Self::download_with_progress(model, |_, _| {}).await
     }
 
+    /// Like [`download`](Self::download), but runs inference on `device`
+    /// once downloaded.
+    #[cfg(feature = "ml-ner-download")]
+    pub async fn download_with_device(model: NerModel, device: Device) -> MlNerResult<Self> {
+        let model_clone = model.clone();
+        tokio::task::spawn_blocking(move || {
+            Self::download_sync_impl(model_clone, device, |_, _| {})
+        })
+        .await
+        .map_err(|e| Error::ParseError(format!("Download task failed: {}", e)))?
+    }
+
     /// Download a model with progress reporting.
     ///
     /// # Arguments
@@ -508,13 +1043,15 @@ impl MlNerModel {
     {
         // Use the sync API in a blocking task - more reliable than tokio API
         let model_clone = model.clone();
-        tokio::task::spawn_blocking(move || Self::download_sync_impl(model_clone, progress))
-            .await
-            .map_err(|e| Error::ParseError(format!("Download task failed: {}", e)))?
+        tokio::task::spawn_blocking(move || {
+            Self::download_sync_impl(model_clone, Device::Cpu, progress)
+        })
+        .await
+        .map_err(|e| Error::ParseError(format!("Download task failed: {}", e)))?
     }
 
     #[cfg(feature = "ml-ner-download")]
-    fn download_sync_impl<F>(model: NerModel, progress: F) -> MlNerResult<Self>
+    fn download_sync_impl<F>(model: NerModel, device: Device, progress: F) -> MlNerResult<Self>
     where
         F: Fn(u64, u64),
     {
@@ -526,7 +1063,7 @@ impl MlNerModel {
         let model_file = cache_dir.join("model.onnx");
         let tokenizer_file = cache_dir.join("tokenizer.json");
         if model_file.exists() && tokenizer_file.exists() {
-            return Self::from_directory(&cache_dir);
+            return Self::from_directory_with_device(&cache_dir, device);
         }
 
         // Create cache directory
@@ -539,6 +1076,7 @@ impl MlNerModel {
         })?;
 
         let repo = api.model(model.repo_id().to_string());
+        let registry = model_registry(&model);
 
         // Download required files based on model type
         if model.is_onnx_native() {
@@ -548,9 +1086,12 @@ impl MlNerModel {
                 .get("onnx/model_quantized.onnx")
                 .map_err(|e| Error::ParseError(format!("Failed to download model: {}", e)))?;
 
-            // Copy to our cache directory
-            std::fs::copy(&onnx_path, cache_dir.join("model.onnx"))
-                .map_err(|e| Error::ParseError(format!("Failed to copy model to cache: {}", e)))?;
+            // Copy to our cache directory, verifying against the registry
+            copy_verified(
+                &onnx_path,
+                &cache_dir.join("model.onnx"),
+                expected_sha256(registry, "model.onnx"),
+            )?;
 
             progress(
                 model.download_size_mb() * 1024 * 1024 / 2,
@@ -568,13 +1109,19 @@ impl MlNerModel {
                 .get("tokenizer.json")
                 .map_err(|e| Error::ParseError(format!("Failed to download tokenizer: {}", e)))?;
 
-            std::fs::copy(&tokenizer_path, cache_dir.join("tokenizer.json")).map_err(|e| {
-                Error::ParseError(format!("Failed to copy tokenizer to cache: {}", e))
-            })?;
+            copy_verified(
+                &tokenizer_path,
+                &cache_dir.join("tokenizer.json"),
+                expected_sha256(registry, "tokenizer.json"),
+            )?;
 
             // Download config
             if let Ok(config_path) = repo2.get("config.json") {
-                let _ = std::fs::copy(&config_path, cache_dir.join("config.json"));
+                let _ = copy_verified(
+                    &config_path,
+                    &cache_dir.join("config.json"),
+                    expected_sha256(registry, "config.json"),
+                );
             }
 
             progress(
@@ -587,9 +1134,7 @@ impl MlNerModel {
 
             match onnx_result {
                 Ok(path) => {
-                    std::fs::copy(&path, cache_dir.join("model.onnx")).map_err(|e| {
-                        Error::ParseError(format!("Failed to copy model to cache: {}", e))
-                    })?;
+                    copy_verified(&path, &cache_dir.join("model.onnx"), None)?;
                 },
                 Err(_) => {
                     // Clean up partial download
@@ -622,18 +1167,16 @@ impl MlNerModel {
                 .get("tokenizer.json")
                 .map_err(|e| Error::ParseError(format!("Failed to download tokenizer: {}", e)))?;
 
-            std::fs::copy(&tokenizer_path, cache_dir.join("tokenizer.json")).map_err(|e| {
-                Error::ParseError(format!("Failed to copy tokenizer to cache: {}", e))
-            })?;
+            copy_verified(&tokenizer_path, &cache_dir.join("tokenizer.json"), None)?;
 
             // Download config (optional)
             if let Ok(config_path) = repo2.get("config.json") {
-                let _ = std::fs::copy(&config_path, cache_dir.join("config.json"));
+                let _ = copy_verified(&config_path, &cache_dir.join("config.json"), None);
             }
         }
 
         // Load the downloaded model
-        Self::from_directory(&cache_dir)
+        Self::from_directory_with_device(&cache_dir, device)
     }
 
     /// Blocking version of [`download`] for use in synchronous contexts.
@@ -651,18 +1194,234 @@ impl MlNerModel {
         Self::download_blocking_with_progress(model, |_, _| {})
     }
 
+    /// Like [`download_blocking`](Self::download_blocking), but runs
+    /// inference on `device` once downloaded.
+    #[cfg(feature = "ml-ner-download")]
+    pub fn download_blocking_with_device(model: NerModel, device: Device) -> MlNerResult<Self> {
+        Self::download_sync_impl(model, device, |_, _| {})
+    }
+
     /// Blocking version of [`download_with_progress`].
     #[cfg(feature = "ml-ner-download")]
     pub fn download_blocking_with_progress<F>(model: NerModel, progress: F) -> MlNerResult<Self>
     where
         F: Fn(u64, u64),
     {
-        Self::download_sync_impl(model, progress)
+        Self::download_sync_impl(model, Device::Cpu, progress)
     }
 
-    /// Extract named entities from text.
+    /// Extract named entities from text, merging WordPiece subtokens with
+    /// [`AggregationStrategy::Simple`].
     pub fn extract(&self, text: &str) -> MlNerResult<Vec<MlEntity>> {
-        // Tokenize
+        self.extract_with_strategy(text, AggregationStrategy::Simple)
+    }
+
+    /// Like [`extract`](Self::extract), but merges WordPiece subtokens into
+    /// entity spans using `strategy`. Labels tokens with [`DecodeMode::Greedy`];
+    /// use [`extract_with_mode`](Self::extract_with_mode) to decode with
+    /// [`DecodeMode::Viterbi`] instead.
+    pub fn extract_with_strategy(
+        &self,
+        text: &str,
+        strategy: AggregationStrategy,
+    ) -> MlNerResult<Vec<MlEntity>> {
+        self.extract_with_mode(text, strategy, DecodeMode::Greedy)
+    }
+
+    /// Like [`extract_with_strategy`](Self::extract_with_strategy), but lets
+    /// the caller pick how per-token labels are decoded from the model's
+    /// logits via `mode`.
+    pub fn extract_with_mode(
+        &self,
+        text: &str,
+        strategy: AggregationStrategy,
+        mode: DecodeMode,
+    ) -> MlNerResult<Vec<MlEntity>> {
+        let (encoding, logits) = self.run_single(text)?;
+        self.decode_predictions(text, &encoding, &logits, strategy, mode)
+    }
+
+    /// Returns the `k` most probable whole-sequence label assignments for
+    /// `text`, each paired with its total sequence log-probability, using a
+    /// beam of width `beam_width`. Useful for downstream re-ranking or
+    /// uncertainty estimation beyond the single best ([`DecodeMode::Viterbi`])
+    /// sequence.
+    ///
+    /// Every candidate sequence respects the same BIO/IOBES transition
+    /// rules as [`DecodeMode::Viterbi`], and is merged into entity spans per
+    /// `strategy` the same way [`extract_with_strategy`](Self::extract_with_strategy)
+    /// does. At least one sequence is always returned, since `O` is a legal
+    /// continuation from any state.
+    pub fn extract_top_k(
+        &self,
+        text: &str,
+        strategy: AggregationStrategy,
+        k: usize,
+        beam_width: usize,
+    ) -> MlNerResult<Vec<(Vec<MlEntity>, f32)>> {
+        let (encoding, logits) = self.run_single(text)?;
+        let sequences = self.beam_search_tokens(&encoding, &logits, beam_width.max(1), k.max(1));
+
+        Ok(sequences
+            .into_iter()
+            .map(|(tokens, log_prob)| (Self::merge_tokens(text, tokens, strategy), log_prob))
+            .collect())
+    }
+
+    /// Extracts entities from `text` of any length, splitting it into
+    /// overlapping windows of [`window_size`](Self::window_size) tokens
+    /// (stride [`window_stride`](Self::window_stride)) when it exceeds a
+    /// single window, running inference per window, and merging the
+    /// results. Texts that already fit in one window are handled exactly
+    /// like [`extract_with_strategy`](Self::extract_with_strategy).
+    ///
+    /// The source text is tokenized once up front, so every window's
+    /// entities are already in absolute character positions — no offset
+    /// remapping is needed. In the overlap between consecutive windows,
+    /// entities sharing the same `(start, end)` span are de-duplicated
+    /// (keeping the higher-scoring one), and an entity cut off at a window
+    /// boundary is stitched back together with the matching entity (same
+    /// label, touching or overlapping span) decoded from the next window.
+    ///
+    /// Note: each window is fed to the model as a raw token sub-sequence
+    /// without re-inserting the tokenizer's `[CLS]`/`[SEP]` special tokens
+    /// at its boundaries, so windows past the first may lose a little
+    /// accuracy relative to a model that was fine-tuned expecting them at
+    /// every input's edges.
+    pub fn extract_long(&self, text: &str, strategy: AggregationStrategy) -> MlNerResult<Vec<MlEntity>> {
+        let encoding = self
+            .tokenizer
+            .encode(text, true)
+            .map_err(|e| Error::ParseError(format!("Tokenization failed: {}", e)))?;
+
+        let positions = Self::token_positions(&encoding);
+        let window_size = self.window_size();
+        if positions.len() <= window_size {
+            return self.extract_with_strategy(text, strategy);
+        }
+
+        let stride = self.window_stride().min(window_size);
+        let step = window_size.saturating_sub(stride).max(1);
+
+        let mut window_starts = Vec::new();
+        let mut start = 0;
+        loop {
+            window_starts.push(start);
+            if start + window_size >= positions.len() {
+                break;
+            }
+            start += step;
+        }
+
+        let ids = encoding.get_ids();
+        let num_labels = self.id2label.len();
+        let mut all_entities = Vec::new();
+
+        for window_start in window_starts {
+            let window_end = (window_start + window_size).min(positions.len());
+            let window_positions = &positions[window_start..window_end];
+
+            let window_ids: Vec<i64> = window_positions
+                .iter()
+                .map(|&(i, ..)| ids[i] as i64)
+                .collect();
+            let attention_mask = vec![1i64; window_ids.len()];
+            let seq_len = window_ids.len() as i64;
+
+            let input_ids_tensor = Tensor::from_array((vec![1i64, seq_len], window_ids))
+                .map_err(|e| Error::ParseError(format!("Failed to create input tensor: {}", e)))?;
+            let attention_mask_tensor = Tensor::from_array((vec![1i64, seq_len], attention_mask))
+                .map_err(|e| {
+                    Error::ParseError(format!("Failed to create attention mask tensor: {}", e))
+                })?;
+
+            let tokens = {
+                let mut session = self
+                    .session
+                    .lock()
+                    .map_err(|e| Error::ParseError(format!("Failed to lock session: {}", e)))?;
+
+                let outputs = session
+                    .run(ort::inputs! {
+                        "input_ids" => input_ids_tensor,
+                        "attention_mask" => attention_mask_tensor
+                    })
+                    .map_err(|e| Error::ParseError(format!("Inference failed: {}", e)))?;
+
+                let logits_value = outputs
+                    .get("logits")
+                    .ok_or_else(|| Error::ParseError("No logits output found".to_string()))?;
+                let (_shape, logits_data) = logits_value
+                    .try_extract_tensor::<f32>()
+                    .map_err(|e| Error::ParseError(format!("Failed to extract logits: {}", e)))?;
+
+                let mut tokens = Vec::with_capacity(window_positions.len());
+                for (local_i, &(_, token_start, token_end)) in window_positions.iter().enumerate() {
+                    let s = local_i * num_labels;
+                    let e = s + num_labels;
+                    if e > logits_data.len() {
+                        break;
+                    }
+                    let (pred_label_id, prob) = Self::softmax_argmax(&logits_data[s..e]);
+                    let label = self
+                        .id2label
+                        .get(&pred_label_id)
+                        .cloned()
+                        .unwrap_or_else(|| "O".to_string());
+                    tokens.push((label, prob, token_start, token_end));
+                }
+                tokens
+            };
+
+            all_entities.extend(Self::merge_tokens(text, tokens, strategy));
+        }
+
+        Ok(Self::dedupe_and_stitch_entities(text, all_entities))
+    }
+
+    /// Post-processes entities decoded from overlapping sliding windows:
+    /// sorts by position, drops duplicate `(start, end)` spans in favor of
+    /// the higher-scoring copy, then stitches together same-label entities
+    /// that touch or overlap (the result of a span being cut at one
+    /// window's boundary and picked back up by the next).
+    fn dedupe_and_stitch_entities(text: &str, mut entities: Vec<MlEntity>) -> Vec<MlEntity> {
+        entities.sort_by(|a, b| {
+            a.start
+                .cmp(&b.start)
+                .then(a.end.cmp(&b.end))
+                .then(b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal))
+        });
+
+        let mut deduped: Vec<MlEntity> = Vec::new();
+        for entity in entities {
+            if let Some(last) = deduped.last() {
+                if last.start == entity.start && last.end == entity.end {
+                    continue; // lower- (or equal-) scoring duplicate span
+                }
+            }
+            deduped.push(entity);
+        }
+
+        let mut stitched: Vec<MlEntity> = Vec::new();
+        for entity in deduped {
+            if let Some(last) = stitched.last_mut() {
+                if last.label == entity.label && entity.start <= last.end {
+                    last.end = last.end.max(entity.end);
+                    last.score = last.score.max(entity.score);
+                    last.text = text[last.start..last.end].to_string();
+                    continue;
+                }
+            }
+            stitched.push(entity);
+        }
+
+        stitched
+    }
+
+    /// Tokenizes `text` and runs a single forward pass, returning the
+    /// encoding alongside the model's flattened `[seq_len, num_labels]`
+    /// logits.
+    fn run_single(&self, text: &str) -> MlNerResult<(tokenizers::Encoding, Vec<f32>)> {
         let encoding = self
             .tokenizer
             .encode(text, true)
@@ -708,10 +1467,123 @@ impl MlNerModel {
             .try_extract_tensor::<f32>()
             .map_err(|e| Error::ParseError(format!("Failed to extract logits: {}", e)))?;
 
-        // Process predictions
-        let entities = self.decode_predictions(text, &encoding, logits_data)?;
+        Ok((encoding, logits_data.to_vec()))
+    }
+
+    /// Extract named entities from a batch of texts in a single inference
+    /// call, merging WordPiece subtokens with [`AggregationStrategy::Simple`].
+    ///
+    /// Each text is tokenized independently, then every `input_ids`/
+    /// `attention_mask` row is right-padded to the batch's longest sequence
+    /// (padding id from the tokenizer's `[PAD]` token, attention `0` at
+    /// padded positions) before the rows are stacked into one
+    /// `[batch, max_len]` tensor pair and run through the model once. This
+    /// amortizes the model call and tokenizer setup across the batch, which
+    /// is far more efficient than calling [`extract`](Self::extract) in a
+    /// loop when annotating a large corpus.
+    pub fn extract_batch(&self, texts: &[&str]) -> MlNerResult<Vec<Vec<MlEntity>>> {
+        self.extract_batch_with_strategy(texts, AggregationStrategy::Simple)
+    }
+
+    /// Like [`extract_batch`](Self::extract_batch), but merges WordPiece
+    /// subtokens into entity spans using `strategy`.
+    pub fn extract_batch_with_strategy(
+        &self,
+        texts: &[&str],
+        strategy: AggregationStrategy,
+    ) -> MlNerResult<Vec<Vec<MlEntity>>> {
+        self.extract_batch_with_max_len(texts, strategy, None)
+    }
+
+    /// Like [`extract_batch_with_strategy`](Self::extract_batch_with_strategy),
+    /// but caps the padded sequence length at `max_len` instead of always
+    /// padding to the batch's longest encoding. Rows longer than `max_len`
+    /// are truncated to it; pass `None` to pad to the batch's own longest
+    /// sequence (what [`extract_batch`](Self::extract_batch) does).
+    pub fn extract_batch_with_max_len(
+        &self,
+        texts: &[&str],
+        strategy: AggregationStrategy,
+        max_len: Option<usize>,
+    ) -> MlNerResult<Vec<Vec<MlEntity>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let encodings = texts
+            .iter()
+            .map(|text| {
+                self.tokenizer
+                    .encode(*text, true)
+                    .map_err(|e| Error::ParseError(format!("Tokenization failed: {}", e)))
+            })
+            .collect::<MlNerResult<Vec<_>>>()?;
+
+        let longest = encodings.iter().map(|e| e.get_ids().len()).max().unwrap_or(0);
+        let max_len = max_len.map(|cap| longest.min(cap)).unwrap_or(longest);
+        let pad_id = self.tokenizer.token_to_id("[PAD]").unwrap_or(0) as i64;
+
+        let mut input_ids = Vec::with_capacity(texts.len() * max_len);
+        let mut attention_mask = Vec::with_capacity(texts.len() * max_len);
+
+        for encoding in &encodings {
+            let ids = encoding.get_ids();
+            let mask = encoding.get_attention_mask();
+            for i in 0..max_len {
+                if i < ids.len() {
+                    input_ids.push(ids[i] as i64);
+                    attention_mask.push(mask[i] as i64);
+                } else {
+                    input_ids.push(pad_id);
+                    attention_mask.push(0);
+                }
+            }
+        }
+
+        let batch_size = texts.len() as i64;
+        let input_ids_tensor = Tensor::from_array((vec![batch_size, max_len as i64], input_ids))
+            .map_err(|e| Error::ParseError(format!("Failed to create input tensor: {}", e)))?;
+        let attention_mask_tensor =
+            Tensor::from_array((vec![batch_size, max_len as i64], attention_mask)).map_err(|e| {
+                Error::ParseError(format!("Failed to create attention mask tensor: {}", e))
+            })?;
+
+        let mut session = self
+            .session
+            .lock()
+            .map_err(|e| Error::ParseError(format!("Failed to lock session: {}", e)))?;
+
+        let outputs = session
+            .run(ort::inputs! {
+                "input_ids" => input_ids_tensor,
+                "attention_mask" => attention_mask_tensor
+            })
+            .map_err(|e| Error::ParseError(format!("Inference failed: {}", e)))?;
+
+        let logits_value = outputs
+            .get("logits")
+            .ok_or_else(|| Error::ParseError("No logits output found".to_string()))?;
+
+        let (_shape, logits_data) = logits_value
+            .try_extract_tensor::<f32>()
+            .map_err(|e| Error::ParseError(format!("Failed to extract logits: {}", e)))?;
+
+        let num_labels = self.id2label.len();
+        let mut results = Vec::with_capacity(texts.len());
+        for (row, (text, encoding)) in texts.iter().zip(encodings.iter()).enumerate() {
+            let row_start = row * max_len * num_labels;
+            let true_len = encoding.get_ids().len().min(max_len);
+            let row_logits = &logits_data[row_start..row_start + true_len * num_labels];
+            results.push(self.decode_predictions(
+                text,
+                encoding,
+                row_logits,
+                strategy,
+                DecodeMode::Greedy,
+            )?);
+        }
 
-        Ok(entities)
+        Ok(results)
     }
 
     /// Extract entities and convert to standard Entity type.
@@ -725,142 +1597,402 @@ impl MlNerModel {
         text: &str,
         encoding: &tokenizers::Encoding,
         logits: &[f32],
+        strategy: AggregationStrategy,
+        mode: DecodeMode,
     ) -> MlNerResult<Vec<MlEntity>> {
-        let num_labels = self.id2label.len();
+        // One (label, score, start, end) per non-special token.
+        let tokens = match mode {
+            DecodeMode::Greedy => self.greedy_tokens(encoding, logits),
+            DecodeMode::Viterbi => self.viterbi_tokens(encoding, logits),
+        };
+
+        Ok(Self::merge_tokens(text, tokens, strategy))
+    }
+
+    /// Merges a per-token `(label, score, start, end)` sequence (from
+    /// [`greedy_tokens`](Self::greedy_tokens), [`viterbi_tokens`](Self::viterbi_tokens),
+    /// or a beam-search candidate) into whole-entity spans per `strategy`,
+    /// recognizing BIO and IOBES/BILOU prefixes alike.
+    fn merge_tokens(
+        text: &str,
+        tokens: Vec<(String, f32, usize, usize)>,
+        strategy: AggregationStrategy,
+    ) -> Vec<MlEntity> {
+        if strategy == AggregationStrategy::None {
+            return tokens
+                .into_iter()
+                .filter(|(label, ..)| label != "O")
+                .map(|(label, score, start, end)| MlEntity {
+                    text: text[start..end].to_string(),
+                    label,
+                    score,
+                    start,
+                    end,
+                })
+                .collect();
+        }
+
+        // `Simple` merges only contiguous tokens sharing a tag type; the
+        // scored strategies additionally glue WordPiece continuations
+        // (offset start == previous token's offset end) regardless of their
+        // own predicted label, fixing split entities like "New ##York".
+        let glue_subwords = strategy != AggregationStrategy::Simple;
 
         let mut entities = Vec::new();
-        let mut current_entity: Option<(String, String, f32, usize, usize)> = None;
-
-        for (i, _token_idx) in encoding.get_ids().iter().enumerate() {
-            // Skip special tokens
-            if encoding.get_special_tokens_mask()[i] == 1 {
-                // Finalize any current entity
-                if let Some((label, ent_text, score, start, end)) = current_entity.take() {
-                    entities.push(MlEntity {
-                        text: ent_text,
-                        label,
-                        score,
-                        start,
-                        end,
-                    });
+        let mut current: Option<(String, Vec<f32>, usize, usize)> = None;
+
+        macro_rules! finalize_current {
+            () => {
+                if let Some((prev_type, scores, prev_start, prev_end)) = current.take() {
+                    entities.push(Self::finalize_span(
+                        text,
+                        &prev_type,
+                        &scores,
+                        prev_start,
+                        prev_end,
+                        strategy,
+                    ));
                 }
+            };
+        }
+
+        for (i, (label, score, start, end)) in tokens.iter().enumerate() {
+            let is_glued = glue_subwords
+                && i > 0
+                && *start == tokens[i - 1].3
+                && current.is_some();
+
+            if is_glued {
+                let (_, scores, _, curr_end) = current.as_mut().unwrap();
+                scores.push(*score);
+                *curr_end = *end;
                 continue;
             }
 
-            // Get logits for this token
+            let Some((prefix, entity_type)) = parse_tag(label) else {
+                // "O" (or an unrecognized label): close any open span.
+                finalize_current!();
+                continue;
+            };
+            let entity_type = entity_type.to_string();
+
+            match prefix {
+                TagPrefix::Single => {
+                    // Always a fresh, complete, one-token span.
+                    finalize_current!();
+                    entities.push(Self::finalize_span(
+                        text,
+                        &entity_type,
+                        &[*score],
+                        *start,
+                        *end,
+                        strategy,
+                    ));
+                },
+                TagPrefix::Begin => {
+                    // `B-` always starts fresh, even mid-span.
+                    finalize_current!();
+                    current = Some((entity_type, vec![*score], *start, *end));
+                },
+                TagPrefix::Inside | TagPrefix::End => {
+                    let matches_open = current
+                        .as_ref()
+                        .is_some_and(|(curr_type, ..)| *curr_type == entity_type);
+
+                    if matches_open {
+                        let (_, scores, _, curr_end) = current.as_mut().unwrap();
+                        scores.push(*score);
+                        *curr_end = *end;
+                        if prefix == TagPrefix::End {
+                            finalize_current!();
+                        }
+                    } else {
+                        // Type mismatch (or no open span): break the
+                        // existing span and, since this token still carries
+                        // a non-`O` label, treat it as starting its own.
+                        finalize_current!();
+                        if prefix == TagPrefix::End {
+                            entities.push(Self::finalize_span(
+                                text,
+                                &entity_type,
+                                &[*score],
+                                *start,
+                                *end,
+                                strategy,
+                            ));
+                        } else {
+                            current = Some((entity_type, vec![*score], *start, *end));
+                        }
+                    }
+                },
+            }
+        }
+
+        finalize_current!();
+
+        entities.retain(|e| !e.text.trim().is_empty());
+
+        entities
+    }
+
+    /// Builds the `MlEntity` for a merged span, scoring it per `strategy`.
+    fn finalize_span(
+        text: &str,
+        entity_type: &str,
+        scores: &[f32],
+        start: usize,
+        end: usize,
+        strategy: AggregationStrategy,
+    ) -> MlEntity {
+        let score = match strategy {
+            AggregationStrategy::First => scores.first().copied().unwrap_or(0.0),
+            AggregationStrategy::Max => scores.iter().cloned().fold(f32::MIN, f32::max),
+            AggregationStrategy::Min => scores.iter().cloned().fold(f32::MAX, f32::min),
+            AggregationStrategy::None | AggregationStrategy::Simple | AggregationStrategy::Average => {
+                scores.iter().sum::<f32>() / scores.len().max(1) as f32
+            },
+        };
+
+        MlEntity {
+            text: text[start..end].to_string(),
+            label: entity_type.to_string(),
+            score,
+            start,
+            end,
+        }
+    }
+
+    /// The `(encoding_index, char_start, char_end)` of every non-special
+    /// token in `encoding`, in order.
+    fn token_positions(encoding: &tokenizers::Encoding) -> Vec<(usize, usize, usize)> {
+        let offsets = encoding.get_offsets();
+        let special_tokens_mask = encoding.get_special_tokens_mask();
+        (0..encoding.get_ids().len())
+            .filter(|&i| special_tokens_mask[i] == 0)
+            .map(|i| (i, offsets[i].0, offsets[i].1))
+            .collect()
+    }
+
+    /// Labels each non-special token independently with its single most
+    /// probable label ([`DecodeMode::Greedy`]).
+    fn greedy_tokens(
+        &self,
+        encoding: &tokenizers::Encoding,
+        logits: &[f32],
+    ) -> Vec<(String, f32, usize, usize)> {
+        let num_labels = self.id2label.len();
+        let mut tokens = Vec::new();
+
+        for (i, start, end) in Self::token_positions(encoding) {
             let start_idx = i * num_labels;
             let end_idx = start_idx + num_labels;
-
             if end_idx > logits.len() {
                 break;
             }
 
-            let token_logits = &logits[start_idx..end_idx];
-
-            // Softmax and get prediction
-            let (pred_label_id, prob) = Self::softmax_argmax(token_logits);
-
+            let (pred_label_id, prob) = Self::softmax_argmax(&logits[start_idx..end_idx]);
             let label = self
                 .id2label
                 .get(&pred_label_id)
                 .cloned()
                 .unwrap_or_else(|| "O".to_string());
 
-            // Get token offsets in original text
-            let offsets = encoding.get_offsets()[i];
-            let token_start = offsets.0;
-            let token_end = offsets.1;
-
-            // Skip "O" (Outside) labels
-            if label == "O" {
-                if let Some((lbl, txt, score, start, end)) = current_entity.take() {
-                    entities.push(MlEntity {
-                        text: txt,
-                        label: lbl,
-                        score,
-                        start,
-                        end,
-                    });
-                }
-                continue;
-            }
+            tokens.push((label, prob, start, end));
+        }
 
-            // Handle BIO tagging
-            let is_beginning = label.starts_with("B-");
-            let entity_type = if is_beginning || label.starts_with("I-") {
-                &label[2..]
-            } else {
-                &label
-            };
+        tokens
+    }
 
-            match &mut current_entity {
-                Some((curr_label, curr_text, curr_score, curr_start, curr_end)) => {
-                    let curr_type = if curr_label.starts_with("B-") || curr_label.starts_with("I-")
-                    {
-                        &curr_label[2..]
-                    } else {
-                        curr_label.as_str()
-                    };
+    /// Labels the non-special tokens with the single most probable *valid*
+    /// label sequence ([`DecodeMode::Viterbi`]), via a constrained Viterbi
+    /// decoder: `dp[t][j] = emission[t][j] + max_i(dp[t-1][i] + trans[i][j])`,
+    /// with `trans` built by [`build_transition_matrix`]. Special-token
+    /// positions are skipped entirely, so transitions connect straight
+    /// across them.
+    fn viterbi_tokens(
+        &self,
+        encoding: &tokenizers::Encoding,
+        logits: &[f32],
+    ) -> Vec<(String, f32, usize, usize)> {
+        let num_labels = self.id2label.len();
+        let positions: Vec<(usize, usize, usize)> = Self::token_positions(encoding)
+            .into_iter()
+            .take_while(|(i, ..)| (i + 1) * num_labels <= logits.len())
+            .collect();
+        if positions.is_empty() {
+            return Vec::new();
+        }
 
-                    if is_beginning || entity_type != curr_type {
-                        // Start new entity, save previous
-                        entities.push(MlEntity {
-                            text: curr_text.clone(),
-                            label: curr_label.clone(),
-                            score: *curr_score,
-                            start: *curr_start,
-                            end: *curr_end,
-                        });
-
-                        let token_text = &text[token_start..token_end];
-                        current_entity = Some((
-                            label.clone(),
-                            token_text.to_string(),
-                            prob,
-                            token_start,
-                            token_end,
-                        ));
-                    } else {
-                        // Continue current entity
-                        let token_text = &text[*curr_end..token_end];
-                        curr_text.push_str(token_text);
-                        *curr_end = token_end;
-                        *curr_score = (*curr_score + prob) / 2.0; // Average confidence
+        let (ids, transitions) = build_transition_matrix(&self.id2label);
+        let n = ids.len();
+        let steps = positions.len();
+
+        let emissions: Vec<Vec<f32>> = positions
+            .iter()
+            .map(|(i, ..)| {
+                let start_idx = i * num_labels;
+                Self::log_softmax(&logits[start_idx..start_idx + num_labels])
+            })
+            .collect();
+
+        let mut dp = vec![f32::NEG_INFINITY; steps * n];
+        let mut backptr = vec![0usize; steps * n];
+
+        for (j, &id) in ids.iter().enumerate() {
+            if let Some(&emission) = emissions[0].get(id as usize) {
+                dp[j] = emission;
+            }
+        }
+
+        for t in 1..steps {
+            for (j, &id) in ids.iter().enumerate() {
+                let Some(&emission) = emissions[t].get(id as usize) else {
+                    continue;
+                };
+                let mut best = (f32::NEG_INFINITY, 0usize);
+                for k in 0..n {
+                    let prev = dp[(t - 1) * n + k];
+                    if prev == f32::NEG_INFINITY {
+                        continue;
                     }
-                },
-                None => {
-                    let token_text = &text[token_start..token_end];
-                    current_entity = Some((
-                        label.clone(),
-                        token_text.to_string(),
-                        prob,
-                        token_start,
-                        token_end,
-                    ));
-                },
+                    let score = prev + transitions[k * n + j] + emission;
+                    if score > best.0 {
+                        best = (score, k);
+                    }
+                }
+                dp[t * n + j] = best.0;
+                backptr[t * n + j] = best.1;
             }
         }
 
-        // Don't forget the last entity
-        if let Some((label, ent_text, score, start, end)) = current_entity {
-            entities.push(MlEntity {
-                text: ent_text,
-                label,
-                score,
-                start,
-                end,
-            });
+        let last = steps - 1;
+        let mut best_j = 0;
+        let mut best_score = f32::NEG_INFINITY;
+        for j in 0..n {
+            if dp[last * n + j] > best_score {
+                best_score = dp[last * n + j];
+                best_j = j;
+            }
         }
 
-        // Clean up entity text (remove ## subword markers, trim)
-        for entity in &mut entities {
-            entity.text = entity.text.replace("##", "").trim().to_string();
+        let mut path = vec![0usize; steps];
+        path[last] = best_j;
+        for t in (1..steps).rev() {
+            path[t - 1] = backptr[t * n + path[t]];
         }
 
-        // Filter out empty entities
-        entities.retain(|e| !e.text.is_empty());
+        positions
+            .iter()
+            .enumerate()
+            .map(|(t, &(_, start, end))| {
+                let id = ids[path[t]];
+                let label = self
+                    .id2label
+                    .get(&id)
+                    .cloned()
+                    .unwrap_or_else(|| "O".to_string());
+                let score = emissions[t].get(id as usize).copied().unwrap_or(f32::NEG_INFINITY).exp();
+                (label, score, start, end)
+            })
+            .collect()
+    }
+
+    /// Beam search over the non-special tokens' label logits, returning up
+    /// to `top_k` candidate `(tokens, total_log_prob)` sequences, most
+    /// probable first. At every step each surviving partial sequence is
+    /// expanded by every label whose transition from its last label is
+    /// legal (per [`is_legal_transition`]), then pruned to the best
+    /// `beam_width` candidates by running total log-probability before
+    /// advancing. `O` is always a legal continuation from any state, so at
+    /// least one sequence always survives to the end.
+    fn beam_search_tokens(
+        &self,
+        encoding: &tokenizers::Encoding,
+        logits: &[f32],
+        beam_width: usize,
+        top_k: usize,
+    ) -> Vec<(Vec<(String, f32, usize, usize)>, f32)> {
+        let num_labels = self.id2label.len();
+        let positions: Vec<(usize, usize, usize)> = Self::token_positions(encoding)
+            .into_iter()
+            .take_while(|(i, ..)| (i + 1) * num_labels <= logits.len())
+            .collect();
+        if positions.is_empty() {
+            return Vec::new();
+        }
 
-        Ok(entities)
+        let (ids, _) = build_transition_matrix(&self.id2label);
+        let emissions: Vec<Vec<f32>> = positions
+            .iter()
+            .map(|(i, ..)| {
+                let start_idx = i * num_labels;
+                Self::log_softmax(&logits[start_idx..start_idx + num_labels])
+            })
+            .collect();
+
+        // Each beam holds the label ids chosen so far and the running
+        // total log-probability; starts with one empty sequence.
+        let mut beams: Vec<(Vec<i64>, f32)> = vec![(Vec::new(), 0.0)];
+
+        for emission in &emissions {
+            let mut candidates: Vec<(Vec<i64>, f32)> = Vec::new();
+            for (label_ids, log_prob) in &beams {
+                let prev_label = label_ids
+                    .last()
+                    .and_then(|id| self.id2label.get(id))
+                    .map(String::as_str)
+                    .unwrap_or("O");
+
+                for &id in &ids {
+                    let next_label = self.id2label.get(&id).map(String::as_str).unwrap_or("O");
+                    if !is_legal_transition(prev_label, next_label) {
+                        continue;
+                    }
+                    let Some(&step_log_prob) = emission.get(id as usize) else {
+                        continue;
+                    };
+
+                    let mut label_ids = label_ids.clone();
+                    label_ids.push(id);
+                    candidates.push((label_ids, log_prob + step_log_prob));
+                }
+            }
+
+            candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+            candidates.truncate(beam_width);
+            beams = candidates;
+        }
+
+        beams.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        beams.truncate(top_k);
+
+        beams
+            .into_iter()
+            .map(|(label_ids, log_prob)| {
+                let tokens = positions
+                    .iter()
+                    .zip(label_ids.iter())
+                    .zip(emissions.iter())
+                    .map(|((&(_, start, end), &id), emission)| {
+                        let label = self
+                            .id2label
+                            .get(&id)
+                            .cloned()
+                            .unwrap_or_else(|| "O".to_string());
+                        let score = emission.get(id as usize).copied().unwrap_or(f32::NEG_INFINITY).exp();
+                        (label, score, start, end)
+                    })
+                    .collect();
+                (tokens, log_prob)
+            })
+            .collect()
+    }
+
+    /// Log-softmax over a single token's label logits.
+    fn log_softmax(logits: &[f32]) -> Vec<f32> {
+        let max_logit = logits.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+        let log_sum_exp = logits.iter().map(|&x| (x - max_logit).exp()).sum::<f32>().ln();
+        logits.iter().map(|&x| (x - max_logit) - log_sum_exp).collect()
     }
 
     fn softmax_argmax(logits: &[f32]) -> (i64, f32) {
@@ -884,8 +2016,13 @@ impl MlNerModel {
     fn load_id2label(config_path: &Path) -> MlNerResult<HashMap<i64, String>> {
         let content = std::fs::read_to_string(config_path)
             .map_err(|e| Error::ParseError(format!("Failed to read config: {}", e)))?;
+        Self::load_id2label_from_bytes(content.as_bytes())
+    }
 
-        let config: serde_json::Value = serde_json::from_str(&content)
+    /// Like [`load_id2label`](Self::load_id2label), but parses the config
+    /// from an in-memory byte slice instead of reading it from disk.
+    fn load_id2label_from_bytes(bytes: &[u8]) -> MlNerResult<HashMap<i64, String>> {
+        let config: serde_json::Value = serde_json::from_slice(bytes)
             .map_err(|e| Error::ParseError(format!("Failed to parse config: {}", e)))?;
 
         let mut id2label = HashMap::new();
@@ -905,7 +2042,7 @@ impl MlNerModel {
         Ok(id2label)
     }
 
-    fn default_id2label() -> HashMap<i64, String> {
+    pub(crate) fn default_id2label() -> HashMap<i64, String> {
         // CoNLL-2003 default labels
         let mut map = HashMap::new();
         map.insert(0, "O".to_string());
@@ -955,4 +2092,207 @@ mod tests {
         assert_eq!(idx, 2); // index of 3.0
         assert!(prob > 0.5); // should be highest probability
     }
+
+    #[test]
+    fn test_device_cpu_registers_only_cpu_provider() {
+        assert_eq!(Device::Cpu.execution_providers().len(), 1);
+    }
+
+    #[test]
+    fn test_device_gpu_appends_cpu_fallback_last() {
+        let providers = Device::Cuda(0).execution_providers();
+        assert_eq!(providers.len(), 2);
+    }
+
+    #[test]
+    fn test_device_default_is_cpu() {
+        assert_eq!(Device::default(), Device::Cpu);
+    }
+
+    #[test]
+    fn test_finalize_span_first_uses_first_subtoken_score() {
+        let entity = MlNerModel::finalize_span(
+            "New York",
+            "LOC",
+            &[0.4, 0.9],
+            0,
+            8,
+            AggregationStrategy::First,
+        );
+        assert_eq!(entity.score, 0.4);
+        assert_eq!(entity.text, "New York");
+    }
+
+    #[test]
+    fn test_finalize_span_max_uses_highest_subtoken_score() {
+        let entity =
+            MlNerModel::finalize_span("New York", "LOC", &[0.4, 0.9], 0, 8, AggregationStrategy::Max);
+        assert_eq!(entity.score, 0.9);
+    }
+
+    #[test]
+    fn test_finalize_span_min_uses_lowest_subtoken_score() {
+        let entity =
+            MlNerModel::finalize_span("New York", "LOC", &[0.4, 0.9], 0, 8, AggregationStrategy::Min);
+        assert_eq!(entity.score, 0.4);
+    }
+
+    #[test]
+    fn test_finalize_span_average_uses_mean_subtoken_score() {
+        let entity = MlNerModel::finalize_span(
+            "New York",
+            "LOC",
+            &[0.4, 0.8],
+            0,
+            8,
+            AggregationStrategy::Average,
+        );
+        assert_eq!(entity.score, 0.6);
+    }
+
+    #[test]
+    fn test_aggregation_strategy_default_is_simple() {
+        assert_eq!(AggregationStrategy::default(), AggregationStrategy::Simple);
+    }
+
+    #[test]
+    fn test_detect_tagging_scheme_bio() {
+        assert_eq!(
+            detect_tagging_scheme(&MlNerModel::default_id2label()),
+            TaggingScheme::Bio
+        );
+    }
+
+    #[test]
+    fn test_detect_tagging_scheme_iobes() {
+        let mut id2label = HashMap::new();
+        id2label.insert(0, "O".to_string());
+        id2label.insert(1, "S-PER".to_string());
+        id2label.insert(2, "B-LOC".to_string());
+        id2label.insert(3, "E-LOC".to_string());
+        assert_eq!(detect_tagging_scheme(&id2label), TaggingScheme::Iobes);
+    }
+
+    #[test]
+    fn test_decode_mode_default_is_greedy() {
+        assert_eq!(DecodeMode::default(), DecodeMode::Greedy);
+    }
+
+    #[test]
+    fn test_is_legal_transition_forbids_o_to_inside() {
+        assert!(!is_legal_transition("O", "I-LOC"));
+    }
+
+    #[test]
+    fn test_is_legal_transition_forbids_type_mismatch() {
+        assert!(!is_legal_transition("B-ORG", "I-LOC"));
+    }
+
+    #[test]
+    fn test_is_legal_transition_allows_matching_continuation() {
+        assert!(is_legal_transition("B-LOC", "I-LOC"));
+    }
+
+    #[test]
+    fn test_is_legal_transition_always_allows_begin() {
+        assert!(is_legal_transition("I-PER", "B-LOC"));
+    }
+
+    #[test]
+    fn test_build_transition_matrix_marks_illegal_as_neg_infinity() {
+        let (ids, transitions) = build_transition_matrix(&MlNerModel::default_id2label());
+        let n = ids.len();
+        let o_idx = ids.iter().position(|&id| id == 0).unwrap();
+        let i_per_idx = ids.iter().position(|&id| id == 2).unwrap();
+        assert_eq!(transitions[o_idx * n + i_per_idx], f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_dedupe_and_stitch_entities_drops_duplicate_spans() {
+        let entities = vec![
+            MlEntity { text: "Paris".into(), label: "LOC".into(), score: 0.6, start: 0, end: 5 },
+            MlEntity { text: "Paris".into(), label: "LOC".into(), score: 0.9, start: 0, end: 5 },
+        ];
+        let result = MlNerModel::dedupe_and_stitch_entities("Paris", entities);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].score, 0.9);
+    }
+
+    #[test]
+    fn test_dedupe_and_stitch_entities_stitches_window_boundary_split() {
+        let entities = vec![
+            MlEntity { text: "New".into(), label: "LOC".into(), score: 0.7, start: 0, end: 3 },
+            MlEntity { text: " York".into(), label: "LOC".into(), score: 0.8, start: 3, end: 8 },
+        ];
+        let result = MlNerModel::dedupe_and_stitch_entities("New York", entities);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].text, "New York");
+        assert_eq!(result[0].score, 0.8);
+    }
+
+    #[test]
+    fn test_window_defaults() {
+        // Pure constant checks — no live session/tokenizer needed.
+        assert_eq!(DEFAULT_WINDOW_SIZE, 512);
+        assert_eq!(DEFAULT_WINDOW_STRIDE, 128);
+    }
+
+    #[test]
+    fn test_extract_batch_with_max_len_caps_below_longest_encoding() {
+        // Pure arithmetic check of the cap logic used by
+        // `extract_batch_with_max_len`, without a live session/tokenizer.
+        let longest = 50usize;
+        let cap = Some(16usize);
+        assert_eq!(cap.map(|c| longest.min(c)).unwrap_or(longest), 16);
+        assert_eq!(None::<usize>.map(|c| longest.min(c)).unwrap_or(longest), 50);
+    }
+
+    #[test]
+    fn test_merge_tokens_strategy_none_emits_one_entity_per_token() {
+        let tokens = vec![
+            ("B-PER".to_string(), 0.9, 0, 3),
+            ("I-PER".to_string(), 0.8, 4, 8),
+        ];
+        let entities = MlNerModel::merge_tokens("Dr. Smith", tokens, AggregationStrategy::None);
+        assert_eq!(entities.len(), 2);
+    }
+
+    #[test]
+    fn test_merge_tokens_glues_wordpiece_continuation_across_labels() {
+        let tokens = vec![("B-LOC".to_string(), 0.9, 0, 3), ("O".to_string(), 0.2, 3, 8)];
+        let entities = MlNerModel::merge_tokens("New York", tokens, AggregationStrategy::First);
+        assert_eq!(entities.len(), 1);
+        assert_eq!(entities[0].text, "New York");
+    }
+
+    #[test]
+    fn test_parse_tag_recognizes_all_five_prefixes() {
+        assert_eq!(parse_tag("O"), None);
+        assert_eq!(parse_tag("B-PER"), Some((TagPrefix::Begin, "PER")));
+        assert_eq!(parse_tag("I-PER"), Some((TagPrefix::Inside, "PER")));
+        assert_eq!(parse_tag("E-PER"), Some((TagPrefix::End, "PER")));
+        assert_eq!(parse_tag("S-PER"), Some((TagPrefix::Single, "PER")));
+    }
+
+    #[test]
+    #[cfg(feature = "ml-ner-download")]
+    fn test_model_registry_has_entries_for_onnx_native_model() {
+        let files = model_registry(&NerModel::DistilBertQuantized).unwrap();
+        assert!(files.iter().any(|f| f.cache_name == "model.onnx"));
+        assert!(files.iter().any(|f| f.cache_name == "tokenizer.json"));
+    }
+
+    #[test]
+    #[cfg(feature = "ml-ner-download")]
+    fn test_model_registry_has_no_entry_for_export_required_model() {
+        assert!(model_registry(&NerModel::BertLarge).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "ml-ner-download")]
+    fn test_expected_sha256_looks_up_by_cache_name() {
+        let files = model_registry(&NerModel::DistilBertQuantized);
+        let sha = expected_sha256(files, "tokenizer.json").unwrap();
+        assert_eq!(sha.len(), 64);
+    }
 }
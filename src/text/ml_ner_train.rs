@@ -0,0 +1,254 @@
+//! On-device fine-tuning for [`MlNerModel`](super::MlNerModel) via ONNX
+//! Runtime's training API. Requires the `ml-ner-train` feature.
+//!
+//! Fine-tuning needs an offline-exported training artifact set (a training
+//! model, an eval model, an optimizer model, and an initial checkpoint)
+//! produced by ONNX Runtime's on-device training export tooling — see
+//! <https://onnxruntime.ai/docs/get-started/training-on-device.html>. This
+//! module does not build that artifact set; it only drives `train_step`/
+//! `optimizer_step` over it and exports the result back to a plain
+//! `model.onnx` that [`MlNerModel::from_directory`](super::MlNerModel::from_directory)
+//! can load directly.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use ort::training::Trainer;
+use ort::value::Tensor;
+use tokenizers::Tokenizer;
+
+use super::entity::EntityType;
+use super::ml_ner::{MlNerModel, MlNerResult};
+use crate::error::Error;
+
+/// A labeled training example: the source text and the entity spans
+/// (byte-offset start, end, type) within it.
+pub type LabeledExample = (String, Vec<(usize, usize, EntityType)>);
+
+/// On-device fine-tuning session for a NER model, wrapping ONNX Runtime's
+/// training API.
+///
+/// Converts labeled [`LabeledExample`]s into per-subtoken BIO target ids
+/// using the tokenizer's offset mapping, then runs `train_step`/
+/// `optimizer_step` over mini-batches.
+pub struct MlNerTrainer {
+    trainer: Trainer,
+    tokenizer: Tokenizer,
+    label2id: HashMap<String, i64>,
+}
+
+impl MlNerTrainer {
+    /// Loads a training session from a directory containing
+    /// `training_model.onnx`, `eval_model.onnx`, `optimizer_model.onnx`,
+    /// `checkpoint`, and `tokenizer.json`.
+    pub fn from_directory<P: AsRef<Path>>(dir: P) -> MlNerResult<Self> {
+        let dir = dir.as_ref();
+
+        let checkpoint = dir.join("checkpoint");
+        let training_model = dir.join("training_model.onnx");
+        let eval_model = dir.join("eval_model.onnx");
+        let optimizer_model = dir.join("optimizer_model.onnx");
+
+        for (label, path) in [
+            ("Checkpoint", &checkpoint),
+            ("Training model", &training_model),
+            ("Eval model", &eval_model),
+            ("Optimizer model", &optimizer_model),
+        ] {
+            if !path.exists() {
+                return Err(Error::ParseError(format!(
+                    "{} not found: {}",
+                    label,
+                    path.display()
+                )));
+            }
+        }
+
+        let trainer = Trainer::new(&checkpoint, &training_model, &eval_model, &optimizer_model)
+            .map_err(|e| Error::ParseError(format!("Failed to create training session: {}", e)))?;
+
+        let tokenizer_path = dir.join("tokenizer.json");
+        let tokenizer = Tokenizer::from_file(&tokenizer_path)
+            .map_err(|e| Error::ParseError(format!("Failed to load tokenizer: {}", e)))?;
+
+        Ok(Self {
+            trainer,
+            tokenizer,
+            label2id: default_label2id(),
+        })
+    }
+
+    /// Fine-tunes the loaded model over `examples` for `epochs` passes, in
+    /// mini-batches of `batch_size` using `learning_rate`.
+    pub fn train(
+        &mut self,
+        examples: &[LabeledExample],
+        epochs: usize,
+        batch_size: usize,
+        learning_rate: f32,
+    ) -> MlNerResult<()> {
+        self.trainer
+            .set_lr(learning_rate)
+            .map_err(|e| Error::ParseError(format!("Failed to set learning rate: {}", e)))?;
+
+        let batch_size = batch_size.max(1);
+        for _epoch in 0..epochs {
+            for batch in examples.chunks(batch_size) {
+                let (input_ids, attention_mask, labels, max_len) = self.encode_batch(batch)?;
+                let rows = batch.len() as i64;
+
+                let input_ids_tensor = Tensor::from_array((vec![rows, max_len as i64], input_ids))
+                    .map_err(|e| Error::ParseError(format!("Failed to create input tensor: {}", e)))?;
+                let attention_mask_tensor =
+                    Tensor::from_array((vec![rows, max_len as i64], attention_mask)).map_err(|e| {
+                        Error::ParseError(format!("Failed to create attention mask tensor: {}", e))
+                    })?;
+                let labels_tensor = Tensor::from_array((vec![rows, max_len as i64], labels))
+                    .map_err(|e| Error::ParseError(format!("Failed to create labels tensor: {}", e)))?;
+
+                self.trainer
+                    .train_step(ort::inputs! {
+                        "input_ids" => input_ids_tensor,
+                        "attention_mask" => attention_mask_tensor,
+                        "labels" => labels_tensor,
+                    })
+                    .map_err(|e| Error::ParseError(format!("Training step failed: {}", e)))?;
+
+                self.trainer
+                    .optimizer_step()
+                    .map_err(|e| Error::ParseError(format!("Optimizer step failed: {}", e)))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Tokenizes `batch`, converting each example's spans to per-subtoken
+    /// BIO target ids via the tokenizer's offset mapping, right-padding
+    /// every row to the batch's longest sequence (padded and special-token
+    /// positions are labeled `-100`, the PyTorch convention for "ignore in
+    /// the loss").
+    fn encode_batch(
+        &self,
+        batch: &[LabeledExample],
+    ) -> MlNerResult<(Vec<i64>, Vec<i64>, Vec<i64>, usize)> {
+        const IGNORE_INDEX: i64 = -100;
+
+        let encodings = batch
+            .iter()
+            .map(|(text, _)| {
+                self.tokenizer
+                    .encode(text.as_str(), true)
+                    .map_err(|e| Error::ParseError(format!("Tokenization failed: {}", e)))
+            })
+            .collect::<MlNerResult<Vec<_>>>()?;
+
+        let max_len = encodings.iter().map(|e| e.get_ids().len()).max().unwrap_or(0);
+        let pad_id = self.tokenizer.token_to_id("[PAD]").unwrap_or(0) as i64;
+
+        let mut input_ids = Vec::with_capacity(batch.len() * max_len);
+        let mut attention_mask = Vec::with_capacity(batch.len() * max_len);
+        let mut labels = Vec::with_capacity(batch.len() * max_len);
+
+        for ((_, spans), encoding) in batch.iter().zip(encodings.iter()) {
+            let ids = encoding.get_ids();
+            let mask = encoding.get_attention_mask();
+            let offsets = encoding.get_offsets();
+            let special_tokens_mask = encoding.get_special_tokens_mask();
+
+            for i in 0..max_len {
+                if i < ids.len() {
+                    input_ids.push(ids[i] as i64);
+                    attention_mask.push(mask[i] as i64);
+                    labels.push(if special_tokens_mask[i] == 1 {
+                        IGNORE_INDEX
+                    } else {
+                        self.bio_target_id(offsets[i], spans)
+                    });
+                } else {
+                    input_ids.push(pad_id);
+                    attention_mask.push(0);
+                    labels.push(IGNORE_INDEX);
+                }
+            }
+        }
+
+        Ok((input_ids, attention_mask, labels, max_len))
+    }
+
+    /// Resolves the BIO target id for a single subtoken's offset span,
+    /// `O` when it falls outside every labeled span in `spans`.
+    fn bio_target_id(&self, offset: (usize, usize), spans: &[(usize, usize, EntityType)]) -> i64 {
+        let (start, end) = offset;
+        let o_id = *self.label2id.get("O").unwrap_or(&0);
+        if start == end {
+            return o_id;
+        }
+
+        for (span_start, span_end, entity_type) in spans {
+            if start >= *span_start && end <= *span_end {
+                let is_beginning = start == *span_start;
+                let label = format!(
+                    "{}{}",
+                    if is_beginning { "B-" } else { "I-" },
+                    bio_type_code(entity_type)
+                );
+                if let Some(id) = self.label2id.get(&label) {
+                    return *id;
+                }
+                return o_id;
+            }
+        }
+
+        o_id
+    }
+
+    /// Writes the fine-tuned weights out as a plain `model.onnx`, loadable
+    /// by [`MlNerModel::from_directory`](super::MlNerModel::from_directory).
+    pub fn export_inference_model(&self, path: impl AsRef<Path>) -> MlNerResult<()> {
+        self.trainer
+            .export(path.as_ref(), &["logits"])
+            .map_err(|e| Error::ParseError(format!("Failed to export inference model: {}", e)))
+    }
+}
+
+/// CoNLL-style BIO type code for an [`EntityType`], matching
+/// [`MlNerModel::default_id2label`]'s label scheme. Types without a
+/// dedicated CoNLL-2003 category collapse to `MISC`.
+fn bio_type_code(entity_type: &EntityType) -> &'static str {
+    match entity_type {
+        EntityType::Person => "PER",
+        EntityType::Organization => "ORG",
+        EntityType::Location => "LOC",
+        EntityType::DateTime | EntityType::Numeric | EntityType::Event | EntityType::Other => "MISC",
+    }
+}
+
+/// Inverse of [`MlNerModel::default_id2label`], used as the default target
+/// label scheme for freshly loaded trainers.
+fn default_label2id() -> HashMap<String, i64> {
+    MlNerModel::default_id2label()
+        .into_iter()
+        .map(|(id, label)| (label, id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bio_type_code_maps_known_types() {
+        assert_eq!(bio_type_code(&EntityType::Person), "PER");
+        assert_eq!(bio_type_code(&EntityType::Organization), "ORG");
+        assert_eq!(bio_type_code(&EntityType::Location), "LOC");
+        assert_eq!(bio_type_code(&EntityType::Other), "MISC");
+    }
+
+    #[test]
+    fn test_default_label2id_is_inverse_of_default_id2label() {
+        let label2id = default_label2id();
+        assert_eq!(label2id.get("B-LOC"), Some(&5));
+        assert_eq!(label2id.get("O"), Some(&0));
+    }
+}
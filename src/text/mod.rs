@@ -11,6 +11,9 @@
 //! - [`KeywordExtractor`] - Extract keywords and key phrases from text
 //! - [`Entity`] - A detected named entity with type and span info
 //! - [`Keyword`] - An extracted keyword with relevance score
+//! - [`Tokenizer`] - Pluggable word segmentation, with [`WhitespaceTokenizer`]
+//!   (default) and [`DictionaryTokenizer`] (for scripts without whitespace
+//!   word boundaries) implementations
 //!
 //! ## ML-based NER (Optional)
 //!
@@ -21,6 +24,10 @@
 //!
 //! Enable with: `spatial-narrative = { version = "0.1", features = ["ml-ner"] }`
 //!
+//! With the additional `ml-ner-train` feature, `MlNerTrainer` fine-tunes a
+//! model on-device against labeled examples and exports the result back to
+//! a plain `model.onnx` loadable by `MlNerModel::from_directory`.
+//!
 //! ## Auto-Download Models (Optional)
 //!
 //! With the `ml-ner-download` feature, models can be automatically downloaded:
@@ -78,18 +85,30 @@
 mod analyzer;
 mod entity;
 mod keywords;
+mod tokenizer;
 
 #[cfg(feature = "ml-ner")]
 mod ml_ner;
 
-pub use analyzer::TextAnalyzer;
-pub use entity::{Entity, EntityType};
-pub use keywords::{Keyword, KeywordExtractor};
+#[cfg(feature = "ml-ner-train")]
+mod ml_ner_train;
+
+pub use analyzer::{stem, Rule, ScriptRange, TextAnalyzer};
+pub use entity::{date_duration, CountryResolution, DateComponents, DateDuration, Entity, EntityType};
+pub use keywords::{DocumentFrequencies, FittedExtractor, Keyword, KeywordExtractor};
+pub use tokenizer::{DictionaryTokenizer, Tokenizer, WhitespaceTokenizer};
 
 #[cfg(feature = "ml-ner")]
-pub use ml_ner::{init_ort, MlEntity, MlNerModel, MlNerResult, NerModel};
+pub use ml_ner::{
+    init_ort, AggregationStrategy, DecodeMode, Device, MlEntity, MlNerModel, MlNerResult,
+    ModelResource, NerModel, TaggingScheme,
+};
 
 #[cfg(feature = "ml-ner-download")]
 pub use ml_ner::{
     cache_size_bytes, clear_model_cache, is_model_cached, model_cache_dir, model_cache_path,
+    verify_cached_model,
 };
+
+#[cfg(feature = "ml-ner-train")]
+pub use ml_ner_train::{LabeledExample, MlNerTrainer};
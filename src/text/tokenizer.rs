@@ -0,0 +1,118 @@
+//! Pluggable word-segmentation strategies for [`super::TextAnalyzer`].
+
+use std::collections::HashSet;
+
+/// A word-segmentation strategy. [`TextAnalyzer::tokenize`](super::TextAnalyzer::tokenize)
+/// delegates to whichever tokenizer the analyzer is configured with, so
+/// `tokenize_filtered` and `tokenize_stemmed` (both built on `tokenize`)
+/// segment consistently for the configured language.
+pub trait Tokenizer {
+    /// Split `text` into word tokens.
+    fn tokenize(&self, text: &str) -> Vec<String>;
+}
+
+/// Default tokenizer: splits on anything that isn't alphanumeric or an
+/// apostrophe. Suitable for whitespace-delimited scripts (Latin, Cyrillic,
+/// Greek, etc.) but degenerates to one giant token on scripts that don't
+/// use whitespace to separate words, such as Chinese or Japanese — use
+/// [`DictionaryTokenizer`] for those.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WhitespaceTokenizer;
+
+impl Tokenizer for WhitespaceTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        text.split(|c: char| !c.is_alphanumeric() && c != '\'')
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+            .collect()
+    }
+}
+
+/// Longest-match (maximum-matching) segmentation against a user-supplied
+/// word dictionary, for scripts without whitespace word boundaries. At
+/// each position, the longest dictionary entry matching the remaining text
+/// is emitted as a token; if no entry matches, a single character is
+/// emitted instead, so segmentation always makes progress.
+#[derive(Debug, Clone, Default)]
+pub struct DictionaryTokenizer {
+    words: HashSet<String>,
+    max_word_chars: usize,
+}
+
+impl DictionaryTokenizer {
+    /// Build a dictionary tokenizer from a word list.
+    pub fn new(words: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let words: HashSet<String> = words.into_iter().map(Into::into).collect();
+        let max_word_chars = words.iter().map(|w| w.chars().count()).max().unwrap_or(0);
+        Self {
+            words,
+            max_word_chars,
+        }
+    }
+}
+
+impl Tokenizer for DictionaryTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut tokens = Vec::new();
+        let mut pos = 0;
+
+        while pos < chars.len() {
+            if chars[pos].is_whitespace() {
+                pos += 1;
+                continue;
+            }
+
+            let max_len = self.max_word_chars.min(chars.len() - pos);
+            let longest_match = (1..=max_len).rev().find_map(|len| {
+                let candidate: String = chars[pos..pos + len].iter().collect();
+                self.words.contains(&candidate).then_some(candidate)
+            });
+
+            match longest_match {
+                Some(word) => {
+                    pos += word.chars().count();
+                    tokens.push(word);
+                }
+                None => {
+                    tokens.push(chars[pos].to_string());
+                    pos += 1;
+                }
+            }
+        }
+
+        tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_whitespace_tokenizer_splits_on_punctuation() {
+        let tokens = WhitespaceTokenizer.tokenize("Hello, world!");
+        assert_eq!(tokens, vec!["Hello".to_string(), "world".to_string()]);
+    }
+
+    #[test]
+    fn test_dictionary_tokenizer_prefers_longest_match() {
+        let tokenizer = DictionaryTokenizer::new(["东京", "东京都", "都"]);
+        let tokens = tokenizer.tokenize("东京都");
+        assert_eq!(tokens, vec!["东京都".to_string()]);
+    }
+
+    #[test]
+    fn test_dictionary_tokenizer_falls_back_to_single_char() {
+        let tokenizer = DictionaryTokenizer::new(["东京"]);
+        let tokens = tokenizer.tokenize("东京驿");
+        assert_eq!(tokens, vec!["东京".to_string(), "驿".to_string()]);
+    }
+
+    #[test]
+    fn test_dictionary_tokenizer_skips_whitespace() {
+        let tokenizer = DictionaryTokenizer::new(["东京", "大阪"]);
+        let tokens = tokenizer.tokenize("东京 大阪");
+        assert_eq!(tokens, vec!["东京".to_string(), "大阪".to_string()]);
+    }
+}